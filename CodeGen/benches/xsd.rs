@@ -0,0 +1,60 @@
+//! Measures the cost of loading and resolving `musicxml.xsd`. This uses a plain `main` instead of
+//! the unstable `test` crate, run it with `cargo bench --bench xsd`.
+
+use libmxgen::generate::paths::Paths;
+use libmxgen::model::creator::Creator;
+use libmxgen::xsd::id::Id;
+use libmxgen::xsd::{Entry, Xsd};
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 20;
+
+fn main() {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_0.parent().unwrap().to_owned();
+    let xsd = Xsd::load_with_imports(&paths.xsd_3_0, &import_dir).unwrap();
+    let ids: Vec<Id> = xsd.entries().map(|e| e.id().clone()).collect();
+    let names: Vec<_> = ids
+        .iter()
+        .filter_map(|id| Some((id.root_node_type()?, id.local_name().to_owned())))
+        .collect();
+    println!("{} entries", ids.len());
+
+    bench("load", || {
+        Xsd::load(&paths.xsd_3_0).unwrap();
+    });
+    bench("load_with_imports", || {
+        Xsd::load_with_imports(&paths.xsd_3_0, &import_dir).unwrap();
+    });
+    bench("find every entry by id", || {
+        for id in &ids {
+            xsd.find(id).unwrap();
+        }
+    });
+    bench("get every entry by name", || {
+        for (root_node_type, name) in &names {
+            xsd.get(*root_node_type, name).unwrap();
+        }
+    });
+    bench("linear scan for every entry (for comparison)", || {
+        let entries: Vec<&Entry> = xsd.entries().collect();
+        for id in &ids {
+            entries.iter().find(|e| e.id() == id).unwrap();
+        }
+    });
+    bench("create and resolve the model", || {
+        Creator::default().create(&xsd).unwrap();
+    });
+}
+
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    // warm up
+    f();
+    let mut total = Duration::default();
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        f();
+        total += start.elapsed();
+    }
+    println!("{:<48} {:>12.3?} / iteration", name, total / ITERATIONS);
+}
@@ -1,6 +1,9 @@
 use libmxgen::generate::paths::Paths;
-use libmxgen::xsd::id::{Id, RootNodeType};
+use libmxgen::xsd::flatten::{copy_imports, Flattener};
+use libmxgen::xsd::write;
 use libmxgen::xsd::Xsd;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
 
 const EXIT_SUCCESS: i32 = 0;
 const EXIT_FAIL: i32 = 1;
@@ -9,7 +12,7 @@ macro_rules! must {
     ($result:expr) => {
         match $result {
             Err(e) => {
-                eprintln!("{}:{} {:?}", file!(), line!(), e);
+                eprintln!("{}:{} {}", file!(), line!(), e);
                 std::process::exit(EXIT_FAIL);
             }
             Ok(o) => o,
@@ -17,12 +20,39 @@ macro_rules! must {
     };
 }
 
+/// Writes a self-contained version of a MusicXML schema in which all group and attribute group
+/// references are inlined, for tools that cannot handle `xs:group` or `xs:attributeGroup`.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "flatten")]
+struct Args {
+    /// The schema to flatten. Defaults to MusicXML 3.0.
+    #[structopt(long, parse(from_os_str))]
+    xsd: Option<PathBuf>,
+    /// Where to write the flattened schema. The schemas that it imports are copied to the same
+    /// directory. Defaults to stdout.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+    /// Also replace references to named simple types with anonymous copies of the types.
+    #[structopt(long)]
+    inline_simple_types: bool,
+}
+
 fn main() {
+    let args = Args::from_args();
     let paths = Paths::default();
-    let mut xsd = must!(Xsd::load(&paths.xsd_3_0));
-    let id = Id::new(RootNodeType::Element, "score-partwise".to_owned());
-    let found = must!(xsd.find(&id)).to_owned();
-    let entity = must!(xsd.remove(found.id()));
-    println!("{:?}", &entity);
+    let path = args.xsd.as_ref().unwrap_or(&paths.xsd_3_0);
+    let xsd = must!(Xsd::load(path));
+    let flattener = Flattener {
+        inline_simple_types: args.inline_simple_types,
+    };
+    let flattened = write::document(&must!(flattener.flatten(&xsd)));
+    match &args.output {
+        Some(output) => {
+            must!(std::fs::write(output, flattened));
+            let dir = output.parent().unwrap_or_else(|| Path::new("."));
+            must!(copy_imports(path, dir));
+        }
+        None => print!("{}", flattened),
+    }
     std::process::exit(EXIT_SUCCESS);
 }
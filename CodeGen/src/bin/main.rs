@@ -1,16 +1,218 @@
-use libmxgen::generate::{run, GenArgs};
+use libmxgen::generate::paths::{Paths, SchemaVersion};
+use libmxgen::generate::{check, run, GenArgs};
+use libmxgen::model::creator::Creator;
+use libmxgen::model::Shape;
+use libmxgen::xsd::diff::SchemaDiff;
+use libmxgen::xsd::id::{Id, RootNodeType};
+use libmxgen::xsd::Xsd;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
+use structopt::StructOpt;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Generates code from the MusicXML schema, and inspects the schema.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "mxgen")]
+struct Args {
+    /// A JSON file of paths to use instead of the ones in this repository, see
+    /// `Paths::from_config`.
+    #[structopt(long, global = true, parse(from_os_str))]
+    config: Option<PathBuf>,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Generates code with one of the backends.
+    Generate(GenerateArgs),
+    /// Fails if the generated files are missing or out of date.
+    Check(GenerateArgs),
+    /// Prints the model of a top-level schema entry, e.g. `element:score-partwise`.
+    Show {
+        /// The id of the entry, e.g. `complexType:note`.
+        id: Id,
+        /// Print the schema entry instead of its model.
+        #[structopt(long)]
+        entry: bool,
+        #[structopt(flatten)]
+        schema: SchemaArgs,
+    },
+    /// Reports the differences between two MusicXML schemas, by default 3.0 and 3.1.
+    Diff {
+        /// The old schema. Defaults to MusicXML 3.0.
+        #[structopt(parse(from_os_str))]
+        old: Option<PathBuf>,
+        /// The new schema. Defaults to MusicXML 3.1.
+        #[structopt(parse(from_os_str))]
+        new: Option<PathBuf>,
+        /// Print the differences as JSON instead of text.
+        #[structopt(long)]
+        json: bool,
+    },
+    /// Lists the ids of the top-level schema entries.
+    List {
+        /// Only list entries of this type, e.g. `complexType`.
+        #[structopt(parse(try_from_str = RootNodeType::parse))]
+        type_: Option<RootNodeType>,
+        #[structopt(flatten)]
+        schema: SchemaArgs,
+    },
+}
+
+#[derive(Debug, StructOpt)]
+struct GenerateArgs {
+    /// The backend to generate with, e.g. `cpp` or `rust`.
+    #[structopt(long, default_value = "cpp")]
+    backend: String,
+    /// The MusicXML versions to generate, e.g. `3.1`. Defaults to 3.0.
+    #[structopt(long = "schema-version")]
+    versions: Vec<SchemaVersion>,
+    /// The schema to generate from. Requires a single schema version.
+    #[structopt(long, parse(from_os_str))]
+    xsd: Option<PathBuf>,
+    /// The directory to write to instead of the backend's default. Requires a single schema
+    /// version.
+    #[structopt(long, parse(from_os_str))]
+    out_dir: Option<PathBuf>,
+    /// A directory of `*.template` files that replace the built-in templates of the same name.
+    #[structopt(long, parse(from_os_str))]
+    templates: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct SchemaArgs {
+    /// The MusicXML version of the schema.
+    #[structopt(long, default_value = "3.0")]
+    schema_version: SchemaVersion,
+    /// The schema to read instead of the one for the schema version.
+    #[structopt(long, parse(from_os_str))]
+    xsd: Option<PathBuf>,
+}
+
+impl SchemaArgs {
+    fn load(&self, paths: &Paths) -> Result<Xsd> {
+        match &self.xsd {
+            Some(xsd) => load(xsd),
+            None => load(paths.xsd(self.schema_version)),
+        }
+    }
+}
+
+impl GenerateArgs {
+    fn into_gen_args(self, paths: Paths) -> Result<GenArgs> {
+        let versions = if self.versions.is_empty() {
+            vec![SchemaVersion::default()]
+        } else {
+            self.versions
+        };
+        let mut paths = paths;
+        if let Some(xsd) = self.xsd {
+            if versions.len() != 1 {
+                return Err("a schema can only be given for one schema version".into());
+            }
+            paths = paths.with_xsd(versions[0], xsd);
+        }
+        if self.templates.is_some() {
+            paths.templates = self.templates;
+        }
+        Ok(GenArgs {
+            paths,
+            versions,
+            backend: self.backend,
+            out_dir: self.out_dir,
+        })
+    }
+}
+
+fn load<P: AsRef<Path>>(path: P) -> Result<Xsd> {
+    let path = path.as_ref();
+    let import_dir = path.parent().unwrap_or(path);
+    Ok(Xsd::load_with_imports(path, import_dir)?)
+}
+
+fn shape(root_node_type: RootNodeType) -> Option<Shape> {
+    match root_node_type {
+        RootNodeType::AttributeGroup => Some(Shape::AttributeGroup),
+        RootNodeType::ComplexType => Some(Shape::Complex),
+        RootNodeType::Element => Some(Shape::Element),
+        RootNodeType::Group => Some(Shape::Group),
+        RootNodeType::SimpleType => Some(Shape::Simple),
+        RootNodeType::Annotation | RootNodeType::Attribute | RootNodeType::Import => None,
+    }
+}
+
+fn show(id: &Id, entry: bool, xsd: &Xsd) -> Result<()> {
+    let found = xsd.find(id)?;
+    let shape = match id.root_node_type().and_then(shape) {
+        Some(shape) if !entry => shape,
+        _ => {
+            println!("{:#?}", found);
+            return Ok(());
+        }
+    };
+    let model = Creator::default().create(xsd)?;
+    let def = model
+        .get(shape, id.local_name())
+        .ok_or_else(|| format!("'{}' was not modeled", id))?;
+    println!("{:#?}", def);
+    Ok(())
+}
+
+fn execute(args: Args) -> Result<()> {
+    let paths = match &args.config {
+        Some(config) => Paths::from_config(config)?,
+        None => Paths::default(),
+    };
+    match args.command {
+        Command::Generate(generate) => run(generate.into_gen_args(paths)?)?,
+        Command::Check(generate) => {
+            let stale = check(generate.into_gen_args(paths)?)?;
+            if !stale.is_empty() {
+                for path in &stale {
+                    eprintln!("stale: {}", path.display());
+                }
+                return Err(format!("{} generated files are out of date", stale.len()).into());
+            }
+        }
+        Command::Show { id, entry, schema } => show(&id, entry, &schema.load(&paths)?)?,
+        Command::Diff { old, new, json } => {
+            let old = load(old.as_ref().unwrap_or(&paths.xsd_3_0))?;
+            let new = load(new.as_ref().unwrap_or(&paths.xsd_3_1))?;
+            let diff = SchemaDiff::compare(&old, &new);
+            if json {
+                println!("{}", diff.to_json()?);
+            } else {
+                print!("{}", diff);
+            }
+        }
+        Command::List { type_, schema } => {
+            let xsd = schema.load(&paths)?;
+            for entry in xsd.entries() {
+                let id = entry.id();
+                if type_.is_none() || id.root_node_type() == type_ {
+                    println!("{}", id);
+                }
+            }
+        }
+    }
+    Ok(())
+}
 
 fn main() {
+    let args = Args::from_args();
+    let timed = matches!(args.command, Command::Generate(_) | Command::Check(_));
     let start = Instant::now();
-    let exit_code = match run(GenArgs::default()) {
+    let exit_code = match execute(args) {
         Ok(()) => 0,
         Err(err) => {
             eprintln!("{}", err);
             1
         }
     };
-    let duration = start.elapsed();
-    println!("Runtime: {:?}", duration);
+    if timed {
+        println!("Runtime: {:?}", start.elapsed());
+    }
     std::process::exit(exit_code);
 }
@@ -2,6 +2,7 @@
 // public error type
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+use crate::xsd::source::Location;
 use core::fmt;
 use std::convert::Infallible;
 use std::fmt::{Debug, Display, Formatter};
@@ -20,6 +21,28 @@ impl Display for crate::error::Error {
     }
 }
 
+impl Error {
+    /// Where in the XSD document the error was found, if known.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Message(me) => me.location.as_ref(),
+        }
+    }
+
+    /// Sets the location of the error unless it already has one, the innermost location is the
+    /// most precise.
+    pub(crate) fn at(mut self, location: Option<Location>) -> Self {
+        match &mut self {
+            Error::Message(me) => {
+                if me.location.is_none() {
+                    me.location = location;
+                }
+            }
+        }
+        self
+    }
+}
+
 impl Debug for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         Display::fmt(self, f)
@@ -45,6 +68,8 @@ pub struct MessageError {
     pub throw_site: ThrowSite,
     pub message: Option<String>,
     pub source: Option<Box<dyn std::error::Error>>,
+    /// Where in the XSD document the error was found, if known.
+    pub location: Option<Location>,
 }
 
 /// The Rust sourcecode file and line number which is the 'throw' site of an error.
@@ -64,6 +89,23 @@ impl Display for ThrowSite {
 
 impl Display for MessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(location) = &self.location {
+            // print like a compiler diagnostic, e.g. `musicxml.xsd:12:5: error: ...`
+            write!(
+                f,
+                "{}: error: {} ({})",
+                location,
+                self.message
+                    .as_deref()
+                    .filter(|msg| !msg.is_empty())
+                    .unwrap_or("error"),
+                self.throw_site
+            )?;
+            if let Some(e) = &self.source {
+                write!(f, " - caused by: {}", e)?;
+            }
+            return Ok(());
+        }
         // self.throw_site.fmt(f)?;
         Display::fmt(&self.throw_site, f)?;
         if let Some(msg) = &self.message {
@@ -122,6 +164,7 @@ macro_rules! wrap {
                     throw_site: throw_site!(),
                     message: Some("error".into()),
                     source: crate::error::box_err(Some(er)),
+                    location: None,
                 }))
             }
         }
@@ -134,6 +177,7 @@ macro_rules! wrap {
                     throw_site: throw_site!(),
                     message: Some($msg.into()),
                     source: crate::error::box_err(Some(er)),
+                    location: None,
                 }))
             }
         }
@@ -146,6 +190,7 @@ macro_rules! wrap {
                     throw_site: throw_site!(),
                     message: Some(format!($fmt, $($arg),+)),
                     source: crate::error::box_err(Some(er)),
+                    location: None,
                 }))
             }
         }
@@ -159,6 +204,7 @@ macro_rules! make_err {
             throw_site: throw_site!(),
             message: Option::<String>::None,
             source: Option::<crate::error::Error>::None,
+            location: None,
         })
     };
     ($msg:expr) => {
@@ -166,6 +212,7 @@ macro_rules! make_err {
             throw_site: throw_site!(),
             message: Some($msg.into()),
             source: Option::<Box<dyn std::error::Error>>::None,
+            location: None,
         })
     };
     ($fmt:expr, $($arg:expr),+) => {
@@ -173,6 +220,7 @@ macro_rules! make_err {
             throw_site: throw_site!(),
             message: Some(format!($fmt, $($arg),+)),
             source: Option::<Box<dyn std::error::Error>>::None,
+            location: None,
         })
     };
 }
@@ -184,3 +232,13 @@ macro_rules! raise {
         }
     }
 }
+
+/// Like `raise!`, but the error carries the location of an XSD node, e.g.
+/// `raise_at!(xsd, node, "unexpected node '{}'", t)`.
+macro_rules! raise_at {
+    ($xsd:expr, $node:expr, $($x:tt)*) => {
+        {
+            Err(make_err!($($x)*).at($xsd.locate($node)))
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::error::Result;
+use crate::generate::paths::{Paths, SchemaVersion};
+use crate::model::Model;
+use std::fmt::{Debug, Formatter};
+use std::fs::{create_dir_all, write};
+use std::path::{Path, PathBuf};
+
+/// # Backend Trait
+///
+/// An object that writes something, e.g. the `mx::core` classes, from a finished `Model`.
+pub trait Backend {
+    /// The name that the backend is selected by, e.g. `cpp`.
+    fn name(&self) -> &'static str;
+
+    /// The directory that the output for `version` is written to when none is given.
+    fn default_dir(&self, paths: &Paths, version: SchemaVersion) -> PathBuf;
+
+    /// Writes the output for `model` to `output`.
+    fn generate(&self, model: Model, output: &Output) -> Result<()>;
+}
+
+/// Where, and for which schema version, a backend writes its output.
+#[derive(Debug, Clone)]
+pub struct Output {
+    /// The schema version that the model was created from.
+    pub version: SchemaVersion,
+    /// The locations of the schemas and of the hand-written code that the output may depend on.
+    pub paths: Paths,
+    /// The directory that the output is written to.
+    pub dir: PathBuf,
+}
+
+impl Output {
+    pub fn new<P: Into<PathBuf>>(version: SchemaVersion, paths: Paths, dir: P) -> Self {
+        Self {
+            version,
+            paths,
+            dir: dir.into(),
+        }
+    }
+
+    /// Writes `contents` to the file at `path`, which is relative to `dir`. Missing directories
+    /// are created.
+    pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(&self, path: P, contents: C) -> Result<()> {
+        let path = self.dir.join(path);
+        if let Some(parent) = path.parent() {
+            wrap!(create_dir_all(parent))?;
+        }
+        wrap!(
+            write(&path, contents),
+            "unable to write '{}'",
+            path.display()
+        )
+    }
+}
+
+/// The backends that can be selected by name.
+pub struct Backends {
+    backends: Vec<Box<dyn Backend>>,
+}
+
+impl Default for Backends {
+    /// The backends that are built in to mxgen.
+    fn default() -> Self {
+        let mut backends = Self::new();
+        backends.register(Box::new(crate::generate::cpp::CppBackend::default()));
+        backends.register(Box::new(crate::generate::rust::RustBackend::default()));
+        backends
+    }
+}
+
+impl Debug for Backends {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Backends{{ backends: [")?;
+        for backend in &self.backends {
+            write!(f, "{},", backend.name())?;
+        }
+        write!(f, "] }}")
+    }
+}
+
+impl Backends {
+    /// No backends.
+    pub fn new() -> Self {
+        Self {
+            backends: Vec::new(),
+        }
+    }
+
+    /// Adds `backend`, replacing any backend that has the same name.
+    pub fn register(&mut self, backend: Box<dyn Backend>) {
+        self.backends.retain(|b| b.name() != backend.name());
+        self.backends.push(backend);
+    }
+
+    /// The backend named `name`.
+    pub fn get<S: AsRef<str>>(&self, name: S) -> Result<&dyn Backend> {
+        let name = name.as_ref();
+        match self.backends.iter().find(|b| b.name() == name) {
+            Some(backend) => Ok(backend.as_ref()),
+            None => raise!(
+                "unknown backend '{}', expected one of: {}",
+                name,
+                self.names().join(", ")
+            ),
+        }
+    }
+
+    /// The names of the backends, in the order that they were registered.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.backends.iter().map(|b| b.name()).collect()
+    }
+}
+
+#[test]
+fn backends_test() {
+    #[derive(Debug)]
+    struct Nothing;
+    impl Backend for Nothing {
+        fn name(&self) -> &'static str {
+            "cpp"
+        }
+        fn default_dir(&self, paths: &Paths, _: SchemaVersion) -> PathBuf {
+            paths.repo.clone()
+        }
+        fn generate(&self, _: Model, _: &Output) -> Result<()> {
+            raise!("nothing")
+        }
+    }
+    let mut backends = Backends::default();
+    assert_eq!(backends.names(), vec!["cpp", "rust"]);
+    assert!(backends.get("json").is_err());
+    backends.register(Box::new(Nothing));
+    assert_eq!(backends.names(), vec!["rust", "cpp"]);
+    let output = Output::new(SchemaVersion::V3_0, Paths::default(), "");
+    assert!(backends
+        .get("cpp")
+        .unwrap()
+        .generate(Model::default(), &output)
+        .is_err());
+}
@@ -164,5 +164,7 @@ pub fn custom_scalar_strings() -> IndexSet<&'static str> {
     set.insert("color");
     set.insert("time-only");
     set.insert("ending-number");
+    set.insert("measure-text");
+    set.insert("smufl-glyph-name");
     set
 }
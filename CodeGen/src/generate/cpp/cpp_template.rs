@@ -1,10 +1,10 @@
 use crate::error::Result;
-use crate::generate::template;
-use crate::generate::template::{render, CORE_CPP, CORE_H};
+use crate::generate::template::{Templates, CORE_CPP, CORE_H};
 use std::borrow::Cow;
 use std::collections::HashMap;
 
 pub(super) fn render_core_h<S>(
+    templates: &Templates,
     contents: S,
     lib_includes: Option<&mut [&str]>,
     std_includes: Option<&mut [&str]>,
@@ -38,10 +38,11 @@ where
         }
     }
     map.insert("contents", contents.as_ref().to_owned());
-    render(CORE_H, &map)
+    templates.render(CORE_H, &map)
 }
 
 pub(super) fn render_core_cpp<S1, S2>(
+    templates: &Templates,
     contents: S1,
     self_include: Option<S2>,
     lib_includes: Option<&mut [&str]>,
@@ -86,5 +87,5 @@ where
         }
     }
     map.insert("contents", contents.as_ref().to_owned());
-    render(CORE_CPP, &map)
+    templates.render(CORE_CPP, &map)
 }
@@ -2,14 +2,60 @@ pub mod constants;
 mod cpp_template;
 mod helpers;
 pub mod modeler;
+mod write_aliases;
+mod write_content_models;
 mod write_custom;
 mod write_enums;
+mod write_lists;
 mod write_numerics;
+mod write_strings;
 mod write_unions;
 pub mod writer;
 
 use crate::error::Result;
-use std::path::Path;
+use crate::generate::backend::{Backend, Output};
+use crate::generate::paths::{Paths, SchemaVersion};
+use crate::generate::template::Templates;
+use crate::model::Model;
+use std::fs::{copy, create_dir_all, read_dir};
+use std::path::{Path, PathBuf};
+
+/// Writes the `mx::core` classes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CppBackend {}
+
+impl Backend for CppBackend {
+    fn name(&self) -> &'static str {
+        "cpp"
+    }
+
+    fn default_dir(&self, paths: &Paths, version: SchemaVersion) -> PathBuf {
+        paths.for_version(version).core
+    }
+
+    fn generate(&self, model: Model, output: &Output) -> Result<()> {
+        seed_core(&output.paths.core, &output.dir)?;
+        let templates = Templates::new(output.paths.templates.as_deref())?;
+        writer::Writer::new(model, output.clone(), templates).write_code()
+    }
+}
+
+/// A version-specific `core` directory starts out as a copy of the files in the 3.0 `core`
+/// directory, which provides the hand-written classes that the generated code depends on. Nothing
+/// is copied if `core` already exists.
+fn seed_core(from: &Path, core: &Path) -> Result<()> {
+    if from == core || core.exists() {
+        return Ok(());
+    }
+    wrap!(create_dir_all(core))?;
+    for entry in wrap!(read_dir(from))? {
+        let path = wrap!(entry)?.path();
+        if let Some(filename) = path.file_name().filter(|_| path.is_file()) {
+            wrap!(copy(&path, core.join(filename)))?;
+        }
+    }
+    Ok(())
+}
 
 fn check_file_exists<P: AsRef<Path>>(path: P) -> Result<()> {
     if !path.as_ref().is_file() {
@@ -7,7 +7,7 @@ use crate::model::builtin::BuiltinString;
 use crate::model::create::{Create, CreateError, CreateResult};
 use crate::model::enumeration::OtherField;
 use crate::model::post_process::PostProcess;
-use crate::model::scalar::{Bound, NumericData, Range, ScalarNumeric};
+use crate::model::scalar::{Bound, NumericData, Range, ScalarNumeric, ScalarString};
 use crate::model::symbol::Symbol;
 use crate::model::transform::Transform;
 use crate::model::Def::Enumeration;
@@ -62,7 +62,19 @@ impl Create for MxModeler {
         if let Id::Root(rid) = entry.id() {
             if rid.type_() == RootNodeType::ComplexType {
                 if rid.name().as_ref() == "dynamics" {
-                    return self.create_dynamics(entry);
+                    return self.create_dynamics(entry, xsd);
+                }
+            }
+            if rid.type_() == RootNodeType::SimpleType {
+                // MusicXML 3.1: a restriction of xs:NMTOKEN without facets, which the default
+                // creator would skip.
+                if rid.name().as_ref() == "smufl-glyph-name" {
+                    return Ok(Some(vec![Def::CustomScalarString(ScalarString {
+                        name: Symbol::new("smufl-glyph-name"),
+                        base_type: Character::NmToken,
+                        documentation: entry.documentation(),
+                        ..Default::default()
+                    })]));
                 }
             }
         }
@@ -164,12 +176,15 @@ impl PostProcess for MxModeler {
                             min: Some(Bound::Exclusive(0.0)),
                             max: None,
                         },
+                        total_digits: None,
+                        fraction_digits: None,
                     };
                     return Ok(Def::ScalarNumber(ScalarNumeric::Decimal(replacement)));
                 }
                 unhandled => {
                     return Err(CreateError {
                         message: format!("Unhandled DerivedSimpleType: '{}'", unhandled),
+                        location: None,
                     })
                 }
             }
@@ -190,7 +205,7 @@ impl MxModeler {
         }
     }
 
-    fn create_dynamics(&self, entry: &Entry) -> CreateResult {
+    fn create_dynamics(&self, entry: &Entry, xsd: &Xsd) -> CreateResult {
         let (ct, p, choice) = self.unwrap_dynamics(entry)?;
         let mut enumer = model::enumeration::Enumeration {
             /// TODO - get the name in a generic manner
@@ -209,6 +224,7 @@ impl MxModeler {
                 if enumer.other_field.is_some() {
                     return Err(CreateError {
                         message: "create_dynamics: multiple 'other' fields found".to_string(),
+                        location: None,
                     });
                 }
                 enumer.other_field = Some(OtherField {
@@ -218,8 +234,12 @@ impl MxModeler {
                 })
             }
         }
-        // TODO - also create the 'wrapping' element named 'dynamics'
-        Ok(Some(vec![Def::Enumeration(enumer)]))
+        // the complex type is kept too, it is the 'wrapping' element that holds the attributes.
+        let mut defs = vec![Def::Enumeration(enumer)];
+        if let Some(complex_type) = DefaultCreate::default().create(entry, xsd)? {
+            defs.extend(complex_type);
+        }
+        Ok(Some(defs))
     }
 
     fn unwrap_dynamics<'a>(
@@ -231,6 +251,7 @@ impl MxModeler {
         } else {
             return Err(CreateError {
                 message: "create_dynamics: wrong type".to_string(),
+                location: None,
             });
         };
         let p = if let complex_type::Payload::Parent(p) = &ct.payload {
@@ -238,6 +259,7 @@ impl MxModeler {
         } else {
             return Err(CreateError {
                 message: "create_dynamics: expected parent".to_string(),
+                location: None,
             });
         };
         let children = if let Some(children) = &p.children {
@@ -245,6 +267,7 @@ impl MxModeler {
         } else {
             return Err(CreateError {
                 message: "create_dynamics: expected children".to_string(),
+                location: None,
             });
         };
         let choice = if let Children::Choice(choice) = children {
@@ -252,6 +275,7 @@ impl MxModeler {
         } else {
             return Err(CreateError {
                 message: "create_dynamics: expected choice".to_string(),
+                location: None,
             });
         };
         Ok((ct, p, choice))
@@ -267,12 +291,14 @@ impl MxModeler {
             } else {
                 return Err(CreateError {
                     message: "unwrap_empty_element: expected ElementDef".to_string(),
+                    location: None,
                 });
             };
             ref_
         } else {
             return Err(CreateError {
                 message: "unwrap_empty_element: expected Element".to_string(),
+                location: None,
             });
         };
 
@@ -293,17 +319,19 @@ impl MxModeler {
             } else {
                 return Err(CreateError {
                     message: "unwrap_empty_element: expected ElementDef".to_string(),
+                    location: None,
                 });
             };
             ref_
         } else {
             return Err(CreateError {
                 message: "unwrap_empty_element: expected Element".to_string(),
+                location: None,
             });
         };
 
         /// TODO - allow all base types?
-        if ref_.type_.is_string() || ref_.type_.is_token() {
+        if ref_.type_.is_string() || ref_.type_.is_token() || is_other_text(&ref_.type_) {
             Ok(Some((ref_.name.as_str(), &ref_.type_)))
         } else {
             Ok(None)
@@ -319,20 +347,28 @@ impl MxModeler {
         } else {
             return Err(CreateError {
                 message: "create_dynamics: unable to unwrap the 'other' field".to_string(),
+                location: None,
             });
         };
-        if *found_stuff.1 != BaseType::String {
+        if *found_stuff.1 != BaseType::String && !is_other_text(found_stuff.1) {
             return return Err(CreateError {
                 message: format!(
                     "unwrap_other_field: unsupported 'other' field type '{}'",
                     found_stuff.1.name()
                 ),
+                location: None,
             });
         }
         Ok(found_stuff.0)
     }
 }
 
+/// In MusicXML 3.1 some 'other' fields, e.g. `other-dynamics`, are of type `other-text`, which is
+/// an `xs:string` with an optional `smufl` attribute.
+fn is_other_text(base_type: &BaseType) -> bool {
+    *base_type == BaseType::Custom("other-text".to_owned())
+}
+
 fn create_pseudo_enum(entry: &Entry, spec: &PseudoEnumSpec) -> CreateResult {
     let original = entry.id().name();
     let rename = format!("{}-enum", original);
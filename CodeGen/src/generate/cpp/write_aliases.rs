@@ -0,0 +1,56 @@
+use crate::error::Result;
+use crate::generate::cpp::cpp_template::render_core_h;
+use crate::generate::cpp::writer::Writer;
+use crate::model::scalar::DerivedSimpleTypeData;
+use crate::model::symbol::Symbol;
+use crate::utils::string_stuff::documentation;
+
+impl Writer {
+    /// Writes each derived simple type as a header that aliases the class of its base type, e.g.
+    /// `using Derived = Base;`. Derived types that add facets to a string type are modeled as
+    /// checked strings instead, see `model_restricted_string`.
+    pub(crate) fn write_aliases(&self, aliases: &[&DerivedSimpleTypeData]) -> Result<()> {
+        for &alias in aliases {
+            let contents = alias_contents(alias)?;
+            let base = Symbol::new(alias.base_type.as_str());
+            let include = format!("mx/core/{}.h", base.pascal());
+            let mut h_includes = vec![include.as_str()];
+            let h = render_core_h(
+                &self.templates,
+                contents,
+                Some(h_includes.as_mut_slice()),
+                None,
+            )?;
+            self.output.write(format!("{}.h", alias.name.pascal()), h)?;
+        }
+        Ok(())
+    }
+}
+
+fn alias_contents(alias: &DerivedSimpleTypeData) -> Result<String> {
+    if alias.base_type.contains(':') {
+        return raise!(
+            "unable to alias '{}' to the built-in type '{}'",
+            alias.name.original(),
+            alias.base_type
+        );
+    }
+    let base = Symbol::new(alias.base_type.as_str());
+    Ok(format!(
+        "{}\n        using {} = {};",
+        documentation(alias.documentation.as_str(), 2)?,
+        alias.name.pascal(),
+        base.pascal()
+    ))
+}
+
+#[test]
+fn alias_contents_smufl() {
+    let alias = DerivedSimpleTypeData {
+        name: Symbol::new("smufl-coda-glyph-name"),
+        base_type: "smufl-glyph-name".to_owned(),
+        documentation: "".to_owned(),
+    };
+    let got = alias_contents(&alias).unwrap();
+    assert!(got.ends_with("        using SmuflCodaGlyphName = SmuflGlyphName;"));
+}
@@ -0,0 +1,404 @@
+use crate::error::Result;
+use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
+use crate::generate::cpp::writer::Writer;
+use crate::model::automaton::Automaton;
+use crate::model::complex_type::{ComplexTypeData, Content, ElementType, Particle};
+use crate::model::element::ElementData;
+use crate::model::symbol::Symbol;
+use crate::model::{Def, Shape};
+use crate::xsd::primitives::BaseType;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The functions of `ContentModels.cpp` and the checks that `isContentValid` makes for each
+/// element.
+#[derive(Debug, Default)]
+struct ContentModels {
+    /// The definitions of the state machines, by function name.
+    functions: BTreeMap<String, String>,
+    /// The type and check of each element, by the name of the complex type that declares it (empty
+    /// for a top-level element) and the element's name. An element is not checked if its type
+    /// cannot be, e.g. one that is derived by extension, or if it is declared with two types.
+    elements: BTreeMap<(String, String), Option<ElementModel>>,
+}
+
+/// How the children of an element are checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ElementModel {
+    /// The name of the element's complex type, which declares the element's children, or empty if
+    /// it has a simple type.
+    type_name: String,
+    /// The expression that checks the element's children.
+    check: String,
+}
+
+impl Writer {
+    /// Writes `ContentModels.h` and `ContentModels.cpp`, with a function for each complex type
+    /// that has child elements which checks the order and number of those children. The functions
+    /// are state machines built from the content model's automaton. An element name can have a
+    /// different type in each complex type that declares it, so `isContentValid` dispatches on the
+    /// name of the parent's complex type as well as the element's name. `getElementType` finds the
+    /// parent's complex type from the root down, which is how `ElementInterface::fromXElement`
+    /// checks every element that it parses.
+    pub(crate) fn write_content_models(
+        &self,
+        complex_types: &[&ComplexTypeData],
+        elements: &[&ElementData],
+    ) -> Result<()> {
+        let mut models = ContentModels::default();
+        for &ct in complex_types {
+            self.add_complex_type(&mut models, ct.name.pascal(), ct)?;
+        }
+        for &e in elements {
+            self.add_element(&mut models, "", &e.name, &e.type_)?;
+        }
+        let mut declarations = String::from(
+            "        /// Returns the name of the complex type of an element that is declared by the \
+             complex type\n        /// named parentType, or by the schema if parentType is \
+             empty, e.g. \"Pitch\" for the pitch\n        /// element of \"Note\". Returns an \
+             empty string if the element is unknown or has a simple type.\n        std::string \
+             getElementType( const std::string& parentType, const std::string& elementName );\n\n\
+             \x20       /// Returns true if the names of an element's children, in document order, \
+             are allowed by\n        /// the content model of its complex type. The element is \
+             found as in getElementType, and\n        /// elements that are unknown are always \
+             valid.\n        bool isContentValid( const std::string& parentType, const \
+             std::string& elementName, const std::vector<std::string>& childNames );\n\n        \
+             /// Each function returns true if the names of an element's children, in document \
+             order,\n        /// are allowed by the content model of its complex type.",
+        );
+        let mut definitions = dispatch(&models.elements);
+        for (function, definition) in &models.functions {
+            let _ = write!(
+                declarations,
+                "\n        bool {}( const std::vector<std::string>& childNames );",
+                function
+            );
+            definitions.push_str("\n\n");
+            definitions.push_str(definition);
+        }
+        let mut std_h = vec!["string", "vector"];
+        let h = render_core_h(
+            &self.templates,
+            declarations,
+            None,
+            Some(std_h.as_mut_slice()),
+        )?;
+        let self_import = "mx/core/ContentModels.h".to_owned();
+        let mut std_cpp = vec!["map", "utility"];
+        let cpp = render_core_cpp(
+            &self.templates,
+            definitions,
+            Some(self_import),
+            None,
+            Some(std_cpp.as_mut_slice()),
+        )?;
+        self.output.write("ContentModels.h", h)?;
+        self.output.write("ContentModels.cpp", cpp)
+    }
+
+    /// Adds the state machine of `ct`, named after `owner`, and the elements of its content model.
+    fn add_complex_type(
+        &self,
+        models: &mut ContentModels,
+        owner: &str,
+        ct: &ComplexTypeData,
+    ) -> Result<()> {
+        let particle = match &ct.content {
+            Content::Particle(p) => p,
+            _ => return Ok(()),
+        };
+        let flattened = wrap!(
+            self.model.flatten(particle),
+            "unable to flatten the content model of '{}'",
+            ct.name.original()
+        )?;
+        let automaton = wrap!(
+            self.model.automaton(particle),
+            "unable to build the content model of '{}'",
+            ct.name.original()
+        )?;
+        let function = function_name(owner);
+        let definition = state_machine(&function, &automaton);
+        if let Some(existing) = models.functions.get(&function) {
+            if existing != &definition {
+                return raise!("two content models are named '{}'", function);
+            }
+            return Ok(());
+        }
+        models.functions.insert(function, definition);
+        let mut stack = vec![&flattened];
+        while let Some(particle) = stack.pop() {
+            match particle {
+                Particle::Element(e) => self.add_element(models, owner, &e.name, &e.type_)?,
+                Particle::Sequence(c) | Particle::Choice(c) | Particle::All(c) => {
+                    stack.extend(c.members.iter().rev())
+                }
+                Particle::GroupRef(_) | Particle::Any(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the check for the children of an element named `name`, which is declared by the
+    /// content model named `owner` (or at the top level if `owner` is empty).
+    fn add_element(
+        &self,
+        models: &mut ContentModels,
+        owner: &str,
+        name: &Symbol,
+        type_: &ElementType,
+    ) -> Result<()> {
+        let simple = || {
+            Some(ElementModel {
+                type_name: String::new(),
+                check: String::from("childNames.empty()"),
+            })
+        };
+        let model = match type_ {
+            ElementType::Named(BaseType::Custom(type_name)) => {
+                match self.model.get(Shape::Complex, type_name) {
+                    Some(Def::ComplexType(ct)) => element_model(&ct.content, ct.name.pascal()),
+                    // a simple type.
+                    _ => simple(),
+                }
+            }
+            ElementType::Named(_) => simple(),
+            ElementType::Anonymous(ct) => {
+                let anonymous = format!("{}{}", owner, name.pascal());
+                self.add_complex_type(models, &anonymous, ct)?;
+                element_model(&ct.content, &anonymous)
+            }
+        };
+        let key = (owner.to_owned(), name.original().to_owned());
+        match models.elements.get(&key) {
+            // e.g. an element that is declared twice in a choice with different types.
+            Some(existing) if existing != &model => {
+                models.elements.insert(key, None);
+            }
+            _ => {
+                models.elements.insert(key, model);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn function_name(owner: &str) -> String {
+    format!("is{}ContentValid", owner)
+}
+
+/// How the children of an element whose type, named `owner`, has `content` are checked, or `None`
+/// if they cannot be.
+fn element_model(content: &Content, owner: &str) -> Option<ElementModel> {
+    let check = match content {
+        Content::Empty | Content::Simple(_) => String::from("childNames.empty()"),
+        Content::Particle(_) => format!("{}( childNames )", function_name(owner)),
+        Content::Extension(_) => return None,
+    };
+    Some(ElementModel {
+        type_name: owner.to_owned(),
+        check,
+    })
+}
+
+/// Produces `getElementType` and `isContentValid`, which look up an element by the complex type
+/// that declares it and its name.
+fn dispatch(elements: &BTreeMap<(String, String), Option<ElementModel>>) -> String {
+    let checked: Vec<(&(String, String), &ElementModel)> = elements
+        .iter()
+        .filter_map(|(key, model)| model.as_ref().map(|model| (key, model)))
+        .collect();
+    let mut s = String::new();
+    s.push_str("        namespace\n");
+    s.push_str("        {\n");
+    s.push_str("            /// The index of an element in the tables of getElementType and isContentValid, or -1.\n");
+    s.push_str(
+        "            int findElement( const std::string& parentType, const std::string& \
+         elementName )\n",
+    );
+    s.push_str("            {\n");
+    s.push_str(
+        "                static const std::map<std::pair<std::string, std::string>, int> \
+         elements =\n",
+    );
+    s.push_str("                {\n");
+    for (i, ((owner, name), _)) in checked.iter().enumerate() {
+        let separator = if i + 1 == checked.len() { "" } else { "," };
+        let _ = writeln!(
+            s,
+            "                    {{ {{ \"{}\", \"{}\" }}, {} }}{}",
+            owner, name, i, separator
+        );
+    }
+    s.push_str("                };\n\n");
+    s.push_str(
+        "                const auto found = elements.find( std::make_pair( parentType, \
+         elementName ) );\n",
+    );
+    s.push_str("                return found == elements.cend() ? -1 : found->second;\n");
+    s.push_str("            }\n");
+    s.push_str("        }\n\n");
+
+    s.push_str(
+        "        std::string getElementType( const std::string& parentType, const std::string& \
+         elementName )\n",
+    );
+    s.push_str("        {\n");
+    s.push_str("            static const char* const types[] =\n");
+    s.push_str("            {\n");
+    for (i, (_, model)) in checked.iter().enumerate() {
+        let separator = if i + 1 == checked.len() { "" } else { "," };
+        let _ = writeln!(s, "                \"{}\"{}", model.type_name, separator);
+    }
+    s.push_str("            };\n\n");
+    s.push_str("            const int element = findElement( parentType, elementName );\n");
+    s.push_str("            return element < 0 ? std::string{} : std::string{ types[element] };\n");
+    s.push_str("        }\n\n");
+
+    s.push_str(
+        "        bool isContentValid( const std::string& parentType, const std::string& \
+         elementName, const std::vector<std::string>& childNames )\n",
+    );
+    s.push_str("        {\n");
+    s.push_str("            switch( findElement( parentType, elementName ) )\n");
+    s.push_str("            {\n");
+    for (i, (_, model)) in checked.iter().enumerate() {
+        let _ = writeln!(s, "                case {}:", i);
+        let _ = writeln!(s, "                    return {};", model.check);
+    }
+    s.push_str("                default:\n");
+    s.push_str("                    return true;\n");
+    s.push_str("            }\n");
+    s.push_str("        }");
+    s
+}
+
+/// Produces a C++ function named `function` that runs `automaton` over `childNames`.
+fn state_machine<T>(function: &str, automaton: &Automaton<T>) -> String {
+    let mut s = String::new();
+    let _ = writeln!(
+        s,
+        "        bool {}( const std::vector<std::string>& childNames )",
+        function
+    );
+    s.push_str("        {\n");
+    s.push_str("            int state = 0;\n");
+    s.push_str("            for( const auto& name : childNames )\n");
+    s.push_str("            {\n");
+    s.push_str("                switch( state )\n");
+    s.push_str("                {\n");
+    for state in 0..automaton.len() {
+        let transitions: Vec<(&str, usize)> = automaton.transitions(state).collect();
+        let wildcard = automaton.wildcard(state);
+        if transitions.is_empty() && wildcard.is_none() {
+            continue;
+        }
+        let _ = writeln!(s, "                    case {}:", state);
+        for (i, (name, next)) in transitions.iter().enumerate() {
+            let keyword = if i == 0 { "if" } else { "else if" };
+            let _ = writeln!(
+                s,
+                "                        {}( name == \"{}\" )",
+                keyword, name
+            );
+            s.push_str("                        {\n");
+            let _ = writeln!(s, "                            state = {};", next);
+            s.push_str("                        }\n");
+        }
+        let otherwise = match wildcard {
+            Some(next) => format!("state = {};", next),
+            None => "return false;".to_owned(),
+        };
+        if transitions.is_empty() {
+            let _ = writeln!(s, "                        {}", otherwise);
+        } else {
+            s.push_str("                        else\n");
+            s.push_str("                        {\n");
+            let _ = writeln!(s, "                            {}", otherwise);
+            s.push_str("                        }\n");
+        }
+        s.push_str("                        break;\n");
+    }
+    s.push_str("                    default:\n");
+    s.push_str("                        return false;\n");
+    s.push_str("                }\n");
+    s.push_str("            }\n");
+    let accepting: Vec<String> = (0..automaton.len())
+        .filter(|&state| automaton.is_accepting(state))
+        .map(|state| format!("state == {}", state))
+        .collect();
+    if accepting.is_empty() {
+        s.push_str("            return false;\n");
+    } else {
+        let _ = writeln!(s, "            return {};", accepting.join(" || "));
+    }
+    s.push_str("        }");
+    s
+}
+
+#[test]
+fn state_machine_test() {
+    use crate::model::automaton::Term;
+    use crate::xsd::Occurs;
+    let optional = Occurs {
+        min_occurs: 0,
+        max_occurs: Some(1),
+    };
+    let term = Term::Sequence(
+        Occurs::default(),
+        vec![
+            Term::Element("step".to_owned(), (), Occurs::default()),
+            Term::Element("alter".to_owned(), (), optional),
+            Term::Element("octave".to_owned(), (), Occurs::default()),
+        ],
+    );
+    let automaton = Automaton::new(&term).unwrap();
+    let got = state_machine("isPitchContentValid", &automaton);
+    assert!(got.starts_with(
+        "        bool isPitchContentValid( const std::vector<std::string>& childNames )\n"
+    ));
+    assert!(got.contains(
+        "                    case 1:\n                        if( name == \"alter\" )\n"
+    ));
+    assert!(got.contains("                        else if( name == \"octave\" )\n"));
+    assert!(got.contains("            return state == 3;\n"));
+}
+
+#[test]
+fn dispatch_test() {
+    let mut elements = BTreeMap::new();
+    let key = |owner: &str, name: &str| (owner.to_owned(), name.to_owned());
+    let model = |type_name: &str, check: &str| {
+        Some(ElementModel {
+            type_name: type_name.to_owned(),
+            check: check.to_owned(),
+        })
+    };
+    elements.insert(
+        key("Note", "pitch"),
+        model("Pitch", "isPitchContentValid( childNames )"),
+    );
+    elements.insert(key("Pitch", "step"), model("", "childNames.empty()"));
+    elements.insert(key("Note", "extended"), None);
+    elements.insert(
+        key("ScoreTimewise", "measure"),
+        model(
+            "ScoreTimewiseMeasure",
+            "isScoreTimewiseMeasureContentValid( childNames )",
+        ),
+    );
+    let got = dispatch(&elements);
+    assert!(got.contains(
+        "                    { { \"Note\", \"pitch\" }, 0 },\n                    { { \"Pitch\", \"step\" }, 1 },\n                    { { \"ScoreTimewise\", \"measure\" }, 2 }\n                };\n"
+    ));
+    assert!(!got.contains("extended"));
+    assert!(got.contains(
+        "                \"Pitch\",\n                \"\",\n                \"ScoreTimewiseMeasure\"\n            };\n"
+    ));
+    assert!(got.contains(
+        "                case 0:\n                    return isPitchContentValid( childNames );\n"
+    ));
+    assert!(
+        got.contains("                case 1:\n                    return childNames.empty();\n")
+    );
+}
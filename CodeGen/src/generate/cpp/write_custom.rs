@@ -1,43 +1,95 @@
 use crate::error::Result;
+use crate::generate::backend::Output;
 use crate::generate::cpp::check_file_exists;
-use crate::generate::paths::Paths;
+use crate::generate::cpp::cpp_template::render_core_h;
+use crate::generate::template::Templates;
 use crate::model::scalar::ScalarString;
 
-pub(super) fn write_color(model: &ScalarString, paths: &Paths) -> Result<()> {
+pub(super) fn write_color(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
     if model.name.original() != "color" {
         return raise!("expected 'color' got '{}'", model.name.original());
     }
-    check_file_exists(paths.core.join("Color.h"))?;
-    check_file_exists(paths.core.join("Color.cpp"))?;
+    check_file_exists(output.dir.join("Color.h"))?;
+    check_file_exists(output.dir.join("Color.cpp"))?;
     Ok(())
 }
 
-pub(super) fn write_comma_separated_text(model: &ScalarString, paths: &Paths) -> Result<()> {
+pub(super) fn write_comma_separated_text(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
     let expected = "comma-separated-text";
     if model.name.original() != expected {
         return raise!("expected '{}' got '{}'", expected, model.name.original());
     }
-    check_file_exists(paths.core.join("CommaSeparatedText.h"))?;
-    check_file_exists(paths.core.join("CommaSeparatedText.cpp"))?;
+    check_file_exists(output.dir.join("CommaSeparatedText.h"))?;
+    check_file_exists(output.dir.join("CommaSeparatedText.cpp"))?;
     Ok(())
 }
 
-pub(super) fn write_time_only(model: &ScalarString, paths: &Paths) -> Result<()> {
+pub(super) fn write_time_only(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
     let expected = "time-only";
     if model.name.original() != expected {
         return raise!("expected '{}' got '{}'", expected, model.name.original());
     }
-    check_file_exists(paths.core.join("TimeOnly.h"))?;
+    check_file_exists(output.dir.join("TimeOnly.h"))?;
     // no cpp file, alias only
     Ok(())
 }
 
-pub(super) fn write_ending_number(model: &ScalarString, paths: &Paths) -> Result<()> {
+pub(super) fn write_ending_number(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
     let expected = "ending-number";
     if model.name.original() != expected {
         return raise!("expected '{}' got '{}'", expected, model.name.original());
     }
-    check_file_exists(paths.core.join("TimeOnly.h"))?;
+    check_file_exists(output.dir.join("TimeOnly.h"))?;
     // no cpp file, alias only
     Ok(())
 }
+
+pub(super) fn write_smufl_glyph_name(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
+    let expected = "smufl-glyph-name";
+    if model.name.original() != expected {
+        return raise!("expected '{}' got '{}'", expected, model.name.original());
+    }
+    // MusicXML 3.1 only, the glyph name is an xs:NMTOKEN. no cpp file, alias only.
+    let contents = "        using SmuflGlyphName = XsNMToken;";
+    let mut includes = vec!["mx/core/XsNMToken.h"];
+    let h = render_core_h(templates, contents, Some(includes.as_mut_slice()), None)?;
+    output.write("SmuflGlyphName.h", h)?;
+    Ok(())
+}
+
+pub(super) fn write_measure_text(
+    model: &ScalarString,
+    output: &Output,
+    templates: &Templates,
+) -> Result<()> {
+    let expected = "measure-text";
+    if model.name.original() != expected {
+        return raise!("expected '{}' got '{}'", expected, model.name.original());
+    }
+    // MusicXML 3.1 only, an xs:token with minLength 1. the minimum length is not enforced.
+    let contents = "        using MeasureText = XsToken;";
+    let mut includes = vec!["mx/core/XsToken.h"];
+    let h = render_core_h(templates, contents, Some(includes.as_mut_slice()), None)?;
+    output.write("MeasureText.h", h)?;
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
 use crate::generate::cpp::writer::Writer;
-use crate::generate::template::{render, ENUM_CPP, ENUM_H, ENUM_WRAPPER_CPP, ENUM_WRAPPER_H};
+use crate::generate::template::{Templates, ENUM_CPP, ENUM_H, ENUM_WRAPPER_CPP, ENUM_WRAPPER_H};
 use crate::model::enumeration::{Enumeration, OtherField};
 use crate::model::Def;
 use crate::utils::string_stuff::{
@@ -9,7 +9,6 @@ use crate::utils::string_stuff::{
 };
 use indexmap::set::IndexSet;
 use std::collections::HashMap;
-use std::fs::OpenOptions;
 use std::io::Write;
 
 pub fn write_tabs<W: Write>(w: &mut W, num: u32) -> std::io::Result<()> {
@@ -43,78 +42,78 @@ impl Writer {
         let mut contents_h = String::new();
         let mut contents_cpp = String::new();
         for (i, enumeration) in enumerations.iter().enumerate() {
-            let enumeration = *enumeration;
-            let first = i == 0;
-            let mut data = HashMap::new();
-            data.insert("classname", enumeration.name.pascal().to_owned());
-            data.insert("banner", sep(enumeration.name.pascal(), 2));
-            data.insert(
-                "documentation",
-                documentation(&enumeration.documentation, 2)?,
-            );
-            data.insert("enum_members_declare", enum_members_declare(enumeration));
-            data.insert("enum_members_parse", enum_members_parse(enumeration));
-            data.insert(
-                "enum_members_to_string",
-                enum_members_to_string(enumeration),
-            );
-            if let Some(of) = &enumeration.other_field {
-                data.insert("default_value_enum", enumeration.default.camel().into());
-                data.insert(
-                    "default_value_string",
-                    enumeration.default.original().into(),
-                );
-                data.insert("to_string_default_return", of.name.original().into());
-                data.insert("other_field_name", of.name.camel().into());
-            } else {
-                let first_member = enumeration.members.first().unwrap();
-                data.insert("default_value_enum", enumeration.default.camel().into());
-                data.insert(
-                    "default_value_string",
-                    enumeration.default.original().into(),
-                );
-                data.insert(
-                    "to_string_default_return",
-                    enumeration.default.original().into(),
-                );
-            }
-            let rendered_h = render(ENUM_H, &data)?;
-            if !first {
-                contents_h.push('\n');
-                contents_h.push('\n');
-            }
-            contents_h.push_str(&rendered_h);
-            let rendered_cpp = render(ENUM_CPP, &data)?;
-            if !first {
-                contents_cpp.push('\n');
-                contents_cpp.push('\n');
-            }
-            contents_cpp.push_str(&rendered_cpp);
-            if let Some(of) = &enumeration.other_field {
-                data.insert("wrapper_classname", of.wrapper_class_name.pascal().into());
+            if i != 0 {
                 contents_h.push_str("\n\n");
                 contents_cpp.push_str("\n\n");
-                contents_h.push_str(render(ENUM_WRAPPER_H, &data)?.as_str());
-                contents_cpp.push_str(render(ENUM_WRAPPER_CPP, &data)?.as_str());
             }
+            let (h, cpp) = render_enum(&self.templates, enumeration)?;
+            contents_h.push_str(&h);
+            contents_cpp.push_str(&cpp);
         }
         let file_h = render_core_h(
+            &self.templates,
             contents_h,
             Some(&mut ["mx/core/EnumsBuiltin.h"]),
             Some(&mut ["iostream", "string", "optional"]),
         )?;
         let file_cpp = render_core_cpp(
+            &self.templates,
             contents_cpp,
             Some("mx/core/Enums.h"),
             None,
             Some(&mut ["sstream"]),
         )?;
-        wrap!(std::fs::write(&self.paths.enums_h, file_h))?;
-        wrap!(std::fs::write(&self.paths.enums_cpp, file_cpp))?;
+        self.write_named_file(&self.output.paths.enums_h, file_h)?;
+        self.write_named_file(&self.output.paths.enums_cpp, file_cpp)?;
         Ok(())
     }
 }
 
+/// Renders the declaration and the definition of `enumeration`, and of its wrapper class if it
+/// has an 'other' field.
+pub(crate) fn render_enum(
+    templates: &Templates,
+    enumeration: &Enumeration,
+) -> Result<(String, String)> {
+    let mut data = HashMap::new();
+    data.insert("classname", enumeration.name.pascal().to_owned());
+    data.insert("banner", sep(enumeration.name.pascal(), 2));
+    data.insert(
+        "documentation",
+        documentation(&enumeration.documentation, 2)?,
+    );
+    data.insert("enum_members_declare", enum_members_declare(enumeration));
+    data.insert("enum_members_parse", enum_members_parse(enumeration));
+    data.insert(
+        "enum_members_to_string",
+        enum_members_to_string(enumeration),
+    );
+    data.insert("default_value_enum", enumeration.default.camel().into());
+    data.insert(
+        "default_value_string",
+        enumeration.default.original().into(),
+    );
+    if let Some(of) = &enumeration.other_field {
+        data.insert("to_string_default_return", of.name.original().into());
+        data.insert("other_field_name", of.name.camel().into());
+    } else {
+        data.insert(
+            "to_string_default_return",
+            enumeration.default.original().into(),
+        );
+    }
+    let mut h = templates.render(ENUM_H, &data)?;
+    let mut cpp = templates.render(ENUM_CPP, &data)?;
+    if let Some(of) = &enumeration.other_field {
+        data.insert("wrapper_classname", of.wrapper_class_name.pascal().into());
+        h.push_str("\n\n");
+        cpp.push_str("\n\n");
+        h.push_str(templates.render(ENUM_WRAPPER_H, &data)?.as_str());
+        cpp.push_str(templates.render(ENUM_WRAPPER_CPP, &data)?.as_str());
+    }
+    Ok((h, cpp))
+}
+
 fn enum_members_declare(e: &Enumeration) -> String {
     let mut s = String::new();
     for (i, m) in e.members.iter().enumerate() {
@@ -0,0 +1,210 @@
+use crate::error::Result;
+use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
+use crate::generate::cpp::writer::Writer;
+use crate::generate::template::{LIST_CPP, LIST_H};
+use crate::model::scalar::{ListData, ScalarNumeric};
+use crate::model::symbol::Symbol;
+use crate::model::{Def, Shape};
+use crate::utils::string_stuff::documentation;
+use crate::xsd::primitives::{BaseType, Primitive};
+use std::collections::HashMap;
+
+/// What we need to know about the item type of a list in order to write the list class.
+struct Item {
+    classname: String,
+    include: String,
+    is_enum: bool,
+}
+
+impl Writer {
+    pub(crate) fn write_lists(&self, lists: &[&ListData]) -> Result<()> {
+        for &list in lists {
+            let item = self.list_item(list)?;
+            let mut data = HashMap::new();
+            data.insert("classname", list.name.pascal().to_owned());
+            data.insert("item_classname", item.classname.clone());
+            data.insert(
+                "documentation",
+                documentation(list.documentation.as_str(), 2)?,
+            );
+            data.insert("parse_item", parse_item(&item));
+            let hcontents = self.templates.render(LIST_H, &data)?;
+            let cppcontents = self.templates.render(LIST_CPP, &data)?;
+            let mut h_includes = vec![item.include.as_str()];
+            let mut std_h = vec!["string", "vector", "ostream"];
+            let h = render_core_h(
+                &self.templates,
+                hcontents,
+                Some(h_includes.as_mut_slice()),
+                Some(std_h.as_mut_slice()),
+            )?;
+            let self_import = format!("mx/core/{}.h", list.name.pascal());
+            let mut std_cpp = vec!["sstream"];
+            let cpp = render_core_cpp(
+                &self.templates,
+                cppcontents,
+                Some(self_import),
+                None,
+                Some(std_cpp.as_mut_slice()),
+            )?;
+            self.output.write(format!("{}.h", list.name.pascal()), h)?;
+            self.output
+                .write(format!("{}.cpp", list.name.pascal()), cpp)?;
+        }
+        Ok(())
+    }
+
+    fn list_item(&self, list: &ListData) -> Result<Item> {
+        match &list.item_type {
+            BaseType::Custom(name) => {
+                let def = match self.model.get(Shape::Simple, name) {
+                    None => {
+                        return raise!(
+                            "the item type '{}' could not be found for list '{}'",
+                            name,
+                            list.name.original()
+                        )
+                    }
+                    Some(found) => found,
+                };
+                let include = match def {
+                    Def::Enumeration(_) => "mx/core/Enums.h".to_owned(),
+                    Def::ScalarNumber(ScalarNumeric::Decimal(_)) => "mx/core/Decimals.h".to_owned(),
+                    Def::ScalarNumber(ScalarNumeric::Integer(_)) => "mx/core/Integers.h".to_owned(),
+                    Def::ScalarString(_)
+                    | Def::CustomScalarString(_)
+                    | Def::DerivedSimpleType(_)
+                    | Def::UnionSimpleType(_) => format!("mx/core/{}.h", def.name().pascal()),
+                    other => {
+                        return raise!(
+                            "'{}' cannot be the item type of list '{}'",
+                            other.name().original(),
+                            list.name.original()
+                        )
+                    }
+                };
+                Ok(Item {
+                    classname: def.name().pascal().to_owned(),
+                    include,
+                    is_enum: matches!(def, Def::Enumeration(_)),
+                })
+            }
+            p => {
+                let include = match p.primitive() {
+                    Primitive::None => return raise!("unexpected item type '{}'", p),
+                    Primitive::Numeric => {
+                        if p.is_decimal() {
+                            "mx/core/Decimals.h"
+                        } else {
+                            "mx/core/Integers.h"
+                        }
+                    }
+                    Primitive::Character => "mx/core/Strings.h",
+                    Primitive::DateType => "mx/core/Date.h",
+                };
+                Ok(Item {
+                    classname: Symbol::new(format!("{}", p)).pascal().to_owned(),
+                    include: include.to_owned(),
+                    is_enum: false,
+                })
+            }
+        }
+    }
+}
+
+fn parse_item(item: &Item) -> String {
+    let mut s = String::new();
+    if item.is_enum {
+        s.push_str(
+            format!(
+                "                const auto item = tryParse{}( token );\n",
+                item.classname
+            )
+            .as_str(),
+        );
+        s.push_str("                if( !item )\n");
+        s.push_str("                {\n");
+        s.push_str("                    return false;\n");
+        s.push_str("                }\n");
+        s.push_str("                values.push_back( *item );");
+    } else {
+        s.push_str(format!("                auto item = {}{{}};\n", item.classname).as_str());
+        s.push_str("                if( !item.parse( token ) )\n");
+        s.push_str("                {\n");
+        s.push_str("                    return false;\n");
+        s.push_str("                }\n");
+        s.push_str("                values.push_back( std::move( item ) );");
+    }
+    s
+}
+
+#[test]
+fn parse_item_enum() {
+    let item = Item {
+        classname: "YesNo".to_owned(),
+        include: "mx/core/Enums.h".to_owned(),
+        is_enum: true,
+    };
+    let got = parse_item(&item);
+    assert!(got.contains("tryParseYesNo( token )"));
+    assert!(got.contains("values.push_back( *item );"));
+}
+
+#[test]
+fn write_lists_test() {
+    use crate::model::creator::Creator;
+    use crate::xsd::Xsd;
+    let xsd = Xsd::parse_str(
+        r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="yes-no">
+        <xs:restriction base="xs:token">
+            <xs:enumeration value="yes"/>
+            <xs:enumeration value="no"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="yes-no-list">
+        <xs:annotation>
+            <xs:documentation>A list of yes and no values.</xs:documentation>
+        </xs:annotation>
+        <xs:list itemType="yes-no"/>
+    </xs:simpleType>
+    <xs:simpleType name="positive-integers">
+        <xs:list itemType="xs:positiveInteger"/>
+    </xs:simpleType>
+</xs:schema>"#,
+    )
+    .unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let mut lists = Vec::new();
+    for def in model.defs() {
+        if let Def::ListSimpleType(list) = def {
+            lists.push(list);
+        }
+    }
+    assert_eq!(lists.len(), 2);
+    let tmp = tempfile::tempdir().unwrap();
+    let output = crate::generate::backend::Output::new(
+        crate::generate::paths::SchemaVersion::V3_0,
+        crate::generate::paths::Paths::default(),
+        tmp.path(),
+    );
+    let templates = crate::generate::template::Templates::embedded().unwrap();
+    let writer = Writer::new(model.clone(), output, templates);
+    writer.write_lists(&lists).unwrap();
+    let read = |filename: &str| std::fs::read_to_string(tmp.path().join(filename)).unwrap();
+    let h = read("YesNoList.h");
+    assert!(h.contains("#include \"mx/core/Enums.h\""));
+    assert!(h.contains("A list of yes and no values."));
+    assert!(h.contains("            explicit YesNoList();\n"));
+    assert!(h.contains("            const std::vector<YesNo>& getValues() const;\n"));
+    assert!(h.contains("std::ostream& toStream( std::ostream& os, const YesNoList& value );"));
+    let cpp = read("YesNoList.cpp");
+    assert!(cpp.contains("#include \"mx/core/YesNoList.h\""));
+    assert!(cpp.contains("const auto item = tryParseYesNo( token );"));
+    let h = read("PositiveIntegers.h");
+    assert!(h.contains("#include \"mx/core/Integers.h\""));
+    assert!(h.contains("            explicit PositiveIntegers();\n"));
+    let cpp = read("PositiveIntegers.cpp");
+    assert!(cpp.contains("auto item = PositiveInteger{};"));
+    assert!(cpp.contains("if( !item.parse( token ) )"));
+}
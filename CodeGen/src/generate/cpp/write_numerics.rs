@@ -2,7 +2,7 @@ use crate::error::Result;
 use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
 use crate::generate::cpp::writer::Writer;
 use crate::generate::template::{
-    render, CORE_H, DECIMAL_BUILTINS_CPP, DECIMAL_BUILTINS_H, DECIMAL_TYPE_CPP, DECIMAL_TYPE_H,
+    CORE_H, DECIMAL_BUILTINS_CPP, DECIMAL_BUILTINS_H, DECIMAL_TYPE_CPP, DECIMAL_TYPE_H,
     INTEGER_BUILTINS_CPP, INTEGER_BUILTINS_H, INTEGER_TYPE_CPP, INTEGER_TYPE_H, NO_DATA,
 };
 use crate::model::scalar::{Bound, NumericData, Range};
@@ -14,7 +14,6 @@ use crate::xsd::primitives::BaseType;
 use crate::xsd::primitives::Numeric;
 use crate::xsd::primitives::Primitive;
 use std::collections::HashMap;
-use std::fs::OpenOptions;
 use std::io::Write;
 use std::ops::Deref;
 
@@ -51,6 +50,8 @@ impl Writer {
                 min: Some(Bound::Inclusive(1 as i64)),
                 max: None,
             },
+            total_digits: None,
+            fraction_digits: None,
         };
         let non_negative_integer = NumericData {
             name: Symbol::new("nonNegativeInteger"),
@@ -60,6 +61,8 @@ impl Writer {
                 min: Some(Bound::Inclusive(0 as i64)),
                 max: None,
             },
+            total_digits: None,
+            fraction_digits: None,
         };
         // i used an 'int range' for Color to clamp the range of the bytes from 0-255. to replace
         // that i'll create a 'Byte' numeric
@@ -73,6 +76,8 @@ impl Writer {
                 min: Some(Bound::Inclusive(0 as i64)),
                 max: Some(Bound::Inclusive(255 as i64)),
             },
+            total_digits: None,
+            fraction_digits: None,
         };
         numerics.push(byte);
         numerics.push(positive_integer);
@@ -91,25 +96,31 @@ impl Writer {
         let mut contents = String::new();
         let mut nothing = HashMap::<String, String>::new();
 
-        let builtins = render(INTEGER_BUILTINS_H, &nothing)?;
+        let builtins = self.templates.render(INTEGER_BUILTINS_H, &nothing)?;
         contents.push_str(&builtins);
         for numeric in numerics {
             let mut data = HashMap::new();
             data.insert("classname", numeric.name.pascal().to_owned());
             data.insert("documentation", documentation(document_int(numeric), 2)?);
-            let rendered_type = render(INTEGER_TYPE_H, &data)?;
+            let rendered_type = self.templates.render(INTEGER_TYPE_H, &data)?;
             contents.push('\n');
             contents.push('\n');
             contents.push_str(&rendered_type);
         }
-        let file_contents =
-            render_core_h(contents, None, Some(&mut ["iostream", "string", "limits"]))?;
-        wrap!(std::fs::write(&self.paths.integers_h, file_contents))?;
+        let file_contents = render_core_h(
+            &self.templates,
+            contents,
+            None,
+            Some(&mut ["iostream", "string", "limits"]),
+        )?;
+        self.write_named_file(&self.output.paths.integers_h, file_contents)?;
         Ok(())
     }
 
     fn write_integers_cpp(&self, numerics: &[NumericData<i64>]) -> Result<()> {
-        let mut contents = render(INTEGER_BUILTINS_CPP, NO_DATA.deref())?;
+        let mut contents = self
+            .templates
+            .render(INTEGER_BUILTINS_CPP, NO_DATA.deref())?;
         for (i, numeric) in numerics.iter().enumerate() {
             let (min, max) = min_max_ints(numeric);
             let classname = numeric.name.pascal();
@@ -118,17 +129,18 @@ impl Writer {
             data.insert("max_val", max);
             data.insert("classname", classname.to_owned());
             let mut rendered = String::from("\n\n");
-            rendered.push_str(render(INTEGER_TYPE_CPP, &data)?.as_str());
+            rendered.push_str(self.templates.render(INTEGER_TYPE_CPP, &data)?.as_str());
             if i < numerics.len() - 1 {}
             contents.push_str(&rendered);
         }
         let file_contents = render_core_cpp(
+            &self.templates,
             contents,
             Some("mx/core/Integers.h"),
             None,
             Some(&mut ["sstream"]),
         )?;
-        wrap!(std::fs::write(&self.paths.integers_cpp, file_contents))?;
+        self.write_named_file(&self.output.paths.integers_cpp, file_contents)?;
         Ok(())
     }
 
@@ -143,6 +155,8 @@ impl Writer {
                 min: Some(Bound::Exclusive(0 as f64)),
                 max: None,
             },
+            total_digits: None,
+            fraction_digits: None,
         };
         numerics.push(positive_divisions_value);
         numerics.sort_by(|a, b| {
@@ -159,7 +173,7 @@ impl Writer {
         let mut contents = String::new();
         let mut nothing = HashMap::<String, String>::new();
 
-        let builtins = render(DECIMAL_BUILTINS_H, &nothing)?;
+        let builtins = self.templates.render(DECIMAL_BUILTINS_H, &nothing)?;
         contents.push_str(&builtins);
         for numeric in numerics {
             let mut data = HashMap::new();
@@ -168,22 +182,25 @@ impl Writer {
                 "documentation",
                 documentation(document_decimal(numeric), 2)?,
             );
-            let rendered_type = render(DECIMAL_TYPE_H, &data)?;
+            let rendered_type = self.templates.render(DECIMAL_TYPE_H, &data)?;
             contents.push('\n');
             contents.push('\n');
             contents.push_str(&rendered_type);
         }
         let file_contents = render_core_h(
+            &self.templates,
             contents,
             None,
             Some(&mut ["iostream", "string", "functional"]),
         )?;
-        wrap!(std::fs::write(&self.paths.decimals_h, file_contents))?;
+        self.write_named_file(&self.output.paths.decimals_h, file_contents)?;
         Ok(())
     }
 
     fn write_decimals_cpp(&self, numerics: &[NumericData<f64>]) -> Result<()> {
-        let mut contents = render(DECIMAL_BUILTINS_CPP, NO_DATA.deref())?;
+        let mut contents = self
+            .templates
+            .render(DECIMAL_BUILTINS_CPP, NO_DATA.deref())?;
         for (i, numeric) in numerics.iter().enumerate() {
             let (minexpr, maxexpr) = minmax_expr_decimal(numeric);
             let defaultval = defaultval(numeric);
@@ -194,17 +211,18 @@ impl Writer {
             data.insert("maxexpr", maxexpr);
             data.insert("defaultval", defaultval);
             let mut rendered = String::from("\n\n");
-            rendered.push_str(render(DECIMAL_TYPE_CPP, &data)?.as_str());
+            rendered.push_str(self.templates.render(DECIMAL_TYPE_CPP, &data)?.as_str());
             if i < numerics.len() - 1 {}
             contents.push_str(&rendered);
         }
         let file_contents = render_core_cpp(
+            &self.templates,
             contents,
             Some("mx/core/Decimals.h"),
             Some(&mut ["mx/core/UnusedParameter.h"]),
             Some(&mut ["sstream", "cmath"]),
         )?;
-        wrap!(std::fs::write(&self.paths.decimals_cpp, file_contents))?;
+        self.write_named_file(&self.output.paths.decimals_cpp, file_contents)?;
         Ok(())
     }
 }
@@ -258,7 +276,26 @@ fn minmax_expr_decimal(numeric: &NumericData<f64>) -> (String, String) {
             Bound::Exclusive(f) => format!("MXMAXEX( {} )", f),
         },
     };
-    (min, max)
+    (min, digits_expr_decimal(numeric, max))
+}
+
+/// Wraps the `max` clamp, which `DecimalRange` applies first, so that values are rounded to the
+/// `totalDigits` and `fractionDigits` facets before they are clamped. Rounding after clamping could
+/// move a value past an exclusive bound. `-1` means no limit.
+fn digits_expr_decimal(numeric: &NumericData<f64>, max: String) -> String {
+    if numeric.total_digits.is_none() && numeric.fraction_digits.is_none() {
+        return max;
+    }
+    let digits = |d: Option<u64>| {
+        d.map(|d| d.to_string())
+            .unwrap_or_else(|| String::from("-1"))
+    };
+    format!(
+        "MXDIGITS( {}, {}, {} )",
+        digits(numeric.total_digits),
+        digits(numeric.fraction_digits),
+        max
+    )
 }
 
 fn defaultval(numeric: &NumericData<f64>) -> String {
@@ -296,7 +333,13 @@ fn document_int(numeric: &NumericData<i64>) -> String {
 }
 
 fn document_decimal(numeric: &NumericData<f64>) -> String {
-    let range = describe_range_decimal(decimal_range_doc_strings(numeric));
+    let mut range = describe_range_decimal(decimal_range_doc_strings(numeric));
+    if let Some(total) = numeric.total_digits {
+        range.push_str(&format!(", totalDigits={}", total));
+    }
+    if let Some(fraction) = numeric.fraction_digits {
+        range.push_str(&format!(", fractionDigits={}", fraction));
+    }
     if numeric.documentation.is_empty() {
         range
     } else {
@@ -321,3 +364,29 @@ fn decimal_range_doc_strings(numeric: &NumericData<f64>) -> (String, String) {
     };
     (min, max)
 }
+
+#[test]
+fn decimal_digits_expr() {
+    let mut numeric = NumericData {
+        name: Symbol::new("tenths"),
+        base_type: Numeric::Decimal,
+        documentation: String::new(),
+        range: Range {
+            min: Some(Bound::Inclusive(0.0)),
+            max: None,
+        },
+        total_digits: None,
+        fraction_digits: None,
+    };
+    assert_eq!(minmax_expr_decimal(&numeric).0, "MXMININ( 0 )");
+    numeric.fraction_digits = Some(2);
+    let (min, max) = minmax_expr_decimal(&numeric);
+    assert_eq!(min, "MXMININ( 0 )");
+    assert_eq!(max, "MXDIGITS( -1, 2, MX_NOOP )");
+    numeric.range.max = Some(Bound::Exclusive(1.0));
+    assert_eq!(
+        minmax_expr_decimal(&numeric).1,
+        "MXDIGITS( -1, 2, MXMAXEX( 1 ) )"
+    );
+    assert!(document_decimal(&numeric).ends_with("fractionDigits=2"));
+}
@@ -0,0 +1,216 @@
+use crate::error::Result;
+use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
+use crate::generate::cpp::writer::Writer;
+use crate::generate::template::{STRING_CPP, STRING_H};
+use crate::model::scalar::ScalarString;
+use crate::utils::string_stuff::documentation;
+use crate::xsd::primitives::Character;
+use crate::xsd::restriction::WhiteSpace;
+use std::collections::HashMap;
+
+impl Writer {
+    /// Writes a class for each scalar string that does not have a custom implementation. The
+    /// generated `parse` function normalizes whitespace and enforces the length and pattern facets.
+    pub(crate) fn write_strings(&self, strings: &[&ScalarString]) -> Result<()> {
+        for &s in strings {
+            let mut data = HashMap::new();
+            data.insert("classname", s.name.pascal().to_owned());
+            data.insert(
+                "documentation",
+                documentation(document_string(s).as_str(), 2)?,
+            );
+            data.insert("normalize", normalize(s.normalization()));
+            data.insert("check_length", check_length(s));
+            data.insert("check_pattern", check_pattern(s)?);
+            let hcontents = self.templates.render(STRING_H, &data)?;
+            let cppcontents = self.templates.render(STRING_CPP, &data)?;
+            let mut std_h = vec!["string", "ostream"];
+            let h = render_core_h(&self.templates, hcontents, None, Some(std_h.as_mut_slice()))?;
+            let self_import = format!("mx/core/{}.h", s.name.pascal());
+            let mut std_cpp = vec!["algorithm"];
+            if s.pattern.is_some() {
+                std_cpp.push("regex");
+            }
+            let cpp = render_core_cpp(
+                &self.templates,
+                cppcontents,
+                Some(self_import),
+                None,
+                Some(std_cpp.as_mut_slice()),
+            )?;
+            self.output.write(format!("{}.h", s.name.pascal()), h)?;
+            self.output.write(format!("{}.cpp", s.name.pascal()), cpp)?;
+        }
+        Ok(())
+    }
+}
+
+fn document_string(s: &ScalarString) -> String {
+    let mut facets = vec![format!("whiteSpace={}", s.normalization())];
+    if let Some(min) = s.min_length {
+        facets.push(format!("minLength={}", min));
+    }
+    if let Some(max) = s.max_length {
+        facets.push(format!("maxLength={}", max));
+    }
+    if let Some(pattern) = &s.pattern {
+        facets.push(format!("pattern={}", pattern));
+    }
+    let facets = format!("Facets: {}", facets.join(", "));
+    if s.documentation.is_empty() {
+        facets
+    } else {
+        format!("{}\n\n{}", s.documentation, facets)
+    }
+}
+
+/// Produces the C++ statements that copy `value` into `normalized`.
+fn normalize(white_space: WhiteSpace) -> String {
+    let mut s = String::new();
+    match white_space {
+        WhiteSpace::Preserve => {
+            s.push_str("            std::string normalized = value;");
+        }
+        WhiteSpace::Replace => {
+            s.push_str("            std::string normalized = value;\n");
+            s.push_str("            std::replace_if( normalized.begin(), normalized.end(), []( char c ){ return c == '\\t' || c == '\\n' || c == '\\r'; }, ' ' );");
+        }
+        WhiteSpace::Collapse => {
+            s.push_str("            std::string normalized;\n");
+            s.push_str("            bool isSpace = false;\n");
+            s.push_str("            for( const char c : value )\n");
+            s.push_str("            {\n");
+            s.push_str(
+                "                if( c == ' ' || c == '\\t' || c == '\\n' || c == '\\r' )\n",
+            );
+            s.push_str("                {\n");
+            s.push_str("                    isSpace = true;\n");
+            s.push_str("                    continue;\n");
+            s.push_str("                }\n");
+            s.push_str("                if( isSpace && !normalized.empty() )\n");
+            s.push_str("                {\n");
+            s.push_str("                    normalized.push_back( ' ' );\n");
+            s.push_str("                }\n");
+            s.push_str("                isSpace = false;\n");
+            s.push_str("                normalized.push_back( c );\n");
+            s.push_str("            }");
+        }
+    }
+    s
+}
+
+/// Produces the C++ statements that return false when `normalized` violates the length facets.
+/// The length is counted in characters, i.e. UTF-8 continuation bytes are not counted.
+fn check_length(s: &ScalarString) -> String {
+    let mut conditions = Vec::new();
+    if let Some(min) = s.min_length {
+        conditions.push(format!("length < {}", min));
+    }
+    if let Some(max) = s.max_length {
+        conditions.push(format!("length > {}", max));
+    }
+    if conditions.is_empty() {
+        return String::new();
+    }
+    let mut result = String::new();
+    result.push_str("            const auto length = std::count_if( normalized.cbegin(), normalized.cend(), []( char c ){ return ( c & 0xC0 ) != 0x80; } );\n");
+    result.push_str(format!("            if( {} )\n", conditions.join(" || ")).as_str());
+    result.push_str("            {\n");
+    result.push_str("                return false;\n");
+    result.push_str("            }");
+    result
+}
+
+/// Produces the C++ statements that return false when `normalized` does not match the pattern.
+fn check_pattern(s: &ScalarString) -> Result<String> {
+    let pattern = match &s.pattern {
+        Some(pattern) => pattern,
+        None => return Ok(String::new()),
+    };
+    let mut result = String::new();
+    result.push_str(
+        format!(
+            "            static const std::regex pattern{{ R\"regex({})regex\" }};\n",
+            pattern.to_cpp()?
+        )
+        .as_str(),
+    );
+    result.push_str("            if( !std::regex_match( normalized, pattern ) )\n");
+    result.push_str("            {\n");
+    result.push_str("                return false;\n");
+    result.push_str("            }");
+    Ok(result)
+}
+
+#[test]
+fn normalize_by_base_type() {
+    use crate::model::symbol::Symbol;
+    let mut s = ScalarString {
+        name: Symbol::new("short-token"),
+        base_type: Character::Token,
+        max_length: Some(8),
+        ..Default::default()
+    };
+    assert_eq!(s.normalization(), WhiteSpace::Collapse);
+    assert!(check_length(&s).contains("if( length > 8 )"));
+    s.base_type = Character::String;
+    assert_eq!(s.normalization(), WhiteSpace::Preserve);
+    s.white_space = Some(WhiteSpace::Replace);
+    assert!(normalize(s.normalization()).contains("std::replace_if"));
+}
+
+#[test]
+fn check_pattern_test() {
+    use crate::model::symbol::Symbol;
+    use crate::xsd::pattern::Pattern;
+    let mut s = ScalarString {
+        name: Symbol::new("glyph"),
+        base_type: Character::Token,
+        ..Default::default()
+    };
+    assert!(check_pattern(&s).unwrap().is_empty());
+    s.pattern = Some(Pattern::new("coda\\c*").unwrap());
+    let got = check_pattern(&s).unwrap();
+    assert!(got.contains(r#"static const std::regex pattern{ R"regex(coda(?:["#));
+    assert!(got.contains("if( !std::regex_match( normalized, pattern ) )"));
+    assert!(document_string(&s).contains("pattern=coda\\c*"));
+}
+
+/// `color`, `time-only` and `ending-number` are written by hand, see `custom_scalar_strings`, so
+/// `check_pattern` does not run for them. The `ColorPattern`, `TimeOnlyPattern` and
+/// `EndingNumberPattern` tests in `mxtest/core` check the hand-written classes against the regexes
+/// that `check_pattern` produces for their patterns, which are these.
+#[test]
+fn custom_string_patterns_test() {
+    use crate::generate::paths::{Paths, SchemaVersion};
+    use crate::model::creator::Creator;
+    use crate::model::{Def, Shape};
+    use crate::xsd::Xsd;
+    let want = [
+        (
+            "color",
+            r#"R"regex(#[0-9A-F]{6}(?:[0-9A-F][0-9A-F])?)regex""#,
+        ),
+        (
+            "time-only",
+            r#"R"regex([1-9][0-9]*(?:, ?[1-9][0-9]*)*)regex""#,
+        ),
+        (
+            "ending-number",
+            r#"R"regex((?:[\x20]*)|(?:[1-9][0-9]*(?:, ?[1-9][0-9]*)*))regex""#,
+        ),
+    ];
+    let paths = Paths::default();
+    for &version in &[SchemaVersion::V3_0, SchemaVersion::V3_1] {
+        let xsd = Xsd::load(paths.xsd(version)).unwrap();
+        let model = Creator::default().create(&xsd).unwrap();
+        for (name, regex) in &want {
+            let s = match model.get(Shape::Simple, name) {
+                Some(Def::ScalarString(s)) => s,
+                other => panic!("expected ScalarString '{}', got {:?}", name, other),
+            };
+            let got = check_pattern(s).unwrap();
+            assert!(got.contains(regex), "{} {}: {}", version, name, got);
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::error::{Error, Result};
 use crate::generate::cpp::cpp_template::{render_core_cpp, render_core_h};
 use crate::generate::cpp::helpers::{default_construct, enum_default};
+use crate::generate::cpp::write_enums::render_enum;
 use crate::generate::cpp::writer::Writer;
 use crate::generate::template::{
-    render, CORE_H, DECIMAL_BUILTINS_CPP, DECIMAL_BUILTINS_H, DECIMAL_TYPE_CPP, DECIMAL_TYPE_H,
+    Templates, CORE_H, DECIMAL_BUILTINS_CPP, DECIMAL_BUILTINS_H, DECIMAL_TYPE_CPP, DECIMAL_TYPE_H,
     INTEGER_BUILTINS_CPP, INTEGER_BUILTINS_H, INTEGER_TYPE_CPP, INTEGER_TYPE_H, NO_DATA,
     NUMBER_OR_NORMAL_CPP, NUMBER_OR_NORMAL_H, POSITIVE_INTEGER_OR_EMPTY_CPP,
     POSITIVE_INTEGER_OR_EMPTY_H, UNION_CPP, UNION_H,
@@ -19,7 +20,6 @@ use crate::xsd::primitives::{BaseType, PrefixedString};
 use indexmap::set::IndexSet;
 use indexmap::Equivalent;
 use std::collections::HashMap;
-use std::fs::{write, OpenOptions};
 use std::io::Write;
 use std::ops::Deref;
 
@@ -69,6 +69,8 @@ impl<'a> Info<'a> {
         let mut map = HashMap::new();
         for &def in self.named_members {
             match def {
+                // in-line members are written in the union's own files.
+                _ if self.union.inline_member(def.name().original()).is_some() => {}
                 Def::Enumeration(e) => {
                     let _ = map.insert("mx/core/Enums.h".to_owned(), ());
                 }
@@ -92,6 +94,11 @@ impl<'a> Info<'a> {
                 Def::UnionSimpleType(s) => {
                     let _ = map.insert(format!("mx/core/{}.h", s.name.pascal()), ());
                 }
+                Def::ListSimpleType(s) => {
+                    let _ = map.insert(format!("mx/core/{}.h", s.name.pascal()), ());
+                }
+                // complex types, elements and groups cannot be members of a union
+                Def::ComplexType(_) | Def::Element(_) | Def::AttributeGroup(_) | Def::Group(_) => {}
             }
         }
         for (_, &include) in self.primitive_members {
@@ -116,7 +123,10 @@ impl Writer {
             for member in &union.members {
                 match member {
                     BaseType::Custom(name) => {
-                        let found = match self.model.get(Shape::Simple, name) {
+                        let found = match union
+                            .inline_member(name)
+                            .or_else(|| self.model.get(Shape::Simple, name))
+                        {
                             None => {
                                 return raise!(
                                     "the dependency '{}' could not be found for union '{}'",
@@ -185,14 +195,18 @@ impl Writer {
                 continue;
             }
 
-            let hpath = self.paths.core.join(format!("{}.h", union.name.pascal()));
-            let cpppath = self.paths.core.join(format!("{}.cpp", union.name.pascal()));
-            let hcontents = render(UNION_H, &data)?;
-            let cppcontents = render(UNION_CPP, &data)?;
+            let (inline_h, inline_cpp) = inline_members(&self.templates, union)?;
+            let hcontents = format!("{}{}", inline_h, self.templates.render(UNION_H, &data)?);
+            let cppcontents = format!("{}{}", inline_cpp, self.templates.render(UNION_CPP, &data)?);
             let mut h_includes = info.h_includes();
-            let mut h_strs: Vec<&str> = h_includes.iter().map(|s| s.as_str()).collect();
             let mut std_h = vec!["string", "variant", "ostream"];
+            if !union.inline_members.is_empty() {
+                h_includes.push("mx/core/EnumsBuiltin.h".to_owned());
+                std_h.push("optional");
+            }
+            let mut h_strs: Vec<&str> = h_includes.iter().map(|s| s.as_str()).collect();
             let h = render_core_h(
+                &self.templates,
                 hcontents,
                 Some(h_strs.as_mut_slice()),
                 Some(std_h.as_mut_slice()),
@@ -200,13 +214,15 @@ impl Writer {
             let self_import = format!("mx/core/{}.h", union.name.pascal());
             let mut std_cpp = vec!["sstream", "type_traits"];
             let cpp = render_core_cpp(
+                &self.templates,
                 cppcontents,
                 Some(self_import),
                 None,
                 Some(std_cpp.as_mut_slice()),
             )?;
-            wrap!(write(hpath, h))?;
-            wrap!(write(cpppath, cpp))?;
+            self.output.write(format!("{}.h", union.name.pascal()), h)?;
+            self.output
+                .write(format!("{}.cpp", union.name.pascal()), cpp)?;
         }
         Ok(())
     }
@@ -250,17 +266,10 @@ impl Writer {
         let filename_cpp = format!("{}.cpp", union.name.pascal());
         let template_h = format!("{}.template", filename_h);
         let template_cpp = format!("{}.template", filename_cpp);
-        let contents_h = render(template_h, &data)?;
-        wrap!(std::fs::write(
-            self.paths.core.join(&filename_h),
-            contents_h
-        ));
-        let contents_cpp = render(template_cpp, &data)?;
-        wrap!(std::fs::write(
-            self.paths.core.join(&filename_cpp),
-            contents_cpp
-        ));
-        Ok(())
+        let contents_h = self.templates.render(template_h, &data)?;
+        self.output.write(&filename_h, contents_h)?;
+        let contents_cpp = self.templates.render(template_cpp, &data)?;
+        self.output.write(&filename_cpp, contents_cpp)
     }
 
     fn custom_unions(&self, union: &UnionData, data: &mut HashMap<&str, String>) -> Result<()> {
@@ -280,9 +289,10 @@ impl Writer {
             } else {
                 return raise!("unexpected type '{}'", union.name.original());
             };
-        let hstuff = render(hfilename, data)?;
-        let cstuff = render(cppfilename, data)?;
+        let hstuff = self.templates.render(hfilename, data)?;
+        let cstuff = self.templates.render(cppfilename, data)?;
         let h = render_core_h(
+            &self.templates,
             hstuff,
             Some(includes.as_mut_slice()),
             Some(vec!["string", "memory", "iostream"].as_mut_slice()),
@@ -290,23 +300,41 @@ impl Writer {
         let self_include = format!("mx/core/{}.h", union.name.pascal());
         let mut c_include = vec!["sstream"];
         let c = render_core_cpp(
+            &self.templates,
             cstuff,
             Some(&self_include),
             None,
             Some(c_include.as_mut_slice()),
         )?;
-        wrap!(write(
-            self.paths.core.join(format!("{}.h", union.name.pascal())),
-            h
-        ))?;
-        wrap!(write(
-            self.paths.core.join(format!("{}.cpp", union.name.pascal())),
-            c
-        ))?;
-        Ok(())
+        self.output.write(format!("{}.h", union.name.pascal()), h)?;
+        self.output.write(format!("{}.cpp", union.name.pascal()), c)
     }
 }
 
+/// Renders the declarations and definitions of the in-line members of `union`, which are written
+/// ahead of the union class in its files. Only enumerations are supported.
+fn inline_members(templates: &Templates, union: &UnionData) -> Result<(String, String)> {
+    let mut h = String::new();
+    let mut cpp = String::new();
+    for def in &union.inline_members {
+        let (enum_h, enum_cpp) = match def {
+            Def::Enumeration(e) => render_enum(templates, e)?,
+            other => {
+                return raise!(
+                    "unsupported in-line member '{}' of union '{}'",
+                    other.name().original(),
+                    union.name.original()
+                )
+            }
+        };
+        h.push_str(&enum_h);
+        h.push_str("\n\n");
+        cpp.push_str(&enum_cpp);
+        cpp.push_str("\n\n");
+    }
+    Ok((h, cpp))
+}
+
 fn parse_def(i: &Info<'_>) -> Result<String> {
     let mut s = String::new();
     for m in &i.union.members {
@@ -587,3 +615,47 @@ fn variants_to_stream_decl(i: &Info<'_>) -> String {
     }
     s
 }
+
+#[test]
+fn inline_members_test() {
+    use crate::model::creator::Creator;
+    use crate::xsd::Xsd;
+    let xsd = Xsd::parse_str(
+        r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="tempo-or-text">
+        <xs:union memberTypes="xs:decimal">
+            <xs:simpleType>
+                <xs:restriction base="xs:token">
+                    <xs:enumeration value="fast"/>
+                    <xs:enumeration value="slow"/>
+                </xs:restriction>
+            </xs:simpleType>
+        </xs:union>
+    </xs:simpleType>
+</xs:schema>"#,
+    )
+    .unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    assert!(model.get(Shape::Simple, "tempo-or-text-member-1").is_none());
+    let union = match model.get(Shape::Simple, "tempo-or-text") {
+        Some(Def::UnionSimpleType(u)) => u,
+        other => panic!("expected UnionSimpleType, got {:?}", other),
+    };
+    let tmp = tempfile::tempdir().unwrap();
+    let output = crate::generate::backend::Output::new(
+        crate::generate::paths::SchemaVersion::V3_0,
+        crate::generate::paths::Paths::default(),
+        tmp.path(),
+    );
+    let templates = crate::generate::template::Templates::embedded().unwrap();
+    let writer = Writer::new(model.clone(), output, templates);
+    writer.write_unions(&[union]).unwrap();
+    let h = std::fs::read_to_string(tmp.path().join("TempoOrText.h")).unwrap();
+    assert!(h.contains("#include \"mx/core/EnumsBuiltin.h\""));
+    assert!(!h.contains("#include \"mx/core/Enums.h\""));
+    assert!(h.contains("        enum class TempoOrTextMember1\n"));
+    assert!(h.contains("            explicit TempoOrText( TempoOrTextMember1 value );"));
+    let cpp = std::fs::read_to_string(tmp.path().join("TempoOrText.cpp")).unwrap();
+    assert!(cpp.contains("tryParseTempoOrTextMember1( const std::string& value )"));
+    assert!(cpp.contains("const auto tempoOrTextMember1 = tryParseTempoOrTextMember1( value );"));
+}
@@ -1,22 +1,28 @@
 use crate::error::Result;
+use crate::generate::backend::Output;
 use crate::generate::cpp::write_custom::{
-    write_color, write_comma_separated_text, write_ending_number, write_time_only,
+    write_color, write_comma_separated_text, write_ending_number, write_measure_text,
+    write_smufl_glyph_name, write_time_only,
 };
-use crate::generate::paths::Paths;
+use crate::generate::template::Templates;
 use crate::model::scalar::ScalarNumeric;
 use crate::model::{Def, Model};
+use std::path::Path;
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Writer {
     pub model: Model,
-    pub paths: Paths,
+    /// Where the files are written. The hand-written classes are expected to be there already.
+    pub output: Output,
+    pub(crate) templates: Templates,
 }
 
 impl Writer {
-    pub fn new(model: Model) -> Self {
+    pub(crate) fn new(model: Model, output: Output, templates: Templates) -> Self {
         Self {
             model,
-            paths: Paths::default(),
+            output,
+            templates,
         }
     }
 
@@ -25,41 +31,65 @@ impl Writer {
         let mut decimals = Vec::new();
         let mut integers = Vec::new();
         let mut unions = Vec::new();
+        let mut lists = Vec::new();
+        let mut aliases = Vec::new();
+        let mut strings = Vec::new();
+        let mut complex_types = Vec::new();
+        let mut elements = Vec::new();
         for model in self.model.defs() {
             match model {
                 Def::Enumeration(e) => enums.push(e),
-                Def::ScalarString(s) => {
-                    // We handle all of the scalar strings that we know of in musicxml.xsd with
-                    // custom implementations, thus there is currently no 'generic' implementation.
-                    return raise!(
-                        "Encountered ScalarString '{}', no handler available.",
-                        s.name.original()
-                    );
-                }
+                // The scalar strings that we know of in musicxml.xsd have custom implementations,
+                // any others get a generic class that enforces their facets.
+                Def::ScalarString(s) => strings.push(s),
                 Def::CustomScalarString(cs) => match cs.name.original() {
-                    "color" => write_color(cs, &self.paths)?,
-                    "comma-separated-text" => write_comma_separated_text(cs, &self.paths)?,
-                    "time-only" => write_time_only(cs, &self.paths)?,
-                    "ending-number" => write_ending_number(cs, &self.paths)?,
+                    "color" => write_color(cs, &self.output, &self.templates)?,
+                    "comma-separated-text" => {
+                        write_comma_separated_text(cs, &self.output, &self.templates)?
+                    }
+                    "time-only" => write_time_only(cs, &self.output, &self.templates)?,
+                    "ending-number" => write_ending_number(cs, &self.output, &self.templates)?,
+                    "measure-text" => write_measure_text(cs, &self.output, &self.templates)?,
+                    "smufl-glyph-name" => {
+                        write_smufl_glyph_name(cs, &self.output, &self.templates)?
+                    }
                     unhandled => return raise!("Unhandled CustomScalarString '{}'", unhandled),
                 },
                 Def::ScalarNumber(sn) => match sn {
                     ScalarNumeric::Decimal(d) => decimals.push(d.to_owned()),
                     ScalarNumeric::Integer(i) => integers.push(i.to_owned()),
                 },
-                Def::DerivedSimpleType(derived_from) => {
-                    return raise!(
-                        "DerivedSimpleTypes not implemented: '{}'",
-                        derived_from.name.original()
-                    )
-                }
+                Def::DerivedSimpleType(d) => aliases.push(d),
                 Def::UnionSimpleType(u) => unions.push(u),
+                Def::ListSimpleType(l) => lists.push(l),
+                // TODO - generate the mx::core element classes from complex types, for now only
+                // their content models are written.
+                Def::ComplexType(ct) => complex_types.push(ct),
+                // TODO - generate document-root readers and writers from elements, for now only
+                // their content models are written.
+                Def::Element(e) => elements.push(e),
+                // attribute groups are expanded into the complex types that reference them.
+                Def::AttributeGroup(_) => {}
+                // TODO - generate the mx::core group classes, see `Model::flatten`.
+                Def::Group(_) => {}
             }
         }
         self.write_enums(&mut enums)?;
         self.write_integers(integers)?;
         self.write_decimals(decimals)?;
         self.write_unions(unions.as_slice())?;
+        self.write_lists(lists.as_slice())?;
+        self.write_aliases(aliases.as_slice())?;
+        self.write_strings(strings.as_slice())?;
+        self.write_content_models(&complex_types, &elements)?;
         Ok(())
     }
+
+    /// Writes one of the files that `Paths` names, e.g. `enums_h`, to the output directory.
+    pub(crate) fn write_named_file<C: AsRef<[u8]>>(&self, path: &Path, contents: C) -> Result<()> {
+        match path.file_name() {
+            Some(filename) => self.output.write(filename, contents),
+            None => raise!("'{}' does not name a file", path.display()),
+        }
+    }
 }
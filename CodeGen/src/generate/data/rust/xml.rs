@@ -0,0 +1,528 @@
+// Generated by mxgen from musicxml.xsd, do not edit.
+
+//! A small XML document model, with just enough of a parser and writer for MusicXML.
+
+use crate::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+/// Reads a generated type from the element that holds it.
+pub(crate) trait FromXml: Sized {
+    fn from_xml(element: &Element) -> Result<Self, Error>;
+}
+
+/// Writes a generated type as an element named `name`.
+pub(crate) trait ToXml {
+    fn to_xml(&self, name: &str) -> Element;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub nodes: Vec<Node>,
+}
+
+impl Element {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            attributes: Vec::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parses the attribute `name` if it is present.
+    pub fn parse_attribute<T>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        match self.attribute(name) {
+            None => Ok(None),
+            Some(value) => value.parse().map(Some).map_err(Into::into),
+        }
+    }
+
+    /// Parses the attribute `name`, which is an error if it is not present.
+    pub fn require_attribute<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Into<Error>,
+    {
+        match self.parse_attribute(name)? {
+            Some(value) => Ok(value),
+            None => Err(Error::new(
+                self.name.as_str(),
+                "",
+                format!("the '{}' attribute is required", name),
+            )),
+        }
+    }
+
+    pub fn set_attribute<T: Display>(&mut self, name: &str, value: &T) {
+        self.attributes.push((name.to_owned(), value.to_string()));
+    }
+
+    pub fn push_element(&mut self, element: Element) {
+        self.nodes.push(Node::Element(element));
+    }
+
+    pub fn push_text<S: Into<String>>(&mut self, text: S) {
+        self.nodes.push(Node::Text(text.into()));
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.nodes.iter().filter_map(|node| match node {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// The text of the element, i.e. all of its text nodes joined together.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for node in &self.nodes {
+            if let Node::Text(s) = node {
+                text.push_str(s);
+            }
+        }
+        text
+    }
+
+    fn write(&self, out: &mut String, depth: usize) {
+        let _ = write!(out, "<{}", self.name);
+        for (key, value) in &self.attributes {
+            let _ = write!(out, " {}=\"{}\"", key, escape(value, true));
+        }
+        if self.nodes.is_empty() {
+            out.push_str("/>");
+            return;
+        }
+        out.push('>');
+        let has_text = self.nodes.iter().any(|node| matches!(node, Node::Text(_)));
+        for node in &self.nodes {
+            match node {
+                Node::Text(s) => out.push_str(&escape(s, false)),
+                Node::Element(element) => {
+                    if !has_text {
+                        newline(out, depth + 1);
+                    }
+                    element.write(out, depth + 1);
+                }
+            }
+        }
+        if !has_text {
+            newline(out, depth);
+        }
+        let _ = write!(out, "</{}>", self.name);
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = String::new();
+        self.write(&mut s, 0);
+        f.write_str(&s)
+    }
+}
+
+fn newline(out: &mut String, depth: usize) {
+    out.push('\n');
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn escape(s: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            '\n' if is_attribute => escaped.push_str("&#10;"),
+            '\t' if is_attribute => escaped.push_str("&#9;"),
+            '\r' => escaped.push_str("&#13;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Parses the text of `element` as a `T`.
+pub(crate) fn parse_text<T>(element: &Element) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    element.text().parse().map_err(Into::into)
+}
+
+/// An element named `name` that holds `value` as its text.
+pub(crate) fn text_element<T: Display>(value: &T, name: &str) -> Element {
+    let mut element = Element::new(name);
+    let text = value.to_string();
+    if !text.is_empty() {
+        element.push_text(text);
+    }
+    element
+}
+
+/// The child elements of an element, which are consumed in order as a content model is read.
+pub(crate) struct Children<'a> {
+    parent: &'a Element,
+    elements: Vec<&'a Element>,
+    position: usize,
+}
+
+impl<'a> Children<'a> {
+    pub fn new(parent: &'a Element) -> Self {
+        Self {
+            parent,
+            elements: parent.elements().collect(),
+            position: 0,
+        }
+    }
+
+    /// The name of the next child.
+    pub fn peek(&self) -> Option<&'a str> {
+        self.elements
+            .get(self.position)
+            .map(|element| element.name.as_str())
+    }
+
+    pub fn is_next(&self, names: &[&str]) -> bool {
+        matches!(self.peek(), Some(name) if names.contains(&name))
+    }
+
+    /// The error for a child that is not allowed where it is, or for a missing child.
+    pub fn unexpected(&self, expected: &[&str]) -> Error {
+        let message = format!("expected one of: {}", expected.join(", "));
+        match self.peek() {
+            Some(name) => Error::new(self.parent.name.as_str(), name, message),
+            None => Error::new(self.parent.name.as_str(), "", message),
+        }
+    }
+
+    /// Reads the next child, which must be named `name`.
+    pub fn one<T, F>(&mut self, name: &str, read: F) -> Result<T, Error>
+    where
+        F: Fn(&Element) -> Result<T, Error>,
+    {
+        if !self.is_next(&[name]) {
+            return Err(self.unexpected(&[name]));
+        }
+        let element = self.elements[self.position];
+        self.position += 1;
+        read(element)
+    }
+
+    /// Reads the next child if it is named `name`.
+    pub fn optional<T, F>(&mut self, name: &str, read: F) -> Result<Option<T>, Error>
+    where
+        F: Fn(&Element) -> Result<T, Error>,
+    {
+        if self.is_next(&[name]) {
+            self.one(name, read).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads up to `max` consecutive children that are named `name`, there must be at least `min`.
+    pub fn repeated<T, F>(
+        &mut self,
+        name: &str,
+        min: usize,
+        max: Option<usize>,
+        read: F,
+    ) -> Result<Vec<T>, Error>
+    where
+        F: Fn(&Element) -> Result<T, Error>,
+    {
+        self.repeated_group(&[name], min, max, |children| children.one(name, &read))
+    }
+
+    /// Reads a sequence or choice if the next child can begin it.
+    pub fn optional_group<T, F>(&mut self, first: &[&str], read: F) -> Result<Option<T>, Error>
+    where
+        F: Fn(&mut Children<'a>) -> Result<T, Error>,
+    {
+        if self.is_next(first) {
+            read(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a sequence or choice up to `max` times, for as long as the next child can begin it.
+    pub fn repeated_group<T, F>(
+        &mut self,
+        first: &[&str],
+        min: usize,
+        max: Option<usize>,
+        read: F,
+    ) -> Result<Vec<T>, Error>
+    where
+        F: Fn(&mut Children<'a>) -> Result<T, Error>,
+    {
+        let mut values = Vec::new();
+        while !matches!(max, Some(max) if values.len() >= max) && self.is_next(first) {
+            let position = self.position;
+            values.push(read(self)?);
+            if self.position == position {
+                // the group matched nothing, reading it again would not either.
+                break;
+            }
+        }
+        if values.len() < min {
+            return Err(self.unexpected(first));
+        }
+        Ok(values)
+    }
+
+    /// Checks that every child has been read.
+    pub fn end(&self) -> Result<(), Error> {
+        match self.peek() {
+            None => Ok(()),
+            Some(name) => Err(Error::new(
+                self.parent.name.as_str(),
+                name,
+                "the element is not allowed here",
+            )),
+        }
+    }
+}
+
+/// Parses `xml`, returning its root element. The prolog, comments and processing instructions
+/// are skipped.
+pub(crate) fn parse(xml: &str) -> Result<Element, Error> {
+    // a byte order mark is not part of the document.
+    let xml = xml.strip_prefix('\u{feff}').unwrap_or(xml);
+    let mut parser = Parser { xml, position: 0 };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if parser.position < xml.len() {
+        return Err(parser.error("content after the root element"));
+    }
+    Ok(root)
+}
+
+struct Parser<'a> {
+    xml: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.xml[self.position..]
+    }
+
+    fn error(&self, message: &str) -> Error {
+        let line = self.xml[..self.position].matches('\n').count() + 1;
+        let near: String = self.rest().chars().take(20).collect();
+        Error::new("xml", near, format!("{} on line {}", message, line))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips past the next `end`.
+    fn skip_past(&mut self, end: &str) -> Result<&'a str, Error> {
+        match self.rest().find(end) {
+            Some(i) => {
+                let skipped = &self.rest()[..i];
+                self.position += i + end.len();
+                Ok(skipped)
+            }
+            None => Err(self.error(&format!("expected '{}'", end))),
+        }
+    }
+
+    /// Skips whitespace, comments, processing instructions and the document type declaration.
+    fn skip_misc(&mut self) -> Result<(), Error> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<!DOCTYPE") {
+                self.skip_doctype()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_doctype(&mut self) -> Result<(), Error> {
+        let mut depth = 0;
+        let mut quote = None;
+        for (i, c) in self.rest().char_indices() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '"') | (None, '\'') => quote = Some(c),
+                (None, '[') => depth += 1,
+                (None, ']') => depth -= 1,
+                (None, '>') if depth == 0 => {
+                    self.position += i + 1;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unterminated document type declaration"))
+    }
+
+    fn name(&mut self) -> Result<&'a str, Error> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || "/>=".contains(c))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.position += end;
+        Ok(&rest[..end])
+    }
+
+    fn expect(&mut self, s: &str) -> Result<(), Error> {
+        if self.rest().starts_with(s) {
+            self.position += s.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", s)))
+        }
+    }
+
+    fn element(&mut self) -> Result<Element, Error> {
+        self.expect("<")?;
+        let mut element = Element::new(self.name()?);
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.position += 1;
+                break;
+            }
+            let key = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(q) if q == '"' || q == '\'' => q,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.position += 1;
+            let raw = self.skip_past(&quote.to_string())?;
+            let value = self.unescape(raw)?;
+            element.attributes.push((key.to_owned(), value));
+        }
+        let mut text = String::new();
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(&format!("'{}' is not closed", element.name)));
+            } else if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.name()?;
+                if name != element.name {
+                    return Err(self.error(&format!("expected '</{}>'", element.name)));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                break;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += "<![CDATA[".len();
+                text.push_str(self.skip_past("]]>")?);
+            } else if rest.starts_with('<') {
+                if !text.is_empty() {
+                    element.push_text(std::mem::take(&mut text));
+                }
+                let child = self.element()?;
+                element.push_element(child);
+            } else {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let raw = &rest[..end];
+                self.position += end;
+                text.push_str(&self.unescape(raw)?);
+            }
+        }
+        if !text.is_empty() {
+            element.push_text(text);
+        }
+        // whitespace between child elements is not text.
+        if element.elements().next().is_some() {
+            element.nodes.retain(|node| match node {
+                Node::Text(s) => !s.trim().is_empty(),
+                Node::Element(_) => true,
+            });
+        }
+        Ok(element)
+    }
+
+    fn unescape(&self, raw: &str) -> Result<String, Error> {
+        if !raw.contains('&') {
+            return Ok(raw.to_owned());
+        }
+        let mut s = String::with_capacity(raw.len());
+        let mut rest = raw;
+        while let Some(i) = rest.find('&') {
+            s.push_str(&rest[..i]);
+            rest = &rest[i + 1..];
+            let end = match rest.find(';') {
+                Some(end) => end,
+                None => return Err(self.error("unterminated entity reference")),
+            };
+            let entity = &rest[..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32),
+                _ if entity.starts_with('#') => {
+                    entity[1..].parse().ok().and_then(std::char::from_u32)
+                }
+                _ => None,
+            };
+            match c {
+                Some(c) => s.push(c),
+                None => return Err(self.error(&format!("unknown entity '&{};'", entity))),
+            }
+            rest = &rest[end + 1..];
+        }
+        s.push_str(rest);
+        Ok(s)
+    }
+}
@@ -1,45 +1,149 @@
+pub mod backend;
 mod compile_mx;
 pub mod cpp;
 pub mod paths;
+pub mod rust;
 mod template;
 
 use crate::error::Result;
+use crate::generate::backend::{Backend, Backends, Output};
 use crate::generate::cpp::modeler::MxModeler;
-use crate::generate::paths::Paths;
+use crate::generate::paths::{Paths, SchemaVersion};
 use crate::model::create::Create;
 use crate::model::creator::Creator;
 use crate::model::post_process::PostProcess;
 use crate::model::transform::Transform;
+use crate::model::Model;
 use crate::xsd::Xsd;
 use cpp::constants::enum_member_substitutions;
 use cpp::constants::{pseudo_enums, reserved_words, suffixed_enum_names};
-use std::fs::read_to_string;
+use std::fs::{read, read_dir};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct GenArgs {
     pub paths: Paths,
+    /// The schema versions to generate. Each is written to the backend's directory for it.
+    pub versions: Vec<SchemaVersion>,
+    /// The name of the backend to generate with, e.g. `cpp`, see `Backends`.
+    pub backend: String,
+    /// The directory to write to instead of the backend's default. Only one version can be
+    /// generated when this is given.
+    pub out_dir: Option<PathBuf>,
 }
 
 impl Default for GenArgs {
     fn default() -> Self {
         Self {
             paths: Paths::default(),
+            versions: vec![SchemaVersion::V3_0],
+            backend: String::from("cpp"),
+            out_dir: None,
         }
     }
 }
 
-/// Generate `mx::core` in C++
+/// Generate `mx::core` in C++, or whatever the selected backend writes.
 pub fn run(args: GenArgs) -> Result<()> {
-    let xsd = read_to_string(&args.paths.xsd_3_0).unwrap();
-    let doc = exile::parse(xsd.as_str()).unwrap();
-    let new_xsd = Xsd::load(&args.paths.xsd_3_0)?;
+    run_with_backends(args, &Backends::default())
+}
+
+/// Generate with the backend named by `args`, which is looked up in `backends`.
+pub fn run_with_backends(args: GenArgs, backends: &Backends) -> Result<()> {
+    let backend = validate(&args, backends)?;
+    for &version in &args.versions {
+        let dir = out_dir(&args, backend, version);
+        wrap!(
+            generate(backend, &Output::new(version, args.paths.clone(), dir)),
+            "unable to generate {} for musicxml {}",
+            backend.name(),
+            version
+        )?;
+    }
+    Ok(())
+}
+
+/// Generates into a temporary directory and compares the result with the files that are already
+/// in the output directories. Returns the files that are missing or out of date.
+pub fn check(args: GenArgs) -> Result<Vec<PathBuf>> {
+    check_with_backends(args, &Backends::default())
+}
+
+/// `check` with the backend named by `args`, which is looked up in `backends`.
+pub fn check_with_backends(args: GenArgs, backends: &Backends) -> Result<Vec<PathBuf>> {
+    let backend = validate(&args, backends)?;
+    let mut stale = Vec::new();
+    for &version in &args.versions {
+        let dir = out_dir(&args, backend, version);
+        // backends may treat an existing directory differently, so generate into a new one.
+        let tmp = wrap!(tempfile::tempdir())?;
+        let generated_dir = tmp.path().join("generated");
+        run_with_backends(
+            GenArgs {
+                versions: vec![version],
+                out_dir: Some(generated_dir.clone()),
+                ..args.clone()
+            },
+            backends,
+        )?;
+        for generated in list_files(&generated_dir)? {
+            let existing = match generated.strip_prefix(&generated_dir) {
+                Ok(relative) => dir.join(relative),
+                Err(e) => return raise!("unexpected file '{}': {}", generated.display(), e),
+            };
+            if !existing.is_file() || wrap!(read(&existing))? != wrap!(read(&generated))? {
+                stale.push(existing);
+            }
+        }
+    }
+    stale.sort();
+    Ok(stale)
+}
+
+fn validate<'a>(args: &GenArgs, backends: &'a Backends) -> Result<&'a dyn Backend> {
+    if args.versions.is_empty() {
+        return raise!("no schema versions were given");
+    }
+    if args.out_dir.is_some() && args.versions.len() > 1 {
+        return raise!("an output directory can only be given for one schema version");
+    }
+    backends.get(&args.backend)
+}
+
+fn out_dir(args: &GenArgs, backend: &dyn Backend, version: SchemaVersion) -> PathBuf {
+    match &args.out_dir {
+        Some(out_dir) => out_dir.clone(),
+        None => backend.default_dir(&args.paths, version),
+    }
+}
+
+/// The files under `dir`, recursively.
+fn list_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in wrap!(read_dir(dir), "unable to read dir '{}'", dir.display())? {
+        let path = wrap!(entry)?.path();
+        if path.is_dir() {
+            files.append(&mut list_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+fn generate(backend: &dyn Backend, output: &Output) -> Result<()> {
+    let model = create_model(output.paths.xsd(output.version))?;
+    backend.generate(model, output)
+}
+
+/// Loads the schema at `xsd` and models it the way `mx::core` does, so that every backend names
+/// things the same way.
+fn create_model(xsd: &Path) -> Result<Model> {
+    let xsd = Xsd::load(xsd)?;
     let transforms: Vec<Box<dyn Transform>> = vec![Box::new(MxModeler::new())];
     let creates: Vec<Box<dyn Create>> = vec![Box::new(MxModeler::new())];
     let post_processors: Vec<Box<dyn PostProcess>> = vec![Box::new(MxModeler::new())];
     let creator = Creator::new_with_default(Some(transforms), Some(creates), Some(post_processors));
-    let models = creator.create(&new_xsd)?;
-    let cpp_writer = cpp::writer::Writer::new(models);
-    cpp_writer.write_code()?;
-    Ok(())
+    creator.create(&xsd)
 }
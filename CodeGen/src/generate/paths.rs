@@ -1,10 +1,52 @@
-use std::fs::{remove_file, File, OpenOptions};
+use crate::error::Result;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
+/// The root of the repository checkout that mxgen was built from. An installed mxgen may be run
+/// where this does not exist, see `Paths::from_config`.
 pub fn repo() -> PathBuf {
     let mut p = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     p.pop();
-    p.canonicalize().unwrap()
+    p.canonicalize().unwrap_or(p)
+}
+
+/// The versions of musicxml.xsd that we can generate `mx::core` from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum SchemaVersion {
+    #[default]
+    V3_0,
+    V3_1,
+}
+
+impl SchemaVersion {
+    pub fn all() -> &'static [SchemaVersion] {
+        &[SchemaVersion::V3_0, SchemaVersion::V3_1]
+    }
+}
+
+impl Display for SchemaVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SchemaVersion::V3_0 => "3.0",
+            SchemaVersion::V3_1 => "3.1",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for SchemaVersion {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "3.0" => Ok(SchemaVersion::V3_0),
+            "3.1" => Ok(SchemaVersion::V3_1),
+            _ => raise!("unknown schema version '{}', expected '3.0' or '3.1'", s),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -19,14 +61,46 @@ pub struct Paths {
     pub decimals_cpp: PathBuf,
     pub xsd_3_0: PathBuf,
     pub xsd_3_1: PathBuf,
+    /// A directory of `*.template` files that replace the built-in templates of the same name.
+    pub templates: Option<PathBuf>,
 }
 
 impl Default for Paths {
     fn default() -> Self {
-        let r = repo();
+        Self::from_repo(repo())
+    }
+}
+
+/// The contents of a paths config file. Every entry is optional, relative paths are relative to
+/// the config file. The generated C++ files, e.g. `enums_h`, are given by name and are written to
+/// `core`, e.g.
+///
+/// ```json
+/// { "repo": "..", "xsd_3_1": "musicxml.xsd", "enums_h": "MxEnums.h", "templates": "templates" }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PathsConfig {
+    repo: Option<PathBuf>,
+    core: Option<PathBuf>,
+    enums_h: Option<PathBuf>,
+    enums_cpp: Option<PathBuf>,
+    integers_h: Option<PathBuf>,
+    integers_cpp: Option<PathBuf>,
+    decimals_h: Option<PathBuf>,
+    decimals_cpp: Option<PathBuf>,
+    xsd_3_0: Option<PathBuf>,
+    xsd_3_1: Option<PathBuf>,
+    templates: Option<PathBuf>,
+}
+
+impl Paths {
+    /// The paths of a repository checkout at `repo`.
+    pub fn from_repo<P: Into<PathBuf>>(repo: P) -> Self {
+        let r = repo.into();
         let core = r.join("Sourcecode").join("private").join("mx").join("core");
         Self {
-            repo: r,
+            repo: r.clone(),
             core: core.clone(),
             enums_h: core.join("Enums.h"),
             enums_cpp: core.join("Enums.cpp"),
@@ -34,28 +108,121 @@ impl Default for Paths {
             integers_cpp: core.join("Integers.cpp"),
             decimals_h: core.join("Decimals.h"),
             decimals_cpp: core.join("Decimals.cpp"),
-            xsd_3_0: repo().join("Documents").join("musicxml.xsd"),
-            xsd_3_1: repo().join("Documents").join("musicxml-3.1.xsd"),
+            xsd_3_0: r.join("Documents").join("musicxml.xsd"),
+            xsd_3_1: r.join("Documents").join("musicxml-3.1.xsd"),
+            templates: None,
         }
     }
-}
 
-fn open_file<P: AsRef<Path>>(p: P) -> File {
-    println!("opening a file for writing: '{}'", p.as_ref().display());
-    OpenOptions::new()
-        .read(true)
-        .write(true)
-        .create(true)
-        .open(p)
-        .unwrap() // TODO - error?
-}
+    /// Reads the paths from the JSON config file at `path`. Paths that the file does not give are
+    /// derived from `repo`, which defaults to the checkout that mxgen was built from.
+    pub fn from_config<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let json = wrap!(
+            read_to_string(path),
+            "unable to read config file '{}'",
+            path.display()
+        )?;
+        let config: PathsConfig = wrap!(
+            serde_json::from_str(&json),
+            "unable to parse config file '{}'",
+            path.display()
+        )?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let resolve = |p: Option<PathBuf>| p.map(|p| dir.join(p));
+        let mut paths = match resolve(config.repo) {
+            Some(repo) => Self::from_repo(repo),
+            None => Self::default(),
+        };
+        if let Some(core) = resolve(config.core) {
+            paths = paths.with_core(core);
+        }
+        // the generated files are always written to the core directory of a schema version, see
+        // `with_core`, so only their names can be configured.
+        let core = paths.core.clone();
+        let filenames = [
+            ("enums_h", config.enums_h, &mut paths.enums_h),
+            ("enums_cpp", config.enums_cpp, &mut paths.enums_cpp),
+            ("integers_h", config.integers_h, &mut paths.integers_h),
+            ("integers_cpp", config.integers_cpp, &mut paths.integers_cpp),
+            ("decimals_h", config.decimals_h, &mut paths.decimals_h),
+            ("decimals_cpp", config.decimals_cpp, &mut paths.decimals_cpp),
+        ];
+        for (key, configured, path) in filenames {
+            if let Some(configured) = configured {
+                if configured.file_name() != Some(configured.as_os_str()) {
+                    return raise!(
+                        "'{}' must be the name of a file in the core directory, not '{}'",
+                        key,
+                        configured.display()
+                    );
+                }
+                *path = core.join(configured);
+            }
+        }
+        for (configured, path) in [
+            (config.xsd_3_0, &mut paths.xsd_3_0),
+            (config.xsd_3_1, &mut paths.xsd_3_1),
+        ] {
+            if let Some(configured) = resolve(configured) {
+                *path = configured;
+            }
+        }
+        paths.templates = resolve(config.templates);
+        Ok(paths)
+    }
 
-impl Paths {
-    pub fn create_core_file<S: AsRef<str>>(&self, filename: S) -> File {
-        let p = self.core.join(filename.as_ref());
-        if p.is_file() {
-            remove_file(&p).unwrap();
+    /// The schema for `version`.
+    pub fn xsd(&self, version: SchemaVersion) -> &Path {
+        match version {
+            SchemaVersion::V3_0 => self.xsd_3_0.as_path(),
+            SchemaVersion::V3_1 => self.xsd_3_1.as_path(),
+        }
+    }
+
+    /// The paths to write `mx::core` to for `version`. 3.0 is written to `Sourcecode/private`,
+    /// other versions are written to a tree of their own, e.g. `Sourcecode/private-3.1`, so that
+    /// `#include "mx/core/Enums.h"` works with either tree on the include path.
+    pub fn for_version(&self, version: SchemaVersion) -> Paths {
+        match version {
+            SchemaVersion::V3_0 => self.clone(),
+            _ => self.with_core(
+                self.repo
+                    .join("Sourcecode")
+                    .join(format!("private-{}", version))
+                    .join("mx")
+                    .join("core"),
+            ),
+        }
+    }
+
+    /// A copy of these paths with `core`, and the files in it, moved to `core`. The files keep
+    /// their names.
+    pub fn with_core<P: Into<PathBuf>>(&self, core: P) -> Paths {
+        let core = core.into();
+        let moved = |p: &Path| match p.file_name() {
+            Some(filename) => core.join(filename),
+            None => core.clone(),
+        };
+        Paths {
+            enums_h: moved(&self.enums_h),
+            enums_cpp: moved(&self.enums_cpp),
+            integers_h: moved(&self.integers_h),
+            integers_cpp: moved(&self.integers_cpp),
+            decimals_h: moved(&self.decimals_h),
+            decimals_cpp: moved(&self.decimals_cpp),
+            core,
+            ..self.clone()
+        }
+    }
+
+    /// A copy of these paths with the schema for `version` at `xsd`.
+    pub fn with_xsd<P: Into<PathBuf>>(&self, version: SchemaVersion, xsd: P) -> Paths {
+        let mut paths = self.clone();
+        match version {
+            SchemaVersion::V3_0 => paths.xsd_3_0 = xsd.into(),
+            SchemaVersion::V3_1 => paths.xsd_3_1 = xsd.into(),
         }
-        open_file(p)
+        paths
     }
 }
@@ -0,0 +1,631 @@
+//! Lowers complex types, and the groups and compositors inside of them, to the structs and enums
+//! of the generated crate.
+
+use crate::error::Result;
+use crate::generate::rust::writer::Writer;
+use crate::model::complex_type::{
+    AttributeData, ComplexTypeData, Compositor, Content, ElementParticle, ElementType, Particle,
+};
+use crate::model::element::ElementData;
+use crate::model::symbol::Symbol;
+use crate::model::{Def, Shape};
+use crate::xsd::primitives::BaseType;
+use crate::xsd::Occurs;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// A struct or enum in the generated crate.
+#[derive(Debug, Clone)]
+pub(crate) struct TypeDef {
+    pub name: String,
+    pub documentation: String,
+    pub kind: Kind,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Kind {
+    /// A complex type, which is read from and written to an element.
+    Complex {
+        attributes: Vec<Attribute>,
+        /// The type of the text, for a complex type with simple content.
+        value: Option<String>,
+        fields: Vec<Field>,
+    },
+    /// A sequence of children, the fields are read and written in order.
+    Sequence(Vec<Field>),
+    /// A choice between children, each field is a variant.
+    Choice(Vec<Field>),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Attribute {
+    pub field: String,
+    pub documentation: String,
+    pub xml: String,
+    pub type_: String,
+    pub required: bool,
+}
+
+/// A struct field or enum variant. It is a `T`, `Option<T>` or `Vec<T>` depending on `occurs`.
+#[derive(Debug, Clone)]
+pub(crate) struct Field {
+    pub name: String,
+    pub documentation: String,
+    pub occurs: Occurs,
+    pub item: Item,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Item {
+    /// A child element.
+    Element {
+        xml: String,
+        type_: String,
+        /// True if the element only holds text of a simple type.
+        is_simple: bool,
+    },
+    /// A sequence or choice, which can begin with any of the children named in `first`.
+    Group {
+        type_: String,
+        first: Vec<String>,
+        /// True if the sequence or choice can be empty.
+        is_nullable: bool,
+    },
+}
+
+/// Names the types that are generated for the compositors in a complex type or group. The names
+/// depend only on the owner, so a group that is spliced into many types names things the same way
+/// each time.
+struct Namer {
+    owner: String,
+    counts: HashMap<&'static str, usize>,
+}
+
+impl Namer {
+    fn new<S: Into<String>>(owner: S) -> Self {
+        Self {
+            owner: owner.into(),
+            counts: HashMap::new(),
+        }
+    }
+
+    fn next(&mut self, kind: &'static str) -> String {
+        let count = self.counts.entry(kind).or_insert(0);
+        *count += 1;
+        match *count {
+            1 => format!("{}{}", self.owner, kind),
+            n => format!("{}{}{}", self.owner, kind, n),
+        }
+    }
+}
+
+/// Accumulates the generated types.
+pub(crate) struct Lowering<'a> {
+    writer: &'a Writer,
+    types: BTreeMap<String, TypeDef>,
+}
+
+impl<'a> Lowering<'a> {
+    pub fn new(writer: &'a Writer) -> Self {
+        Self {
+            writer,
+            types: BTreeMap::new(),
+        }
+    }
+
+    /// The generated types, sorted by name.
+    pub fn into_types(self) -> Vec<TypeDef> {
+        self.types.into_values().collect()
+    }
+
+    fn add(&mut self, t: TypeDef) {
+        self.types.entry(t.name.clone()).or_insert(t);
+    }
+
+    /// Adds the struct for a named complex type and returns its name.
+    pub fn complex_type(&mut self, ct: &ComplexTypeData) -> Result<String> {
+        let mut name = ct.name.pascal().to_owned();
+        // e.g. the string complex type would hide std::string::String.
+        if PRELUDE.contains(&name.as_str()) {
+            name.push_str("Type");
+        }
+        self.add_complex_type(&name, ct)?;
+        Ok(name)
+    }
+
+    /// Adds the struct for a complex type, which is named `name` because anonymous complex types
+    /// are named after the element that they belong to.
+    fn add_complex_type(&mut self, name: &str, ct: &ComplexTypeData) -> Result<()> {
+        if self.types.contains_key(name) {
+            return Ok(());
+        }
+        let content = self.content(ct)?.clone();
+        let mut fields = Vec::new();
+        let mut value = None;
+        match content {
+            Content::Empty => {}
+            Content::Simple(bt) => value = Some(self.simple_type(&bt)?),
+            Content::Particle(p) => {
+                let mut namer = Namer::new(name);
+                self.sequence_members(&mut namer, std::slice::from_ref(&p), &mut fields)?;
+            }
+            Content::Extension(_) => unreachable!(),
+        }
+        dedupe(&mut fields);
+        let mut attributes = Vec::new();
+        for a in &ct.all_attributes {
+            attributes.push(self.attribute(a)?);
+        }
+        for a in &mut attributes {
+            let collides = fields.iter().any(|f| f.name == a.field);
+            if collides || (value.is_some() && a.field == "value") {
+                a.field = format!("{}_attribute", a.field.trim_end_matches('_'));
+            }
+        }
+        self.add(TypeDef {
+            name: name.to_owned(),
+            documentation: ct.documentation.clone(),
+            kind: Kind::Complex {
+                attributes,
+                value,
+                fields,
+            },
+        });
+        Ok(())
+    }
+
+    /// Adds the type of a top-level element, i.e. a document root, and returns its name.
+    pub fn root(&mut self, e: &ElementData) -> Result<String> {
+        let particle = ElementParticle {
+            name: e.name.clone(),
+            type_: e.type_.clone(),
+            occurs: e.occurs,
+            documentation: e.documentation.clone(),
+        };
+        match self.element(&Namer::new(""), &particle, pascal)?.item {
+            Item::Element {
+                type_,
+                is_simple: false,
+                ..
+            } => Ok(type_),
+            _ => raise!("the root element '{}' has a simple type", e.name.original()),
+        }
+    }
+
+    /// The content of `ct`, or of its base type for an extension. In musicxml.xsd an extension
+    /// only adds attributes, which `all_attributes` already includes.
+    fn content<'c>(&'c self, ct: &'c ComplexTypeData) -> Result<&'c Content> {
+        let mut content = &ct.content;
+        while let Content::Extension(base) = content {
+            content = match self.writer.model.get(Shape::Complex, base.original()) {
+                Some(Def::ComplexType(base)) => &base.content,
+                _ => return raise!("base type '{}' not found", base.original()),
+            };
+        }
+        Ok(content)
+    }
+
+    fn attribute(&self, a: &AttributeData) -> Result<Attribute> {
+        Ok(Attribute {
+            field: field_name(a.name.original()),
+            documentation: a.documentation.clone(),
+            xml: a.name.original().to_owned(),
+            type_: self.simple_type(&a.type_)?,
+            required: a.required,
+        })
+    }
+
+    /// The Rust type of a simple type.
+    fn simple_type(&self, bt: &BaseType) -> Result<String> {
+        let s = match bt {
+            BaseType::Custom(name) => match self.writer.simple_def(name) {
+                Some(_) => return self.writer.type_name(name),
+                // e.g. xs:anyURI or the attributes in the xml and xlink namespaces.
+                None => "String",
+            },
+            BaseType::Decimal => "f64",
+            BaseType::Byte
+            | BaseType::Int
+            | BaseType::Integer
+            | BaseType::Long
+            | BaseType::NegativeInteger
+            | BaseType::NonPositiveInteger
+            | BaseType::Short => "i64",
+            BaseType::PositiveInteger => "PositiveInteger",
+            BaseType::NonNegativeInteger
+            | BaseType::UnsignedLong
+            | BaseType::UnsignedInt
+            | BaseType::UnsignedShort
+            | BaseType::UnsignedByte => "NonNegativeInteger",
+            _ => "String",
+        };
+        Ok(s.to_owned())
+    }
+
+    /// Adds the members of a sequence to `fields`. A member that occurs exactly once and is itself
+    /// a sequence, or a reference to a sequence group, is spliced in.
+    fn sequence_members(
+        &mut self,
+        namer: &mut Namer,
+        members: &[Particle],
+        fields: &mut Vec<Field>,
+    ) -> Result<()> {
+        for member in members {
+            let member = self.simplify(member)?;
+            match &member {
+                Particle::Sequence(c) if is_once(&c.occurs) => {
+                    self.sequence_members(namer, &c.members, fields)?
+                }
+                Particle::GroupRef(r) if is_once(&r.occurs) => {
+                    let group = self.group(r.name.original())?;
+                    match group {
+                        Particle::Sequence(c) if is_once(&c.occurs) => {
+                            let mut group_namer = Namer::new(self.group_type_name(&r.name));
+                            self.sequence_members(&mut group_namer, &c.members, fields)?
+                        }
+                        _ => fields.push(self.field(namer, &member)?),
+                    }
+                }
+                _ => fields.push(self.field(namer, &member)?),
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the members of a choice to `variants`. A member that occurs exactly once and is itself
+    /// an anonymous choice is spliced in.
+    fn choice_members(
+        &mut self,
+        namer: &mut Namer,
+        members: &[Particle],
+        variants: &mut Vec<Field>,
+    ) -> Result<()> {
+        for member in members {
+            let member = self.simplify(member)?;
+            match &member {
+                Particle::Choice(c) if is_once(&c.occurs) => {
+                    self.choice_members(namer, &c.members, variants)?
+                }
+                Particle::Element(e) => variants.push(self.element(namer, e, pascal)?),
+                Particle::Sequence(c) => {
+                    let name = match self.writer.model.flatten(&member)? {
+                        Particle::Sequence(flat) => variant_name(&flat),
+                        _ => variant_name(c),
+                    };
+                    let type_ = format!("{}{}", namer.owner, name);
+                    let mut fields = Vec::new();
+                    self.sequence_members(&mut Namer::new(&type_), &c.members, &mut fields)?;
+                    dedupe(&mut fields);
+                    self.add(TypeDef {
+                        name: type_.clone(),
+                        documentation: String::new(),
+                        kind: Kind::Sequence(fields),
+                    });
+                    variants.push(self.group_field(name, type_, c.occurs, &member)?);
+                }
+                _ => {
+                    let mut field = self.field(namer, &member)?;
+                    field.name = match &field.item {
+                        Item::Group { type_, .. } => {
+                            type_.trim_start_matches(namer.owner.as_str()).to_owned()
+                        }
+                        Item::Element { .. } => unreachable!(),
+                    };
+                    variants.push(field);
+                }
+            }
+        }
+        dedupe(&mut variants[..]);
+        Ok(())
+    }
+
+    /// The field for a member of a sequence that is not spliced in.
+    fn field(&mut self, namer: &mut Namer, member: &Particle) -> Result<Field> {
+        match member {
+            Particle::Element(e) => self.element(namer, e, field_name),
+            Particle::Sequence(c) => {
+                let type_ = namer.next("Sequence");
+                let mut fields = Vec::new();
+                self.sequence_members(&mut Namer::new(&type_), &c.members, &mut fields)?;
+                dedupe(&mut fields);
+                self.add(TypeDef {
+                    name: type_.clone(),
+                    documentation: String::new(),
+                    kind: Kind::Sequence(fields),
+                });
+                self.group_field("sequence".to_owned(), type_, c.occurs, member)
+            }
+            Particle::Choice(c) => {
+                let type_ = namer.next("Choice");
+                self.choice(&type_, c)?;
+                self.group_field("choice".to_owned(), type_, c.occurs, member)
+            }
+            Particle::GroupRef(r) => {
+                let type_ = self.group_type_name(&r.name);
+                if !self.types.contains_key(&type_) {
+                    let documentation = match self.writer.model.get(Shape::Group, r.name.original())
+                    {
+                        Some(Def::Group(g)) => g.documentation.clone(),
+                        _ => String::new(),
+                    };
+                    let kind = match self.group(r.name.original())? {
+                        Particle::Choice(c) if is_once(&c.occurs) => {
+                            let mut variants = Vec::new();
+                            self.choice_members(
+                                &mut Namer::new(&type_),
+                                &c.members,
+                                &mut variants,
+                            )?;
+                            Kind::Choice(variants)
+                        }
+                        group => {
+                            let mut fields = Vec::new();
+                            let members = std::slice::from_ref(&group);
+                            self.sequence_members(&mut Namer::new(&type_), members, &mut fields)?;
+                            dedupe(&mut fields);
+                            Kind::Sequence(fields)
+                        }
+                    };
+                    self.add(TypeDef {
+                        name: type_.clone(),
+                        documentation,
+                        kind,
+                    });
+                }
+                self.group_field(field_name(r.name.original()), type_, r.occurs, member)
+            }
+            Particle::All(_) => raise!("xs:all is not supported"),
+            Particle::Any(_) => raise!("xs:any is not supported"),
+        }
+    }
+
+    fn choice(&mut self, type_: &str, c: &Compositor) -> Result<()> {
+        let mut variants = Vec::new();
+        self.choice_members(&mut Namer::new(type_), &c.members, &mut variants)?;
+        self.add(TypeDef {
+            name: type_.to_owned(),
+            documentation: String::new(),
+            kind: Kind::Choice(variants),
+        });
+        Ok(())
+    }
+
+    fn group_field(
+        &self,
+        name: String,
+        type_: String,
+        occurs: Occurs,
+        particle: &Particle,
+    ) -> Result<Field> {
+        let content = self.writer.model.flatten(particle)?;
+        let mut first = BTreeSet::new();
+        first_names(&content, &mut first);
+        Ok(Field {
+            name,
+            documentation: String::new(),
+            occurs,
+            item: Item::Group {
+                type_,
+                first: first.into_iter().collect(),
+                is_nullable: is_content_nullable(&content),
+            },
+        })
+    }
+
+    fn element(
+        &mut self,
+        namer: &Namer,
+        e: &ElementParticle,
+        name: fn(&str) -> String,
+    ) -> Result<Field> {
+        let (type_, is_simple) = match &e.type_ {
+            ElementType::Named(BaseType::Custom(type_name))
+                if self.writer.simple_def(type_name).is_none() =>
+            {
+                match self.writer.model.get(Shape::Complex, type_name) {
+                    Some(Def::ComplexType(ct)) => (self.complex_type(ct)?, false),
+                    // a simple type that was not modeled, e.g. yyyy-mm-dd, is held as a string.
+                    _ => (String::from("String"), true),
+                }
+            }
+            ElementType::Named(bt) => (self.simple_type(bt)?, true),
+            ElementType::Anonymous(ct) => {
+                let type_ = format!("{}{}", namer.owner, e.name.pascal());
+                self.add_complex_type(&type_, ct)?;
+                (type_, false)
+            }
+        };
+        Ok(Field {
+            name: name(e.name.original()),
+            documentation: e.documentation.clone(),
+            occurs: e.occurs,
+            item: Item::Element {
+                xml: e.name.original().to_owned(),
+                type_,
+                is_simple,
+            },
+        })
+    }
+
+    /// The particle of the group named `name`.
+    fn group(&self, name: &str) -> Result<Particle> {
+        match self.writer.model.get(Shape::Group, name) {
+            Some(Def::Group(g)) => self.simplify(&g.particle),
+            _ => raise!("group '{}' not found", name),
+        }
+    }
+
+    /// Groups are named after themselves unless that would collide with a type.
+    fn group_type_name(&self, name: &Symbol) -> String {
+        let pascal = name.pascal();
+        let collides = self
+            .writer
+            .model
+            .defs()
+            .any(|d| !matches!(d, Def::Group(_)) && d.name().pascal() == pascal);
+        if collides {
+            format!("{}Group", pascal)
+        } else {
+            pascal.to_owned()
+        }
+    }
+
+    /// Removes compositors that only have one member, and group references whose group is a
+    /// single element, when the `Occurs` of one of the two is exactly once.
+    fn simplify(&self, particle: &Particle) -> Result<Particle> {
+        let (occurs, inner) = match particle {
+            Particle::Sequence(c) | Particle::Choice(c) if c.members.len() == 1 => {
+                (c.occurs, c.members[0].clone())
+            }
+            Particle::GroupRef(r) => match self.group(r.name.original())? {
+                Particle::Element(e) => (r.occurs, Particle::Element(e)),
+                _ => return Ok(particle.clone()),
+            },
+            _ => return Ok(particle.clone()),
+        };
+        if is_once(&occurs) {
+            self.simplify(&inner)
+        } else if is_once(inner.occurs()) {
+            self.simplify(&with_occurs(inner, occurs))
+        } else {
+            Ok(particle.clone())
+        }
+    }
+}
+
+fn is_once(occurs: &Occurs) -> bool {
+    *occurs == Occurs::default()
+}
+
+fn with_occurs(particle: Particle, occurs: Occurs) -> Particle {
+    match particle {
+        Particle::Element(mut e) => {
+            e.occurs = occurs;
+            Particle::Element(e)
+        }
+        Particle::Sequence(mut c) => {
+            c.occurs = occurs;
+            Particle::Sequence(c)
+        }
+        Particle::Choice(mut c) => {
+            c.occurs = occurs;
+            Particle::Choice(c)
+        }
+        Particle::All(mut c) => {
+            c.occurs = occurs;
+            Particle::All(c)
+        }
+        Particle::GroupRef(mut r) => {
+            r.occurs = occurs;
+            Particle::GroupRef(r)
+        }
+        Particle::Any(mut a) => {
+            a.occurs = occurs;
+            Particle::Any(a)
+        }
+    }
+}
+
+/// Adds the names of the elements that `particle` can begin with to `names`. There must be no
+/// group references in `particle`.
+fn first_names(particle: &Particle, names: &mut BTreeSet<String>) {
+    match particle {
+        Particle::Element(e) => {
+            names.insert(e.name.original().to_owned());
+        }
+        Particle::Sequence(c) => {
+            for member in &c.members {
+                first_names(member, names);
+                if !is_nullable(member) {
+                    break;
+                }
+            }
+        }
+        Particle::Choice(c) | Particle::All(c) => {
+            for member in &c.members {
+                first_names(member, names);
+            }
+        }
+        Particle::GroupRef(_) | Particle::Any(_) => {}
+    }
+}
+
+fn is_nullable(particle: &Particle) -> bool {
+    particle.occurs().min_occurs == 0 || is_content_nullable(particle)
+}
+
+/// True if the content of `particle`, i.e. ignoring its own `Occurs`, can be empty.
+fn is_content_nullable(particle: &Particle) -> bool {
+    match particle {
+        Particle::Element(_) | Particle::Any(_) => false,
+        Particle::Sequence(c) | Particle::All(c) => c.members.iter().all(is_nullable),
+        Particle::Choice(c) => c.members.iter().any(is_nullable),
+        Particle::GroupRef(_) => false,
+    }
+}
+
+/// The variant name for a sequence in a choice, after its first required element, e.g. the
+/// sequences in the choice at the start of `note` are `Grace`, `Cue` and `Duration`. There must be
+/// no group references in `c`.
+fn variant_name(c: &Compositor) -> String {
+    fn find(particle: &Particle, required: bool) -> Option<&Symbol> {
+        if required && particle.occurs().min_occurs == 0 {
+            return None;
+        }
+        match particle {
+            Particle::Element(e) => Some(&e.name),
+            Particle::Sequence(c) => c.members.iter().find_map(|m| find(m, required)),
+            _ => None,
+        }
+    }
+    let members = || c.members.iter();
+    members()
+        .find_map(|m| find(m, true))
+        .or_else(|| members().find_map(|m| find(m, false)))
+        .map(|name| name.pascal().to_owned())
+        .unwrap_or_else(|| String::from("Sequence"))
+}
+
+/// Appends a number to fields that have the same name as an earlier field.
+fn dedupe(fields: &mut [Field]) {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for field in fields.iter_mut() {
+        let count = seen.entry(field.name.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            field.name = format!("{}_{}", field.name, count);
+        }
+    }
+}
+
+/// The names that a generated type must not have, because the generated code uses them.
+const PRELUDE: &[&str] = &[
+    "Box", "Children", "Document", "Element", "Error", "Option", "Result", "String", "Vec",
+];
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do", "dyn", "else",
+    "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static",
+    "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// The snake case field name for an XML name, with a trailing underscore if it is a keyword.
+fn field_name(xml: &str) -> String {
+    let name = Symbol::new(xml).snake().to_owned();
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+fn pascal(xml: &str) -> String {
+    Symbol::new(xml).pascal().to_owned()
+}
+
+#[test]
+fn field_name_test() {
+    assert_eq!(field_name("time-modification"), "time_modification");
+    assert_eq!(field_name("type"), "type_");
+}
@@ -0,0 +1,75 @@
+mod content;
+mod write_complex_types;
+mod write_enums;
+mod write_numbers;
+mod write_strings;
+mod write_unions;
+pub mod writer;
+
+use crate::error::Result;
+use crate::generate::backend::{Backend, Output};
+use crate::generate::paths::{Paths, SchemaVersion};
+use crate::model::Model;
+use crate::utils::string_stuff::documentation;
+use std::path::PathBuf;
+
+/// Writes the MusicXML types as a standalone Rust crate.
+#[derive(Debug, Clone)]
+pub struct RustBackend {
+    /// The package name in the generated `Cargo.toml`.
+    pub crate_name: String,
+}
+
+impl Default for RustBackend {
+    fn default() -> Self {
+        Self {
+            crate_name: String::from("mxcore"),
+        }
+    }
+}
+
+impl Backend for RustBackend {
+    fn name(&self) -> &'static str {
+        "rust"
+    }
+
+    /// `Rust/mxcore` for 3.0, and e.g. `Rust/mxcore-3.1` for other versions.
+    fn default_dir(&self, paths: &Paths, version: SchemaVersion) -> PathBuf {
+        let dir = paths.repo.join("Rust");
+        match version {
+            SchemaVersion::V3_0 => dir.join(&self.crate_name),
+            _ => dir.join(format!("{}-{}", self.crate_name, version)),
+        }
+    }
+
+    fn generate(&self, model: Model, output: &Output) -> Result<()> {
+        writer::Writer::new(model, output.clone(), self.crate_name.clone()).write_code()
+    }
+}
+
+/// The doc comment lines for `text`, each ending in a newline, or nothing if `text` is empty.
+fn doc<S: AsRef<str>>(text: S, indents: usize) -> Result<String> {
+    let text = text.as_ref().trim();
+    if text.is_empty() {
+        return Ok(String::new());
+    }
+    let mut s = documentation(text, indents)?;
+    s.push('\n');
+    Ok(s)
+}
+
+/// A raw string literal that holds `s`, with enough `#`s that `s` cannot end it early.
+fn raw_string<S: AsRef<str>>(s: S) -> String {
+    let s = s.as_ref();
+    let mut hashes = String::from("#");
+    while s.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, s, hashes)
+}
+
+#[test]
+fn raw_string_test() {
+    assert_eq!(raw_string(r"^(?:\d+)$"), r###"r#"^(?:\d+)$"#"###);
+    assert_eq!(raw_string(r##"a"#b"##), r####"r##"a"#b"##"####);
+}
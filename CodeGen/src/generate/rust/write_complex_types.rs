@@ -0,0 +1,406 @@
+use crate::error::Result;
+use crate::generate::rust::content::{Attribute, Field, Item, Kind, TypeDef};
+use crate::generate::rust::doc;
+use crate::generate::rust::writer::Writer;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+impl Writer {
+    /// Writes `complex.rs`, with a struct for each complex type and for each sequence that can
+    /// repeat, and an enum for each choice. Complex types are read from, and written to, elements.
+    /// Sequences and choices are read from, and written to, the children of an element.
+    pub(crate) fn write_complex_types(&self, types: &[TypeDef]) -> Result<()> {
+        let mut contents = String::new();
+        for t in types {
+            let s = match &t.kind {
+                Kind::Complex {
+                    attributes,
+                    value,
+                    fields,
+                } => complex(t, attributes, value.as_deref(), fields),
+                Kind::Sequence(fields) => sequence(t, fields),
+                Kind::Choice(variants) => choice(t, variants),
+            };
+            contents.push_str(&wrap!(s, "unable to generate '{}'", t.name)?);
+            contents.push('\n');
+        }
+        self.write_module(
+            "complex",
+            &[
+                "crate::xml::{self, Children, Element, FromXml, ToXml}",
+                "crate::*",
+            ],
+            &contents,
+        )
+    }
+}
+
+fn complex(
+    t: &TypeDef,
+    attributes: &[Attribute],
+    value: Option<&str>,
+    fields: &[Field],
+) -> Result<String> {
+    let name = &t.name;
+    let mut s = doc(&t.documentation, 0)?;
+    s.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(s, "pub struct {} {{", name);
+    for a in attributes {
+        s.push_str(&doc(&a.documentation, 1)?);
+        let type_ = if a.required {
+            a.type_.clone()
+        } else {
+            format!("Option<{}>", a.type_)
+        };
+        let _ = writeln!(s, "    pub {}: {},", a.field, type_);
+    }
+    if let Some(value) = value {
+        let _ = writeln!(s, "    pub value: {},", value);
+    }
+    s.push_str(&struct_fields(fields)?);
+    s.push_str("}\n\n");
+
+    let _ = writeln!(s, "impl FromXml for {} {{", name);
+    s.push_str("    fn from_xml(element: &Element) -> Result<Self, Error> {\n");
+    s.push_str("        let children = &mut Children::new(element);\n");
+    let _ = writeln!(s, "        let value = {} {{", name);
+    for a in attributes {
+        let function = if a.required {
+            "require_attribute"
+        } else {
+            "parse_attribute"
+        };
+        let _ = writeln!(
+            s,
+            "            {}: element.{}({:?})?,",
+            a.field, function, a.xml
+        );
+    }
+    if value.is_some() {
+        s.push_str("            value: xml::parse_text(element)?,\n");
+    }
+    for f in fields {
+        let _ = writeln!(s, "            {}: {},", f.name, read(f));
+    }
+    s.push_str("        };\n        children.end()?;\n        Ok(value)\n    }\n}\n\n");
+
+    let _ = writeln!(s, "impl ToXml for {} {{", name);
+    if attributes.is_empty() && value.is_none() && fields.is_empty() {
+        s.push_str("    fn to_xml(&self, name: &str) -> Element {\n");
+        s.push_str("        Element::new(name)\n    }\n}\n");
+        return Ok(s);
+    }
+    s.push_str("    fn to_xml(&self, name: &str) -> Element {\n");
+    s.push_str("        let mut element = Element::new(name);\n");
+    for a in attributes {
+        if a.required {
+            let _ = writeln!(
+                s,
+                "        element.set_attribute({:?}, &self.{});",
+                a.xml, a.field
+            );
+        } else {
+            let _ = writeln!(s, "        if let Some(value) = &self.{} {{", a.field);
+            let _ = writeln!(s, "            element.set_attribute({:?}, value);", a.xml);
+            s.push_str("        }\n");
+        }
+    }
+    if value.is_some() {
+        s.push_str("        let text = self.value.to_string();\n");
+        s.push_str("        if !text.is_empty() {\n");
+        s.push_str("            element.push_text(text);\n");
+        s.push_str("        }\n");
+    }
+    for f in fields {
+        s.push_str(&write_field(f, "element", "&mut element", 2));
+    }
+    s.push_str("        element\n    }\n}\n");
+    Ok(s)
+}
+
+fn sequence(t: &TypeDef, fields: &[Field]) -> Result<String> {
+    let name = &t.name;
+    let mut s = doc(&t.documentation, 0)?;
+    s.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(s, "pub struct {} {{", name);
+    s.push_str(&struct_fields(fields)?);
+    s.push_str("}\n\n");
+
+    let _ = writeln!(s, "impl {} {{", name);
+    s.push_str("    pub(crate) fn read(children: &mut Children<'_>) -> Result<Self, Error> {\n");
+    let _ = writeln!(s, "        Ok({} {{", name);
+    for f in fields {
+        let _ = writeln!(s, "            {}: {},", f.name, read(f));
+    }
+    s.push_str("        })\n    }\n\n");
+    s.push_str("    pub(crate) fn write(&self, parent: &mut Element) {\n");
+    for f in fields {
+        s.push_str(&write_field(f, "parent", "parent", 2));
+    }
+    s.push_str("    }\n}\n");
+    Ok(s)
+}
+
+fn choice(t: &TypeDef, variants: &[Field]) -> Result<String> {
+    let name = &t.name;
+    let mut s = doc(&t.documentation, 0)?;
+    s.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+    let _ = writeln!(s, "pub enum {} {{", name);
+    for v in variants {
+        s.push_str(&doc(&v.documentation, 1)?);
+        let _ = writeln!(s, "    {}({}),", v.name, field_type(v));
+    }
+    s.push_str("}\n\n");
+
+    let _ = writeln!(s, "impl {} {{", name);
+    s.push_str("    pub(crate) fn read(children: &mut Children<'_>) -> Result<Self, Error> {\n");
+    s.push_str("        match children.peek() {\n");
+    // a child that can begin more than one variant is read as the first of them.
+    let mut seen = BTreeSet::new();
+    let mut fallback = None;
+    for v in variants {
+        let names: Vec<String> = first(v)
+            .into_iter()
+            .filter(|n| seen.insert(n.clone()))
+            .map(|n| format!("Some({:?})", n))
+            .collect();
+        if !names.is_empty() {
+            let _ = writeln!(
+                s,
+                "            {} => Ok({}::{}({})),",
+                names.join(" | "),
+                name,
+                v.name,
+                read(v)
+            );
+        }
+        if fallback.is_none() && is_nullable(v) {
+            fallback = Some(v);
+        }
+    }
+    match fallback {
+        Some(v) => {
+            let _ = writeln!(s, "            _ => Ok({}::{}({})),", name, v.name, read(v));
+        }
+        None => {
+            let expected: Vec<String> = seen.iter().map(|n| format!("{:?}", n)).collect();
+            let _ = writeln!(
+                s,
+                "            _ => Err(children.unexpected(&[{}])),",
+                expected.join(", ")
+            );
+        }
+    }
+    s.push_str("        }\n    }\n\n");
+    s.push_str("    pub(crate) fn write(&self, parent: &mut Element) {\n");
+    s.push_str("        match self {\n");
+    for v in variants {
+        let _ = writeln!(s, "            {}::{}(value) => {{", name, v.name);
+        s.push_str(&write_value(v, "value", 4));
+        s.push_str("            }\n");
+    }
+    s.push_str("        }\n    }\n}\n");
+    Ok(s)
+}
+
+fn struct_fields(fields: &[Field]) -> Result<String> {
+    let mut s = String::new();
+    for f in fields {
+        s.push_str(&doc(&f.documentation, 1)?);
+        let _ = writeln!(s, "    pub {}: {},", f.name, field_type(f));
+    }
+    Ok(s)
+}
+
+fn item_type(item: &Item) -> &str {
+    match item {
+        Item::Element { type_, .. } | Item::Group { type_, .. } => type_,
+    }
+}
+
+fn field_type(f: &Field) -> String {
+    let type_ = item_type(&f.item);
+    match (f.occurs.min_occurs, f.occurs.max_occurs) {
+        (1, Some(1)) => type_.to_owned(),
+        (0, Some(1)) => format!("Option<{}>", type_),
+        _ => format!("Vec<{}>", type_),
+    }
+}
+
+/// The names of the children that can begin `f`.
+fn first(f: &Field) -> Vec<String> {
+    match &f.item {
+        Item::Element { xml, .. } => vec![xml.clone()],
+        Item::Group { first, .. } => first.clone(),
+    }
+}
+
+fn is_nullable(f: &Field) -> bool {
+    f.occurs.min_occurs == 0
+        || matches!(
+            f.item,
+            Item::Group {
+                is_nullable: true,
+                ..
+            }
+        )
+}
+
+/// The expression that reads `f` from `children`.
+fn read(f: &Field) -> String {
+    let min = f.occurs.min_occurs;
+    let max = match f.occurs.max_occurs {
+        Some(max) => format!("Some({})", max),
+        None => String::from("None"),
+    };
+    match &f.item {
+        Item::Element {
+            xml,
+            type_,
+            is_simple,
+        } => {
+            let function = if *is_simple {
+                format!("xml::parse_text::<{}>", type_)
+            } else {
+                format!("{}::from_xml", type_)
+            };
+            match (min, f.occurs.max_occurs) {
+                (1, Some(1)) => format!("children.one({:?}, {})?", xml, function),
+                (0, Some(1)) => format!("children.optional({:?}, {})?", xml, function),
+                _ => format!(
+                    "children.repeated({:?}, {}, {}, {})?",
+                    xml, min, max, function
+                ),
+            }
+        }
+        Item::Group {
+            type_,
+            first,
+            is_nullable,
+        } => {
+            let first: Vec<String> = first.iter().map(|n| format!("{:?}", n)).collect();
+            let first = first.join(", ");
+            // a group that can be empty is read zero times when none of its children are next.
+            let min = if *is_nullable { 0 } else { min };
+            match (f.occurs.min_occurs, f.occurs.max_occurs) {
+                (1, Some(1)) => format!("{}::read(children)?", type_),
+                (0, Some(1)) => format!("children.optional_group(&[{}], {}::read)?", first, type_),
+                _ => format!(
+                    "children.repeated_group(&[{}], {}, {}, {}::read)?",
+                    first, min, max, type_
+                ),
+            }
+        }
+    }
+}
+
+/// The statements that write the field `f` of `self` to `parent`, which is an `Element` named
+/// `parent_name` that is borrowed mutably by `parent_ref`.
+fn write_field(f: &Field, parent_name: &str, parent_ref: &str, indents: usize) -> String {
+    let indent = "    ".repeat(indents);
+    match (f.occurs.min_occurs, f.occurs.max_occurs) {
+        (1, Some(1)) => format!(
+            "{}{};\n",
+            indent,
+            write_item(
+                &f.item,
+                &format!("self.{}", f.name),
+                parent_name,
+                parent_ref
+            )
+        ),
+        (0, Some(1)) => format!(
+            "{0}if let Some(value) = &self.{1} {{\n{0}    {2};\n{0}}}\n",
+            indent,
+            f.name,
+            write_item(&f.item, "value", parent_name, parent_ref)
+        ),
+        _ => format!(
+            "{0}for value in &self.{1} {{\n{0}    {2};\n{0}}}\n",
+            indent,
+            f.name,
+            write_item(&f.item, "value", parent_name, parent_ref)
+        ),
+    }
+}
+
+/// The statements that write the variant `v`, which is borrowed by `value`, to `parent`.
+fn write_value(v: &Field, value: &str, indents: usize) -> String {
+    let indent = "    ".repeat(indents);
+    match (v.occurs.min_occurs, v.occurs.max_occurs) {
+        (1, Some(1)) => format!(
+            "{}{};\n",
+            indent,
+            write_item(&v.item, value, "parent", "parent")
+        ),
+        (0, Some(1)) => format!(
+            "{0}if let Some(value) = {1} {{\n{0}    {2};\n{0}}}\n",
+            indent,
+            value,
+            write_item(&v.item, "value", "parent", "parent")
+        ),
+        _ => format!(
+            "{0}for value in {1} {{\n{0}    {2};\n{0}}}\n",
+            indent,
+            value,
+            write_item(&v.item, "value", "parent", "parent")
+        ),
+    }
+}
+
+/// The statement, without its semicolon, that writes `value` to `parent`. `value` is either a
+/// reference, or a field of `self`.
+fn write_item(item: &Item, value: &str, parent_name: &str, parent_ref: &str) -> String {
+    match item {
+        Item::Element {
+            xml,
+            is_simple: true,
+            ..
+        } => {
+            let reference = if value.starts_with("self.") {
+                format!("&{}", value)
+            } else {
+                value.to_owned()
+            };
+            format!(
+                "{}.push_element(xml::text_element({}, {:?}))",
+                parent_name, reference, xml
+            )
+        }
+        Item::Element { xml, .. } => {
+            format!("{}.push_element({}.to_xml({:?}))", parent_name, value, xml)
+        }
+        Item::Group { .. } => format!("{}.write({})", value, parent_ref),
+    }
+}
+
+#[test]
+fn choice_test() {
+    use crate::xsd::Occurs;
+    let variant = |name: &str, min_occurs: u64| Field {
+        name: name.to_owned(),
+        documentation: String::new(),
+        occurs: Occurs {
+            min_occurs,
+            max_occurs: Some(1),
+        },
+        item: Item::Element {
+            xml: name.to_lowercase(),
+            type_: String::from("Empty"),
+            is_simple: false,
+        },
+    };
+    let t = TypeDef {
+        name: String::from("ArrowChoice"),
+        documentation: String::new(),
+        kind: Kind::Choice(vec![variant("Straight", 1), variant("Curved", 0)]),
+    };
+    let got = choice(&t, &[variant("Straight", 1), variant("Curved", 0)]).unwrap();
+    assert!(got.contains("    Straight(Empty),\n    Curved(Option<Empty>),\n"));
+    assert!(got.contains(
+        "            Some(\"straight\") => Ok(ArrowChoice::Straight(children.one(\"straight\", Empty::from_xml)?)),\n"
+    ));
+    assert!(got.contains(
+        "            _ => Ok(ArrowChoice::Curved(children.optional(\"curved\", Empty::from_xml)?)),\n"
+    ));
+}
@@ -0,0 +1,122 @@
+use crate::error::Result;
+use crate::generate::rust::doc;
+use crate::generate::rust::writer::Writer;
+use crate::model::enumeration::Enumeration;
+use std::fmt::Write;
+
+impl Writer {
+    /// Writes `enums.rs`, with an enum for each enumeration. An enumeration with an 'other' field
+    /// gets a variant that holds any string that is not one of its members.
+    pub(crate) fn write_enums(&self, enums: &[&Enumeration]) -> Result<()> {
+        let mut contents = String::new();
+        for &e in enums {
+            contents.push_str(&enumeration(e)?);
+            contents.push('\n');
+        }
+        self.write_module(
+            "enums",
+            &["crate::Error", "std::fmt", "std::str::FromStr"],
+            &contents,
+        )
+    }
+}
+
+pub(super) fn enumeration(e: &Enumeration) -> Result<String> {
+    let name = e.name.pascal();
+    let mut s = doc(&e.documentation, 0)?;
+    if e.members.iter().all(|m| m.pascal() != e.default.pascal()) {
+        return raise!(
+            "the default of '{}', '{}', is not one of its members",
+            e.name.original(),
+            e.default.original()
+        );
+    }
+    let derives = match e.other_field {
+        None => "Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default",
+        Some(_) => "Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default",
+    };
+    let _ = writeln!(s, "#[derive({})]", derives);
+    let _ = writeln!(s, "pub enum {} {{", name);
+    for member in &e.members {
+        if member.pascal() == e.default.pascal() {
+            s.push_str("    #[default]\n");
+        }
+        let _ = writeln!(s, "    {},", member.pascal());
+    }
+    if let Some(other) = &e.other_field {
+        let _ = writeln!(s, "    {}(String),", other.name.pascal());
+    }
+    s.push_str("}\n\n");
+
+    let _ = writeln!(s, "impl FromStr for {} {{", name);
+    s.push_str("    type Err = Error;\n\n");
+    s.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+    s.push_str("        match crate::collapse(s).as_str() {\n");
+    for member in &e.members {
+        let _ = writeln!(
+            s,
+            "            {:?} => Ok({}::{}),",
+            member.original(),
+            name,
+            member.pascal()
+        );
+    }
+    match &e.other_field {
+        Some(other) => {
+            let _ = writeln!(
+                s,
+                "            other => Ok({}::{}(other.to_owned())),",
+                name,
+                other.name.pascal()
+            );
+        }
+        None => {
+            let _ = writeln!(
+                s,
+                "            _ => Err(Error::new({:?}, s, \"not one of the enumerated values\")),",
+                e.name.original()
+            );
+        }
+    }
+    s.push_str("        }\n    }\n}\n\n");
+
+    let _ = writeln!(s, "impl fmt::Display for {} {{", name);
+    s.push_str("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {\n");
+    s.push_str("        let s = match self {\n");
+    for member in &e.members {
+        let _ = writeln!(
+            s,
+            "            {}::{} => {:?},",
+            name,
+            member.pascal(),
+            member.original()
+        );
+    }
+    if let Some(other) = &e.other_field {
+        let _ = writeln!(
+            s,
+            "            {}::{}(other) => other.as_str(),",
+            name,
+            other.name.pascal()
+        );
+    }
+    s.push_str("        };\n        f.write_str(s)\n    }\n}\n");
+    Ok(s)
+}
+
+#[test]
+fn enumeration_test() {
+    use crate::model::symbol::Symbol;
+    let e = Enumeration {
+        name: Symbol::new("above-below"),
+        members: vec![Symbol::new("above"), Symbol::new("below")],
+        documentation: String::from("The above-below type is used to indicate whether one element appears above or below another element."),
+        default: Symbol::new("below"),
+        other_field: None,
+    };
+    let got = enumeration(&e).unwrap();
+    assert!(got.starts_with("/// The above-below type is used"));
+    assert!(got.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]\npub enum AboveBelow {\n    Above,\n    #[default]\n    Below,\n}\n"));
+    assert!(got.contains("            \"above\" => Ok(AboveBelow::Above),\n"));
+    assert!(got.contains("            AboveBelow::Below => \"below\",\n"));
+}
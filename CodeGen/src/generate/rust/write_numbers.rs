@@ -0,0 +1,341 @@
+use crate::error::Result;
+use crate::generate::rust::doc;
+use crate::generate::rust::writer::Writer;
+use crate::model::scalar::{Bound, NumericData, Range, ScalarNumeric};
+use crate::model::symbol::Symbol;
+use crate::xsd::primitives::Numeric;
+use std::fmt::Write;
+
+impl Writer {
+    /// Writes `numbers.rs`, with a newtype for each number that checks its range when constructed.
+    /// `xs:positiveInteger` and `xs:nonNegativeInteger` are added because unions and complex types
+    /// refer to them.
+    pub(crate) fn write_numbers(&self, numbers: &[&ScalarNumeric]) -> Result<()> {
+        let mut contents = integer(&non_negative_integer())?;
+        contents.push('\n');
+        contents.push_str(&integer(&positive_integer())?);
+        for &n in numbers {
+            contents.push('\n');
+            contents.push_str(&match n {
+                ScalarNumeric::Decimal(d) => decimal(d)?,
+                ScalarNumeric::Integer(i) => integer(i)?,
+            });
+        }
+        self.write_module(
+            "numbers",
+            &["crate::Error", "std::fmt", "std::str::FromStr"],
+            &contents,
+        )
+    }
+}
+
+fn positive_integer() -> NumericData<i64> {
+    NumericData {
+        name: Symbol::new("positiveInteger"),
+        base_type: Numeric::PositiveInteger,
+        documentation: String::from("The built-in primitive xs:positiveInteger"),
+        range: Range {
+            min: Some(Bound::Inclusive(1)),
+            max: None,
+        },
+        total_digits: None,
+        fraction_digits: None,
+    }
+}
+
+fn non_negative_integer() -> NumericData<i64> {
+    NumericData {
+        name: Symbol::new("nonNegativeInteger"),
+        base_type: Numeric::NonNegativeInteger,
+        documentation: String::from("The built-in primitive xs:nonNegativeInteger"),
+        range: Range {
+            min: Some(Bound::Inclusive(0)),
+            max: None,
+        },
+        total_digits: None,
+        fraction_digits: None,
+    }
+}
+
+/// The inclusive range of an integer.
+fn int_range(numeric: &NumericData<i64>) -> (Option<i64>, Option<i64>) {
+    let min = numeric.range.min.as_ref().map(|bound| match bound {
+        Bound::Inclusive(i) => *i,
+        Bound::Exclusive(e) => *e + 1,
+    });
+    let max = numeric.range.max.as_ref().map(|bound| match bound {
+        Bound::Inclusive(i) => *i,
+        Bound::Exclusive(e) => *e - 1,
+    });
+    (min, max)
+}
+
+fn describe_range<T: ToString>(min: Option<T>, max: Option<T>) -> String {
+    let describe = |bound: Option<T>| {
+        bound
+            .map(|b| b.to_string())
+            .unwrap_or_else(|| String::from("None"))
+    };
+    format!("Range: min={}, max={}", describe(min), describe(max))
+}
+
+fn document<T>(numeric: &NumericData<T>, range: String) -> String
+where
+    T: std::fmt::Debug + Clone + Copy + PartialEq + PartialOrd + Default,
+{
+    if numeric.documentation.is_empty() {
+        range
+    } else {
+        format!("{}\n\n{}", numeric.documentation, range)
+    }
+}
+
+fn integer(numeric: &NumericData<i64>) -> Result<String> {
+    let name = numeric.name.pascal();
+    let type_name = numeric.name.original();
+    let (min, max) = int_range(numeric);
+    // zero, unless that is out of range.
+    let default = match (min, max) {
+        (Some(min), _) if min > 0 => min,
+        (_, Some(max)) if max < 0 => max,
+        _ => 0,
+    };
+    let mut s = doc(document(numeric, describe_range(min, max)), 0)?;
+    s.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash");
+    s.push_str(if default == 0 {
+        ", Default)]\n"
+    } else {
+        ")]\n"
+    });
+    let _ = writeln!(s, "pub struct {}(i64);\n", name);
+    let _ = writeln!(s, "impl {} {{", name);
+    s.push_str("    /// Returns an error if `value` is out of range.\n");
+    s.push_str("    pub fn new(value: i64) -> Result<Self, Error> {\n");
+    if let Some(min) = min {
+        let _ = writeln!(s, "        if value < {} {{", min);
+        let _ = writeln!(
+            s,
+            "            return Err(Error::new({:?}, value.to_string(), \"the minimum is {}\"));",
+            type_name, min
+        );
+        s.push_str("        }\n");
+    }
+    if let Some(max) = max {
+        let _ = writeln!(s, "        if value > {} {{", max);
+        let _ = writeln!(
+            s,
+            "            return Err(Error::new({:?}, value.to_string(), \"the maximum is {}\"));",
+            type_name, max
+        );
+        s.push_str("        }\n");
+    }
+    let _ = writeln!(s, "        Ok({}(value))", name);
+    s.push_str("    }\n\n");
+    s.push_str("    pub fn value(self) -> i64 {\n        self.0\n    }\n}\n\n");
+
+    if default != 0 {
+        let _ = writeln!(s, "impl Default for {} {{", name);
+        let _ = writeln!(
+            s,
+            "    fn default() -> Self {{\n        {}({})\n    }}\n}}\n",
+            name, default
+        );
+    }
+
+    let _ = writeln!(s, "impl FromStr for {} {{", name);
+    s.push_str("    type Err = Error;\n\n");
+    s.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+    s.push_str("        match crate::collapse(s).parse() {\n");
+    s.push_str("            Ok(value) => Self::new(value),\n");
+    let _ = writeln!(
+        s,
+        "            Err(_) => Err(Error::new({:?}, s, \"not an integer\")),",
+        type_name
+    );
+    s.push_str("        }\n    }\n}\n\n");
+    s.push_str(&display(name));
+    Ok(s)
+}
+
+fn decimal(numeric: &NumericData<f64>) -> Result<String> {
+    let name = numeric.name.pascal();
+    let type_name = numeric.name.original();
+    let bound = |b: Option<&Bound<f64>>| {
+        b.map(|b| match b {
+            Bound::Inclusive(f) => format!("{}", f),
+            Bound::Exclusive(f) => format!("{} (exclusive)", f),
+        })
+    };
+    let mut range = describe_range(
+        bound(numeric.range.min.as_ref()),
+        bound(numeric.range.max.as_ref()),
+    );
+    if let Some(total) = numeric.total_digits {
+        let _ = write!(range, ", totalDigits={}", total);
+    }
+    if let Some(fraction) = numeric.fraction_digits {
+        let _ = write!(range, ", fractionDigits={}", fraction);
+    }
+    let has_digits = numeric.total_digits.is_some() || numeric.fraction_digits.is_some();
+    let mut s = doc(document(numeric, range), 0)?;
+    let default = default_decimal(numeric);
+    s.push_str("#[derive(Debug, Clone, Copy, PartialEq, PartialOrd");
+    s.push_str(if default == 0.0 {
+        ", Default)]\n"
+    } else {
+        ")]\n"
+    });
+    let _ = writeln!(s, "pub struct {}(f64);\n", name);
+    let _ = writeln!(s, "impl {} {{", name);
+    s.push_str("    /// Returns an error if `value` is out of range or is not finite.");
+    s.push_str(if has_digits {
+        " The value is rounded to the\n    /// digits facets before its range is checked.\n"
+    } else {
+        "\n"
+    });
+    s.push_str("    pub fn new(value: f64) -> Result<Self, Error> {\n");
+    s.push_str("        if !value.is_finite() {\n");
+    let _ = writeln!(
+        s,
+        "            return Err(Error::new({:?}, value.to_string(), \"not a finite number\"));",
+        type_name
+    );
+    s.push_str("        }\n");
+    if has_digits {
+        let digits = |d: Option<u64>| match d {
+            Some(d) => format!("Some({})", d),
+            None => String::from("None"),
+        };
+        let _ = writeln!(
+            s,
+            "        let value = crate::round_digits({}, {}, value);",
+            digits(numeric.total_digits),
+            digits(numeric.fraction_digits)
+        );
+    }
+    let check = |s: &mut String, bound: &Bound<f64>, is_min: bool| {
+        let (condition, message) = match (bound, is_min) {
+            (Bound::Inclusive(f), true) => {
+                (format!("value < {:?}", f), format!("the minimum is {}", f))
+            }
+            (Bound::Exclusive(f), true) => (
+                format!("value <= {:?}", f),
+                format!("it must be greater than {}", f),
+            ),
+            (Bound::Inclusive(f), false) => {
+                (format!("value > {:?}", f), format!("the maximum is {}", f))
+            }
+            (Bound::Exclusive(f), false) => (
+                format!("value >= {:?}", f),
+                format!("it must be less than {}", f),
+            ),
+        };
+        let _ = writeln!(s, "        if {} {{", condition);
+        let _ = writeln!(
+            s,
+            "            return Err(Error::new({:?}, value.to_string(), {:?}));",
+            type_name, message
+        );
+        s.push_str("        }\n");
+    };
+    if let Some(min) = &numeric.range.min {
+        check(&mut s, min, true);
+    }
+    if let Some(max) = &numeric.range.max {
+        check(&mut s, max, false);
+    }
+    let _ = writeln!(s, "        Ok({}(value))", name);
+    s.push_str("    }\n\n");
+    s.push_str("    pub fn value(self) -> f64 {\n        self.0\n    }\n}\n\n");
+
+    if default != 0.0 {
+        let _ = writeln!(s, "impl Default for {} {{", name);
+        let _ = writeln!(
+            s,
+            "    fn default() -> Self {{\n        {}({:?})\n    }}\n}}\n",
+            name, default
+        );
+    }
+
+    let _ = writeln!(s, "impl FromStr for {} {{", name);
+    s.push_str("    type Err = Error;\n\n");
+    s.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+    s.push_str("        match crate::parse_decimal(s) {\n");
+    s.push_str("            Some(value) => Self::new(value),\n");
+    let _ = writeln!(
+        s,
+        "            None => Err(Error::new({:?}, s, \"not a decimal\")),",
+        type_name
+    );
+    s.push_str("        }\n    }\n}\n\n");
+    s.push_str(&display(name));
+    Ok(s)
+}
+
+/// The same default that `mx::core` uses, i.e. zero unless the minimum is greater than that.
+fn default_decimal(numeric: &NumericData<f64>) -> f64 {
+    match numeric.range.min.as_ref() {
+        Some(Bound::Inclusive(f)) if *f > 0.0 => *f,
+        Some(Bound::Exclusive(f)) if *f >= 0.0 => *f + 1.0,
+        _ => 0.0,
+    }
+}
+
+fn display(name: &str) -> String {
+    let mut s = String::new();
+    let _ = writeln!(s, "impl fmt::Display for {} {{", name);
+    s.push_str("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {\n");
+    s.push_str("        write!(f, \"{}\", self.0)\n    }\n}\n");
+    s
+}
+
+#[test]
+fn integer_test() {
+    let got = integer(&positive_integer()).unwrap();
+    assert!(got.contains("pub struct PositiveInteger(i64);\n"));
+    assert!(got.contains("        if value < 1 {\n"));
+    assert!(!got.contains("the maximum"));
+    assert!(got.contains("        PositiveInteger(1)\n"));
+}
+
+#[test]
+fn decimal_test() {
+    let numeric = NumericData {
+        name: Symbol::new("positive-divisions"),
+        base_type: Numeric::Decimal,
+        documentation: String::new(),
+        range: Range {
+            min: Some(Bound::Exclusive(0.0)),
+            max: None,
+        },
+        total_digits: None,
+        fraction_digits: None,
+    };
+    let got = decimal(&numeric).unwrap();
+    assert!(got.starts_with("/// Range: min=0 (exclusive), max=None\n"));
+    assert!(got.contains("        if value <= 0.0 {\n"));
+    assert!(got.contains("        PositiveDivisions(1.0)\n"));
+    assert!(!got.contains("round_digits"));
+}
+
+#[test]
+fn decimal_digits_test() {
+    let numeric = NumericData {
+        name: Symbol::new("price"),
+        base_type: Numeric::Decimal,
+        documentation: String::new(),
+        range: Range {
+            min: Some(Bound::Inclusive(0.0)),
+            max: Some(Bound::Exclusive(1000.0)),
+        },
+        total_digits: Some(5),
+        fraction_digits: Some(2),
+    };
+    let got = decimal(&numeric).unwrap();
+    assert!(got
+        .starts_with("/// Range: min=0, max=1000 (exclusive), totalDigits=5, fractionDigits=2\n"));
+    // the value is rounded before the range is checked, like mx::core.
+    let round = got.find("        let value = crate::round_digits(Some(5), Some(2), value);\n");
+    let check = got.find("        if value >= 1000.0 {\n");
+    assert!(round.unwrap() < check.unwrap());
+}
@@ -0,0 +1,144 @@
+use crate::error::Result;
+use crate::generate::rust::writer::Writer;
+use crate::generate::rust::{doc, raw_string};
+use crate::model::scalar::ScalarString;
+use crate::xsd::restriction::WhiteSpace;
+use std::fmt::Write;
+
+impl Writer {
+    /// Writes `strings.rs`, with a newtype for each string that normalizes whitespace when parsed
+    /// and checks the length and pattern facets when constructed.
+    pub(crate) fn write_strings(&self, strings: &[&ScalarString]) -> Result<()> {
+        let mut contents = String::new();
+        let mut uses = vec!["crate::Error", "std::fmt", "std::str::FromStr"];
+        if strings.iter().any(|s| s.pattern.is_some()) {
+            uses.insert(0, "lazy_static::lazy_static");
+            uses.insert(1, "regex::Regex");
+        }
+        for &s in strings {
+            contents.push_str(&string(s)?);
+            contents.push('\n');
+        }
+        self.write_module("strings", &uses, &contents)
+    }
+}
+
+fn document_string(s: &ScalarString) -> String {
+    let mut facets = vec![format!("whiteSpace={}", s.normalization())];
+    if let Some(min) = s.min_length {
+        facets.push(format!("minLength={}", min));
+    }
+    if let Some(max) = s.max_length {
+        facets.push(format!("maxLength={}", max));
+    }
+    if let Some(pattern) = &s.pattern {
+        facets.push(format!("pattern=`{}`", pattern));
+    }
+    let facets = format!("Facets: {}", facets.join(", "));
+    if s.documentation.is_empty() {
+        facets
+    } else {
+        format!("{}\n\n{}", s.documentation, facets)
+    }
+}
+
+fn string(s: &ScalarString) -> Result<String> {
+    let name = s.name.pascal();
+    let type_name = s.name.original();
+    // an empty string is only a sensible default if it is a valid value.
+    let empty_is_valid = s.min_length.unwrap_or(0) == 0
+        && match &s.pattern {
+            None => true,
+            Some(pattern) => pattern.regex()?.is_match(""),
+        };
+    let mut out = doc(document_string(s), 0)?;
+    if empty_is_valid {
+        out.push_str("#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]\n");
+    } else {
+        out.push_str("#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+    }
+    let _ = writeln!(out, "pub struct {}(String);\n", name);
+    let _ = writeln!(out, "impl {} {{", name);
+    out.push_str("    /// Returns an error if `value` does not satisfy the facets.\n");
+    out.push_str("    pub fn new<S: Into<String>>(value: S) -> Result<Self, Error> {\n");
+    out.push_str("        let value = value.into();\n");
+    if s.min_length.is_some() || s.max_length.is_some() {
+        out.push_str("        let length = value.chars().count();\n");
+    }
+    if let Some(min) = s.min_length {
+        let _ = writeln!(out, "        if length < {} {{", min);
+        let _ = writeln!(
+            out,
+            "            return Err(Error::new({:?}, value, \"the minimum length is {}\"));",
+            type_name, min
+        );
+        out.push_str("        }\n");
+    }
+    if let Some(max) = s.max_length {
+        let _ = writeln!(out, "        if length > {} {{", max);
+        let _ = writeln!(
+            out,
+            "            return Err(Error::new({:?}, value, \"the maximum length is {}\"));",
+            type_name, max
+        );
+        out.push_str("        }\n");
+    }
+    if let Some(pattern) = &s.pattern {
+        out.push_str("        lazy_static! {\n");
+        let _ = writeln!(
+            out,
+            "            static ref PATTERN: Regex = Regex::new({}).unwrap();",
+            raw_string(pattern.to_rust()?)
+        );
+        out.push_str("        }\n");
+        out.push_str("        if !PATTERN.is_match(&value) {\n");
+        let _ = writeln!(
+            out,
+            "            return Err(Error::new({:?}, value, \"the pattern does not match\"));",
+            type_name
+        );
+        out.push_str("        }\n");
+    }
+    let _ = writeln!(out, "        Ok({}(value))", name);
+    out.push_str("    }\n\n");
+    out.push_str("    pub fn value(&self) -> &str {\n        &self.0\n    }\n}\n\n");
+
+    let _ = writeln!(out, "impl FromStr for {} {{", name);
+    out.push_str("    type Err = Error;\n\n");
+    out.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+    let normalized = match s.normalization() {
+        WhiteSpace::Preserve => "s",
+        WhiteSpace::Replace => "crate::replace(s)",
+        WhiteSpace::Collapse => "crate::collapse(s)",
+    };
+    let _ = writeln!(out, "        Self::new({})", normalized);
+    out.push_str("    }\n}\n\n");
+
+    let _ = writeln!(out, "impl fmt::Display for {} {{", name);
+    out.push_str("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {\n");
+    out.push_str("        f.write_str(&self.0)\n    }\n}\n");
+    Ok(out)
+}
+
+#[test]
+fn string_test() {
+    use crate::model::symbol::Symbol;
+    use crate::xsd::pattern::Pattern;
+    use crate::xsd::primitives::Character;
+    let mut s = ScalarString {
+        name: Symbol::new("time-only"),
+        base_type: Character::Token,
+        pattern: Some(Pattern::new("[1-9][0-9]*(, ?[1-9][0-9]*)*").unwrap()),
+        ..Default::default()
+    };
+    let got = string(&s).unwrap();
+    assert!(got.contains("#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]\n"));
+    assert!(got.contains("static ref PATTERN: Regex = Regex::new(r#\"^(?:"));
+    assert!(got.contains("        Self::new(crate::collapse(s))\n"));
+    s.pattern = None;
+    s.max_length = Some(8);
+    let got = string(&s).unwrap();
+    assert!(got.contains("Hash, Default)]\n"));
+    assert!(got.contains("        if length > 8 {\n"));
+    assert!(!got.contains("PATTERN"));
+}
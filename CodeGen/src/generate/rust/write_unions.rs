@@ -0,0 +1,178 @@
+use crate::error::Result;
+use crate::generate::rust::doc;
+use crate::generate::rust::write_enums::enumeration;
+use crate::generate::rust::writer::Writer;
+use crate::model::scalar::{ScalarNumeric, UnionData};
+use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::primitives::BaseType;
+use std::fmt::Write;
+
+/// How a member of a union is held by the variant for it.
+struct Member {
+    variant: String,
+    type_: String,
+    /// The pattern and expression of an `if let` that parses `s` as the `type_` into `value`.
+    parse: String,
+    /// Constructs the default value of the `type_` if there is one.
+    default: Option<String>,
+    is_copy: bool,
+    is_eq: bool,
+}
+
+impl Writer {
+    /// Writes `unions.rs`, with an enum for each union that has a variant for each member type.
+    /// Like `xs:union`, a value is parsed as the first member type that it is valid for.
+    pub(crate) fn write_unions(&self, unions: &[&UnionData]) -> Result<()> {
+        let mut contents = String::new();
+        for &u in unions {
+            for def in &u.inline_members {
+                match def {
+                    Def::Enumeration(e) => contents.push_str(&enumeration(e)?),
+                    other => {
+                        return raise!(
+                            "unsupported in-line member '{}' of union '{}'",
+                            other.name().original(),
+                            u.name.original()
+                        )
+                    }
+                }
+                contents.push('\n');
+            }
+            let members = wrap!(
+                u.members
+                    .iter()
+                    .map(|bt| self.member(u, bt))
+                    .collect::<Result<Vec<Member>>>(),
+                "unable to generate union '{}'",
+                u.name.original()
+            )?;
+            contents.push_str(&union(u, &members)?);
+            contents.push('\n');
+        }
+        self.write_module(
+            "unions",
+            &["crate::*", "std::fmt", "std::str::FromStr"],
+            &contents,
+        )
+    }
+
+    fn member(&self, u: &UnionData, base_type: &BaseType) -> Result<Member> {
+        let parse_as = |type_: &str| format!("Ok(value) = s.parse::<{}>()", type_);
+        match base_type {
+            BaseType::Decimal => Ok(Member {
+                variant: String::from("Decimal"),
+                type_: String::from("f64"),
+                parse: String::from("Some(value) = crate::parse_decimal(s)"),
+                default: Some(String::from("0.0")),
+                is_copy: true,
+                is_eq: false,
+            }),
+            BaseType::Integer => Ok(Member {
+                variant: String::from("Integer"),
+                type_: String::from("i64"),
+                parse: String::from("Ok(value) = crate::collapse(s).parse::<i64>()"),
+                default: Some(String::from("0")),
+                is_copy: true,
+                is_eq: true,
+            }),
+            // the numbers module always has a PositiveInteger.
+            BaseType::PositiveInteger => Ok(Member {
+                variant: String::from("PositiveInteger"),
+                type_: String::from("PositiveInteger"),
+                parse: parse_as("PositiveInteger"),
+                default: Some(String::from("PositiveInteger::default()")),
+                is_copy: true,
+                is_eq: true,
+            }),
+            BaseType::Custom(name) => {
+                let (def, type_) = match u.inline_member(name) {
+                    Some(inline) => (inline, inline.name().pascal().to_owned()),
+                    None => (
+                        self.simple_def(name)
+                            .ok_or_else(|| make_err!("simple type '{}' not found", name))?,
+                        self.type_name(name)?,
+                    ),
+                };
+                let (has_default, is_copy, is_eq) = match def {
+                    Def::Enumeration(e) => (true, e.other_field.is_none(), true),
+                    Def::ScalarNumber(ScalarNumeric::Decimal(_)) => (true, true, false),
+                    Def::ScalarNumber(ScalarNumeric::Integer(_)) => (true, true, true),
+                    Def::ScalarString(_) | Def::CustomScalarString(_) => (false, false, true),
+                    _ => (false, false, false),
+                };
+                Ok(Member {
+                    variant: Symbol::new(name).pascal().to_owned(),
+                    parse: parse_as(&type_),
+                    default: if has_default {
+                        Some(format!("{}::default()", type_))
+                    } else {
+                        None
+                    },
+                    type_,
+                    is_copy,
+                    is_eq,
+                })
+            }
+            unsupported => raise!("unsupported union member {:?}", unsupported),
+        }
+    }
+}
+
+fn union(u: &UnionData, members: &[Member]) -> Result<String> {
+    let name = u.name.pascal();
+    let first = match members.first() {
+        Some(first) => first,
+        None => return raise!("union '{}' has no members", u.name.original()),
+    };
+    let mut derives = vec!["Debug", "Clone"];
+    if members.iter().all(|m| m.is_copy) {
+        derives.push("Copy");
+    }
+    derives.push("PartialEq");
+    if members.iter().all(|m| m.is_eq) {
+        derives.push("Eq");
+    }
+    let mut s = doc(&u.documentation, 0)?;
+    let _ = writeln!(s, "#[derive({})]", derives.join(", "));
+    let _ = writeln!(s, "pub enum {} {{", name);
+    for m in members {
+        let _ = writeln!(s, "    {}({}),", m.variant, m.type_);
+    }
+    s.push_str("}\n\n");
+
+    if let Some(default) = &first.default {
+        let _ = writeln!(s, "impl Default for {} {{", name);
+        s.push_str("    fn default() -> Self {\n");
+        let _ = writeln!(s, "        {}::{}({})", name, first.variant, default);
+        s.push_str("    }\n}\n\n");
+    }
+
+    let _ = writeln!(s, "impl FromStr for {} {{", name);
+    s.push_str("    type Err = Error;\n\n");
+    s.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+    for m in members {
+        let _ = writeln!(s, "        if let {} {{", m.parse);
+        let _ = writeln!(s, "            return Ok({}::{}(value));", name, m.variant);
+        s.push_str("        }\n");
+    }
+    let _ = writeln!(
+        s,
+        "        Err(Error::new({:?}, s, \"not valid for any member of the union\"))",
+        u.name.original()
+    );
+    s.push_str("    }\n}\n\n");
+
+    let _ = writeln!(s, "impl fmt::Display for {} {{", name);
+    s.push_str("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {\n");
+    s.push_str("        match self {\n");
+    for m in members {
+        let _ = writeln!(
+            s,
+            "            {}::{}(value) => fmt::Display::fmt(value, f),",
+            name, m.variant
+        );
+    }
+    s.push_str("        }\n    }\n}\n");
+    Ok(s)
+}
@@ -0,0 +1,423 @@
+use crate::error::Result;
+use crate::generate::backend::Output;
+use crate::generate::rust::content::Lowering;
+use crate::generate::rust::doc;
+use crate::model::element::ElementData;
+use crate::model::scalar::DerivedSimpleTypeData;
+use crate::model::{Def, Model, Shape};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct Writer {
+    pub model: Model,
+    pub output: Output,
+    pub crate_name: String,
+}
+
+impl Writer {
+    pub fn new(model: Model, output: Output, crate_name: String) -> Self {
+        Self {
+            model,
+            output,
+            crate_name,
+        }
+    }
+
+    pub fn write_code(&self) -> Result<()> {
+        let mut enums = Vec::new();
+        let mut numbers = Vec::new();
+        let mut strings = Vec::new();
+        let mut unions = Vec::new();
+        let mut aliases = Vec::new();
+        let mut complex_types = Vec::new();
+        let mut roots = Vec::new();
+        for def in self.model.defs() {
+            match def {
+                Def::Enumeration(e) => enums.push(e),
+                // Unlike in mx::core, the strings that musicxml.xsd defines are not hand-written,
+                // the generated type enforces their facets.
+                Def::ScalarString(s) | Def::CustomScalarString(s) => strings.push(s),
+                Def::ScalarNumber(n) => numbers.push(n),
+                Def::DerivedSimpleType(d) => aliases.push(d),
+                Def::UnionSimpleType(u) => unions.push(u),
+                // TODO - generate lists, musicxml.xsd does not currently have any.
+                Def::ListSimpleType(_) => {}
+                Def::ComplexType(c) => complex_types.push(c),
+                Def::Element(e) => roots.push(e),
+                // attribute groups and groups are expanded into the complex types that use them.
+                Def::AttributeGroup(_) | Def::Group(_) => {}
+            }
+        }
+        // the model is a hash map, sort so that the generated code does not change from run to run.
+        enums.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        numbers.sort_by(|a, b| a.name().pascal().cmp(b.name().pascal()));
+        strings.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        unions.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        aliases.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        complex_types.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        roots.sort_by(|a, b| a.name.pascal().cmp(b.name.pascal()));
+        let mut modules = Vec::new();
+        if !enums.is_empty() {
+            self.write_enums(&enums)?;
+            modules.push("enums");
+        }
+        self.write_numbers(&numbers)?;
+        modules.push("numbers");
+        let uses_regex = strings.iter().any(|s| s.pattern.is_some());
+        if !strings.is_empty() {
+            self.write_strings(&strings)?;
+            modules.push("strings");
+        }
+        if !unions.is_empty() {
+            self.write_unions(&unions)?;
+            modules.push("unions");
+        }
+        if !aliases.is_empty() {
+            self.write_aliases(&aliases)?;
+            modules.push("aliases");
+        }
+        let mut lowering = Lowering::new(self);
+        for &c in &complex_types {
+            wrap!(
+                lowering.complex_type(c),
+                "unable to generate complex type '{}'",
+                c.name.original()
+            )?;
+        }
+        let mut documents = Vec::new();
+        for &e in &roots {
+            let type_ = wrap!(
+                lowering.root(e),
+                "unable to generate element '{}'",
+                e.name.original()
+            )?;
+            documents.push((e, type_));
+        }
+        let types = lowering.into_types();
+        if !types.is_empty() {
+            self.write_complex_types(&types)?;
+            modules.push("complex");
+            self.output.write(src("xml.rs"), XML)?;
+            modules.push("xml");
+        }
+        if !documents.is_empty() {
+            self.write_document(&documents)?;
+            modules.push("document");
+        }
+        self.write_lib(&modules)?;
+        self.write_error()?;
+        self.write_manifest(uses_regex)?;
+        Ok(())
+    }
+
+    /// The simple type named `name`. The mx-cpp transform renames some simple types, e.g. `step`
+    /// becomes `step-enum`, but the elements and attributes that refer to them keep the original
+    /// name.
+    pub(crate) fn simple_def<S: AsRef<str>>(&self, name: S) -> Option<&Def> {
+        let name = name.as_ref();
+        self.model
+            .get(Shape::Simple, name)
+            .or_else(|| self.model.get(Shape::Simple, format!("{}-enum", name)))
+    }
+
+    /// The name of the Rust type that is generated for the simple type named `name`.
+    pub(crate) fn type_name<S: AsRef<str>>(&self, name: S) -> Result<String> {
+        let name = name.as_ref();
+        match self.simple_def(name) {
+            Some(Def::ListSimpleType(_)) => raise!("'{}' is a list, which is unsupported", name),
+            Some(def) => Ok(def.name().pascal().to_owned()),
+            None => raise!("simple type '{}' not found", name),
+        }
+    }
+
+    /// Writes `src/{name}.rs`, which begins with the generated-code header and `uses`.
+    pub(crate) fn write_module(&self, name: &str, uses: &[&str], contents: &str) -> Result<()> {
+        let mut s = String::from(HEADER);
+        s.push('\n');
+        for u in uses {
+            s.push_str(&format!("use {};\n", u));
+        }
+        if !uses.is_empty() {
+            s.push('\n');
+        }
+        s.push_str(contents.trim_end());
+        s.push('\n');
+        self.output.write(src(format!("{}.rs", name)), s)
+    }
+
+    fn write_aliases(&self, aliases: &[&DerivedSimpleTypeData]) -> Result<()> {
+        let mut contents = String::new();
+        let mut uses = vec![];
+        for &alias in aliases {
+            contents.push_str(&doc(&alias.documentation, 0)?);
+            contents.push_str(&format!(
+                "pub type {} = {};\n\n",
+                alias.name.pascal(),
+                self.type_name(&alias.base_type)?
+            ));
+        }
+        if !contents.is_empty() {
+            uses.push("crate::*");
+        }
+        self.write_module("aliases", &uses, &contents)
+    }
+
+    /// Writes `document.rs`, with an enum that holds any of the elements that can be the root of a
+    /// document, and parses from and displays as a complete XML document.
+    fn write_document(&self, roots: &[(&ElementData, String)]) -> Result<()> {
+        let mut s = String::from(
+            "/// A MusicXML document, which is one of the top-level elements of musicxml.xsd.\n",
+        );
+        s.push_str("#[derive(Debug, Clone, PartialEq)]\npub enum Document {\n");
+        for (e, type_) in roots {
+            s.push_str(&format!("    {}({}),\n", e.name.pascal(), type_));
+        }
+        s.push_str("}\n\nimpl FromStr for Document {\n    type Err = Error;\n\n");
+        s.push_str("    fn from_str(s: &str) -> Result<Self, Error> {\n");
+        s.push_str("        let root = xml::parse(s)?;\n");
+        s.push_str("        match root.name.as_str() {\n");
+        for (e, type_) in roots {
+            s.push_str(&format!(
+                "            {:?} => Ok(Document::{}({}::from_xml(&root)?)),\n",
+                e.name.original(),
+                e.name.pascal(),
+                type_
+            ));
+        }
+        s.push_str(
+            "            other => Err(Error::new(\"document\", other, \"not a root element\")),\n",
+        );
+        s.push_str("        }\n    }\n}\n\n");
+        s.push_str("impl fmt::Display for Document {\n");
+        s.push_str("    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {\n");
+        s.push_str("        let (root, doctype) = match self {\n");
+        for (e, _) in roots {
+            let name = e.name.original();
+            // e.g. score-partwise has the public identifier of the partwise DTD.
+            let dtd = name.trim_start_matches("score-");
+            let mut kind = dtd.to_owned();
+            if let Some(c) = kind.get_mut(0..1) {
+                c.make_ascii_uppercase();
+            }
+            s.push_str(&format!(
+                "            Document::{}(value) => (\n                value.to_xml({:?}),\n                {:?},\n            ),\n",
+                e.name.pascal(),
+                name,
+                format!(
+                    "{} PUBLIC \"-//Recordare//DTD MusicXML {} {}//EN\" \"http://www.musicxml.org/dtds/{}.dtd\"",
+                    name, self.output.version, kind, dtd
+                )
+            ));
+        }
+        s.push_str("        };\n");
+        s.push_str(
+            "        writeln!(f, r#\"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\"#)?;\n",
+        );
+        s.push_str("        writeln!(f, \"<!DOCTYPE {}>\", doctype)?;\n");
+        s.push_str("        writeln!(f, \"{}\", root)\n    }\n}\n");
+        self.write_module(
+            "document",
+            &[
+                "crate::xml::{self, FromXml, ToXml}",
+                "crate::*",
+                "std::fmt",
+                "std::str::FromStr",
+            ],
+            &s,
+        )
+    }
+
+    fn write_lib(&self, modules: &[&str]) -> Result<()> {
+        let mut s = format!(
+            "//! The MusicXML {} types.\n//!\n//! {}\n\n",
+            self.output.version,
+            HEADER.trim_start_matches("// ").trim_end()
+        );
+        if modules.contains(&"complex") {
+            // the choices in musicxml.xsd often have one element with many children and others
+            // that are empty, boxing the large ones would make the types harder to use.
+            s.push_str("#![allow(clippy::large_enum_variant)]\n\n");
+        }
+        let mut all = modules.to_vec();
+        all.push("error");
+        all.sort_unstable();
+        for m in &all {
+            s.push_str(&format!("mod {};\n", m));
+        }
+        s.push('\n');
+        for m in &all {
+            match *m {
+                "error" => s.push_str("pub use error::Error;\n"),
+                "document" => s.push_str("pub use document::Document;\n"),
+                // reading and writing xml is an implementation detail of the generated types.
+                "xml" => {}
+                _ => s.push_str(&format!("pub use {}::*;\n", m)),
+            }
+        }
+        s.push_str(LIB_FUNCTIONS);
+        self.output.write(src("lib.rs"), s)
+    }
+
+    fn write_error(&self) -> Result<()> {
+        self.write_module(
+            "error",
+            &[
+                "std::convert::Infallible",
+                "std::fmt",
+                "std::num::{ParseFloatError, ParseIntError}",
+            ],
+            ERROR,
+        )
+    }
+
+    fn write_manifest(&self, uses_regex: bool) -> Result<()> {
+        let mut s = format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2018\"\n\n[dependencies]\n",
+            self.crate_name
+        );
+        if uses_regex {
+            s.push_str("lazy_static = \"1.4.0\"\nregex = \"1.3.9\"\n");
+        }
+        self.output.write("Cargo.toml", s)
+    }
+}
+
+/// The path of a file in the `src` directory of the crate.
+fn src<S: AsRef<str>>(filename: S) -> PathBuf {
+    Path::new("src").join(filename.as_ref())
+}
+
+/// The XML reader and writer that the generated types use.
+const XML: &str = include_str!("../data/rust/xml.rs");
+
+const HEADER: &str = "// Generated by mxgen from musicxml.xsd, do not edit.\n";
+
+const LIB_FUNCTIONS: &str = r#"
+/// The whitespace characters of XML.
+fn is_xml_space(c: char) -> bool {
+    c == ' ' || c == '\t' || c == '\n' || c == '\r'
+}
+
+/// Applies `whiteSpace="replace"`, i.e. each tab, newline and carriage return becomes a space.
+#[allow(dead_code)]
+pub(crate) fn replace(s: &str) -> String {
+    s.chars()
+        .map(|c| if is_xml_space(c) { ' ' } else { c })
+        .collect()
+}
+
+/// Applies `whiteSpace="collapse"`, i.e. runs of whitespace become a single space and leading and
+/// trailing whitespace is removed.
+#[allow(dead_code)]
+pub(crate) fn collapse(s: &str) -> String {
+    s.split(is_xml_space)
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parses an `xs:decimal`, which unlike an `f64` has no exponent, infinity or NaN.
+#[allow(dead_code)]
+pub(crate) fn parse_decimal(s: &str) -> Option<f64> {
+    let s = s.trim_matches(is_xml_space);
+    let unsigned = s.strip_prefix('-').or_else(|| s.strip_prefix('+')).unwrap_or(s);
+    let digits = unsigned.chars().filter(char::is_ascii_digit).count();
+    let points = unsigned.chars().filter(|&c| c == '.').count();
+    if digits == 0 || points > 1 || digits + points != unsigned.len() {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Rounds `value` to the `totalDigits` and `fractionDigits` facets, like `roundDigits` in
+/// `mx::core`. A value with too many integer digits becomes the largest one with `total_digits`.
+#[allow(dead_code)]
+pub(crate) fn round_digits(
+    total_digits: Option<i32>,
+    fraction_digits: Option<i32>,
+    value: f64,
+) -> f64 {
+    let mut value = value;
+    if let Some(fraction_digits) = fraction_digits {
+        let shift = 10f64.powi(fraction_digits);
+        value = (value * shift).round() / shift;
+    }
+    let total_digits = match total_digits {
+        Some(total_digits) if total_digits > 0 && value != 0.0 => total_digits,
+        _ => return value,
+    };
+    // leading zeros are not digits, e.g. 0.05 has one digit and -1 integer digits.
+    let integer_digits = value.abs().log10().floor() as i32 + 1;
+    if integer_digits > total_digits {
+        return (10f64.powi(total_digits) - 1.0).copysign(value);
+    }
+    let shift = 10f64.powi(total_digits - integer_digits);
+    (value * shift).round() / shift
+}
+"#;
+
+const ERROR: &str = r#"/// The error that is returned when a value is not allowed by its MusicXML type, or when a document
+/// does not follow the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    type_name: String,
+    value: String,
+    message: String,
+}
+
+impl Error {
+    pub(crate) fn new<T, V, M>(type_name: T, value: V, message: M) -> Self
+    where
+        T: Into<String>,
+        V: Into<String>,
+        M: Into<String>,
+    {
+        Self {
+            type_name: type_name.into(),
+            value: value.into(),
+            message: message.into(),
+        }
+    }
+
+    /// The name of the type or element in musicxml.xsd, e.g. `above-below`.
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// The value that was not allowed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a valid {}: {}",
+            self.value, self.type_name, self.message
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(error: ParseIntError) -> Self {
+        Error::new("xs:integer", "", error.to_string())
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(error: ParseFloatError) -> Self {
+        Error::new("xs:decimal", "", error.to_string())
+    }
+}
+
+/// Parsing a `String` cannot fail.
+impl From<Infallible> for Error {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
+"#;
@@ -2,9 +2,9 @@ use crate::error::Result;
 use handlebars::Handlebars;
 use lazy_static::lazy_static;
 use serde::Serialize;
-use std::borrow::Borrow;
-use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -22,59 +22,146 @@ pub const INTEGER_BUILTINS_CPP: &str = "integer_builtins.cpp.template";
 pub const INTEGER_BUILTINS_H: &str = "integer_builtins.h.template";
 pub const INTEGER_TYPE_CPP: &str = "integer_type.cpp.template";
 pub const INTEGER_TYPE_H: &str = "integer_type.h.template";
+pub const LIST_CPP: &str = "list.cpp.template";
+pub const LIST_H: &str = "list.h.template";
 pub const NUMBER_OR_NORMAL_CPP: &str = "NumberOrNormal.cpp.template";
 pub const NUMBER_OR_NORMAL_H: &str = "NumberOrNormal.h.template";
 pub const POSITIVE_INTEGER_OR_EMPTY_CPP: &str = "PositiveIntegerOrEmpty.cpp.template";
 pub const POSITIVE_INTEGER_OR_EMPTY_H: &str = "PositiveIntegerOrEmpty.h.template";
+pub const STRING_CPP: &str = "string.cpp.template";
+pub const STRING_H: &str = "string.h.template";
 pub const UNION_CPP: &str = "union.cpp.template";
 pub const UNION_H: &str = "union.h.template";
 
-lazy_static! {
-    static ref DIR: PathBuf = manifest_dir().join("src").join("generate").join("data");
-}
-
-lazy_static! {
-    static ref HANDLEBARS: Handlebars<'static> = {
-        let mut handlebars = Handlebars::new();
-        let files = list_template_files().unwrap();
-        for (name, path) in &files {
-            handlebars.register_template_string(name, read(path).unwrap());
-        }
-        handlebars
-    };
-}
+/// The templates that are built in to mxgen, so that it does not need the source tree at runtime.
+const EMBEDDED: &[(&str, &str)] = &[
+    (CORE_CPP, include_str!("data/core.cpp.template")),
+    (CORE_H, include_str!("data/core.h.template")),
+    (
+        DECIMAL_BUILTINS_CPP,
+        include_str!("data/decimal_builtins.cpp.template"),
+    ),
+    (
+        DECIMAL_BUILTINS_H,
+        include_str!("data/decimal_builtins.h.template"),
+    ),
+    (
+        DECIMAL_TYPE_CPP,
+        include_str!("data/decimal_type.cpp.template"),
+    ),
+    (DECIMAL_TYPE_H, include_str!("data/decimal_type.h.template")),
+    (ENUM_CPP, include_str!("data/enum.cpp.template")),
+    (ENUM_H, include_str!("data/enum.h.template")),
+    (
+        ENUM_WRAPPER_CPP,
+        include_str!("data/enum_wrapper.cpp.template"),
+    ),
+    (ENUM_WRAPPER_H, include_str!("data/enum_wrapper.h.template")),
+    (
+        INTEGER_BUILTINS_CPP,
+        include_str!("data/integer_builtins.cpp.template"),
+    ),
+    (
+        INTEGER_BUILTINS_H,
+        include_str!("data/integer_builtins.h.template"),
+    ),
+    (
+        INTEGER_TYPE_CPP,
+        include_str!("data/integer_type.cpp.template"),
+    ),
+    (INTEGER_TYPE_H, include_str!("data/integer_type.h.template")),
+    (LIST_CPP, include_str!("data/list.cpp.template")),
+    (LIST_H, include_str!("data/list.h.template")),
+    (
+        NUMBER_OR_NORMAL_CPP,
+        include_str!("data/NumberOrNormal.cpp.template"),
+    ),
+    (
+        NUMBER_OR_NORMAL_H,
+        include_str!("data/NumberOrNormal.h.template"),
+    ),
+    (
+        POSITIVE_INTEGER_OR_EMPTY_CPP,
+        include_str!("data/PositiveIntegerOrEmpty.cpp.template"),
+    ),
+    (
+        POSITIVE_INTEGER_OR_EMPTY_H,
+        include_str!("data/PositiveIntegerOrEmpty.h.template"),
+    ),
+    (STRING_CPP, include_str!("data/string.cpp.template")),
+    (STRING_H, include_str!("data/string.h.template")),
+    (UNION_CPP, include_str!("data/union.cpp.template")),
+    (UNION_H, include_str!("data/union.h.template")),
+];
 
 lazy_static! {
     pub(crate) static ref NO_DATA: HashMap<String, String> = HashMap::new();
 }
 
-pub(crate) fn render<STR, SERIALIZE>(name: STR, data: &SERIALIZE) -> Result<String>
-where
-    STR: AsRef<str>,
-    SERIALIZE: Serialize,
-{
-    let h = &HANDLEBARS;
-    let rendered = h
-        .render(name.as_ref(), data)
-        .map_err(|e| make_err!("unable to render template '{}': {}", name.as_ref(), e))?;
-    Ok(rendered
-        .replace("&amp;", "&")
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-        .replace("&quot;", "\""))
+/// The templates of one generator run: the built-in ones, some of which may be replaced by the
+/// files of a templates directory.
+pub(crate) struct Templates {
+    handlebars: Handlebars<'static>,
 }
 
-fn template_dir() -> &'static Path {
-    &DIR
-}
+impl Templates {
+    /// The built-in templates, with those in `dir`, if given, replacing the ones of the same name.
+    pub(crate) fn new(dir: Option<&Path>) -> Result<Self> {
+        match dir {
+            Some(dir) => Self::load_dir(dir),
+            None => Self::embedded(),
+        }
+    }
 
-fn manifest_dir() -> PathBuf {
-    let dir = env!("CARGO_MANIFEST_DIR");
-    PathBuf::from(dir)
+    /// The built-in templates.
+    pub(crate) fn embedded() -> Result<Self> {
+        let mut handlebars = Handlebars::new();
+        for (name, template) in EMBEDDED {
+            handlebars
+                .register_template_string(name, template)
+                .map_err(|e| make_err!("unable to parse template '{}': {}", name, e))?;
+        }
+        Ok(Self { handlebars })
+    }
+
+    /// The built-in templates, with the `*.template` files in `dir` replacing the ones that have
+    /// the same names. Templates that are not in `dir` stay as they are.
+    pub(crate) fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let files = list_template_files(dir.as_ref())?;
+        let mut templates = Self::embedded()?;
+        for (name, path) in &files {
+            templates
+                .handlebars
+                .register_template_string(name, read(path)?)
+                .map_err(|e| make_err!("unable to parse template '{}': {}", path.display(), e))?;
+        }
+        Ok(templates)
+    }
+
+    pub(crate) fn render<STR, SERIALIZE>(&self, name: STR, data: &SERIALIZE) -> Result<String>
+    where
+        STR: AsRef<str>,
+        SERIALIZE: Serialize,
+    {
+        let rendered = self
+            .handlebars
+            .render(name.as_ref(), data)
+            .map_err(|e| make_err!("unable to render template '{}': {}", name.as_ref(), e))?;
+        Ok(rendered
+            .replace("&amp;", "&")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#x3D;", "=")
+            .replace("&#x27;", "'"))
+    }
 }
 
-fn template_path<S: AsRef<str>>(filename: S) -> PathBuf {
-    template_dir().join(filename.as_ref())
+impl Debug for Templates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names: BTreeSet<&String> = self.handlebars.get_templates().keys().collect();
+        f.debug_struct("Templates").field("names", &names).finish()
+    }
 }
 
 fn read<P: AsRef<Path>>(p: P) -> Result<String> {
@@ -86,10 +173,8 @@ fn read<P: AsRef<Path>>(p: P) -> Result<String> {
         .map_err(|e| make_err!("cannot read file '{}': {}", p.display(), e))?)
 }
 
-fn list_template_files() -> Result<Vec<(String, PathBuf)>> {
-    let dir = template_dir();
+fn list_template_files(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
     let dir = dir
-        .clone()
         .canonicalize()
         .map_err(|e| make_err!("directory not found '{}': {}", dir.display(), e))?;
     if !dir.is_dir() {
@@ -127,3 +212,15 @@ fn list_template_files() -> Result<Vec<(String, PathBuf)>> {
     }
     Ok(list)
 }
+
+#[test]
+fn embedded_test() {
+    let names: Vec<&str> = EMBEDDED.iter().map(|(name, _)| *name).collect();
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("generate")
+        .join("data");
+    for (name, _) in list_template_files(&dir).unwrap() {
+        assert!(names.contains(&name.as_str()), "'{}' is not embedded", name);
+    }
+}
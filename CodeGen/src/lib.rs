@@ -11,4 +11,5 @@ pub mod error;
 pub mod generate;
 pub mod model;
 pub mod utils;
+pub mod validate;
 pub mod xsd;
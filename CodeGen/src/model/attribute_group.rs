@@ -0,0 +1,12 @@
+use crate::model::complex_type::AttributeItem;
+use crate::model::symbol::Symbol;
+
+/// Represents a named `xs:attributeGroup` definition, e.g. `print-style`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct AttributeGroupData {
+    pub name: Symbol,
+    pub documentation: String,
+    /// The attributes in the order they are given in the XSD, which may include references to other
+    /// attribute groups.
+    pub attributes: Vec<AttributeItem>,
+}
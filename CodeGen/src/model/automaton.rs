@@ -0,0 +1,586 @@
+use crate::error::Result;
+use crate::model::complex_type::Particle;
+use crate::model::Model;
+use crate::xsd::Occurs;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The largest `minOccurs` or bounded `maxOccurs` that is unrolled into an automaton.
+const MAX_REPEAT: u64 = 256;
+
+/// The most members of an `xs:all`, every subset of which becomes a state.
+const MAX_ALL: usize = 12;
+
+/// A content model to build an `Automaton` from. Each element carries a `T`, e.g. its declaration,
+/// which the automaton gives back for each child element that it matches.
+#[derive(Debug, Clone)]
+pub enum Term<T> {
+    Element(String, T, Occurs),
+    /// An `xs:any`, which matches any element.
+    Any(Occurs),
+    Sequence(Occurs, Vec<Term<T>>),
+    Choice(Occurs, Vec<Term<T>>),
+    /// The members of an `xs:all` are elements, each of which may appear at most once.
+    All(Occurs, Vec<Term<T>>),
+}
+
+impl<T> Term<T> {
+    pub fn occurs(&self) -> Occurs {
+        match self {
+            Term::Element(_, _, occurs)
+            | Term::Any(occurs)
+            | Term::Sequence(occurs, _)
+            | Term::Choice(occurs, _)
+            | Term::All(occurs, _) => *occurs,
+        }
+    }
+}
+
+/// A deterministic automaton that accepts the sequences of child element names allowed by a
+/// content model. Building it fails if the content model violates the Unique Particle Attribution
+/// constraint, i.e. if a child element could be matched by more than one particle.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    /// The element particles, a transition refers to one of these by index.
+    particles: Vec<Leaf<T>>,
+    /// State 0 is the start state.
+    states: Vec<State>,
+}
+
+#[derive(Debug, Clone)]
+struct Leaf<T> {
+    /// `None` for `xs:any`.
+    name: Option<String>,
+    value: Option<T>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    accepting: bool,
+    transitions: BTreeMap<String, Transition>,
+    /// Where an element that has no transition of its own goes, if there is an `xs:any`.
+    wildcard: Option<Transition>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    state: usize,
+    particle: usize,
+}
+
+/// Where a list of child elements stopped matching an `Automaton`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The index of the first child that could not be matched, which is the number of children if
+    /// they all matched but more are required.
+    pub position: usize,
+    /// The names of the elements that were allowed at `position`.
+    pub expected: Vec<String>,
+}
+
+impl<T: Clone> Automaton<T> {
+    pub fn new(term: &Term<T>) -> Result<Self> {
+        let mut nfa = Nfa::default();
+        let mut particles = Vec::new();
+        let term = index(term, &mut particles);
+        let (start, end) = nfa.repeat(&term)?;
+        Ok(Self {
+            states: nfa.determinize(start, end, &particles)?,
+            particles,
+        })
+    }
+}
+
+impl<T> Automaton<T> {
+    pub fn start(&self) -> usize {
+        0
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Whether the children seen so far are complete in `state`.
+    pub fn is_accepting(&self, state: usize) -> bool {
+        self.states[state].accepting
+    }
+
+    /// The state after an element named `name` in `state`, and the value of the element particle
+    /// that matched it, which is `None` for a wildcard.
+    pub fn next(&self, state: usize, name: &str) -> Option<(usize, Option<&T>)> {
+        let s = &self.states[state];
+        s.transitions
+            .get(name)
+            .or(s.wildcard.as_ref())
+            .map(|t| (t.state, self.particles[t.particle].value.as_ref()))
+    }
+
+    /// The element names that have a transition from `state`, in order.
+    pub fn transitions(&self, state: usize) -> impl Iterator<Item = (&str, usize)> {
+        self.states[state]
+            .transitions
+            .iter()
+            .map(|(name, t)| (name.as_str(), t.state))
+    }
+
+    /// The state that any other element goes to from `state`, if there is a wildcard.
+    pub fn wildcard(&self, state: usize) -> Option<usize> {
+        self.states[state].wildcard.map(|t| t.state)
+    }
+
+    /// Matches `names`, returning the value of the particle that matched each of them.
+    pub fn run<S: AsRef<str>>(
+        &self,
+        names: &[S],
+    ) -> std::result::Result<Vec<Option<&T>>, Mismatch> {
+        let mut state = self.start();
+        let mut values = Vec::with_capacity(names.len());
+        for (position, name) in names.iter().enumerate() {
+            match self.next(state, name.as_ref()) {
+                Some((next, value)) => {
+                    state = next;
+                    values.push(value);
+                }
+                None => return Err(self.mismatch(state, position)),
+            }
+        }
+        if self.is_accepting(state) {
+            Ok(values)
+        } else {
+            Err(self.mismatch(state, names.len()))
+        }
+    }
+
+    /// The value of the first element particle named `name`.
+    pub fn declaration(&self, name: &str) -> Option<&T> {
+        self.particles
+            .iter()
+            .find(|p| p.name.as_deref() == Some(name))
+            .and_then(|p| p.value.as_ref())
+    }
+
+    fn mismatch(&self, state: usize, position: usize) -> Mismatch {
+        Mismatch {
+            position,
+            expected: self.states[state].transitions.keys().cloned().collect(),
+        }
+    }
+}
+
+impl Model {
+    /// Builds the automaton for a complex type's `particle`, with its group references flattened.
+    pub fn automaton(&self, particle: &Particle) -> Result<Automaton<()>> {
+        Automaton::new(&term(&self.flatten(particle)?)?)
+    }
+}
+
+/// Converts a flattened model `Particle` into a `Term`.
+fn term(particle: &Particle) -> Result<Term<()>> {
+    let members =
+        |members: &[Particle]| -> Result<Vec<Term<()>>> { members.iter().map(term).collect() };
+    Ok(match particle {
+        Particle::Element(e) => Term::Element(e.name.original().to_owned(), (), e.occurs),
+        Particle::Sequence(c) => Term::Sequence(c.occurs, members(&c.members)?),
+        Particle::Choice(c) => Term::Choice(c.occurs, members(&c.members)?),
+        Particle::All(c) => Term::All(c.occurs, members(&c.members)?),
+        Particle::Any(a) => Term::Any(a.occurs),
+        Particle::GroupRef(g) => {
+            return raise!(
+                "the group reference '{}' has not been flattened",
+                g.name.original()
+            )
+        }
+    })
+}
+
+/// A `Term` in which each element particle has been replaced by its index.
+#[derive(Debug)]
+enum Indexed {
+    Leaf(usize, Occurs),
+    Sequence(Occurs, Vec<Indexed>),
+    Choice(Occurs, Vec<Indexed>),
+    All(Occurs, Vec<Indexed>),
+}
+
+fn index<T: Clone>(term: &Term<T>, particles: &mut Vec<Leaf<T>>) -> Indexed {
+    match term {
+        Term::Element(name, value, occurs) => {
+            particles.push(Leaf {
+                name: Some(name.clone()),
+                value: Some(value.clone()),
+            });
+            Indexed::Leaf(particles.len() - 1, *occurs)
+        }
+        Term::Any(occurs) => {
+            particles.push(Leaf {
+                name: None,
+                value: None,
+            });
+            Indexed::Leaf(particles.len() - 1, *occurs)
+        }
+        Term::Sequence(occurs, terms) => Indexed::Sequence(*occurs, index_all(terms, particles)),
+        Term::Choice(occurs, terms) => Indexed::Choice(*occurs, index_all(terms, particles)),
+        Term::All(occurs, terms) => Indexed::All(*occurs, index_all(terms, particles)),
+    }
+}
+
+fn index_all<T: Clone>(terms: &[Term<T>], particles: &mut Vec<Leaf<T>>) -> Vec<Indexed> {
+    terms.iter().map(|t| index(t, particles)).collect()
+}
+
+impl Indexed {
+    fn occurs(&self) -> Occurs {
+        match self {
+            Indexed::Leaf(_, occurs)
+            | Indexed::Sequence(occurs, _)
+            | Indexed::Choice(occurs, _)
+            | Indexed::All(occurs, _) => *occurs,
+        }
+    }
+}
+
+/// A nondeterministic automaton with empty transitions. Each repetition of a particle gets its own
+/// nodes, but its transitions refer to the same particle.
+#[derive(Debug, Default)]
+struct Nfa {
+    /// The transitions from each node, `None` is an empty transition, otherwise the particle.
+    edges: Vec<Vec<(Option<usize>, usize)>>,
+}
+
+impl Nfa {
+    fn node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    fn edge(&mut self, from: usize, particle: Option<usize>, to: usize) {
+        self.edges[from].push((particle, to));
+    }
+
+    /// Builds `term` repeated as allowed by its occurs, returning its start and end nodes.
+    fn repeat(&mut self, term: &Indexed) -> Result<(usize, usize)> {
+        let occurs = term.occurs();
+        let max = occurs.max_occurs.unwrap_or(occurs.min_occurs);
+        if max > MAX_REPEAT {
+            return raise!(
+                "an occurrence of {} is too large to build an automaton for",
+                max
+            );
+        }
+        let start = self.node();
+        let mut current = start;
+        for _ in 0..occurs.min_occurs {
+            let (s, e) = self.once(term)?;
+            self.edge(current, None, s);
+            current = e;
+        }
+        let end = self.node();
+        self.edge(current, None, end);
+        match occurs.max_occurs {
+            None => {
+                let (s, e) = self.once(term)?;
+                self.edge(current, None, s);
+                self.edge(e, None, s);
+                self.edge(e, None, end);
+            }
+            Some(max) => {
+                for _ in occurs.min_occurs..max {
+                    let (s, e) = self.once(term)?;
+                    self.edge(current, None, s);
+                    self.edge(e, None, end);
+                    current = e;
+                }
+            }
+        }
+        Ok((start, end))
+    }
+
+    /// Builds one repetition of `term`.
+    fn once(&mut self, term: &Indexed) -> Result<(usize, usize)> {
+        let start = self.node();
+        let end = self.node();
+        match term {
+            Indexed::Leaf(particle, _) => self.edge(start, Some(*particle), end),
+            Indexed::Sequence(_, members) => {
+                let mut current = start;
+                for member in members {
+                    let (s, e) = self.repeat(member)?;
+                    self.edge(current, None, s);
+                    current = e;
+                }
+                self.edge(current, None, end);
+            }
+            // a choice with no members matches nothing.
+            Indexed::Choice(_, members) => {
+                for member in members {
+                    let (s, e) = self.repeat(member)?;
+                    self.edge(start, None, s);
+                    self.edge(e, None, end);
+                }
+            }
+            // a node for each subset of the members that have been seen.
+            Indexed::All(_, members) => {
+                if members.len() > MAX_ALL {
+                    return raise!("an xs:all with more than {} members", MAX_ALL);
+                }
+                let mut required = 0usize;
+                let mut particles = Vec::new();
+                for (i, member) in members.iter().enumerate() {
+                    let occurs = member.occurs();
+                    match member {
+                        Indexed::Leaf(particle, _) if occurs.max_occurs == Some(1) => {
+                            particles.push(*particle)
+                        }
+                        _ => return raise!("the members of an xs:all must be single elements"),
+                    }
+                    if occurs.min_occurs > 0 {
+                        required |= 1 << i;
+                    }
+                }
+                let subsets: Vec<usize> =
+                    (0..1usize << members.len()).map(|_| self.node()).collect();
+                self.edge(start, None, subsets[0]);
+                for (seen, &node) in subsets.iter().enumerate() {
+                    for (i, &particle) in particles.iter().enumerate() {
+                        if seen & (1 << i) == 0 {
+                            self.edge(node, Some(particle), subsets[seen | 1 << i]);
+                        }
+                    }
+                    if seen & required == required {
+                        self.edge(node, None, end);
+                    }
+                }
+            }
+        }
+        Ok((start, end))
+    }
+
+    fn closure(&self, nodes: &mut BTreeSet<usize>) {
+        let mut stack: Vec<usize> = nodes.iter().copied().collect();
+        while let Some(node) = stack.pop() {
+            for &(particle, to) in &self.edges[node] {
+                if particle.is_none() && nodes.insert(to) {
+                    stack.push(to);
+                }
+            }
+        }
+    }
+
+    /// The subset construction, which also checks Unique Particle Attribution: from any state, an
+    /// element name must lead to a single particle.
+    fn determinize<T>(
+        &self,
+        start: usize,
+        end: usize,
+        particles: &[Leaf<T>],
+    ) -> Result<Vec<State>> {
+        let mut first: BTreeSet<usize> = std::iter::once(start).collect();
+        self.closure(&mut first);
+        let mut ids: HashMap<BTreeSet<usize>, usize> = HashMap::new();
+        let mut sets = vec![first.clone()];
+        ids.insert(first, 0);
+        let mut states = Vec::new();
+        while states.len() < sets.len() {
+            let set = sets[states.len()].clone();
+            // the nodes reached from this set, by particle.
+            let mut moves: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+            for &node in &set {
+                for &(particle, to) in &self.edges[node] {
+                    if let Some(particle) = particle {
+                        moves.entry(particle).or_default().insert(to);
+                    }
+                }
+            }
+            let mut state = State {
+                accepting: set.contains(&end),
+                ..State::default()
+            };
+            let wildcards: Vec<usize> = moves
+                .keys()
+                .copied()
+                .filter(|&p| particles[p].name.is_none())
+                .collect();
+            if wildcards.len() > 1 {
+                return raise!("the content model has overlapping wildcards");
+            }
+            for (particle, mut targets) in moves {
+                self.closure(&mut targets);
+                let next = match ids.get(&targets) {
+                    Some(&id) => id,
+                    None => {
+                        ids.insert(targets.clone(), sets.len());
+                        sets.push(targets);
+                        sets.len() - 1
+                    }
+                };
+                let transition = Transition {
+                    state: next,
+                    particle,
+                };
+                match &particles[particle].name {
+                    None => state.wildcard = Some(transition),
+                    Some(name) => {
+                        if !wildcards.is_empty() {
+                            return raise!(
+                                "the content model is ambiguous, '{}' matches both an element \
+                                 and a wildcard",
+                                name
+                            );
+                        }
+                        if state.transitions.insert(name.clone(), transition).is_some() {
+                            return raise!(
+                                "the content model is ambiguous, '{}' matches more than one \
+                                 particle",
+                                name
+                            );
+                        }
+                    }
+                }
+            }
+            states.push(state);
+        }
+        Ok(states)
+    }
+}
+
+#[cfg(test)]
+fn occurs(min_occurs: u64, max_occurs: Option<u64>) -> Occurs {
+    Occurs {
+        min_occurs,
+        max_occurs,
+    }
+}
+
+#[cfg(test)]
+fn element(name: &str, min: u64, max: Option<u64>) -> Term<&str> {
+    Term::Element(name.to_owned(), name, occurs(min, max))
+}
+
+#[test]
+fn automaton_sequence() {
+    // (a, b?, c{2,3})
+    let term = Term::Sequence(
+        Occurs::default(),
+        vec![
+            element("a", 1, Some(1)),
+            element("b", 0, Some(1)),
+            element("c", 2, Some(3)),
+        ],
+    );
+    let automaton = Automaton::new(&term).unwrap();
+    assert!(automaton.run(&["a", "c", "c"]).is_ok());
+    assert!(automaton.run(&["a", "b", "c", "c", "c"]).is_ok());
+    assert_eq!(
+        automaton.run(&["a", "c"]),
+        Err(Mismatch {
+            position: 2,
+            expected: vec!["c".to_owned()]
+        })
+    );
+    assert_eq!(
+        automaton.run(&["a", "c", "b"]),
+        Err(Mismatch {
+            position: 2,
+            expected: vec!["c".to_owned()]
+        })
+    );
+    assert_eq!(
+        automaton.run(&["c"]),
+        Err(Mismatch {
+            position: 0,
+            expected: vec!["a".to_owned()]
+        })
+    );
+}
+
+#[test]
+fn automaton_choice_values() {
+    // (a | (b, a))*, where the two a particles are distinguished by what precedes them.
+    let term = Term::Choice(
+        occurs(0, None),
+        vec![
+            Term::Element("a".to_owned(), 1, Occurs::default()),
+            Term::Sequence(
+                Occurs::default(),
+                vec![
+                    Term::Element("b".to_owned(), 2, Occurs::default()),
+                    Term::Element("a".to_owned(), 3, Occurs::default()),
+                ],
+            ),
+        ],
+    );
+    let automaton = Automaton::new(&term).unwrap();
+    let got = automaton.run(&["a", "b", "a", "a"]).unwrap();
+    assert_eq!(got, vec![Some(&1), Some(&2), Some(&3), Some(&1)]);
+    assert!(automaton.run::<&str>(&[]).is_ok());
+    assert!(automaton.run(&["b"]).is_err());
+}
+
+#[test]
+fn automaton_all() {
+    let term = Term::All(
+        Occurs::default(),
+        vec![element("a", 1, Some(1)), element("b", 0, Some(1))],
+    );
+    let automaton = Automaton::new(&term).unwrap();
+    assert!(automaton.run(&["a"]).is_ok());
+    assert!(automaton.run(&["b", "a"]).is_ok());
+    assert!(automaton.run(&["a", "b"]).is_ok());
+    assert!(automaton.run(&["b"]).is_err());
+    assert!(automaton.run(&["a", "a"]).is_err());
+}
+
+#[test]
+fn automaton_wildcard() {
+    let term = Term::Sequence(
+        Occurs::default(),
+        vec![element("a", 1, Some(1)), Term::Any(occurs(0, None))],
+    );
+    let automaton = Automaton::new(&term).unwrap();
+    assert_eq!(
+        automaton.run(&["a", "x", "a"]).unwrap(),
+        vec![Some(&"a"), None, None]
+    );
+}
+
+#[test]
+fn automaton_unique_particle_attribution() {
+    // (a?, a) is ambiguous, the first a could be matched by either particle.
+    let term = Term::Sequence(
+        Occurs::default(),
+        vec![element("a", 0, Some(1)), element("a", 1, Some(1))],
+    );
+    let err = Automaton::new(&term).unwrap_err().to_string();
+    assert!(
+        err.contains("'a' matches more than one particle"),
+        "{}",
+        err
+    );
+    // ((a, b) | (a, c)) is ambiguous.
+    let term = Term::Choice(
+        Occurs::default(),
+        vec![
+            Term::Sequence(
+                Occurs::default(),
+                vec![element("a", 1, Some(1)), element("b", 1, Some(1))],
+            ),
+            Term::Sequence(
+                Occurs::default(),
+                vec![element("a", 1, Some(1)), element("c", 1, Some(1))],
+            ),
+        ],
+    );
+    assert!(Automaton::new(&term).is_err());
+    // (a, xs:any) is ambiguous when the wildcard competes with a.
+    let term = Term::Sequence(
+        Occurs::default(),
+        vec![element("a", 0, Some(1)), Term::Any(Occurs::default())],
+    );
+    assert!(Automaton::new(&term).is_err());
+    // a repeated particle is not ambiguous.
+    let term = Term::Sequence(occurs(1, Some(3)), vec![element("a", 0, Some(1))]);
+    assert!(Automaton::new(&term).is_ok());
+}
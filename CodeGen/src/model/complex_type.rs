@@ -0,0 +1,125 @@
+use crate::model::symbol::Symbol;
+use crate::xsd::any::{Namespace, ProcessContents};
+use crate::xsd::primitives::BaseType;
+use crate::xsd::Occurs;
+
+/// Represents an `xs:complexType`, i.e. the thing that, for the most part, becomes an element class
+/// in `mx::core`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ComplexTypeData {
+    pub name: Symbol,
+    pub documentation: String,
+    /// The attributes in the order they are given in the XSD. Attribute group references are not
+    /// expanded here.
+    pub attributes: Vec<AttributeItem>,
+    /// The complete set of attributes, with attribute group references expanded and the attributes
+    /// of an extension's base type included. This is empty until `Model::resolve` has been called.
+    pub all_attributes: Vec<AttributeData>,
+    pub content: Content,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum AttributeItem {
+    Attribute(AttributeData),
+    AttributeGroupRef(Symbol),
+    /// An `xs:anyAttribute` wildcard.
+    AnyAttribute(Wildcard),
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct AttributeData {
+    pub name: Symbol,
+    /// For an attribute declared with `ref`, e.g. `ref="xml:lang"`, this will be the referenced
+    /// name as a `BaseType::Custom`.
+    pub type_: BaseType,
+    /// True if the attribute was declared with `ref` instead of `type`.
+    pub is_ref: bool,
+    pub required: bool,
+    pub default: Option<String>,
+    pub fixed: Option<String>,
+    pub documentation: String,
+}
+
+/// The allowable content of a complex type.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub enum Content {
+    /// The complex type has no text or child elements (it may still have attributes).
+    #[default]
+    Empty,
+    /// Derived from `xs:simpleContent`. The element holds a text value of the `base` type.
+    Simple(BaseType),
+    /// Derived from `xs:complexContent`. The complex type extends another complex type, named by
+    /// `base`.
+    Extension(Symbol),
+    /// The complex type has child elements.
+    Particle(Particle),
+}
+
+/// A member of a content model.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Particle {
+    Element(ElementParticle),
+    Sequence(Compositor),
+    Choice(Compositor),
+    /// An `xs:all`, its members are elements that may appear in any order.
+    All(Compositor),
+    GroupRef(GroupRefParticle),
+    /// An `xs:any` wildcard.
+    Any(AnyParticle),
+}
+
+impl Particle {
+    pub fn occurs(&self) -> &Occurs {
+        match self {
+            Particle::Element(x) => &x.occurs,
+            Particle::Sequence(x) => &x.occurs,
+            Particle::Choice(x) => &x.occurs,
+            Particle::All(x) => &x.occurs,
+            Particle::GroupRef(x) => &x.occurs,
+            Particle::Any(x) => &x.occurs,
+        }
+    }
+}
+
+/// An `xs:sequence`, `xs:choice` or `xs:all`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Compositor {
+    pub occurs: Occurs,
+    pub members: Vec<Particle>,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ElementParticle {
+    pub name: Symbol,
+    pub type_: ElementType,
+    pub occurs: Occurs,
+    pub documentation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum ElementType {
+    /// The element's type is given by name, e.g. `type="xs:string"` or `type="credit"`.
+    Named(BaseType),
+    /// The element's type is defined in-line by an anonymous `xs:complexType`.
+    Anonymous(Box<ComplexTypeData>),
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct GroupRefParticle {
+    pub name: Symbol,
+    pub occurs: Occurs,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct AnyParticle {
+    pub wildcard: Wildcard,
+    pub occurs: Occurs,
+}
+
+/// The namespaces allowed by an `xs:any` or `xs:anyAttribute`, and how their content is validated.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+pub struct Wildcard {
+    pub namespace: Namespace,
+    pub process_contents: ProcessContents,
+    pub documentation: String,
+}
@@ -1,4 +1,5 @@
 use crate::model::Def;
+use crate::xsd::source::Location;
 use crate::xsd::{Entry, Xsd};
 use std::fmt::{Display, Formatter};
 
@@ -41,10 +42,15 @@ pub type CreateResult = std::result::Result<Option<Vec<Def>>, CreateError>;
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct CreateError {
     pub message: String,
+    /// The location of the XSD entry that the error was raised for, if known.
+    pub location: Option<Location>,
 }
 
 impl Display for CreateError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(location) = &self.location {
+            write!(f, "{}: error: ", location)?;
+        }
         write!(f, "{}", self.message.as_str())
     }
 }
@@ -59,7 +65,16 @@ impl CreateError {
     pub fn new<S: AsRef<str>>(message: S) -> Self {
         Self {
             message: message.as_ref().into(),
+            location: None,
+        }
+    }
+
+    /// Sets the location unless the error already has one.
+    pub fn at(mut self, location: Option<&Location>) -> Self {
+        if self.location.is_none() {
+            self.location = location.cloned();
         }
+        self
     }
 }
 
@@ -68,16 +83,19 @@ macro_rules! make_create_err {
     () => {
         crate::model::create::CreateError{
             message: "".to_owned(),
+            location: None,
         }
     };
     ($msg:expr) => {
         crate::model::create::CreateError{
             message: $msg.into(),
+            location: None,
         }
     };
     ($fmt:expr, $($arg:expr),+) => {
         crate::model::create::CreateError{
             message: format!($fmt, $($arg),+),
+            location: None,
         }
     };
 }
@@ -1,8 +1,11 @@
 use crate::error::Result;
+use crate::model::complex_type::AttributeData;
 use crate::model::create::{Create, CreateError};
 use crate::model::post_process::PostProcess;
+use crate::model::symbol::Symbol;
 use crate::model::transform::Transform;
 use crate::model::{Def, DefaultCreate, Model};
+use crate::xsd::primitives::{BaseType, PrefixedParse};
 use crate::xsd::{Entry, Xsd};
 use std::borrow::Cow;
 use std::fmt::{Debug, Formatter};
@@ -76,43 +79,94 @@ impl Creator {
             let mut entry = entry.clone();
             if let Some(transforms) = &self.transforms {
                 for transform in transforms {
-                    entry = wrap!(transform.transform(&entry, xsd))?;
+                    entry = wrap!(transform
+                        .transform(&entry, xsd)
+                        .map_err(|e| e.at(entry.id().location())))?;
                 }
             }
-            let mut is_handled = false;
-            for create in &self.creates {
-                if let Some(mut more_models) = wrap!(create.create(&entry, xsd))? {
-                    is_handled = true;
-                    if let Some(post_processors) = &self.post_processors {
-                        let mut post_processed_models = Vec::new();
-                        for model in &more_models {
-                            let mut model_being_processed = model.clone();
-                            for post_processor in post_processors {
-                                model_being_processed =
-                                    wrap!(post_processor.process(&model_being_processed, xsd))?;
-                            }
-                            post_processed_models.push(model_being_processed);
-                        }
-                        more_models = post_processed_models;
-                    }
-                    defs.append(&mut more_models);
-                    break;
-                }
-            }
-            if !is_handled {
-                return raise!(
-                    "the entry {} was not handled by any Create objects",
-                    entry.id()
-                );
-            }
+            defs.append(&mut self.create_defs(&entry, xsd)?);
         }
         let mut model = Model::default();
         for def in defs {
-            model.add(def);
+            model.add(def)?;
         }
+        self.create_imports(xsd, xsd, &mut model)?;
+        model.resolve()?;
         Ok(model)
     }
 
+    /// Runs `entry` through the `Create` objects and the post processors.
+    fn create_defs(&self, entry: &Entry, xsd: &Xsd) -> Result<Vec<Def>> {
+        for create in &self.creates {
+            if let Some(defs) = wrap!(create
+                .create(entry, xsd)
+                .map_err(|e| e.at(entry.id().location())))?
+            {
+                let post_processors = match &self.post_processors {
+                    None => return Ok(defs),
+                    Some(x) => x,
+                };
+                let mut post_processed = Vec::new();
+                for def in defs {
+                    post_processed.push(wrap!(post_process(post_processors, def, xsd)
+                        .map_err(|e| e.at(entry.id().location())))?);
+                }
+                return Ok(post_processed);
+            }
+        }
+        Err(make_err!(
+            "the entry {} was not handled by any Create objects",
+            entry.id()
+        )
+        .at(entry.id().location().cloned()))
+    }
+
+    /// Adds the top-level attributes of the schemas imported by `xsd`, at any depth, to the model
+    /// under the names that `root` qualifies them with, e.g. `xml:lang`. Imports whose namespace
+    /// has no prefix in `root` cannot be referred to and are skipped.
+    fn create_imports(&self, root: &Xsd, xsd: &Xsd, model: &mut Model) -> Result<()> {
+        for import in xsd.imports() {
+            let prefix = match import.target_namespace().and_then(|ns| root.prefix_of(ns)) {
+                Some(x) => x,
+                None => continue,
+            };
+            let qualify = |name: &str| format!("{}:{}", prefix, name);
+            for entry in import.entries() {
+                let a = match entry {
+                    Entry::Attribute(x) => x,
+                    _ => continue,
+                };
+                let type_ = match &a.simple_type {
+                    Some(st) => {
+                        let mut st = st.as_ref().clone();
+                        st.name = qualify(&st.name);
+                        let name = st.name.clone();
+                        for def in self.create_defs(&Entry::SimpleType(st), import)? {
+                            model.add_imported(def)?;
+                        }
+                        BaseType::Custom(name)
+                    }
+                    None => wrap!(
+                        BaseType::parse_prefixed(a.defined_by.value(), import.prefix()),
+                        "unable to parse the type of attribute '{}'",
+                        qualify(&a.name)
+                    )?,
+                };
+                model.add_attribute(AttributeData {
+                    name: Symbol::new(qualify(&a.name)),
+                    type_,
+                    is_ref: false,
+                    required: a.required,
+                    default: a.default.clone(),
+                    fixed: a.fixed.clone(),
+                    documentation: a.documentation(),
+                })?;
+            }
+            self.create_imports(root, import, model)?;
+        }
+        Ok(())
+    }
+
     fn transform<'a>(
         &'a self,
         entry: &'a Entry,
@@ -121,3 +175,25 @@ impl Creator {
         Ok(Cow::Borrowed(entry))
     }
 }
+
+/// Runs `def` through the `post_processors`. The in-line members of a union are processed, before
+/// the union itself, as though they were top-level types.
+fn post_process(
+    post_processors: &[Box<dyn PostProcess>],
+    def: Def,
+    xsd: &Xsd,
+) -> std::result::Result<Def, CreateError> {
+    let mut def = def;
+    if let Def::UnionSimpleType(union) = &mut def {
+        let inline_members = std::mem::take(&mut union.inline_members);
+        for inline in inline_members {
+            union
+                .inline_members
+                .push(post_process(post_processors, inline, xsd)?);
+        }
+    }
+    for post_processor in post_processors {
+        def = post_processor.process(&def, xsd)?;
+    }
+    Ok(def)
+}
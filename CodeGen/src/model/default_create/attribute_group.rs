@@ -0,0 +1,24 @@
+use crate::model::attribute_group::AttributeGroupData;
+use crate::model::create::CreateResult;
+use crate::model::default_create::complex_type::model_attributes;
+use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::Xsd;
+
+pub(super) fn model_attribute_group(ag: &AttributeGroup, xsd: &Xsd) -> CreateResult {
+    let def = match ag {
+        AttributeGroup::Def(def) => def,
+        AttributeGroup::Ref(r) => {
+            return Err(make_create_err!(
+                "unexpected top-level attributeGroup reference '{}'",
+                r.ref_
+            ))
+        }
+    };
+    Ok(Some(vec![Def::AttributeGroup(AttributeGroupData {
+        name: Symbol::new(def.name.as_str()),
+        documentation: def.documentation(),
+        attributes: model_attributes(&def.attributes, xsd)?,
+    })]))
+}
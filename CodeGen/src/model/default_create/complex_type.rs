@@ -0,0 +1,350 @@
+use crate::model::complex_type::{
+    AnyParticle, AttributeData, AttributeItem, ComplexTypeData, Compositor, Content,
+    ElementParticle, ElementType, GroupRefParticle, Particle, Wildcard,
+};
+use crate::model::create::{CreateError, CreateResult};
+use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::all::All;
+use crate::xsd::any::{Any, AnyAttribute};
+use crate::xsd::attribute::Attribute;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::attributes::{self, Attributes};
+use crate::xsd::choice::{Choice, ChoiceItem};
+use crate::xsd::complex_type::{Children, ComplexType, Payload};
+use crate::xsd::element::Element;
+use crate::xsd::group::Group;
+use crate::xsd::primitives::{BaseType, PrefixedParse};
+use crate::xsd::sequence::{self, Sequence};
+use crate::xsd::{simple_content, Xsd};
+
+pub(super) fn model_complex_type(ct: &ComplexType, xsd: &Xsd) -> CreateResult {
+    let data = complex_type_data(ct, xsd)?;
+    Ok(Some(vec![Def::ComplexType(data)]))
+}
+
+/// Converts a complex type, named or anonymous, into its model representation.
+pub(super) fn complex_type_data(
+    ct: &ComplexType,
+    xsd: &Xsd,
+) -> std::result::Result<ComplexTypeData, CreateError> {
+    let (attributes, content) = match &ct.payload {
+        Payload::ComplexContent(cc) => (
+            model_attributes(&cc.extension.attributes, xsd)?,
+            Content::Extension(Symbol::new(cc.extension.base.as_str())),
+        ),
+        Payload::SimpleContent(sc) => match &sc.payload {
+            simple_content::Payload::Extension(ext) => (
+                model_attributes(&ext.attributes, xsd)?,
+                Content::Simple(parse_base_type(&ext.base, xsd)?),
+            ),
+        },
+        Payload::Parent(p) => (
+            model_attributes(&p.attributes, xsd)?,
+            match &p.children {
+                None => Content::Empty,
+                Some(Children::All(a)) => Content::Particle(model_all(a, xsd)?),
+                Some(Children::Choice(c)) => Content::Particle(model_choice(c, xsd)?),
+                Some(Children::Group(g)) => Content::Particle(model_group(g)?),
+                Some(Children::Sequence(s)) => Content::Particle(model_sequence(s, xsd)?),
+            },
+        ),
+        Payload::None => (Vec::new(), Content::Empty),
+    };
+    Ok(ComplexTypeData {
+        name: Symbol::new(ct.name.as_str()),
+        documentation: ct.documentation(),
+        attributes,
+        all_attributes: Vec::new(),
+        content,
+    })
+}
+
+pub(super) fn parse_base_type<S: AsRef<str>>(
+    s: S,
+    xsd: &Xsd,
+) -> std::result::Result<BaseType, CreateError> {
+    BaseType::parse_prefixed(s.as_ref(), xsd.prefix())
+        .map_err(|e| make_create_err!("unable to parse type '{}': {}", s.as_ref(), e))
+}
+
+pub(super) fn model_attributes(
+    attributes: &Attributes,
+    xsd: &Xsd,
+) -> std::result::Result<Vec<AttributeItem>, CreateError> {
+    let mut items = Vec::new();
+    for item in attributes {
+        match item {
+            attributes::AttributeItem::Attribute(a) => {
+                items.push(AttributeItem::Attribute(model_attribute(a, xsd)?))
+            }
+            attributes::AttributeItem::AttributeGroup(AttributeGroup::Ref(r)) => items.push(
+                AttributeItem::AttributeGroupRef(Symbol::new(r.ref_.as_str())),
+            ),
+            attributes::AttributeItem::AnyAttribute(a) => {
+                items.push(AttributeItem::AnyAttribute(any_attribute_wildcard(a)))
+            }
+            attributes::AttributeItem::AttributeGroup(AttributeGroup::Def(d)) => {
+                return Err(make_create_err!(
+                    "unexpected attributeGroup definition '{}' in attribute list",
+                    d.name
+                ))
+            }
+        }
+    }
+    Ok(items)
+}
+
+pub(super) fn model_attribute(
+    a: &Attribute,
+    xsd: &Xsd,
+) -> std::result::Result<AttributeData, CreateError> {
+    let name = if a.name.is_empty() {
+        a.defined_by.value()
+    } else {
+        a.name.as_str()
+    };
+    Ok(AttributeData {
+        name: Symbol::new(name),
+        type_: parse_base_type(a.defined_by.value(), xsd)?,
+        is_ref: a.defined_by.is_ref(),
+        required: a.required,
+        default: a.default.clone(),
+        fixed: a.fixed.clone(),
+        documentation: a.documentation(),
+    })
+}
+
+pub(super) fn model_sequence(
+    s: &Sequence,
+    xsd: &Xsd,
+) -> std::result::Result<Particle, CreateError> {
+    let mut members = Vec::new();
+    for member in &s.members {
+        members.push(match member {
+            sequence::Member::Any(a) => model_any(a),
+            sequence::Member::Choice(c) => model_choice(c, xsd)?,
+            sequence::Member::Element(e) => model_element(e, xsd)?,
+            sequence::Member::Group(g) => model_group(g)?,
+            sequence::Member::Sequence(s) => model_sequence(s, xsd)?,
+        });
+    }
+    Ok(Particle::Sequence(Compositor {
+        occurs: s.occurs,
+        members,
+    }))
+}
+
+pub(super) fn model_choice(c: &Choice, xsd: &Xsd) -> std::result::Result<Particle, CreateError> {
+    let mut members = Vec::new();
+    for item in &c.choices {
+        members.push(match item {
+            ChoiceItem::Any(a) => model_any(a),
+            ChoiceItem::Choice(c) => model_choice(c, xsd)?,
+            ChoiceItem::Element(e) => model_element(e, xsd)?,
+            ChoiceItem::Group(g) => model_group(g)?,
+            ChoiceItem::Sequence(s) => model_sequence(s, xsd)?,
+        });
+    }
+    Ok(Particle::Choice(Compositor {
+        occurs: c.occurs,
+        members,
+    }))
+}
+
+pub(super) fn model_all(a: &All, xsd: &Xsd) -> std::result::Result<Particle, CreateError> {
+    let mut members = Vec::new();
+    for e in &a.elements {
+        members.push(model_element(e, xsd)?);
+    }
+    Ok(Particle::All(Compositor {
+        occurs: a.occurs,
+        members,
+    }))
+}
+
+fn model_any(a: &Any) -> Particle {
+    Particle::Any(AnyParticle {
+        wildcard: Wildcard {
+            namespace: a.namespace.clone(),
+            process_contents: a.process_contents,
+            documentation: a.documentation(),
+        },
+        occurs: a.occurs,
+    })
+}
+
+fn any_attribute_wildcard(a: &AnyAttribute) -> Wildcard {
+    Wildcard {
+        namespace: a.namespace.clone(),
+        process_contents: a.process_contents,
+        documentation: a.documentation(),
+    }
+}
+
+fn model_group(g: &Group) -> std::result::Result<Particle, CreateError> {
+    match g {
+        Group::Reference(r) => Ok(Particle::GroupRef(GroupRefParticle {
+            name: Symbol::new(r.ref_.as_str()),
+            occurs: r.occurs,
+        })),
+        Group::Definition(d) => Err(make_create_err!(
+            "unexpected group definition '{}' in a content model",
+            d.id
+        )),
+    }
+}
+
+pub(super) fn model_element(e: &Element, xsd: &Xsd) -> std::result::Result<Particle, CreateError> {
+    Ok(Particle::Element(ElementParticle {
+        name: Symbol::new(e.name()),
+        type_: model_element_type(e, xsd)?,
+        occurs: *e.occurs(),
+        documentation: e.documentation(),
+    }))
+}
+
+pub(super) fn model_element_type(
+    e: &Element,
+    xsd: &Xsd,
+) -> std::result::Result<ElementType, CreateError> {
+    match e {
+        Element::Definition(def) => Ok(ElementType::Anonymous(Box::new(complex_type_data(
+            &def.complex_type,
+            xsd,
+        )?))),
+        Element::Reference(r) => Ok(ElementType::Named(r.type_.clone())),
+    }
+}
+
+#[test]
+fn model_arrow() {
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+	<xs:complexType name="arrow">
+		<xs:annotation>
+			<xs:documentation>The arrow element represents an arrow.</xs:documentation>
+		</xs:annotation>
+		<xs:choice>
+			<xs:sequence>
+				<xs:element name="arrow-direction" type="arrow-direction"/>
+				<xs:element name="arrow-style" type="arrow-style" minOccurs="0"/>
+			</xs:sequence>
+			<xs:element name="circular-arrow" type="circular-arrow"/>
+		</xs:choice>
+		<xs:attributeGroup ref="print-style"/>
+		<xs:attribute name="smufl" type="smufl-glyph-name" use="required"/>
+	</xs:complexType>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let ct = ComplexType::from_xml(doc.root(), Lineage::Index(6), &xsd).unwrap();
+    let data = complex_type_data(&ct, &xsd).unwrap();
+    assert_eq!(data.name.original(), "arrow");
+    assert_eq!(data.documentation, "The arrow element represents an arrow.");
+    assert_eq!(data.attributes.len(), 2);
+    match data.attributes.first().unwrap() {
+        AttributeItem::AttributeGroupRef(r) => assert_eq!(r.original(), "print-style"),
+        AttributeItem::Attribute(_) => panic!("expected AttributeGroupRef got Attribute"),
+        AttributeItem::AnyAttribute(_) => panic!("expected AttributeGroupRef got AnyAttribute"),
+    }
+    match data.attributes.get(1).unwrap() {
+        AttributeItem::AttributeGroupRef(_) => panic!("expected Attribute got AttributeGroupRef"),
+        AttributeItem::Attribute(a) => {
+            assert_eq!(a.name.original(), "smufl");
+            assert_eq!(a.type_, BaseType::Custom("smufl-glyph-name".to_owned()));
+            assert!(a.required);
+        }
+        AttributeItem::AnyAttribute(_) => panic!("expected Attribute got AnyAttribute"),
+    }
+    let choice = match &data.content {
+        Content::Particle(Particle::Choice(c)) => c,
+        other => panic!("expected Choice got {:?}", other),
+    };
+    assert_eq!(choice.members.len(), 2);
+    match choice.members.first().unwrap() {
+        Particle::Sequence(s) => {
+            assert_eq!(s.members.len(), 2);
+            assert_eq!(s.members.get(1).unwrap().occurs().min_occurs, 0);
+        }
+        other => panic!("expected Sequence got {:?}", other),
+    }
+}
+
+#[test]
+fn model_simple_content() {
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+    <xs:complexType name="hole-closed">
+        <xs:simpleContent>
+            <xs:extension base="hole-closed-value">
+                <xs:attribute name="location" type="hole-closed-location"/>
+            </xs:extension>
+        </xs:simpleContent>
+    </xs:complexType>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let ct = ComplexType::from_xml(doc.root(), Lineage::Index(6), &xsd).unwrap();
+    let data = complex_type_data(&ct, &xsd).unwrap();
+    assert_eq!(
+        data.content,
+        Content::Simple(BaseType::Custom("hole-closed-value".to_owned()))
+    );
+    assert_eq!(data.attributes.len(), 1);
+}
+
+#[test]
+fn model_wildcards() {
+    use crate::xsd::any::{Namespace, ProcessContents};
+    use crate::xsd::id::Lineage;
+    let xml_str = r###"
+    <xs:complexType name="extensible">
+        <xs:sequence>
+            <xs:element name="name" type="xs:string"/>
+            <xs:any namespace="##other" processContents="lax" minOccurs="0" maxOccurs="unbounded"/>
+        </xs:sequence>
+        <xs:anyAttribute processContents="skip"/>
+    </xs:complexType>"###;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let ct = ComplexType::from_xml(doc.root(), Lineage::Index(6), &xsd).unwrap();
+    let data = complex_type_data(&ct, &xsd).unwrap();
+    match data.attributes.first().unwrap() {
+        AttributeItem::AnyAttribute(w) => {
+            assert_eq!(w.namespace, Namespace::Any);
+            assert_eq!(w.process_contents, ProcessContents::Skip);
+        }
+        other => panic!("expected AnyAttribute got {:?}", other),
+    }
+    let seq = match &data.content {
+        Content::Particle(Particle::Sequence(s)) => s,
+        other => panic!("expected Sequence got {:?}", other),
+    };
+    match seq.members.get(1).unwrap() {
+        Particle::Any(a) => {
+            assert_eq!(a.wildcard.namespace, Namespace::Other);
+            assert_eq!(a.wildcard.process_contents, ProcessContents::Lax);
+            assert!(a.occurs.max_occurs.is_none());
+        }
+        other => panic!("expected Any got {:?}", other),
+    }
+}
+
+#[test]
+fn model_all_elements() {
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+    <xs:complexType name="point">
+        <xs:all>
+            <xs:element name="x" type="xs:decimal"/>
+            <xs:element name="y" type="xs:decimal" minOccurs="0"/>
+        </xs:all>
+    </xs:complexType>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let ct = ComplexType::from_xml(doc.root(), Lineage::Index(6), &xsd).unwrap();
+    let data = complex_type_data(&ct, &xsd).unwrap();
+    match &data.content {
+        Content::Particle(Particle::All(c)) => assert_eq!(c.members.len(), 2),
+        other => panic!("expected All got {:?}", other),
+    }
+}
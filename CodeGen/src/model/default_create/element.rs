@@ -0,0 +1,75 @@
+use crate::model::create::CreateResult;
+use crate::model::default_create::complex_type::model_element_type;
+use crate::model::element::ElementData;
+use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::element::Element;
+use crate::xsd::Xsd;
+
+pub(super) fn model_element(e: &Element, xsd: &Xsd) -> CreateResult {
+    Ok(Some(vec![Def::Element(ElementData {
+        name: Symbol::new(e.name()),
+        type_: model_element_type(e, xsd)?,
+        occurs: *e.occurs(),
+        documentation: e.documentation(),
+    })]))
+}
+
+#[test]
+fn model_score_partwise() {
+    use crate::model::complex_type::{Content, ElementType, Particle};
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+	<xs:element name="score-partwise" block="extension substitution" final="\#all">
+		<xs:annotation>
+			<xs:documentation>bleep bleep bloop bloop</xs:documentation>
+		</xs:annotation>
+		<xs:complexType>
+			<xs:sequence>
+				<xs:group ref="score-header"/>
+				<xs:element name="part" maxOccurs="unbounded">
+					<xs:complexType>
+						<xs:sequence>
+							<xs:element name="measure" maxOccurs="unbounded">
+								<xs:complexType>
+									<xs:group ref="music-data"/>
+									<xs:attributeGroup ref="measure-attributes"/>
+								</xs:complexType>
+							</xs:element>
+						</xs:sequence>
+						<xs:attributeGroup ref="part-attributes"/>
+					</xs:complexType>
+				</xs:element>
+			</xs:sequence>
+			<xs:attributeGroup ref="document-attributes"/>
+		</xs:complexType>
+	</xs:element>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let element = Element::from_xml(doc.root(), Lineage::Index(3), &xsd).unwrap();
+    let defs = model_element(&element, &xsd).unwrap().unwrap();
+    assert_eq!(defs.len(), 1);
+    let data = match defs.first().unwrap() {
+        Def::Element(x) => x,
+        other => panic!("expected Element got {:?}", other),
+    };
+    assert_eq!(data.name.original(), "score-partwise");
+    assert_eq!(data.documentation, "bleep bleep bloop bloop");
+    let ct = match &data.type_ {
+        ElementType::Anonymous(ct) => ct,
+        ElementType::Named(n) => panic!("expected Anonymous got Named({})", n),
+    };
+    assert_eq!(ct.attributes.len(), 1);
+    let seq = match &ct.content {
+        Content::Particle(Particle::Sequence(s)) => s,
+        other => panic!("expected Sequence got {:?}", other),
+    };
+    assert_eq!(seq.members.len(), 2);
+    match seq.members.get(1).unwrap() {
+        Particle::Element(part) => {
+            assert_eq!(part.name.original(), "part");
+            assert!(part.occurs.max_occurs.is_none());
+        }
+        other => panic!("expected Element got {:?}", other),
+    }
+}
@@ -0,0 +1,70 @@
+use crate::model::complex_type::{Compositor, Particle};
+use crate::model::create::CreateResult;
+use crate::model::default_create::complex_type::{model_choice, model_element, model_sequence};
+use crate::model::group::GroupData;
+use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::group::{GroupDefinition, Member};
+use crate::xsd::{Occurs, Xsd};
+
+pub(super) fn model_group_definition(g: &GroupDefinition, xsd: &Xsd) -> CreateResult {
+    let mut members = Vec::new();
+    for member in &g.members {
+        members.push(match member {
+            Member::Choice(c) => model_choice(c, xsd)?,
+            Member::Element(e) => model_element(e, xsd)?,
+            Member::Sequence(s) => model_sequence(s, xsd)?,
+        });
+    }
+    // a group should hold exactly one compositor, but if it does not then we treat its members as
+    // a sequence.
+    let particle = if members.len() == 1 {
+        members.remove(0)
+    } else {
+        Particle::Sequence(Compositor {
+            occurs: Occurs::default(),
+            members,
+        })
+    };
+    Ok(Some(vec![Def::Group(GroupData {
+        name: Symbol::new(g.id.name()),
+        documentation: g.documentation(),
+        particle,
+    })]))
+}
+
+#[test]
+fn model_editorial() {
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+	<xs:group name="editorial">
+		<xs:annotation>
+			<xs:documentation>The editorial group specifies editorial information for a musical element.</xs:documentation>
+		</xs:annotation>
+		<xs:sequence>
+			<xs:group ref="footnote" minOccurs="0"/>
+			<xs:group ref="level" minOccurs="0"/>
+		</xs:sequence>
+	</xs:group>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let g = GroupDefinition::from_xml(doc.root(), Lineage::Index(3), &xsd).unwrap();
+    let defs = model_group_definition(&g, &xsd).unwrap().unwrap();
+    let data = match defs.first().unwrap() {
+        Def::Group(x) => x,
+        other => panic!("expected Group got {:?}", other),
+    };
+    assert_eq!(data.name.original(), "editorial");
+    let seq = match &data.particle {
+        Particle::Sequence(s) => s,
+        other => panic!("expected Sequence got {:?}", other),
+    };
+    assert_eq!(seq.members.len(), 2);
+    match seq.members.get(1).unwrap() {
+        Particle::GroupRef(r) => {
+            assert_eq!(r.name.original(), "level");
+            assert_eq!(r.occurs.min_occurs, 0);
+        }
+        other => panic!("expected GroupRef got {:?}", other),
+    }
+}
@@ -1,12 +1,20 @@
+mod attribute_group;
+mod complex_type;
+mod element;
 mod enumeration;
+mod group;
 mod scalar;
 
 #[macro_use]
 use crate::model::create::{Create, CreateError, CreateResult};
 
+use crate::model::default_create::attribute_group::model_attribute_group;
+use crate::model::default_create::complex_type::model_complex_type;
+use crate::model::default_create::element::model_element;
 use crate::model::default_create::enumeration::{is_enumeration, model_enumeration};
+use crate::model::default_create::group::model_group_definition;
 use crate::model::default_create::scalar::{
-    model_derived_simple_type, model_scalar_number, model_scalar_string,
+    model_derived_simple_type, model_list_simple_type, model_scalar_number, model_scalar_string,
 };
 use crate::model::enumeration::Enumeration;
 use crate::model::scalar::ScalarNumeric;
@@ -35,11 +43,12 @@ impl Create for DefaultCreate {
     fn create(&self, entry: &Entry, xsd: &Xsd) -> CreateResult {
         match entry {
             Entry::Annotation(_) => Ok(Some(Vec::new())), // TODO - implement Annotation
-            Entry::AttributeGroup(_) => Ok(Some(Vec::new())), // TODO - implement AttributeGroup
-            Entry::ComplexType(_) => Ok(Some(Vec::new())), // TODO - implement ComplexType
-            Entry::Element(_) => Ok(Some(Vec::new())),    // TODO - implement Element
-            Entry::Group(_) => Ok(Some(Vec::new())),      // TODO - implement Group
-            Entry::Import(_) => Ok(Some(Vec::new())),     // TODO - implement Import
+            Entry::Attribute(_) => Ok(Some(Vec::new())),  // TODO - implement Attribute
+            Entry::AttributeGroup(ag) => model_attribute_group(ag, xsd),
+            Entry::ComplexType(ct) => model_complex_type(ct, xsd),
+            Entry::Element(e) => model_element(e, xsd),
+            Entry::Group(g) => model_group_definition(g, xsd),
+            Entry::Import(_) => Ok(Some(Vec::new())), // TODO - implement Import
             Entry::SimpleType(st) => dispatch_simple_type(st, xsd),
         }
     }
@@ -52,6 +61,8 @@ fn dispatch_simple_type(st: &SimpleType, xsd: &Xsd) -> CreateResult {
         result
     } else if let Some(result) = model_scalar_number(st, xsd) {
         result
+    } else if let Some(result) = model_list_simple_type(st, xsd) {
+        result
     } else if let Some(result) = model_derived_simple_type(st, xsd) {
         result
     } else {
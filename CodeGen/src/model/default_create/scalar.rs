@@ -1,14 +1,19 @@
 #[macro_use]
 use crate::model::create::{CreateError, CreateResult};
+use crate::model::default_create::complex_type::parse_base_type;
+use crate::model::default_create::dispatch_simple_type;
 use crate::model::scalar::{
-    Bound, DerivedSimpleTypeData, NumericData, Range, ScalarNumeric, ScalarString, UnionData,
+    Bound, DerivedSimpleTypeData, ListData, NumericData, Range, ScalarNumeric, ScalarString,
+    UnionData,
 };
 use crate::model::symbol::Symbol;
 use crate::model::Def;
+use crate::xsd::id::RootNodeType;
+use crate::xsd::pattern::Pattern;
 use crate::xsd::primitives::{BaseType, Character, Numeric, Primitive};
-use crate::xsd::restriction::{Facet, Number, Restriction};
+use crate::xsd::restriction::{Facet, Number, Restriction, WhiteSpace};
 use crate::xsd::simple_type::{Payload, SimpleType};
-use crate::xsd::Xsd;
+use crate::xsd::{Entry, Xsd};
 
 pub(super) fn model_scalar_string(st: &SimpleType, _xsd: &Xsd) -> Option<CreateResult> {
     match &st.payload {
@@ -16,59 +21,106 @@ pub(super) fn model_scalar_string(st: &SimpleType, _xsd: &Xsd) -> Option<CreateR
             if r.facets.is_empty() {
                 return None;
             }
-            if !r.base.is_character() {
-                return None;
+            let base_type = r.base.as_character()?;
+            Some(scalar_string(st, base_type, &r.facets))
+        }
+        _ => None,
+    }
+}
+
+/// Models `st` as a string of the built-in `base_type` with the given facets.
+fn scalar_string(st: &SimpleType, base_type: Character, facets: &[Facet]) -> CreateResult {
+    let mut scalar_string = match base_type {
+        Character::Language | Character::Name | Character::NormalizedString => {
+            return Err(make_create_err!("'{}' is not supported", base_type));
+        }
+        _ => ScalarString {
+            name: Symbol::new(st.name.as_str()),
+            base_type,
+            documentation: st.documentation(),
+            ..Default::default()
+        },
+    };
+    for facet in facets {
+        match facet {
+            Facet::Enumeration(_)
+            | Facet::MaxExclusive(_)
+            | Facet::MaxInclusive(_)
+            | Facet::MinExclusive(_)
+            | Facet::MinInclusive(_)
+            | Facet::FractionDigits(_)
+            | Facet::TotalDigits(_) => {
+                return Err(make_create_err!("unsupported facet '{:?}'", facet))
             }
-            let base_type = r.base.as_character().unwrap();
-            let mut scalar_string = match base_type {
-                Character::Language | Character::Name | Character::NormalizedString => {
-                    return some_create_err!("'{}' is not supported", base_type);
-                }
-                _ => ScalarString {
-                    name: Symbol::new(st.name.as_str()),
-                    base_type,
-                    documentation: st.documentation(),
-                    ..Default::default()
-                },
-            };
-            for facet in &r.facets {
-                match facet {
-                    Facet::Enumeration(_)
-                    | Facet::MaxExclusive(_)
-                    | Facet::MaxInclusive(_)
-                    | Facet::MinExclusive(_)
-                    | Facet::MinInclusive(_) => {
-                        return some_create_err!("unsupported facet '{:?}'", facet)
-                    }
-                    Facet::Length(l) => {
-                        scalar_string.min_length = Some(*l);
-                        scalar_string.max_length = Some(*l);
-                    }
-                    Facet::MaxLength(l) => scalar_string.max_length = Some(*l),
-                    Facet::MinLength(l) => scalar_string.min_length = Some(*l),
-                    Facet::Pattern(p) => scalar_string.pattern = Some(p.clone()),
-                }
+            Facet::Length(l) => {
+                scalar_string.min_length = Some(*l);
+                scalar_string.max_length = Some(*l);
+            }
+            Facet::MaxLength(l) => scalar_string.max_length = Some(*l),
+            Facet::MinLength(l) => scalar_string.min_length = Some(*l),
+            Facet::Pattern(p) => {
+                let pattern = match Pattern::new(p) {
+                    Ok(pattern) => pattern,
+                    Err(e) => return Err(make_create_err!("invalid pattern facet: {}", e)),
+                };
+                scalar_string.pattern = Some(match scalar_string.pattern.take() {
+                    Some(previous) => previous.or(pattern),
+                    None => pattern,
+                });
             }
-            return Some(Ok(Some(vec![Def::ScalarString(scalar_string)])));
+            Facet::WhiteSpace(w) => scalar_string.white_space = Some(*w),
+        }
+    }
+    Ok(Some(vec![Def::ScalarString(scalar_string)]))
+}
+
+/// Models a restriction that adds facets to the named simple type `base`, e.g.
+/// `smufl-coda-glyph-name`, which adds a pattern to `smufl-glyph-name`. So that the facets are
+/// enforced, the type is a string of the built-in type that `base` is ultimately derived from.
+/// Returns `None` if `base` is not ultimately a string type.
+fn model_restricted_string(
+    st: &SimpleType,
+    base: &str,
+    facets: &[Facet],
+    xsd: &Xsd,
+) -> Option<CreateResult> {
+    let mut name = base;
+    loop {
+        let restriction = match xsd.get(RootNodeType::SimpleType, name) {
+            Some(Entry::SimpleType(SimpleType {
+                payload: Payload::Restriction(r),
+                ..
+            })) => r,
+            _ => return None,
+        };
+        if !restriction.facets.is_empty() {
+            return some_create_err!(
+                "the facets of '{}' are not supported because its base '{}' also has facets",
+                st.name,
+                name
+            );
+        }
+        match &restriction.base {
+            BaseType::Custom(custom) => name = custom,
+            other => return Some(scalar_string(st, other.as_character()?, facets)),
         }
-        _ => return None,
     }
 }
 
-pub(super) fn model_scalar_number(st: &SimpleType, _xsd: &Xsd) -> Option<CreateResult> {
+pub(super) fn model_scalar_number(st: &SimpleType, xsd: &Xsd) -> Option<CreateResult> {
     match &st.payload {
         Payload::Restriction(r) => {
             if !r.base.is_numeric() {
                 return None;
             }
             let base_type = r.base.as_numeric().unwrap();
-            return Some(produce_the_scalar_numeric(base_type, r, st, _xsd));
+            Some(produce_the_scalar_numeric(base_type, r, st, xsd))
         }
-        _ => return None,
+        _ => None,
     }
 }
 
-pub(super) fn model_derived_simple_type(st: &SimpleType, _xsd: &Xsd) -> Option<CreateResult> {
+pub(super) fn model_derived_simple_type(st: &SimpleType, xsd: &Xsd) -> Option<CreateResult> {
     match &st.payload {
         Payload::Restriction(r) => {
             let base_type = if let BaseType::Custom(s) = &r.base {
@@ -77,6 +129,11 @@ pub(super) fn model_derived_simple_type(st: &SimpleType, _xsd: &Xsd) -> Option<C
                 // not a derived type
                 return None;
             };
+            if !r.facets.is_empty() {
+                if let Some(result) = model_restricted_string(st, base_type, &r.facets, xsd) {
+                    return Some(result);
+                }
+            }
             return Some(Ok(Some(vec![Def::DerivedSimpleType(
                 DerivedSimpleTypeData {
                     name: Symbol::new(&st.name),
@@ -86,11 +143,27 @@ pub(super) fn model_derived_simple_type(st: &SimpleType, _xsd: &Xsd) -> Option<C
             )])));
         }
         Payload::Union(u) => {
-            return Some(Ok(Some(vec![Def::UnionSimpleType(UnionData {
+            // in-line members stay with the union, which refers to them by their synthesized names.
+            let mut inline_members = Vec::new();
+            for inline in &u.simple_types {
+                match dispatch_simple_type(inline, xsd) {
+                    Ok(Some(mut defs)) if defs.len() == 1 => inline_members.append(&mut defs),
+                    Ok(_) => {
+                        return some_create_err!(
+                            "unable to model the in-line member '{}' of union '{}'",
+                            inline.name,
+                            st.name
+                        )
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            Some(Ok(Some(vec![Def::UnionSimpleType(UnionData {
                 name: Symbol::new(&st.name),
                 members: u.members.clone(),
                 documentation: st.documentation(),
-            })])));
+                inline_members,
+            })])))
         }
         payload => {
             return Some(Err(make_create_err!(
@@ -101,13 +174,30 @@ pub(super) fn model_derived_simple_type(st: &SimpleType, _xsd: &Xsd) -> Option<C
     }
 }
 
+pub(super) fn model_list_simple_type(st: &SimpleType, xsd: &Xsd) -> Option<CreateResult> {
+    let list = match &st.payload {
+        Payload::List(list) => list,
+        _ => return None,
+    };
+    let item_type = match parse_base_type(&list.item_type, xsd) {
+        Ok(ok) => ok,
+        Err(e) => return Some(Err(e)),
+    };
+    Some(Ok(Some(vec![Def::ListSimpleType(ListData {
+        name: Symbol::new(st.name.as_str()),
+        item_type,
+        documentation: st.documentation(),
+    })])))
+}
+
 fn produce_the_scalar_numeric(
     base_type: Numeric,
     r: &Restriction,
     st: &SimpleType,
     _xsd: &Xsd,
 ) -> CreateResult {
-    let mut scalar_numeric = match base_type {
+    let (total_digits, fraction_digits) = parse_digits(&r.facets)?;
+    let scalar_numeric = match base_type {
         Numeric::Byte | Numeric::UnsignedByte => {
             return Err(make_create_err!(
                 "Unsupported numeric type: '{}'",
@@ -119,6 +209,8 @@ fn produce_the_scalar_numeric(
             base_type,
             documentation: st.documentation(),
             range: parse_decimal_range(Range::default(), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
 
         Numeric::NegativeInteger => ScalarNumeric::Integer(NumericData {
@@ -126,24 +218,32 @@ fn produce_the_scalar_numeric(
             base_type,
             documentation: st.documentation(),
             range: parse_integer_range(Range::new(None, Some(Bound::Inclusive(-1))), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
         Numeric::NonNegativeInteger => ScalarNumeric::Integer(NumericData {
             name: Symbol::new(st.name.as_str()),
             base_type,
             documentation: st.documentation(),
             range: parse_integer_range(Range::new(Some(Bound::Inclusive(0)), None), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
         Numeric::NonPositiveInteger => ScalarNumeric::Integer(NumericData {
             name: Symbol::new(st.name.as_str()),
             base_type,
             documentation: st.documentation(),
             range: parse_integer_range(Range::new(None, Some(Bound::Inclusive(0))), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
         Numeric::PositiveInteger => ScalarNumeric::Integer(NumericData {
             name: Symbol::new(st.name.as_str()),
             base_type,
             documentation: st.documentation(),
             range: parse_integer_range(Range::new(Some(Bound::Inclusive(1)), None), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
         Numeric::Int
         | Numeric::Integer
@@ -156,11 +256,35 @@ fn produce_the_scalar_numeric(
             base_type,
             documentation: st.documentation(),
             range: parse_integer_range(Range::default(), &r.facets)?,
+            total_digits,
+            fraction_digits,
         }),
     };
     Ok(Some(vec![Def::ScalarNumber(scalar_numeric)]))
 }
 
+fn parse_digits(facets: &[Facet]) -> std::result::Result<(Option<u64>, Option<u64>), CreateError> {
+    let mut total_digits = None;
+    let mut fraction_digits = None;
+    for facet in facets {
+        match facet {
+            Facet::TotalDigits(x) => total_digits = Some(*x),
+            Facet::FractionDigits(x) => fraction_digits = Some(*x),
+            _ => {}
+        }
+    }
+    if let (Some(total), Some(fraction)) = (total_digits, fraction_digits) {
+        if fraction > total {
+            return Err(make_create_err!(
+                "fractionDigits '{}' is greater than totalDigits '{}'",
+                fraction,
+                total
+            ));
+        }
+    }
+    Ok((total_digits, fraction_digits))
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Wow what an incredible mess
 
@@ -182,7 +306,15 @@ fn parse_decimal_range(
             | Facet::Length(_)
             | Facet::MaxLength(_)
             | Facet::MinLength(_)
-            | Facet::Pattern(_) => return Err(make_create_err!("unsupported facet '{:?}'", facet)),
+            | Facet::Pattern(_)
+            | Facet::WhiteSpace(WhiteSpace::Preserve)
+            | Facet::WhiteSpace(WhiteSpace::Replace) => {
+                return Err(make_create_err!("unsupported facet '{:?}'", facet))
+            }
+            // numbers are always parsed with collapsed whitespace
+            Facet::WhiteSpace(WhiteSpace::Collapse) => {}
+            // the digits facets do not constrain the range, see parse_digits
+            Facet::FractionDigits(_) | Facet::TotalDigits(_) => {}
 
             Facet::MaxExclusive(n) => {
                 let new_upper = UpperFloatBound::Exclusive(match n {
@@ -257,7 +389,7 @@ fn parse_decimal_range(
         }),
         max: max.and_then(|some| match some {
             UpperFloatBound::Inclusive(x) => Some(Bound::Inclusive(x)),
-            UpperFloatBound::Exclusive(x) => Some(Bound::Inclusive(x)),
+            UpperFloatBound::Exclusive(x) => Some(Bound::Exclusive(x)),
         }),
     })
 }
@@ -280,7 +412,15 @@ fn parse_integer_range(
             | Facet::Length(_)
             | Facet::MaxLength(_)
             | Facet::MinLength(_)
-            | Facet::Pattern(_) => return Err(make_create_err!("unsupported facet '{:?}'", facet)),
+            | Facet::Pattern(_)
+            | Facet::WhiteSpace(WhiteSpace::Preserve)
+            | Facet::WhiteSpace(WhiteSpace::Replace) => {
+                return Err(make_create_err!("unsupported facet '{:?}'", facet))
+            }
+            // numbers are always parsed with collapsed whitespace
+            Facet::WhiteSpace(WhiteSpace::Collapse) => {}
+            // the digits facets do not constrain the range, see parse_digits
+            Facet::FractionDigits(_) | Facet::TotalDigits(_) => {}
 
             Facet::MaxExclusive(n) => {
                 let new_upper = UpperIntegerBound::Exclusive(match n {
@@ -355,7 +495,7 @@ fn parse_integer_range(
         }),
         max: max.and_then(|some| match some {
             UpperIntegerBound::Inclusive(x) => Some(Bound::Inclusive(x)),
-            UpperIntegerBound::Exclusive(x) => Some(Bound::Inclusive(x)),
+            UpperIntegerBound::Exclusive(x) => Some(Bound::Exclusive(x)),
         }),
     })
 }
@@ -650,3 +790,46 @@ fn lower_float_bound_is_other_more_restrictive() {
         assert_eq!(got, t.want, "'{:?}", t)
     }
 }
+
+#[test]
+fn model_list() {
+    use crate::xsd::id::Lineage;
+    let xml_str = r#"
+    <xs:simpleType name="positive-integers">
+        <xs:annotation>
+            <xs:documentation>A list of positive integers.</xs:documentation>
+        </xs:annotation>
+        <xs:list itemType="xs:positiveInteger"/>
+    </xs:simpleType>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let st = SimpleType::from_xml(doc.root(), Lineage::Index(4), &xsd).unwrap();
+    let defs = model_list_simple_type(&st, &xsd).unwrap().unwrap().unwrap();
+    let list = match defs.first().unwrap() {
+        Def::ListSimpleType(x) => x,
+        other => panic!("expected ListSimpleType got {:?}", other),
+    };
+    assert_eq!(list.name.original(), "positive-integers");
+    assert_eq!(list.item_type, BaseType::PositiveInteger);
+    assert_eq!(list.documentation, "A list of positive integers.");
+}
+
+#[test]
+fn parse_ranges() {
+    let facets = vec![
+        Facet::WhiteSpace(WhiteSpace::Collapse),
+        Facet::MinExclusive(Number::Decimal(0.0)),
+        Facet::MaxExclusive(Number::Decimal(1.0)),
+    ];
+    let got = parse_decimal_range(Range::default(), &facets).unwrap();
+    assert_eq!(got.min, Some(Bound::Exclusive(0.0)));
+    assert_eq!(got.max, Some(Bound::Exclusive(1.0)));
+    let facets = vec![
+        Facet::WhiteSpace(WhiteSpace::Collapse),
+        Facet::MaxExclusive(Number::Integer(16)),
+    ];
+    let got = parse_integer_range(Range::default(), &facets).unwrap();
+    assert_eq!(got.max, Some(Bound::Exclusive(16)));
+    let facets = vec![Facet::WhiteSpace(WhiteSpace::Preserve)];
+    assert!(parse_integer_range(Range::default(), &facets).is_err());
+}
@@ -0,0 +1,13 @@
+use crate::model::complex_type::ElementType;
+use crate::model::symbol::Symbol;
+use crate::xsd::Occurs;
+
+/// Represents a top-level `xs:element` declaration, e.g. `score-partwise`. These are the elements
+/// that can serve as the root of a document.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ElementData {
+    pub name: Symbol,
+    pub type_: ElementType,
+    pub occurs: Occurs,
+    pub documentation: String,
+}
@@ -0,0 +1,12 @@
+use crate::model::complex_type::Particle;
+use crate::model::symbol::Symbol;
+
+/// Represents a named `xs:group` definition, e.g. `editorial` or `full-note`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct GroupData {
+    pub name: Symbol,
+    pub documentation: String,
+    /// The content model of the group. This is the group's `xs:sequence` or `xs:choice`, and it may
+    /// contain references to other groups, see `Model::flatten`.
+    pub particle: Particle,
+}
@@ -1,19 +1,31 @@
 #[macro_use]
 pub mod create;
 
+pub mod attribute_group;
+pub mod automaton;
 pub mod builtin;
+pub mod complex_type;
 pub mod creator;
 pub mod default_create;
+pub mod element;
 pub mod enumeration;
+pub mod group;
 pub mod post_process;
+mod resolve;
 pub mod scalar;
 pub mod symbol;
 pub mod transform;
 
 use crate::error::Result;
+use crate::model::attribute_group::AttributeGroupData;
+use crate::model::complex_type::{AttributeData, ComplexTypeData, Particle};
 use crate::model::create::{Create, CreateError, CreateResult};
+use crate::model::element::ElementData;
 use crate::model::enumeration::Enumeration;
-use crate::model::scalar::{DerivedSimpleTypeData, ScalarNumeric, ScalarString, UnionData};
+use crate::model::group::GroupData;
+use crate::model::scalar::{
+    DerivedSimpleTypeData, ListData, ScalarNumeric, ScalarString, UnionData,
+};
 use crate::model::symbol::Symbol;
 use crate::xsd::primitives::BaseType;
 use crate::xsd::restriction::Facet;
@@ -32,6 +44,11 @@ pub enum Def {
     CustomScalarString(ScalarString),
     DerivedSimpleType(DerivedSimpleTypeData),
     UnionSimpleType(UnionData),
+    ListSimpleType(ListData),
+    ComplexType(ComplexTypeData),
+    Element(ElementData),
+    AttributeGroup(AttributeGroupData),
+    Group(GroupData),
 }
 
 impl Def {
@@ -43,23 +60,23 @@ impl Def {
             Def::CustomScalarString(x) => &x.name,
             Def::DerivedSimpleType(x) => &x.name,
             Def::UnionSimpleType(x) => &x.name,
+            Def::ListSimpleType(x) => &x.name,
+            Def::ComplexType(x) => &x.name,
+            Def::Element(x) => &x.name,
+            Def::AttributeGroup(x) => &x.name,
+            Def::Group(x) => &x.name,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 pub enum Shape {
+    #[default]
     Simple,
     Complex,
-    // TODO - not sure
-    // AttributeGroup,
-    // Element
-}
-
-impl Default for Shape {
-    fn default() -> Self {
-        Shape::Simple
-    }
+    Element,
+    AttributeGroup,
+    Group,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
@@ -71,6 +88,11 @@ pub struct Ipseity {
 #[derive(Debug, Clone, Default)]
 pub struct Model {
     map: HashMap<Ipseity, Def>,
+    /// The top-level attributes of imported schemas, keyed by qualified name, e.g. `xml:lang`.
+    attributes: HashMap<String, AttributeData>,
+    /// The in-line simple types of `attributes`, keyed by qualified name, e.g. `xml:space-type`.
+    /// These are not part of `defs` because they are not generated.
+    imported: HashMap<String, Def>,
 }
 
 impl Model {
@@ -82,6 +104,11 @@ impl Model {
             Def::CustomScalarString(_) => Shape::Simple,
             Def::DerivedSimpleType(_) => Shape::Simple,
             Def::UnionSimpleType(_) => Shape::Simple,
+            Def::ListSimpleType(_) => Shape::Simple,
+            Def::ComplexType(_) => Shape::Complex,
+            Def::Element(_) => Shape::Element,
+            Def::AttributeGroup(_) => Shape::AttributeGroup,
+            Def::Group(_) => Shape::Group,
         };
         let ipseity = Ipseity {
             shape,
@@ -105,6 +132,42 @@ impl Model {
         self.get_ips(&ips)
     }
 
+    /// Adds a top-level attribute of an imported schema. Its name must be qualified, e.g. `xml:lang`.
+    pub fn add_attribute(&mut self, attribute: AttributeData) -> Result<()> {
+        let name = attribute.name.original().to_owned();
+        match self.attributes.insert(name.clone(), attribute) {
+            None => Ok(()),
+            Some(_) => raise!("duplicate attribute '{}'", name),
+        }
+    }
+
+    /// Adds the in-line simple type of an imported attribute, see `add_attribute`.
+    pub fn add_imported(&mut self, def: Def) -> Result<()> {
+        let name = def.name().original().to_owned();
+        match self.imported.insert(name.clone(), def) {
+            None => Ok(()),
+            Some(_) => raise!("duplicate imported type '{}'", name),
+        }
+    }
+
+    /// The top-level attribute of an imported schema, e.g. `attribute("xml:lang")`.
+    pub fn attribute<S: AsRef<str>>(&self, qname: S) -> Option<&AttributeData> {
+        self.attributes.get(qname.as_ref())
+    }
+
+    /// The in-line simple type of an imported attribute, e.g. `imported("xml:space-type")`.
+    pub fn imported<S: AsRef<str>>(&self, qname: S) -> Option<&Def> {
+        self.imported.get(qname.as_ref())
+    }
+
+    /// The declaration of an attribute of a complex type. For an attribute declared with `ref`, e.g.
+    /// `ref="xml:lang"`, this is the referenced attribute with the `use`, `default` and `fixed` of
+    /// the reference. Returns an error if the referenced attribute was not modeled, which is the
+    /// case when the schema was loaded without its imports.
+    pub fn declaration(&self, attribute: &AttributeData) -> Result<AttributeData> {
+        resolve::declaration(self, attribute)
+    }
+
     pub fn defs(&self) -> impl Iterator<Item = &Def> {
         self.map.values()
     }
@@ -112,4 +175,23 @@ impl Model {
     pub fn iter(&self) -> impl Iterator<Item = (&Ipseity, &Def)> {
         self.map.iter()
     }
+
+    /// Resolves references between definitions. This expands attribute group references so that
+    /// every complex type knows its complete set of attributes, see
+    /// `ComplexTypeData::all_attributes`, and makes sure that every group reference can be
+    /// flattened. If imported attributes were added, then every `ref` attribute must refer to one.
+    pub fn resolve(&mut self) -> Result<()> {
+        resolve::resolve_attributes(self)?;
+        resolve::check_attribute_references(self)?;
+        resolve::check_group_references(self)
+    }
+
+    /// Returns a copy of `particle` in which every group reference has been replaced by the content
+    /// of the referenced group. The `Occurs` of a reference is carried onto the group's content,
+    /// and content that occurs exactly once is spliced into a parent compositor of the same kind.
+    /// For example, `editorial` becomes a sequence of the `footnote` and `level` elements, each of
+    /// which has `minOccurs="0"`.
+    pub fn flatten(&self, particle: &Particle) -> Result<Particle> {
+        resolve::flatten(self, particle, &mut Vec::new())
+    }
 }
@@ -0,0 +1,582 @@
+use crate::error::Result;
+use crate::model::complex_type::{
+    AttributeData, AttributeItem, ComplexTypeData, Compositor, Content, ElementType, Particle,
+};
+use crate::model::{Def, Ipseity, Model, Shape};
+use crate::xsd::Occurs;
+
+/// Computes `ComplexTypeData::all_attributes` for every complex type in the model, including the
+/// anonymous complex types of elements and groups.
+pub(super) fn resolve_attributes(model: &mut Model) -> Result<()> {
+    let mut resolved: Vec<(Ipseity, Def)> = Vec::new();
+    for (ipseity, def) in model.iter() {
+        match def {
+            Def::ComplexType(ct) => {
+                let mut ct = ct.clone();
+                resolve_complex_type(model, &mut ct)?;
+                resolved.push((ipseity.clone(), Def::ComplexType(ct)));
+            }
+            Def::Element(e) => {
+                if let ElementType::Anonymous(_) = &e.type_ {
+                    let mut e = e.clone();
+                    resolve_element_type(model, &mut e.type_)?;
+                    resolved.push((ipseity.clone(), Def::Element(e)));
+                }
+            }
+            Def::Group(g) => {
+                let mut g = g.clone();
+                resolve_particle(model, &mut g.particle)?;
+                resolved.push((ipseity.clone(), Def::Group(g)));
+            }
+            _ => {}
+        }
+    }
+    for (ipseity, def) in resolved {
+        model.map.insert(ipseity, def);
+    }
+    Ok(())
+}
+
+/// Makes sure that every `ref` attribute of a complex type has a declaration, see
+/// `Model::declaration`. Nothing is checked if the model has no imported attributes.
+pub(super) fn check_attribute_references(model: &Model) -> Result<()> {
+    if model.attributes.is_empty() {
+        return Ok(());
+    }
+    for def in model.defs() {
+        if let Def::ComplexType(ct) = def {
+            for a in &ct.all_attributes {
+                wrap!(
+                    declaration(model, a),
+                    "unable to resolve an attribute of complex type '{}'",
+                    ct.name.original()
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn declaration(model: &Model, a: &AttributeData) -> Result<AttributeData> {
+    if !a.is_ref {
+        return Ok(a.clone());
+    }
+    let declared = match model.attribute(a.name.original()) {
+        Some(x) => x,
+        None => return raise!("attribute '{}' could not be found", a.name.original()),
+    };
+    Ok(AttributeData {
+        name: a.name.clone(),
+        type_: declared.type_.clone(),
+        is_ref: true,
+        required: a.required,
+        default: a.default.clone().or_else(|| declared.default.clone()),
+        fixed: a.fixed.clone().or_else(|| declared.fixed.clone()),
+        documentation: if a.documentation.is_empty() {
+            declared.documentation.clone()
+        } else {
+            a.documentation.clone()
+        },
+    })
+}
+
+/// Flattens the content of every complex type and group so that dangling or cyclic group
+/// references are reported.
+pub(super) fn check_group_references(model: &Model) -> Result<()> {
+    for def in model.defs() {
+        match def {
+            Def::ComplexType(ct) => {
+                if let Content::Particle(p) = &ct.content {
+                    wrap!(
+                        flatten(model, p, &mut Vec::new()),
+                        "unable to flatten complex type '{}'",
+                        ct.name.original()
+                    )?;
+                }
+            }
+            Def::Group(g) => {
+                wrap!(
+                    flatten(model, &g.particle, &mut Vec::new()),
+                    "unable to flatten group '{}'",
+                    g.name.original()
+                )?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// See `Model::flatten`. `groups` holds the names of the groups currently being inlined and is used
+/// to detect cycles.
+pub(super) fn flatten(
+    model: &Model,
+    particle: &Particle,
+    groups: &mut Vec<String>,
+) -> Result<Particle> {
+    match particle {
+        Particle::Element(e) => Ok(Particle::Element(e.clone())),
+        Particle::Any(a) => Ok(Particle::Any(a.clone())),
+        // the members of an xs:all are elements, there are no groups to flatten.
+        Particle::All(c) => Ok(Particle::All(c.clone())),
+        Particle::Sequence(c) => Ok(Particle::Sequence(flatten_compositor(
+            model, c, groups, true,
+        )?)),
+        Particle::Choice(c) => Ok(Particle::Choice(flatten_compositor(
+            model, c, groups, false,
+        )?)),
+        Particle::GroupRef(r) => {
+            let name = r.name.original();
+            if groups.iter().any(|g| g == name) {
+                return raise!("group '{}' references itself", name);
+            }
+            let group = match model.get(Shape::Group, name) {
+                Some(Def::Group(x)) => x,
+                _ => return raise!("group '{}' could not be found", name),
+            };
+            groups.push(name.to_owned());
+            let content = flatten(model, &group.particle, groups)?;
+            groups.pop();
+            Ok(apply_occurs(content, r.occurs))
+        }
+    }
+}
+
+fn flatten_compositor(
+    model: &Model,
+    compositor: &Compositor,
+    groups: &mut Vec<String>,
+    is_sequence: bool,
+) -> Result<Compositor> {
+    let mut members = Vec::new();
+    for member in &compositor.members {
+        let is_group_ref = matches!(member, Particle::GroupRef(_));
+        let flattened = flatten(model, member, groups)?;
+        // the content of a group that occurs exactly once can be spliced into a compositor of the
+        // same kind without changing its meaning.
+        match flattened {
+            Particle::Sequence(c) if is_group_ref && is_sequence && is_once(&c.occurs) => {
+                members.extend(c.members)
+            }
+            Particle::Choice(c) if is_group_ref && !is_sequence && is_once(&c.occurs) => {
+                members.extend(c.members)
+            }
+            other => members.push(other),
+        }
+    }
+    Ok(Compositor {
+        occurs: compositor.occurs,
+        members,
+    })
+}
+
+/// Carries the `Occurs` of a group reference onto the group's content. If both specify something
+/// other than exactly once, the content is wrapped in a sequence that carries the reference's
+/// `Occurs`.
+fn apply_occurs(content: Particle, occurs: Occurs) -> Particle {
+    if is_once(&occurs) {
+        return content;
+    }
+    if !is_once(content.occurs()) {
+        return Particle::Sequence(Compositor {
+            occurs,
+            members: vec![content],
+        });
+    }
+    match content {
+        Particle::Element(mut e) => {
+            e.occurs = occurs;
+            Particle::Element(e)
+        }
+        Particle::Sequence(mut c) => {
+            c.occurs = occurs;
+            Particle::Sequence(c)
+        }
+        Particle::Choice(mut c) => {
+            c.occurs = occurs;
+            Particle::Choice(c)
+        }
+        Particle::All(mut c) => {
+            c.occurs = occurs;
+            Particle::All(c)
+        }
+        Particle::Any(mut a) => {
+            a.occurs = occurs;
+            Particle::Any(a)
+        }
+        Particle::GroupRef(mut r) => {
+            r.occurs = occurs;
+            Particle::GroupRef(r)
+        }
+    }
+}
+
+fn is_once(occurs: &Occurs) -> bool {
+    *occurs == Occurs::default()
+}
+
+fn resolve_complex_type(model: &Model, ct: &mut ComplexTypeData) -> Result<()> {
+    ct.all_attributes = complete_attributes(model, ct, &mut Vec::new())?;
+    if let Content::Particle(p) = &mut ct.content {
+        resolve_particle(model, p)?;
+    }
+    Ok(())
+}
+
+fn resolve_particle(model: &Model, particle: &mut Particle) -> Result<()> {
+    match particle {
+        Particle::Element(e) => resolve_element_type(model, &mut e.type_),
+        Particle::Sequence(c) | Particle::Choice(c) | Particle::All(c) => {
+            for member in &mut c.members {
+                resolve_particle(model, member)?;
+            }
+            Ok(())
+        }
+        Particle::GroupRef(_) | Particle::Any(_) => Ok(()),
+    }
+}
+
+fn resolve_element_type(model: &Model, element_type: &mut ElementType) -> Result<()> {
+    match element_type {
+        ElementType::Named(_) => Ok(()),
+        ElementType::Anonymous(ct) => resolve_complex_type(model, ct),
+    }
+}
+
+/// The attributes of the extension base, if any, followed by the complex type's own attributes.
+/// `bases` holds the names of the types currently being extended and is used to detect cycles.
+fn complete_attributes(
+    model: &Model,
+    ct: &ComplexTypeData,
+    bases: &mut Vec<String>,
+) -> Result<Vec<AttributeData>> {
+    let mut attributes = Vec::new();
+    if let Content::Extension(base) = &ct.content {
+        let base_name = base.original();
+        if bases.iter().any(|b| b == base_name) {
+            return raise!(
+                "cyclic extension of '{}' by '{}'",
+                base_name,
+                ct.name.original()
+            );
+        }
+        let base_ct = match model.get(Shape::Complex, base_name) {
+            Some(Def::ComplexType(x)) => x,
+            _ => {
+                return raise!(
+                    "complex type '{}' extends '{}' which could not be found",
+                    ct.name.original(),
+                    base_name
+                )
+            }
+        };
+        bases.push(base_name.to_owned());
+        for a in complete_attributes(model, base_ct, bases)? {
+            add(&mut attributes, a)?;
+        }
+        bases.pop();
+    }
+    expand(model, &ct.attributes, &mut Vec::new(), &mut attributes)?;
+    Ok(attributes)
+}
+
+/// `groups` holds the names of the attribute groups currently being expanded and is used to detect
+/// cycles.
+fn expand(
+    model: &Model,
+    items: &[AttributeItem],
+    groups: &mut Vec<String>,
+    out: &mut Vec<AttributeData>,
+) -> Result<()> {
+    for item in items {
+        match item {
+            AttributeItem::Attribute(a) => add(out, a.clone())?,
+            // wildcards do not declare attributes.
+            AttributeItem::AnyAttribute(_) => {}
+            AttributeItem::AttributeGroupRef(r) => {
+                let name = r.original();
+                if groups.iter().any(|g| g == name) {
+                    return raise!("attributeGroup '{}' references itself", name);
+                }
+                let group = match model.get(Shape::AttributeGroup, name) {
+                    Some(Def::AttributeGroup(x)) => x,
+                    _ => return raise!("attributeGroup '{}' could not be found", name),
+                };
+                groups.push(name.to_owned());
+                expand(model, &group.attributes, groups, out)?;
+                groups.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+fn add(attributes: &mut Vec<AttributeData>, a: AttributeData) -> Result<()> {
+    if attributes
+        .iter()
+        .any(|x| x.name.original() == a.name.original())
+    {
+        return raise!("duplicate attribute '{}'", a.name.original());
+    }
+    attributes.push(a);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::attribute_group::AttributeGroupData;
+    use crate::model::complex_type::{ElementParticle, GroupRefParticle};
+    use crate::model::group::GroupData;
+    use crate::model::symbol::Symbol;
+    use crate::xsd::primitives::BaseType;
+
+    fn attribute(name: &str) -> AttributeItem {
+        AttributeItem::Attribute(AttributeData {
+            name: Symbol::new(name),
+            type_: BaseType::String,
+            is_ref: false,
+            required: false,
+            default: None,
+            fixed: None,
+            documentation: "".to_owned(),
+        })
+    }
+
+    fn group_ref(name: &str) -> AttributeItem {
+        AttributeItem::AttributeGroupRef(Symbol::new(name))
+    }
+
+    fn group(name: &str, attributes: Vec<AttributeItem>) -> Def {
+        Def::AttributeGroup(AttributeGroupData {
+            name: Symbol::new(name),
+            documentation: "".to_owned(),
+            attributes,
+        })
+    }
+
+    fn complex(name: &str, attributes: Vec<AttributeItem>, content: Content) -> Def {
+        Def::ComplexType(ComplexTypeData {
+            name: Symbol::new(name),
+            documentation: "".to_owned(),
+            attributes,
+            all_attributes: Vec::new(),
+            content,
+        })
+    }
+
+    fn names(model: &Model, name: &str) -> Vec<String> {
+        match model.get(Shape::Complex, name).unwrap() {
+            Def::ComplexType(ct) => ct
+                .all_attributes
+                .iter()
+                .map(|a| a.name.original().to_owned())
+                .collect(),
+            other => panic!("expected ComplexType got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn nested_groups() {
+        let mut model = Model::default();
+        model
+            .add(group(
+                "position",
+                vec![attribute("default-x"), attribute("default-y")],
+            ))
+            .unwrap();
+        model
+            .add(group("font", vec![attribute("font-family")]))
+            .unwrap();
+        model
+            .add(group(
+                "print-style",
+                vec![group_ref("position"), group_ref("font"), attribute("color")],
+            ))
+            .unwrap();
+        model
+            .add(complex(
+                "accidental-mark",
+                vec![group_ref("print-style"), attribute("placement")],
+                Content::Empty,
+            ))
+            .unwrap();
+        model
+            .add(complex(
+                "accidental-mark-ext",
+                vec![attribute("id")],
+                Content::Extension(Symbol::new("accidental-mark")),
+            ))
+            .unwrap();
+        model.resolve().unwrap();
+        let expected = vec![
+            "default-x",
+            "default-y",
+            "font-family",
+            "color",
+            "placement",
+        ];
+        assert_eq!(names(&model, "accidental-mark"), expected);
+        let mut expected = expected;
+        expected.push("id");
+        assert_eq!(names(&model, "accidental-mark-ext"), expected);
+    }
+
+    #[test]
+    fn group_cycle() {
+        let mut model = Model::default();
+        model.add(group("a", vec![group_ref("b")])).unwrap();
+        model.add(group("b", vec![group_ref("a")])).unwrap();
+        model
+            .add(complex("c", vec![group_ref("a")], Content::Empty))
+            .unwrap();
+        assert!(model.resolve().is_err());
+    }
+
+    #[test]
+    fn missing_group() {
+        let mut model = Model::default();
+        model
+            .add(complex("c", vec![group_ref("nope")], Content::Empty))
+            .unwrap();
+        assert!(model.resolve().is_err());
+    }
+
+    #[test]
+    fn duplicate_attribute() {
+        let mut model = Model::default();
+        model.add(group("a", vec![attribute("x")])).unwrap();
+        model
+            .add(complex(
+                "c",
+                vec![group_ref("a"), attribute("x")],
+                Content::Empty,
+            ))
+            .unwrap();
+        assert!(model.resolve().is_err());
+    }
+
+    fn optional() -> Occurs {
+        Occurs {
+            min_occurs: 0,
+            max_occurs: Some(1),
+        }
+    }
+
+    fn element(name: &str, occurs: Occurs) -> Particle {
+        Particle::Element(ElementParticle {
+            name: Symbol::new(name),
+            type_: ElementType::Named(BaseType::String),
+            occurs,
+            documentation: "".to_owned(),
+        })
+    }
+
+    fn content_ref(name: &str, occurs: Occurs) -> Particle {
+        Particle::GroupRef(GroupRefParticle {
+            name: Symbol::new(name),
+            occurs,
+        })
+    }
+
+    fn sequence(members: Vec<Particle>) -> Particle {
+        Particle::Sequence(Compositor {
+            occurs: Occurs::default(),
+            members,
+        })
+    }
+
+    fn content_group(name: &str, particle: Particle) -> Def {
+        Def::Group(GroupData {
+            name: Symbol::new(name),
+            documentation: "".to_owned(),
+            particle,
+        })
+    }
+
+    #[test]
+    fn flatten_editorial() {
+        let mut model = Model::default();
+        model
+            .add(content_group(
+                "footnote",
+                sequence(vec![element("footnote", Occurs::default())]),
+            ))
+            .unwrap();
+        model
+            .add(content_group(
+                "level",
+                sequence(vec![element("level", Occurs::default())]),
+            ))
+            .unwrap();
+        model
+            .add(content_group(
+                "editorial",
+                sequence(vec![
+                    content_ref("footnote", optional()),
+                    content_ref("level", optional()),
+                ]),
+            ))
+            .unwrap();
+        model.resolve().unwrap();
+        let particle = sequence(vec![
+            element("pitch", Occurs::default()),
+            content_ref("editorial", Occurs::default()),
+        ]);
+        let flattened = model.flatten(&particle).unwrap();
+        // the editorial sequence is spliced into the parent, and each single-element group takes
+        // on the occurs of its reference.
+        let want = sequence(vec![
+            element("pitch", Occurs::default()),
+            Particle::Sequence(Compositor {
+                occurs: optional(),
+                members: vec![element("footnote", Occurs::default())],
+            }),
+            Particle::Sequence(Compositor {
+                occurs: optional(),
+                members: vec![element("level", Occurs::default())],
+            }),
+        ]);
+        assert_eq!(flattened, want);
+    }
+
+    #[test]
+    fn flatten_wraps_repeated_content() {
+        let mut model = Model::default();
+        let unbounded = Occurs {
+            min_occurs: 0,
+            max_occurs: None,
+        };
+        let choice = Particle::Choice(Compositor {
+            occurs: unbounded,
+            members: vec![element("a", Occurs::default())],
+        });
+        model.add(content_group("g", choice.clone())).unwrap();
+        let flattened = model
+            .flatten(&sequence(vec![content_ref("g", optional())]))
+            .unwrap();
+        let want = sequence(vec![Particle::Sequence(Compositor {
+            occurs: optional(),
+            members: vec![choice],
+        })]);
+        assert_eq!(flattened, want);
+    }
+
+    #[test]
+    fn flatten_group_cycle() {
+        let mut model = Model::default();
+        model
+            .add(content_group(
+                "a",
+                sequence(vec![content_ref("b", Occurs::default())]),
+            ))
+            .unwrap();
+        model
+            .add(content_group(
+                "b",
+                sequence(vec![content_ref("a", Occurs::default())]),
+            ))
+            .unwrap();
+        assert!(model.resolve().is_err());
+    }
+}
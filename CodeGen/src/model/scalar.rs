@@ -1,7 +1,10 @@
 use crate::error::Result;
 use crate::model::builtin::BuiltinString;
 use crate::model::symbol::Symbol;
+use crate::model::Def;
+use crate::xsd::pattern::Pattern;
 use crate::xsd::primitives::{BaseType, Character, Numeric};
+use crate::xsd::restriction::WhiteSpace;
 use std::fmt::Debug;
 use std::num::NonZeroU64;
 
@@ -12,7 +15,24 @@ pub struct ScalarString {
     pub documentation: String,
     pub min_length: Option<u64>,
     pub max_length: Option<u64>,
-    pub pattern: Option<String>,
+    /// The `pattern` facets, combined into one pattern if there are several.
+    pub pattern: Option<Pattern>,
+    /// The `whiteSpace` facet. When `None` the normalization implied by the `base_type` applies.
+    pub white_space: Option<WhiteSpace>,
+}
+
+impl ScalarString {
+    /// The `whiteSpace` facet if there is one, otherwise the normalization of the base type.
+    pub fn normalization(&self) -> WhiteSpace {
+        if let Some(w) = self.white_space {
+            return w;
+        }
+        match self.base_type {
+            Character::String => WhiteSpace::Preserve,
+            Character::NormalizedString => WhiteSpace::Replace,
+            _ => WhiteSpace::Collapse,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
@@ -81,6 +101,10 @@ where
     pub base_type: Numeric,
     pub documentation: String,
     pub range: Range<T>,
+    /// The `totalDigits` facet, i.e. the maximum number of significant digits.
+    pub total_digits: Option<u64>,
+    /// The `fractionDigits` facet, i.e. the maximum number of digits after the decimal point.
+    pub fraction_digits: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
@@ -93,6 +117,28 @@ pub struct DerivedSimpleTypeData {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
 pub struct UnionData {
     pub name: Symbol,
+    /// The member types. An in-line member is given as `BaseType::Custom` with the name that was
+    /// synthesized for it, see `inline_members`.
     pub members: Vec<BaseType>,
     pub documentation: String,
+    /// The models of the in-line `xs:simpleType` members. They belong to the union and are not
+    /// added to the `Model` on their own.
+    pub inline_members: Vec<Def>,
+}
+
+impl UnionData {
+    /// The in-line member that `members` refers to as `name`, if there is one.
+    pub fn inline_member<S: AsRef<str>>(&self, name: S) -> Option<&Def> {
+        self.inline_members
+            .iter()
+            .find(|def| def.name().original() == name.as_ref())
+    }
+}
+
+/// Represents an `xs:list`, i.e. a whitespace-separated list of values of the `item_type`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ListData {
+    pub name: Symbol,
+    pub item_type: BaseType,
+    pub documentation: String,
 }
@@ -0,0 +1,81 @@
+use crate::model::automaton::Term;
+use crate::xsd::choice::ChoiceItem;
+use crate::xsd::complex_type::Children;
+use crate::xsd::element::Element;
+use crate::xsd::group::{self, Group};
+use crate::xsd::id::RootNodeType;
+use crate::xsd::sequence::{self, Sequence};
+use crate::xsd::{Entry, Occurs, Xsd};
+
+/// An element declaration and the schema in which its references are resolved.
+pub(super) type Decl<'a> = (&'a Xsd, &'a Element);
+
+/// The content model of `children` with its group references resolved.
+pub(super) fn content_model<'a>(schema: &'a Xsd, children: &'a Children) -> Term<Decl<'a>> {
+    match children {
+        Children::All(all) => Term::All(
+            all.occurs,
+            all.elements.iter().map(|e| element(schema, e)).collect(),
+        ),
+        Children::Choice(choice) => self::choice(schema, choice.occurs, &choice.choices),
+        Children::Group(group) => self::group(schema, group),
+        Children::Sequence(sequence) => self::sequence(schema, sequence),
+    }
+}
+
+fn element<'a>(schema: &'a Xsd, element: &'a Element) -> Term<Decl<'a>> {
+    Term::Element(
+        element.name().to_owned(),
+        (schema, element),
+        *element.occurs(),
+    )
+}
+
+fn sequence<'a>(schema: &'a Xsd, sequence: &'a Sequence) -> Term<Decl<'a>> {
+    let items = sequence.members.iter().map(|member| match member {
+        sequence::Member::Any(any) => Term::Any(any.occurs),
+        sequence::Member::Choice(choice) => self::choice(schema, choice.occurs, &choice.choices),
+        sequence::Member::Element(e) => element(schema, e),
+        sequence::Member::Group(group) => self::group(schema, group),
+        sequence::Member::Sequence(sequence) => self::sequence(schema, sequence),
+    });
+    Term::Sequence(sequence.occurs, items.collect())
+}
+
+fn choice<'a>(schema: &'a Xsd, occurs: Occurs, choices: &'a [ChoiceItem]) -> Term<Decl<'a>> {
+    let items = choices.iter().map(|item| match item {
+        ChoiceItem::Any(any) => Term::Any(any.occurs),
+        ChoiceItem::Choice(choice) => self::choice(schema, choice.occurs, &choice.choices),
+        ChoiceItem::Element(e) => element(schema, e),
+        ChoiceItem::Group(group) => self::group(schema, group),
+        ChoiceItem::Sequence(sequence) => self::sequence(schema, sequence),
+    });
+    Term::Choice(occurs, items.collect())
+}
+
+/// A group is a sequence of its members, repeated as the reference to it says. A reference that
+/// cannot be resolved matches nothing.
+fn group<'a>(schema: &'a Xsd, group: &'a Group) -> Term<Decl<'a>> {
+    let (schema, occurs, def) = match group {
+        Group::Definition(def) => (schema, Occurs::default(), Some(def)),
+        Group::Reference(r) => match schema.resolve_schema(&r.ref_) {
+            Some((schema, name)) => match schema.get(RootNodeType::Group, name) {
+                Some(Entry::Group(def)) => (schema, r.occurs, Some(def)),
+                _ => (schema, r.occurs, None),
+            },
+            None => (schema, r.occurs, None),
+        },
+    };
+    let items = def
+        .into_iter()
+        .flat_map(|def| &def.members)
+        .map(|member| match member {
+            group::Member::Choice(choice) => self::choice(schema, choice.occurs, &choice.choices),
+            group::Member::Element(e) => element(schema, e),
+            group::Member::Sequence(sequence) => self::sequence(schema, sequence),
+        });
+    match def {
+        Some(_) => Term::Sequence(occurs, items.collect()),
+        None => Term::Choice(occurs, Vec::new()),
+    }
+}
@@ -0,0 +1,571 @@
+//! Validates instance documents, e.g. the MusicXML files in `Resources/foundsuite`, against a
+//! parsed `Xsd`.
+
+mod content;
+mod value;
+
+use crate::error::Result;
+use crate::model::automaton::Automaton;
+use crate::validate::content::{content_model, Decl};
+use crate::validate::value::SimpleRef;
+use crate::xsd::attribute::Attribute;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::attributes::{AttributeItem, Attributes};
+use crate::xsd::common::DefinedBy;
+use crate::xsd::complex_type::{ComplexType, Payload};
+use crate::xsd::element::Element;
+use crate::xsd::id::RootNodeType;
+use crate::xsd::primitives::{BaseType, PrefixedParse};
+use crate::xsd::simple_content;
+use crate::xsd::{Entry, Xsd};
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::rc::Rc;
+
+/// Checks XML documents against the declarations of an `Xsd`. The schema should be loaded with its
+/// imports, see `Xsd::load_with_imports`, otherwise attributes such as `xml:lang` are not checked.
+#[derive(Debug)]
+pub struct Validator<'a> {
+    xsd: &'a Xsd,
+    /// Compiled `pattern` facets, `None` for those that cannot be compiled.
+    patterns: RefCell<HashMap<String, Option<Regex>>>,
+    /// What each element declaration allows, by the address of the declaration.
+    declarations: RefCell<HashMap<usize, Option<Rc<Declared<'a>>>>>,
+}
+
+/// A way in which a document does not conform to the schema.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Violation {
+    /// An XPath-like location, e.g. `/score-partwise/part[2]/measure[1]/note[3]/@default-x`. An
+    /// index is given when an element has siblings of the same name.
+    pub path: String,
+    pub kind: ViolationKind,
+    pub message: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ViolationKind {
+    /// An element is not allowed where it is, or required elements are missing.
+    Content,
+    /// Text where only elements are allowed.
+    Text,
+    /// A required attribute is missing.
+    MissingAttribute,
+    /// An attribute that is not declared for the element.
+    UnknownAttribute,
+    /// A value that is not one of the `enumeration` facets.
+    Enumeration,
+    /// A number outside of the range of its type or its `min*`, `max*` or `*Digits` facets.
+    Range,
+    /// A value that is too long or too short.
+    Length,
+    /// A value that does not match a `pattern` facet.
+    Pattern,
+    /// A value that is not valid for its built-in type, e.g. `abc` for `xs:decimal`, or that
+    /// differs from a `fixed` value.
+    Value,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// The type of an element.
+#[derive(Debug)]
+enum ElementType<'a> {
+    Complex(&'a Xsd, &'a ComplexType),
+    Simple(SimpleRef<'a>),
+}
+
+/// The allowed content of a complex type, with its base types taken into account.
+#[derive(Debug)]
+enum Content<'a> {
+    Empty,
+    Simple(SimpleRef<'a>),
+    Elements(Automaton<Decl<'a>>),
+    /// A content model that no automaton could be built for, e.g. because it is ambiguous.
+    Invalid(String),
+}
+
+/// The attributes and content allowed by an element declaration.
+#[derive(Debug)]
+struct Declared<'a> {
+    attributes: Vec<AttributeUse<'a>>,
+    /// Whether there is an `xs:anyAttribute`.
+    wildcard: bool,
+    content: Content<'a>,
+}
+
+/// An attribute that may appear on an element.
+#[derive(Debug)]
+struct AttributeUse<'a> {
+    name: &'a str,
+    required: bool,
+    fixed: Option<&'a str>,
+    type_: Option<SimpleRef<'a>>,
+}
+
+impl<'a> Validator<'a> {
+    pub fn new(xsd: &'a Xsd) -> Self {
+        Self {
+            xsd,
+            patterns: RefCell::new(HashMap::new()),
+            declarations: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Validates the document rooted at `root`, which must be a top-level element of the schema.
+    pub fn validate(&self, root: &exile::Element) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        let name = root.fullname();
+        let path = NodePath {
+            parent: None,
+            name: &name,
+            index: None,
+        };
+        match self.xsd.get(RootNodeType::Element, &name) {
+            Some(Entry::Element(decl)) => {
+                self.element(root, self.xsd, decl, &path, &mut violations)
+            }
+            _ => violations.push(Violation {
+                path: path.to_string(),
+                kind: ViolationKind::Content,
+                message: format!("'{}' is not a top-level element", root.fullname()),
+            }),
+        }
+        violations
+    }
+
+    pub fn validate_str<S: AsRef<str>>(&self, xml: S) -> Result<Vec<Violation>> {
+        let doc = wrap!(exile::parse(xml.as_ref()))?;
+        Ok(self.validate(doc.root()))
+    }
+
+    /// Validates the document in `filepath`, which may be UTF-8 or, with a byte order mark, UTF-16.
+    pub fn validate_file<P: AsRef<Path>>(&self, filepath: P) -> Result<Vec<Violation>> {
+        let bytes = wrap!(
+            std::fs::read(filepath.as_ref()),
+            "unable to read '{}'",
+            filepath.as_ref().display()
+        )?;
+        let xml = wrap!(
+            decode(bytes),
+            "unable to decode '{}'",
+            filepath.as_ref().display()
+        )?;
+        self.validate_str(xml)
+    }
+
+    fn element(
+        &self,
+        node: &exile::Element,
+        schema: &'a Xsd,
+        decl: &'a Element,
+        path: &NodePath<'_>,
+        violations: &mut Vec<Violation>,
+    ) {
+        let declared = match self.declared(schema, decl) {
+            Some(declared) => declared,
+            // the type could not be resolved, e.g. because an import was not loaded.
+            None => return,
+        };
+        let uses = &declared.attributes;
+        self.attributes(node, uses, declared.wildcard, path, violations);
+        let text = node.text().unwrap_or_default();
+        match &declared.content {
+            Content::Empty => {
+                if let Some(child) = node.children().next() {
+                    violations.push(Violation {
+                        path: path.to_string(),
+                        kind: ViolationKind::Content,
+                        message: format!("unexpected element '{}'", child.fullname()),
+                    });
+                }
+                self.no_text(&text, path, violations);
+            }
+            Content::Simple(simple) => {
+                if let Some(child) = node.children().next() {
+                    violations.push(Violation {
+                        path: path.to_string(),
+                        kind: ViolationKind::Content,
+                        message: format!("unexpected element '{}'", child.fullname()),
+                    });
+                }
+                self.value(simple, &text, path, violations);
+            }
+            Content::Elements(automaton) => {
+                self.no_text(&text, path, violations);
+                self.children(node, automaton, path, violations);
+            }
+            Content::Invalid(message) => violations.push(Violation {
+                path: path.to_string(),
+                kind: ViolationKind::Content,
+                message: format!("the content model cannot be checked: {}", message),
+            }),
+        }
+    }
+
+    fn no_text(&self, text: &str, path: &NodePath<'_>, violations: &mut Vec<Violation>) {
+        if !text.trim().is_empty() {
+            violations.push(Violation {
+                path: path.to_string(),
+                kind: ViolationKind::Text,
+                message: format!("unexpected text '{}'", text.trim()),
+            });
+        }
+    }
+
+    /// Checks the order and number of the child elements, then validates each of them.
+    fn children(
+        &self,
+        node: &exile::Element,
+        automaton: &Automaton<Decl<'a>>,
+        path: &NodePath<'_>,
+        violations: &mut Vec<Violation>,
+    ) {
+        let children: Vec<&exile::Element> = node.children().collect();
+        let names: Vec<String> = children.iter().map(|c| c.fullname()).collect();
+        let paths = child_paths(path, &names);
+        let matched = automaton.run(&names);
+        if let Err(mismatch) = &matched {
+            let (path, message) = match names.get(mismatch.position) {
+                Some(name) => (
+                    paths[mismatch.position].to_string(),
+                    format!("unexpected element '{}'", name),
+                ),
+                None => (
+                    path.to_string(),
+                    format!("'{}' is incomplete", node.fullname()),
+                ),
+            };
+            let message = match mismatch.expected.as_slice() {
+                [] => message,
+                [one] => format!("{}, expected '{}'", message, one),
+                many => format!("{}, expected one of '{}'", message, many.join("', '")),
+            };
+            violations.push(Violation {
+                path,
+                kind: ViolationKind::Content,
+                message,
+            });
+        }
+        for (i, child) in children.iter().enumerate() {
+            // when the children do not match, each is validated against a declaration of its name.
+            let decl = match &matched {
+                Ok(decls) => decls[i],
+                Err(_) => automaton.declaration(&names[i]),
+            };
+            match decl {
+                Some((schema, decl)) => self.element(child, schema, decl, &paths[i], violations),
+                // matched by xs:any, so it is validated if there is a declaration for it.
+                None => {
+                    if let Some(Entry::Element(decl)) =
+                        self.xsd.get(RootNodeType::Element, &names[i])
+                    {
+                        self.element(child, self.xsd, decl, &paths[i], violations);
+                    }
+                }
+            }
+        }
+    }
+
+    fn attributes(
+        &self,
+        node: &exile::Element,
+        uses: &[AttributeUse<'a>],
+        wildcard: bool,
+        path: &NodePath<'_>,
+        violations: &mut Vec<Violation>,
+    ) {
+        for attribute_use in uses {
+            if attribute_use.required && !node.attributes.map().contains_key(attribute_use.name) {
+                violations.push(Violation {
+                    path: path.to_string(),
+                    kind: ViolationKind::MissingAttribute,
+                    message: format!("missing required attribute '{}'", attribute_use.name),
+                });
+            }
+        }
+        for (key, value) in node.attributes.map() {
+            if key == "xmlns" || key.starts_with("xmlns:") {
+                continue;
+            }
+            let attribute_use = match uses.iter().find(|u| u.name == key) {
+                Some(u) => u,
+                None if wildcard => continue,
+                None => {
+                    violations.push(Violation {
+                        path: format!("{}/@{}", path, key),
+                        kind: ViolationKind::UnknownAttribute,
+                        message: format!("unknown attribute '{}'", key),
+                    });
+                    continue;
+                }
+            };
+            if let Some(fixed) = attribute_use.fixed {
+                if value != fixed {
+                    violations.push(Violation {
+                        path: format!("{}/@{}", path, key),
+                        kind: ViolationKind::Value,
+                        message: format!("'{}' must be '{}'", value, fixed),
+                    });
+                }
+            }
+            if let Some(type_) = &attribute_use.type_ {
+                self.value(type_, value, &format_args!("{}/@{}", path, key), violations);
+            }
+        }
+    }
+
+    fn value(
+        &self,
+        type_: &SimpleRef<'a>,
+        value: &str,
+        path: &dyn Display,
+        v: &mut Vec<Violation>,
+    ) {
+        if let Err((kind, message)) = self.check_value(type_, value) {
+            v.push(Violation {
+                path: path.to_string(),
+                kind,
+                message,
+            });
+        }
+    }
+
+    fn declared(&self, schema: &'a Xsd, decl: &'a Element) -> Option<Rc<Declared<'a>>> {
+        let key = decl as *const Element as usize;
+        if let Some(declared) = self.declarations.borrow().get(&key) {
+            return declared.clone();
+        }
+        let declared = match self.element_type(schema, decl)? {
+            ElementType::Complex(schema, complex_type) => {
+                let mut attributes = Vec::new();
+                let mut wildcard = false;
+                let content =
+                    self.complex_type(schema, complex_type, &mut attributes, &mut wildcard);
+                Declared {
+                    attributes,
+                    wildcard,
+                    content,
+                }
+            }
+            ElementType::Simple(simple) => Declared {
+                attributes: Vec::new(),
+                wildcard: false,
+                content: Content::Simple(simple),
+            },
+        };
+        let declared = Some(Rc::new(declared));
+        self.declarations.borrow_mut().insert(key, declared.clone());
+        declared
+    }
+
+    /// The type of an element declaration, or `None` if it cannot be found.
+    fn element_type(&self, schema: &'a Xsd, decl: &'a Element) -> Option<ElementType<'a>> {
+        match decl {
+            Element::Definition(def) => Some(ElementType::Complex(schema, &def.complex_type)),
+            Element::Reference(r) => match &r.type_ {
+                BaseType::Custom(qname) => {
+                    let (schema, name) = schema.resolve_schema(qname)?;
+                    match schema.get(RootNodeType::ComplexType, name) {
+                        Some(Entry::ComplexType(ct)) => Some(ElementType::Complex(schema, ct)),
+                        _ => SimpleRef::named(schema, name).map(ElementType::Simple),
+                    }
+                }
+                builtin => Some(ElementType::Simple(SimpleRef::Builtin(builtin.clone()))),
+            },
+        }
+    }
+
+    /// Adds the attributes of `complex_type`, and of the types it is derived from, to `uses` and
+    /// returns its content.
+    fn complex_type(
+        &self,
+        schema: &'a Xsd,
+        complex_type: &'a ComplexType,
+        uses: &mut Vec<AttributeUse<'a>>,
+        wildcard: &mut bool,
+    ) -> Content<'a> {
+        match &complex_type.payload {
+            Payload::Parent(parent) => {
+                self.attribute_uses(schema, &parent.attributes, uses, wildcard);
+                match &parent.children {
+                    Some(children) => match Automaton::new(&content_model(schema, children)) {
+                        Ok(automaton) => Content::Elements(automaton),
+                        Err(e) => Content::Invalid(e.to_string()),
+                    },
+                    None => Content::Empty,
+                }
+            }
+            Payload::SimpleContent(simple_content) => {
+                let simple_content::Payload::Extension(extension) = &simple_content.payload;
+                self.attribute_uses(schema, &extension.attributes, uses, wildcard);
+                self.base(schema, &extension.base, uses, wildcard)
+            }
+            Payload::ComplexContent(complex_content) => {
+                let extension = &complex_content.extension;
+                self.attribute_uses(schema, &extension.attributes, uses, wildcard);
+                self.base(schema, &extension.base, uses, wildcard)
+            }
+            Payload::None => Content::Empty,
+        }
+    }
+
+    /// The content of the `base` of an extension, whose attributes are added to `uses`.
+    fn base(
+        &self,
+        schema: &'a Xsd,
+        base: &str,
+        uses: &mut Vec<AttributeUse<'a>>,
+        wildcard: &mut bool,
+    ) -> Content<'a> {
+        let simple = match BaseType::parse_prefixed(base, schema.prefix()) {
+            Ok(BaseType::Custom(_)) => {
+                let (schema, name) = match schema.resolve_schema(base) {
+                    Some(found) => found,
+                    None => return Content::Empty,
+                };
+                if let Some(Entry::ComplexType(ct)) = schema.get(RootNodeType::ComplexType, name) {
+                    return self.complex_type(schema, ct, uses, wildcard);
+                }
+                SimpleRef::named(schema, name)
+            }
+            Ok(builtin) => Some(SimpleRef::Builtin(builtin)),
+            Err(_) => None,
+        };
+        simple.map(Content::Simple).unwrap_or(Content::Empty)
+    }
+
+    fn attribute_uses(
+        &self,
+        schema: &'a Xsd,
+        attributes: &'a Attributes,
+        uses: &mut Vec<AttributeUse<'a>>,
+        wildcard: &mut bool,
+    ) {
+        for item in attributes {
+            match item {
+                AttributeItem::Attribute(a) => uses.push(self.attribute_use(schema, a)),
+                AttributeItem::AttributeGroup(AttributeGroup::Def(def)) => {
+                    self.attribute_uses(schema, &def.attributes, uses, wildcard)
+                }
+                AttributeItem::AttributeGroup(AttributeGroup::Ref(r)) => {
+                    if let Some((schema, name)) = schema.resolve_schema(&r.ref_) {
+                        if let Some(Entry::AttributeGroup(AttributeGroup::Def(def))) =
+                            schema.get(RootNodeType::AttributeGroup, name)
+                        {
+                            self.attribute_uses(schema, &def.attributes, uses, wildcard)
+                        }
+                    }
+                }
+                AttributeItem::AnyAttribute(_) => *wildcard = true,
+            }
+        }
+    }
+
+    fn attribute_use(&self, schema: &'a Xsd, attribute: &'a Attribute) -> AttributeUse<'a> {
+        let (name, type_) = match &attribute.defined_by {
+            // e.g. xml:lang, which is declared by an imported schema.
+            DefinedBy::Ref(qname) => {
+                let type_ = match schema.resolve_schema(qname) {
+                    Some((schema, name)) => match schema.get(RootNodeType::Attribute, name) {
+                        Some(Entry::Attribute(decl)) => SimpleRef::attribute(schema, decl),
+                        _ => None,
+                    },
+                    None => None,
+                };
+                (qname.as_str(), type_)
+            }
+            DefinedBy::Type(_) => (
+                attribute.name.as_str(),
+                SimpleRef::attribute(schema, attribute),
+            ),
+        };
+        AttributeUse {
+            name,
+            required: attribute.required,
+            fixed: attribute.fixed.as_deref(),
+            type_,
+        }
+    }
+}
+
+fn decode(bytes: Vec<u8>) -> Result<String> {
+    let utf16 = |bytes: &[u8], from_bytes: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| from_bytes([pair[0], pair[1]]))
+            .collect();
+        wrap!(String::from_utf16(&units))
+    };
+    match bytes.as_slice() {
+        [0xFF, 0xFE, rest @ ..] => utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => utf16(rest, u16::from_be_bytes),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => wrap!(String::from_utf8(rest.to_vec())),
+        _ => wrap!(String::from_utf8(bytes)),
+    }
+}
+
+/// The location of an element, which is only formatted when there is a violation.
+#[derive(Clone, Copy, Debug)]
+struct NodePath<'p> {
+    parent: Option<&'p NodePath<'p>>,
+    name: &'p str,
+    /// The position among siblings of the same name, if there are any.
+    index: Option<usize>,
+}
+
+impl Display for NodePath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{}", parent)?;
+        }
+        write!(f, "/{}", self.name)?;
+        if let Some(index) = self.index {
+            write!(f, "[{}]", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// The paths of the children of the element at `path`, given their `names`.
+fn child_paths<'p>(path: &'p NodePath<'p>, names: &'p [String]) -> Vec<NodePath<'p>> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for name in names {
+        *counts.entry(name.as_str()).or_default() += 1;
+    }
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    names
+        .iter()
+        .map(|name| {
+            let index = seen.entry(name.as_str()).or_default();
+            *index += 1;
+            NodePath {
+                parent: Some(path),
+                name,
+                index: Some(*index).filter(|_| counts[name.as_str()] > 1),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn child_paths_test() {
+    let names: Vec<String> = vec!["a".into(), "b".into(), "a".into()];
+    let root = NodePath {
+        parent: None,
+        name: "x",
+        index: None,
+    };
+    let got: Vec<String> = child_paths(&root, &names)
+        .iter()
+        .map(|p| p.to_string())
+        .collect();
+    assert_eq!(got, vec!["/x/a[1]", "/x/b", "/x/a[2]"]);
+}
@@ -0,0 +1,396 @@
+use crate::validate::{Validator, ViolationKind};
+use crate::xsd::attribute::Attribute;
+use crate::xsd::common::DefinedBy;
+use crate::xsd::id::RootNodeType;
+use crate::xsd::pattern::Pattern;
+use crate::xsd::primitives::{BaseType, PrefixedParse};
+use crate::xsd::restriction::{Facet, Number, Restriction, WhiteSpace};
+use crate::xsd::simple_type::{Payload, SimpleType};
+use crate::xsd::union::Union;
+use crate::xsd::{Entry, Xsd};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+/// The outcome of checking a value, with the kind of violation and a message if it is invalid.
+pub(super) type Checked = std::result::Result<(), (ViolationKind, String)>;
+
+/// A simple type that a value can be checked against.
+#[derive(Clone, Debug)]
+pub(super) enum SimpleRef<'a> {
+    Builtin(BaseType),
+    /// A simple type and the schema in which its references are resolved.
+    Defined(&'a Xsd, &'a SimpleType),
+}
+
+impl<'a> SimpleRef<'a> {
+    /// The simple type named `name` in `schema`.
+    pub(super) fn named(schema: &'a Xsd, name: &str) -> Option<Self> {
+        match schema.get(RootNodeType::SimpleType, name) {
+            Some(Entry::SimpleType(st)) => Some(SimpleRef::Defined(schema, st)),
+            _ => None,
+        }
+    }
+
+    /// The type given by a `type` or `base` attribute of `schema`, e.g. `xs:token` or `yes-no`.
+    fn parse(schema: &'a Xsd, base_type: &BaseType) -> Option<Self> {
+        match base_type {
+            BaseType::Custom(qname) => {
+                let (schema, name) = schema.resolve_schema(qname)?;
+                Self::named(schema, name)
+            }
+            builtin => Some(SimpleRef::Builtin(builtin.clone())),
+        }
+    }
+
+    /// The type of an attribute declaration, which is either in-line or named by its `type`.
+    pub(super) fn attribute(schema: &'a Xsd, attribute: &'a Attribute) -> Option<Self> {
+        if let Some(st) = &attribute.simple_type {
+            return Some(SimpleRef::Defined(schema, st));
+        }
+        match &attribute.defined_by {
+            DefinedBy::Type(t) => {
+                Self::parse(schema, &BaseType::parse_prefixed(t, schema.prefix()).ok()?)
+            }
+            DefinedBy::Ref(_) => None,
+        }
+    }
+}
+
+lazy_static! {
+    static ref INTEGER: Regex = Regex::new(r"^[+-]?\d+$").unwrap();
+    static ref DECIMAL: Regex = Regex::new(r"^[+-]?(\d+(\.\d*)?|\.\d+)$").unwrap();
+    static ref NAME: Regex = Regex::new(r"^[\p{L}_:][\p{L}\p{N}._:\-]*$").unwrap();
+    static ref NCNAME: Regex = Regex::new(r"^[\p{L}_][\p{L}\p{N}._\-]*$").unwrap();
+    static ref NMTOKEN: Regex = Regex::new(r"^[\p{L}\p{N}._:\-]+$").unwrap();
+    static ref LANGUAGE: Regex = Regex::new(r"^[a-zA-Z]{1,8}(-[a-zA-Z0-9]{1,8})*$").unwrap();
+    static ref DATE: Regex = Regex::new(r"^-?\d{4,}-\d{2}-\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref DATE_TIME: Regex =
+        Regex::new(r"^-?\d{4,}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})?$")
+            .unwrap();
+    static ref TIME: Regex =
+        Regex::new(r"^\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref DURATION: Regex =
+        Regex::new(r"^-?P(\d+Y)?(\d+M)?(\d+D)?(T(\d+H)?(\d+M)?(\d+(\.\d+)?S)?)?$").unwrap();
+    static ref G_DAY: Regex = Regex::new(r"^---\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref G_MONTH: Regex = Regex::new(r"^--\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref G_MONTH_DAY: Regex = Regex::new(r"^--\d{2}-\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref G_YEAR: Regex = Regex::new(r"^-?\d{4,}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+    static ref G_YEAR_MONTH: Regex = Regex::new(r"^-?\d{4,}-\d{2}(Z|[+-]\d{2}:\d{2})?$").unwrap();
+}
+
+impl<'a> Validator<'a> {
+    pub(super) fn check_value(&self, type_: &SimpleRef<'a>, value: &str) -> Checked {
+        match type_ {
+            SimpleRef::Builtin(builtin) => {
+                check_builtin(builtin, &normalize(value, builtin_white_space(builtin)))
+            }
+            SimpleRef::Defined(schema, st) => match &st.payload {
+                Payload::Restriction(r) => self.check_restriction(schema, r, value),
+                Payload::List(list) => {
+                    let item_type = BaseType::parse_prefixed(&list.item_type, schema.prefix());
+                    let item_type = match item_type.ok().and_then(|t| SimpleRef::parse(schema, &t))
+                    {
+                        Some(t) => t,
+                        None => return Ok(()),
+                    };
+                    for item in value.split_whitespace() {
+                        self.check_value(&item_type, item)?;
+                    }
+                    Ok(())
+                }
+                Payload::Union(union) => self.check_union(schema, st, union, value),
+            },
+        }
+    }
+
+    fn check_restriction(&self, schema: &'a Xsd, r: &'a Restriction, value: &str) -> Checked {
+        let base = SimpleRef::parse(schema, &r.base);
+        let white_space = r
+            .facets
+            .iter()
+            .find_map(|f| match f {
+                Facet::WhiteSpace(w) => Some(*w),
+                _ => None,
+            })
+            .unwrap_or_else(|| self.white_space(base.as_ref()));
+        let value = normalize(value, white_space);
+        let value = value.as_str();
+        if let Some(base) = &base {
+            self.check_value(base, value)?;
+        }
+        let enumerations: Vec<&str> = r
+            .facets
+            .iter()
+            .filter_map(|f| match f {
+                Facet::Enumeration(e) => Some(e.as_str()),
+                _ => None,
+            })
+            .collect();
+        if !enumerations.is_empty() && !enumerations.contains(&value) {
+            return Err((
+                ViolationKind::Enumeration,
+                format!("'{}' is not one of '{}'", value, enumerations.join("', '")),
+            ));
+        }
+        self.check_patterns(r, value)?;
+        for facet in &r.facets {
+            check_facet(facet, value)?;
+        }
+        Ok(())
+    }
+
+    /// Several patterns in one restriction are alternatives, so the value must match one of them.
+    /// Patterns that cannot be translated, such as those using Unicode blocks, are not checked.
+    fn check_patterns(&self, r: &Restriction, value: &str) -> Checked {
+        let patterns: Vec<&str> = r
+            .facets
+            .iter()
+            .filter_map(|f| match f {
+                Facet::Pattern(p) => Some(p.as_str()),
+                _ => None,
+            })
+            .collect();
+        let mut cache = self.patterns.borrow_mut();
+        let mut checked = false;
+        for pattern in &patterns {
+            let regex = cache
+                .entry((*pattern).to_owned())
+                .or_insert_with(|| Pattern::new(pattern).and_then(|p| p.regex()).ok());
+            if let Some(regex) = regex {
+                if regex.is_match(value) {
+                    return Ok(());
+                }
+                checked = true;
+            }
+        }
+        if !checked {
+            return Ok(());
+        }
+        Err((
+            ViolationKind::Pattern,
+            format!(
+                "'{}' does not match the pattern '{}'",
+                value,
+                patterns.join("' or '")
+            ),
+        ))
+    }
+
+    fn check_union(
+        &self,
+        schema: &'a Xsd,
+        st: &SimpleType,
+        union: &'a Union,
+        value: &str,
+    ) -> Checked {
+        for member in &union.members {
+            let member = match union.inline_member(member) {
+                Some(inline) => Some(SimpleRef::Defined(schema, inline)),
+                None => SimpleRef::parse(schema, member),
+            };
+            match member {
+                Some(member) if self.check_value(&member, value).is_err() => continue,
+                // a member that cannot be resolved is given the benefit of the doubt.
+                _ => return Ok(()),
+            }
+        }
+        Err((
+            ViolationKind::Value,
+            format!("'{}' is not a valid '{}'", value, st.name),
+        ))
+    }
+
+    /// How whitespace is normalized for values of `type_`.
+    fn white_space(&self, type_: Option<&SimpleRef<'a>>) -> WhiteSpace {
+        match type_ {
+            Some(SimpleRef::Builtin(builtin)) => builtin_white_space(builtin),
+            Some(SimpleRef::Defined(schema, st)) => match &st.payload {
+                Payload::Restriction(r) => r
+                    .facets
+                    .iter()
+                    .find_map(|f| match f {
+                        Facet::WhiteSpace(w) => Some(*w),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| {
+                        self.white_space(SimpleRef::parse(schema, &r.base).as_ref())
+                    }),
+                Payload::List(_) | Payload::Union(_) => WhiteSpace::Collapse,
+            },
+            None => WhiteSpace::Preserve,
+        }
+    }
+}
+
+fn builtin_white_space(builtin: &BaseType) -> WhiteSpace {
+    match builtin {
+        BaseType::String => WhiteSpace::Preserve,
+        BaseType::NormalizedString => WhiteSpace::Replace,
+        _ => WhiteSpace::Collapse,
+    }
+}
+
+fn normalize(value: &str, white_space: WhiteSpace) -> String {
+    match white_space {
+        WhiteSpace::Preserve => value.to_owned(),
+        WhiteSpace::Replace => value.replace(['\t', '\n', '\r'], " "),
+        WhiteSpace::Collapse => value.split_whitespace().collect::<Vec<_>>().join(" "),
+    }
+}
+
+fn check_builtin(builtin: &BaseType, value: &str) -> Checked {
+    let invalid = || {
+        Err((
+            ViolationKind::Value,
+            format!("'{}' is not a valid '{}'", value, builtin),
+        ))
+    };
+    let (min, max): (i128, i128) = match builtin {
+        BaseType::Byte => (i8::MIN.into(), i8::MAX.into()),
+        BaseType::Short => (i16::MIN.into(), i16::MAX.into()),
+        BaseType::Int => (i32::MIN.into(), i32::MAX.into()),
+        BaseType::Long => (i64::MIN.into(), i64::MAX.into()),
+        BaseType::UnsignedByte => (0, u8::MAX.into()),
+        BaseType::UnsignedShort => (0, u16::MAX.into()),
+        BaseType::UnsignedInt => (0, u32::MAX.into()),
+        BaseType::UnsignedLong => (0, u64::MAX.into()),
+        BaseType::NonNegativeInteger => (0, i128::MAX),
+        BaseType::PositiveInteger => (1, i128::MAX),
+        BaseType::NonPositiveInteger => (i128::MIN, 0),
+        BaseType::NegativeInteger => (i128::MIN, -1),
+        BaseType::Integer => (i128::MIN, i128::MAX),
+        BaseType::Decimal => {
+            return if DECIMAL.is_match(value) {
+                Ok(())
+            } else {
+                invalid()
+            }
+        }
+        other => {
+            let regex: Option<&Regex> = match other {
+                BaseType::ID | BaseType::IDREF => Some(&NCNAME),
+                BaseType::Name => Some(&NAME),
+                BaseType::NmToken => Some(&NMTOKEN),
+                BaseType::Language => Some(&LANGUAGE),
+                BaseType::Date => Some(&DATE),
+                BaseType::DateTime => Some(&DATE_TIME),
+                BaseType::Time => Some(&TIME),
+                BaseType::Duration => Some(&DURATION),
+                BaseType::GDay => Some(&G_DAY),
+                BaseType::GMonth => Some(&G_MONTH),
+                BaseType::GMonthDay => Some(&G_MONTH_DAY),
+                BaseType::GYear => Some(&G_YEAR),
+                BaseType::GYearMonth => Some(&G_YEAR_MONTH),
+                _ => None,
+            };
+            // a duration needs at least one component, and a time component after 'T'.
+            let empty_duration =
+                *other == BaseType::Duration && (value.ends_with('P') || value.ends_with('T'));
+            return match regex {
+                Some(regex) if !regex.is_match(value) || empty_duration => invalid(),
+                _ => Ok(()),
+            };
+        }
+    };
+    if !INTEGER.is_match(value) {
+        return invalid();
+    }
+    // a number too large for an i128 is out of range of all but xs:integer.
+    let in_range = match value.trim_start_matches('+').parse::<i128>() {
+        Ok(n) => n >= min && n <= max,
+        Err(_) => *builtin == BaseType::Integer,
+    };
+    if in_range {
+        Ok(())
+    } else {
+        Err((
+            ViolationKind::Range,
+            format!("'{}' is out of range for '{}'", value, builtin),
+        ))
+    }
+}
+
+/// Checks the numeric and length facets. The value has already been checked against the base
+/// type, so a value that is not a number is not checked against numeric facets.
+fn check_facet(facet: &Facet, value: &str) -> Checked {
+    let number = value.parse::<f64>().ok();
+    let length = value.chars().count() as u64;
+    let range = |message: String| Err((ViolationKind::Range, message));
+    let too_long = |message: String| Err((ViolationKind::Length, message));
+    match (facet, number) {
+        (Facet::MinInclusive(n), Some(x)) if x < as_f64(n) => {
+            range(format!("'{}' is less than the minimum of {}", value, n))
+        }
+        (Facet::MinExclusive(n), Some(x)) if x <= as_f64(n) => {
+            range(format!("'{}' must be greater than {}", value, n))
+        }
+        (Facet::MaxInclusive(n), Some(x)) if x > as_f64(n) => {
+            range(format!("'{}' is greater than the maximum of {}", value, n))
+        }
+        (Facet::MaxExclusive(n), Some(x)) if x >= as_f64(n) => {
+            range(format!("'{}' must be less than {}", value, n))
+        }
+        (Facet::TotalDigits(n), Some(_)) if digits(value).0 > *n => {
+            range(format!("'{}' has more than {} digits", value, n))
+        }
+        (Facet::FractionDigits(n), Some(_)) if digits(value).1 > *n => {
+            range(format!("'{}' has more than {} fraction digits", value, n))
+        }
+        (Facet::Length(n), _) if length != *n => {
+            too_long(format!("'{}' must be {} characters long", value, n))
+        }
+        (Facet::MinLength(n), _) if length < *n => {
+            too_long(format!("'{}' is shorter than {} characters", value, n))
+        }
+        (Facet::MaxLength(n), _) if length > *n => {
+            too_long(format!("'{}' is longer than {} characters", value, n))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn as_f64(number: &Number) -> f64 {
+    match number {
+        Number::Integer(i) => *i as f64,
+        Number::Decimal(d) => *d,
+    }
+}
+
+/// The number of significant digits, and the number of digits after the decimal point, of a
+/// decimal, e.g. `(3, 1)` for `-012.50` and `(1, 3)` for `0.001`.
+fn digits(value: &str) -> (u64, u64) {
+    let value = value.trim_start_matches(['+', '-']);
+    let (integer, fraction) = match value.find('.') {
+        Some(i) => (&value[..i], &value[i + 1..]),
+        None => (value, ""),
+    };
+    let fraction = fraction.trim_end_matches('0');
+    let all = format!("{}{}", integer, fraction);
+    let total = all.trim_start_matches('0').len() as u64;
+    (total, fraction.len() as u64)
+}
+
+#[test]
+fn check_builtin_test() {
+    assert!(check_builtin(&BaseType::Decimal, "-1.5").is_ok());
+    assert!(check_builtin(&BaseType::Decimal, ".5").is_ok());
+    assert!(check_builtin(&BaseType::Decimal, "1e5").is_err());
+    assert!(check_builtin(&BaseType::PositiveInteger, "+3").is_ok());
+    let (kind, _) = check_builtin(&BaseType::PositiveInteger, "0").unwrap_err();
+    assert_eq!(kind, ViolationKind::Range);
+    let (kind, _) = check_builtin(&BaseType::Byte, "1.0").unwrap_err();
+    assert_eq!(kind, ViolationKind::Value);
+    assert!(check_builtin(&BaseType::Date, "2012-12-03").is_ok());
+    assert!(check_builtin(&BaseType::Date, "12/03/2012").is_err());
+    assert!(check_builtin(&BaseType::Duration, "P1DT2H").is_ok());
+    assert!(check_builtin(&BaseType::Duration, "P").is_err());
+    assert!(check_builtin(&BaseType::Language, "en-US").is_ok());
+    assert!(check_builtin(&BaseType::NmToken, "a b").is_err());
+}
+
+#[test]
+fn digits_test() {
+    assert_eq!(digits("-012.50"), (3, 1));
+    assert_eq!(digits("100"), (3, 0));
+    assert_eq!(digits("0.001"), (1, 3));
+}
@@ -0,0 +1,77 @@
+use crate::error::Result;
+use crate::xsd::annotation::Annotation;
+use crate::xsd::constants::{ALL, ANNOTATION, ELEMENT};
+use crate::xsd::element::Element;
+use crate::xsd::id::{Id, Lineage};
+use crate::xsd::write::Node;
+use crate::xsd::{Occurs, Xsd};
+
+/// Represents an `xs:all`, i.e. each of the `elements` may appear at most once, in any order.
+#[derive(Clone, Debug)]
+pub struct All {
+    pub id: Id,
+    pub annotation: Option<Annotation>,
+    pub occurs: Occurs,
+    pub elements: Vec<Element>,
+}
+
+impl All {
+    pub fn documentation(&self) -> String {
+        if let Some(annotation) = &self.annotation {
+            return annotation.documentation();
+        }
+        "".to_owned()
+    }
+
+    pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
+        check!(ALL, node, xsd)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
+        let mut annotation = None;
+        let mut elements = Vec::new();
+        for inner in node.children() {
+            let t = inner.name.as_str();
+            match t {
+                ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
+                ELEMENT => elements.push(Element::from_xml(inner, lineage.clone(), xsd)?),
+                _ => return raise_at!(xsd, inner, "unknown {} member: '{}'", ALL, t),
+            }
+        }
+        let occurs = Occurs::from_xml(node)?;
+        if occurs.min_occurs > 1 || occurs.max_occurs != Some(1) {
+            return raise!("{} may occur at most once", ALL);
+        }
+        Ok(All {
+            id,
+            annotation,
+            occurs,
+            elements,
+        })
+    }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, ALL)
+            .occurs(&self.occurs)
+            .annotation(xsd, &self.annotation)
+            .children(self.elements.iter().map(|e| e.to_xml(xsd)))
+    }
+}
+
+#[test]
+fn parse() {
+    let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::ComplexType, "foo".into());
+    let xml_str = r#"
+    <xs:all minOccurs="0">
+        <xs:element name="top" type="xs:decimal"/>
+        <xs:element name="bottom" type="xs:decimal" minOccurs="0"/>
+    </xs:all>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let all = All::from_xml(
+        doc.root(),
+        Lineage::Parent(parent),
+        &Xsd::fragment("xs", doc.root()),
+    )
+    .unwrap();
+    assert_eq!(all.occurs.min_occurs, 0);
+    assert_eq!(all.elements.len(), 2);
+    assert_eq!(all.elements[1].occurs().min_occurs, 0);
+}
@@ -2,6 +2,7 @@
 use crate::error::Result;
 use crate::xsd::constants::{ANNOTATION, APP_INFO, DOCUMENTATION, NAME};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::Xsd;
 use std::fmt::{Debug, Formatter};
 
@@ -29,7 +30,7 @@ impl Annotation {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(ANNOTATION, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut items = Vec::new();
         for inner in node.children() {
             let t = inner.name.as_str();
@@ -50,12 +51,20 @@ impl Annotation {
         }
         Ok(Annotation { id, items })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let items = self.items.iter().map(|item| match item {
+            Item::Documentation(s) => Node::new(xsd, DOCUMENTATION).text(s.as_str()),
+            Item::AppInfo(s) => Node::new(xsd, APP_INFO).text(s.as_str()),
+        });
+        Node::new(xsd, ANNOTATION).children(items)
+    }
 }
 
 #[test]
 fn parse() {
     let want_index: u64 = 13;
-    let want_name = "4594507136952412519";
+    let want_name = "annotation[0]";
     let want_doc = "bishop is sleeping.";
     let xml = exile::Element {
         namespace: Some("xs".to_owned()),
@@ -70,7 +79,7 @@ fn parse() {
     };
 
     let annotation =
-        Annotation::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+        Annotation::from_xml(&xml, Lineage::Index(want_index), &Xsd::fragment("xs", &xml)).unwrap();
     let got_doc = annotation.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_index = annotation.id.index().unwrap();
@@ -84,7 +93,7 @@ fn parse() {
 #[test]
 fn parse_foo_err() {
     let want_index: u64 = 13;
-    let want_name = "4594507136952412519";
+    let want_name = "annotation[0]";
     let want_doc = "bishop is sleeping.";
     let xml = exile::Element {
         namespace: Some("xs".to_owned()),
@@ -98,14 +107,18 @@ fn parse_foo_err() {
         })],
     };
 
-    let result = Annotation::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("foo"));
+    let result = Annotation::from_xml(
+        &xml,
+        Lineage::Index(want_index),
+        &Xsd::fragment("foo", &xml),
+    );
     assert!(result.is_err());
 }
 
 #[test]
 fn parse_foo_ok() {
     let want_index: u64 = 13;
-    let want_name = "4594507136952412519";
+    let want_name = "annotation[0]";
     let want_doc = "bishop is sleeping.";
     let xml = exile::Element {
         namespace: Some("foo".to_owned()),
@@ -119,6 +132,10 @@ fn parse_foo_ok() {
         })],
     };
 
-    let result = Annotation::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("foo"));
+    let result = Annotation::from_xml(
+        &xml,
+        Lineage::Index(want_index),
+        &Xsd::fragment("foo", &xml),
+    );
     assert!(result.is_ok());
 }
@@ -0,0 +1,226 @@
+use crate::error::Result;
+use crate::xsd::annotation::Annotation;
+use crate::xsd::constants::{ANNOTATION, ANY, ANY_ATTRIBUTE, NAMESPACE, PROCESS_CONTENTS};
+use crate::xsd::id::{Id, Lineage};
+use crate::xsd::write::Node;
+use crate::xsd::{Occurs, Xsd};
+use std::fmt::{Display, Formatter};
+
+/// Represents an `xs:any` wildcard, i.e. any element from the allowed namespaces.
+#[derive(Clone, Debug)]
+pub struct Any {
+    pub id: Id,
+    pub annotation: Option<Annotation>,
+    pub occurs: Occurs,
+    pub namespace: Namespace,
+    pub process_contents: ProcessContents,
+}
+
+/// Represents an `xs:anyAttribute` wildcard, i.e. any attribute from the allowed namespaces.
+#[derive(Clone, Debug)]
+pub struct AnyAttribute {
+    pub id: Id,
+    pub annotation: Option<Annotation>,
+    pub namespace: Namespace,
+    pub process_contents: ProcessContents,
+}
+
+/// The `namespace` attribute of a wildcard.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum Namespace {
+    /// `##any`, which is the default.
+    #[default]
+    Any,
+    /// `##other`, i.e. any namespace other than the target namespace.
+    Other,
+    /// A whitespace-separated list of namespace URIs, which may include `##targetNamespace` and
+    /// `##local`.
+    List(Vec<String>),
+}
+
+impl Display for Namespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Namespace::Any => write!(f, "##any"),
+            Namespace::Other => write!(f, "##other"),
+            Namespace::List(list) => write!(f, "{}", list.join(" ")),
+        }
+    }
+}
+
+impl Namespace {
+    fn parse<S: AsRef<str>>(s: S) -> Namespace {
+        match s.as_ref().trim() {
+            "##any" => Namespace::Any,
+            "##other" => Namespace::Other,
+            list => Namespace::List(list.split_whitespace().map(|s| s.to_owned()).collect()),
+        }
+    }
+
+    fn from_xml(node: &exile::Element) -> Namespace {
+        node.attributes
+            .map()
+            .get(NAMESPACE)
+            .map(Namespace::parse)
+            .unwrap_or_default()
+    }
+}
+
+/// The `processContents` attribute of a wildcard, i.e. how the matched content is validated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub enum ProcessContents {
+    /// The content must be valid against a declaration, which must be available. The default.
+    #[default]
+    Strict,
+    /// The content is validated if a declaration is available.
+    Lax,
+    /// The content is not validated.
+    Skip,
+}
+
+impl Display for ProcessContents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ProcessContents::Strict => "strict",
+            ProcessContents::Lax => "lax",
+            ProcessContents::Skip => "skip",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl ProcessContents {
+    fn from_xml(node: &exile::Element) -> Result<ProcessContents> {
+        match node.attributes.map().get(PROCESS_CONTENTS) {
+            None => Ok(ProcessContents::default()),
+            Some(s) => match s.as_str() {
+                "strict" => Ok(ProcessContents::Strict),
+                "lax" => Ok(ProcessContents::Lax),
+                "skip" => Ok(ProcessContents::Skip),
+                other => raise!("invalid {} '{}'", PROCESS_CONTENTS, other),
+            },
+        }
+    }
+}
+
+impl Any {
+    pub fn documentation(&self) -> String {
+        if let Some(annotation) = &self.annotation {
+            return annotation.documentation();
+        }
+        "".to_owned()
+    }
+
+    pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
+        check!(ANY, node, xsd)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
+        Ok(Any {
+            id,
+            annotation: parse_annotation(node, lineage, xsd, ANY)?,
+            occurs: Occurs::from_xml(node)?,
+            namespace: Namespace::from_xml(node),
+            process_contents: ProcessContents::from_xml(node)?,
+        })
+    }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let node = Node::new(xsd, ANY).occurs(&self.occurs);
+        write_wildcard(node, &self.namespace, self.process_contents)
+            .annotation(xsd, &self.annotation)
+    }
+}
+
+impl AnyAttribute {
+    pub fn documentation(&self) -> String {
+        if let Some(annotation) = &self.annotation {
+            return annotation.documentation();
+        }
+        "".to_owned()
+    }
+
+    pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
+        check!(ANY_ATTRIBUTE, node, xsd)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
+        Ok(AnyAttribute {
+            id,
+            annotation: parse_annotation(node, lineage, xsd, ANY_ATTRIBUTE)?,
+            namespace: Namespace::from_xml(node),
+            process_contents: ProcessContents::from_xml(node)?,
+        })
+    }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let node = Node::new(xsd, ANY_ATTRIBUTE);
+        write_wildcard(node, &self.namespace, self.process_contents)
+            .annotation(xsd, &self.annotation)
+    }
+}
+
+/// A wildcard may only contain an annotation.
+fn parse_annotation(
+    node: &exile::Element,
+    lineage: Lineage,
+    xsd: &Xsd,
+    what: &str,
+) -> Result<Option<Annotation>> {
+    let mut annotation = None;
+    for inner in node.children() {
+        let t = inner.name.as_str();
+        match t {
+            ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
+            _ => return raise_at!(xsd, inner, "unexpected {} member '{}'", what, t),
+        }
+    }
+    Ok(annotation)
+}
+
+/// Adds the `namespace` and `processContents` attributes unless they have their default values.
+fn write_wildcard(node: Node, namespace: &Namespace, process_contents: ProcessContents) -> Node {
+    node.attr_opt(
+        NAMESPACE,
+        Some(namespace).filter(|&ns| *ns != Namespace::Any),
+    )
+    .attr_opt(
+        PROCESS_CONTENTS,
+        Some(process_contents).filter(|&pc| pc != ProcessContents::Strict),
+    )
+}
+
+#[test]
+fn parse_any() {
+    let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::Element, "foo".into());
+    let xml_str = r###"<xs:any namespace="##other" processContents="lax" minOccurs="0" maxOccurs="unbounded"/>"###;
+    let doc = exile::parse(xml_str).unwrap();
+    let any = Any::from_xml(
+        doc.root(),
+        Lineage::Parent(parent),
+        &Xsd::fragment("xs", doc.root()),
+    )
+    .unwrap();
+    assert_eq!(any.namespace, Namespace::Other);
+    assert_eq!(any.process_contents, ProcessContents::Lax);
+    assert_eq!(any.occurs.min_occurs, 0);
+    assert!(any.occurs.max_occurs.is_none());
+}
+
+#[test]
+fn parse_any_attribute() {
+    let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::Element, "foo".into());
+    let xml_str =
+        r###"<xs:anyAttribute namespace="##targetNamespace http://www.w3.org/1999/xlink"/>"###;
+    let doc = exile::parse(xml_str).unwrap();
+    let any = AnyAttribute::from_xml(
+        doc.root(),
+        Lineage::Parent(parent),
+        &Xsd::fragment("xs", doc.root()),
+    )
+    .unwrap();
+    assert_eq!(
+        any.namespace,
+        Namespace::List(vec![
+            "##targetNamespace".to_owned(),
+            "http://www.w3.org/1999/xlink".to_owned()
+        ])
+    );
+    assert_eq!(any.process_contents, ProcessContents::Strict);
+}
@@ -1,8 +1,12 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::common::DefinedBy;
-use crate::xsd::constants::{ANNOTATION, ATTRIBUTE, DEFAULT, FIXED, NAME, REF, TYPE};
+use crate::xsd::constants::{
+    ANNOTATION, ATTRIBUTE, DEFAULT, FIXED, NAME, REF, REQUIRED, SIMPLE_TYPE, TYPE, USE,
+};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::simple_type::SimpleType;
+use crate::xsd::write::Node;
 use crate::xsd::{use_required, Xsd};
 
 #[derive(Clone, Debug)]
@@ -14,6 +18,9 @@ pub struct Attribute {
     pub required: bool,
     pub default: Option<String>,
     pub fixed: Option<String>,
+    /// An anonymous `xs:simpleType` defined in-line, e.g. `xml:space`. When present, `defined_by`
+    /// holds the name that was synthesized for it, e.g. `space-type`.
+    pub simple_type: Option<Box<SimpleType>>,
 }
 
 impl Attribute {
@@ -27,20 +34,46 @@ impl Attribute {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(ATTRIBUTE, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let name = Self::parse_name(node);
-        let defined_by = Self::parse_defined_by(node)?;
+        let (annotation, simple_type) = Self::parse_children(node, &name, lineage, xsd)?;
+        let defined_by = if let Some(st) = &simple_type {
+            DefinedBy::Type(st.name.clone())
+        } else {
+            Self::parse_defined_by(node)?
+        };
         Ok(Attribute {
             id,
             name,
-            annotation: Self::parse_annotation(node, lineage, xsd)?,
+            annotation,
             required: use_required(node),
             defined_by,
             default: node.attributes.map().get(DEFAULT).cloned(),
             fixed: node.attributes.map().get(FIXED).cloned(),
+            simple_type: simple_type.map(Box::new),
         })
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let node =
+            Node::new(xsd, ATTRIBUTE).attr_opt(NAME, Some(&self.name).filter(|n| !n.is_empty()));
+        // an in-line simple type is written as such, rather than by its synthesized name.
+        let node = match (&self.defined_by, &self.simple_type) {
+            (_, Some(_)) => node,
+            (DefinedBy::Ref(r), None) => node.attr(REF, r),
+            (DefinedBy::Type(t), None) => node.attr(TYPE, t),
+        };
+        let node = node
+            .attr_opt(USE, if self.required { Some(REQUIRED) } else { None })
+            .attr_opt(DEFAULT, self.default.as_ref())
+            .attr_opt(FIXED, self.fixed.as_ref())
+            .annotation(xsd, &self.annotation);
+        match &self.simple_type {
+            Some(st) => node.child(st.to_xml_anonymous(xsd)),
+            None => node,
+        }
+    }
+
     fn parse_defined_by(node: &exile::Element) -> Result<DefinedBy> {
         if let Some(ref_) = node.attributes.map().get(REF) {
             Ok(DefinedBy::Ref(ref_.clone()))
@@ -59,18 +92,59 @@ impl Attribute {
         }
     }
 
-    fn parse_annotation(
+    fn parse_children(
         node: &exile::Element,
+        name: &str,
         lineage: Lineage,
         xsd: &Xsd,
-    ) -> Result<Option<Annotation>> {
+    ) -> Result<(Option<Annotation>, Option<SimpleType>)> {
+        let mut annotation = None;
+        let mut simple_type = None;
         for child in node.children() {
             let t = child.name.as_str();
             match t {
-                ANNOTATION => return Ok(Some(Annotation::from_xml(node, lineage, xsd)?)),
-                _ => return raise!("unexpected node '{}'", t),
+                ANNOTATION => annotation = Some(Annotation::from_xml(child, lineage.clone(), xsd)?),
+                SIMPLE_TYPE => {
+                    let st_name = format!("{}-type", name);
+                    simple_type = Some(SimpleType::from_xml_anonymous(
+                        child,
+                        lineage.clone(),
+                        xsd,
+                        st_name,
+                    )?)
+                }
+                _ => return raise_at!(xsd, child, "unexpected node '{}'", t),
             }
         }
-        Ok(None)
+        Ok((annotation, simple_type))
     }
 }
+
+#[test]
+fn parse_inline_simple_type() {
+    let xml_str = r#"
+    <xs:attribute name="space">
+        <xs:annotation>
+            <xs:documentation>whitespace handling</xs:documentation>
+        </xs:annotation>
+        <xs:simpleType>
+            <xs:restriction base="xs:NCName">
+                <xs:enumeration value="default"/>
+                <xs:enumeration value="preserve"/>
+            </xs:restriction>
+        </xs:simpleType>
+    </xs:attribute>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let a = Attribute::from_xml(
+        doc.root(),
+        Lineage::Index(3),
+        &Xsd::fragment("xs", doc.root()),
+    )
+    .unwrap();
+    assert_eq!(a.name, "space");
+    assert_eq!(a.documentation(), "whitespace handling");
+    assert_eq!(a.defined_by, DefinedBy::Type("space-type".to_owned()));
+    let st = a.simple_type.unwrap();
+    assert_eq!(st.name, "space-type");
+    assert_eq!(st.id.to_string(), "attribute:space/simpleType:space-type");
+}
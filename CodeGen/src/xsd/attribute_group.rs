@@ -3,6 +3,7 @@ use crate::xsd::annotation::Annotation;
 use crate::xsd::attributes::{add_attributes_from_xml, Attributes};
 use crate::xsd::constants::{ANNOTATION, ATTRIBUTE_GROUP, NAME, REF};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::{name_attribute, ref_attribute, Xsd};
 
 #[derive(Clone, Debug)]
@@ -44,7 +45,7 @@ impl AttributeGroup {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<AttributeGroup> {
         check!(ATTRIBUTE_GROUP, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         if let Some(_ref_) = node.attributes.map().get(REF) {
             Ok(AttributeGroup::Ref(AttributeGroupRef::from_xml(
                 node, lineage, xsd,
@@ -56,6 +57,13 @@ impl AttributeGroup {
         }
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        match self {
+            AttributeGroup::Def(x) => x.to_xml(xsd),
+            AttributeGroup::Ref(x) => x.to_xml(xsd),
+        }
+    }
+
     pub fn is_ref(&self) -> bool {
         match self {
             AttributeGroup::Def(_) => false,
@@ -91,6 +99,13 @@ impl AttributeGroupDef {
         })
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, ATTRIBUTE_GROUP)
+            .attr(NAME, &self.name)
+            .annotation(xsd, &self.annotation)
+            .children(self.attributes.iter().map(|a| a.to_xml(xsd)))
+    }
+
     fn parse_annotation(
         node: &exile::Element,
         lineage: Lineage,
@@ -124,6 +139,12 @@ impl AttributeGroupRef {
         })
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, ATTRIBUTE_GROUP)
+            .attr(REF, &self.ref_)
+            .annotation(xsd, &self.annotation)
+    }
+
     fn parse_annotation(
         node: &exile::Element,
         lineage: Lineage,
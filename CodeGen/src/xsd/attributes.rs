@@ -1,8 +1,10 @@
 use crate::error::Result;
+use crate::xsd::write::Node;
 
+use crate::xsd::any::AnyAttribute;
 use crate::xsd::attribute::Attribute;
 use crate::xsd::attribute_group::AttributeGroup;
-use crate::xsd::constants::{ANNOTATION, ATTRIBUTE, ATTRIBUTE_GROUP};
+use crate::xsd::constants::{ANNOTATION, ANY_ATTRIBUTE, ATTRIBUTE, ATTRIBUTE_GROUP};
 use crate::xsd::id::Lineage;
 use crate::xsd::Xsd;
 
@@ -12,6 +14,7 @@ pub type Attributes = Vec<AttributeItem>;
 pub enum AttributeItem {
     AttributeGroup(AttributeGroup),
     Attribute(Attribute),
+    AnyAttribute(AnyAttribute),
 }
 
 impl AttributeItem {
@@ -22,13 +25,25 @@ impl AttributeItem {
             ATTRIBUTE_GROUP => Ok(Self::AttributeGroup(AttributeGroup::from_xml(
                 node, lineage, xsd,
             )?)),
-            _ => return raise!("unexpected node '{}'", t),
+            ANY_ATTRIBUTE => Ok(Self::AnyAttribute(AnyAttribute::from_xml(
+                node, lineage, xsd,
+            )?)),
+            _ => raise_at!(xsd, node, "unexpected node '{}'", t),
+        }
+    }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        match self {
+            AttributeItem::AttributeGroup(x) => x.to_xml(xsd),
+            AttributeItem::Attribute(x) => x.to_xml(xsd),
+            AttributeItem::AnyAttribute(x) => x.to_xml(xsd),
         }
     }
 }
 
 /// Ignores `parent` but parses each of its children into an AttributeItem. Ignores `annotation`
-/// and returns an error if anything else other then `attribute` or `attributeGroup` is found.
+/// and returns an error if anything else other then `attribute`, `attributeGroup` or
+/// `anyAttribute` is found.
 pub fn add_attributes_from_xml(
     parent: &exile::Element,
     lineage: Lineage,
@@ -51,10 +66,12 @@ fn parse_attribute_group() {
     let xml_str = r#"<xs:attributeGroup ref="document-attributes"/>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let result = AttributeItem::from_xml(&xml, Lineage::Parent(parent), &Xsd::new("xs")).unwrap();
+    let result =
+        AttributeItem::from_xml(xml, Lineage::Parent(parent), &Xsd::fragment("xs", xml)).unwrap();
     match &result {
         AttributeItem::AttributeGroup(x) => assert!(x.is_ref()),
         AttributeItem::Attribute(_) => panic!("expected AttributeGroup got Attribute"),
+        AttributeItem::AnyAttribute(_) => panic!("expected AttributeGroup got AnyAttribute"),
     }
 }
 
@@ -64,10 +81,12 @@ fn parse_attribute() {
     let xml_str = r#"<xs:attribute name="accelerate" type="yes-no"/>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let result = AttributeItem::from_xml(&xml, Lineage::Parent(parent), &Xsd::new("xs")).unwrap();
+    let result =
+        AttributeItem::from_xml(xml, Lineage::Parent(parent), &Xsd::fragment("xs", xml)).unwrap();
     match &result {
         AttributeItem::AttributeGroup(_) => panic!("expected AttributeGroup got Attribute"),
         AttributeItem::Attribute(a) => assert_eq!(a.name, "accelerate"),
+        AttributeItem::AnyAttribute(_) => panic!("expected Attribute got AnyAttribute"),
     }
 }
 
@@ -86,14 +105,17 @@ fn attributes() {
 	</xs:attributeGroup>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let result = add_attributes_from_xml(&xml, Lineage::Parent(parent), &Xsd::new("xs")).unwrap();
+    let result =
+        add_attributes_from_xml(xml, Lineage::Parent(parent), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(result.len(), 4);
     match result.get(0).unwrap() {
         AttributeItem::AttributeGroup(ag) => assert!(ag.is_ref()),
         AttributeItem::Attribute(_) => panic!("wrong variant"),
+        AttributeItem::AnyAttribute(_) => panic!("wrong variant"),
     }
     match result.get(1).unwrap() {
         AttributeItem::AttributeGroup(_) => panic!("wrong variant"),
         AttributeItem::Attribute(a) => assert!(a.defined_by.is_type()),
+        AttributeItem::AnyAttribute(_) => panic!("wrong variant"),
     }
 }
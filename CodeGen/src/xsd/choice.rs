@@ -1,21 +1,26 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
-use crate::xsd::constants::{ANNOTATION, CHOICE, ELEMENT, GROUP, NAME, SEQUENCE};
+use crate::xsd::any::Any;
+use crate::xsd::constants::{ANNOTATION, ANY, CHOICE, ELEMENT, GROUP, NAME, SEQUENCE};
 use crate::xsd::element::Element;
 use crate::xsd::group::Group;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::sequence::Sequence;
-use crate::xsd::Xsd;
+use crate::xsd::write::Node;
+use crate::xsd::{Occurs, Xsd};
 
 #[derive(Clone, Debug)]
 pub struct Choice {
     pub id: Id,
     pub annotation: Option<Annotation>,
+    pub occurs: Occurs,
     pub choices: Choices,
 }
 
 #[derive(Clone, Debug)]
 pub enum ChoiceItem {
+    Any(Any),
+    Choice(Choice),
     Element(Element),
     Group(Group),
     Sequence(Sequence),
@@ -33,13 +38,19 @@ impl Choice {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(CHOICE, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut annotation = None;
         let mut choices = Choices::new();
         for inner in node.children() {
             let t = inner.name.as_str();
             match t {
                 ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
+                ANY => choices.push(ChoiceItem::Any(Any::from_xml(inner, lineage.clone(), xsd)?)),
+                CHOICE => choices.push(ChoiceItem::Choice(Choice::from_xml(
+                    inner,
+                    lineage.clone(),
+                    xsd,
+                )?)),
                 ELEMENT => choices.push(ChoiceItem::Element(Element::from_xml(
                     inner,
                     lineage.clone(),
@@ -55,15 +66,37 @@ impl Choice {
                     lineage.clone(),
                     xsd,
                 )?)),
-                _ => return raise!("cannot parse '{}', unexpected node '{}'", CHOICE, t),
+                _ => {
+                    return raise_at!(
+                        xsd,
+                        inner,
+                        "cannot parse '{}', unexpected node '{}'",
+                        CHOICE,
+                        t
+                    )
+                }
             }
         }
         Ok(Choice {
             id,
             annotation,
+            occurs: Occurs::from_xml(node)?,
             choices,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, CHOICE)
+            .occurs(&self.occurs)
+            .annotation(xsd, &self.annotation)
+            .children(self.choices.iter().map(|item| match item {
+                ChoiceItem::Any(x) => x.to_xml(xsd),
+                ChoiceItem::Choice(x) => x.to_xml(xsd),
+                ChoiceItem::Element(x) => x.to_xml(xsd),
+                ChoiceItem::Group(x) => x.to_xml(xsd),
+                ChoiceItem::Sequence(x) => x.to_xml(xsd),
+            }))
+    }
 }
 /*
    <xs:choice>
@@ -109,11 +142,8 @@ fn parse_credit() {
     </xs:choice>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let ch = Choice::from_xml(&xml, Lineage::Parent(parent), &Xsd::new("xs")).unwrap();
-    assert_eq!(
-        format!("{}", ch.id),
-        "element:foo:choice:863778347360799337"
-    );
+    let ch = Choice::from_xml(xml, Lineage::Parent(parent), &Xsd::fragment("xs", xml)).unwrap();
+    assert_eq!(format!("{}", ch.id), "element:foo/choice[0]");
     assert_eq!(ch.documentation().as_str(), "");
     assert_eq!(ch.choices.len(), 2);
     let ele = if let ChoiceItem::Element(el) = ch.choices.get(0).unwrap() {
@@ -130,3 +160,19 @@ fn parse_credit() {
     assert_eq!(seq.occurs.min_occurs, 1);
     assert_eq!(seq.occurs.max_occurs, Some(1));
 }
+
+#[test]
+fn parse_occurs() {
+    let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::Element, "foo".into());
+    let xml_str = r#"
+    <xs:choice minOccurs="0" maxOccurs="unbounded">
+        <xs:element name="p" type="empty"/>
+        <xs:element name="f" type="empty"/>
+    </xs:choice>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xml = doc.root();
+    let ch = Choice::from_xml(xml, Lineage::Parent(parent), &Xsd::fragment("xs", xml)).unwrap();
+    assert_eq!(ch.occurs.min_occurs, 0);
+    assert!(ch.occurs.max_occurs.is_none());
+    assert_eq!(ch.choices.len(), 2);
+}
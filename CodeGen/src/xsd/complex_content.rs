@@ -3,6 +3,7 @@ use crate::xsd::annotation::Annotation;
 use crate::xsd::constants::{ANNOTATION, COMPLEX_CONTENT, EXTENSION, NAME};
 use crate::xsd::extension::Extension;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::Xsd;
 
 #[derive(Clone, Debug)]
@@ -22,7 +23,7 @@ impl ComplexContent {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(COMPLEX_CONTENT, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut annotation = None;
         let mut extension = None;
         for inner in node.children() {
@@ -32,7 +33,7 @@ impl ComplexContent {
                 EXTENSION => {
                     extension = Some(Extension::from_xml(inner, lineage.clone(), xsd)?);
                 }
-                _ => return raise!("unsupported simpleContent node '{}'", t),
+                _ => return raise_at!(xsd, inner, "unsupported simpleContent node '{}'", t),
             }
         }
         let extension = if let Some(ext) = extension {
@@ -46,6 +47,12 @@ impl ComplexContent {
             extension,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, COMPLEX_CONTENT)
+            .annotation(xsd, &self.annotation)
+            .child(self.extension.to_xml(xsd))
+    }
 }
 
 #[test]
@@ -63,9 +70,9 @@ fn parse() {
 		</xs:complexContent>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:complexContent:10793997753492324063".to_owned();
+    let want_id = "element:foo/complexContent[0]".to_owned();
     let want_doc = "";
-    let sc = ComplexContent::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let sc = ComplexContent::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = sc.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", sc.id);
@@ -84,5 +91,6 @@ fn parse() {
             assert!(x.required);
         }
         AttributeItem::AttributeGroup(_) => panic!("expected 'Attribute' but got 'AttributeGroup'"),
+        AttributeItem::AnyAttribute(_) => panic!("expected 'Attribute' but got 'AnyAttribute'"),
     }
 }
@@ -1,16 +1,18 @@
 use crate::error::Result;
+use crate::xsd::all::All;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::attributes::{AttributeItem, Attributes};
 use crate::xsd::choice::Choice;
 use crate::xsd::complex_content::ComplexContent;
 use crate::xsd::constants::{
-    ANNOTATION, ATTRIBUTE, ATTRIBUTE_GROUP, CHOICE, COMPLEX_CONTENT, COMPLEX_TYPE, GROUP, NAME,
-    SEQUENCE, SIMPLE_CONTENT,
+    ALL, ANNOTATION, ANY_ATTRIBUTE, ATTRIBUTE, ATTRIBUTE_GROUP, CHOICE, COMPLEX_CONTENT,
+    COMPLEX_TYPE, GROUP, NAME, SEQUENCE, SIMPLE_CONTENT,
 };
 use crate::xsd::group::Group;
 use crate::xsd::id::{Id, Lineage, RootNodeId, RootNodeType};
 use crate::xsd::sequence::Sequence;
 use crate::xsd::simple_content::SimpleContent;
+use crate::xsd::write::Node;
 use crate::xsd::Xsd;
 
 #[derive(Clone, Debug)]
@@ -31,6 +33,7 @@ pub enum Payload {
 
 #[derive(Clone, Debug)]
 pub enum Children {
+    All(All),
     Choice(Choice),
     Group(Group),
     Sequence(Sequence),
@@ -52,14 +55,15 @@ impl ComplexType {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(COMPLEX_TYPE, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut annotation = None;
         let mut payload = Payload::None;
+        let mut has_attributes = false;
         for inner in node.children() {
             let t = inner.name.as_str();
             match t {
                 ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
-                CHOICE | GROUP | SEQUENCE => {
+                ALL | CHOICE | GROUP | SEQUENCE => {
                     payload = Payload::Parent(Parent::from_xml(node, lineage.clone(), xsd)?);
                     break;
                 }
@@ -77,8 +81,24 @@ impl ComplexType {
                         xsd,
                     )?)
                 }
-                ATTRIBUTE | ATTRIBUTE_GROUP => { /* will be parsed by Parent::from_xml() */ }
-                _ => return raise!("unexpected node '{}' while parsing complexType", t),
+                ANY_ATTRIBUTE | ATTRIBUTE | ATTRIBUTE_GROUP => {
+                    // will be parsed by Parent::from_xml()
+                    has_attributes = true;
+                }
+                _ => {
+                    return raise_at!(
+                        xsd,
+                        inner,
+                        "unexpected node '{}' while parsing complexType",
+                        t
+                    )
+                }
+            }
+        }
+        // a complexType with attributes but no child elements, e.g. `empty-placement`
+        if has_attributes {
+            if let Payload::None = payload {
+                payload = Payload::Parent(Parent::from_xml(node, lineage.clone(), xsd)?);
             }
         }
         let name = if let Some(n) = node.attributes.map().get(NAME) {
@@ -93,6 +113,29 @@ impl ComplexType {
             payload,
         })
     }
+
+    /// Writes the `xs:complexType`, an anonymous one, i.e. one that is defined in an element, has
+    /// an empty `name`.
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let node = Node::new(xsd, COMPLEX_TYPE)
+            .attr_opt(NAME, Some(&self.name).filter(|n| !n.is_empty()))
+            .annotation(xsd, &self.annotation);
+        match &self.payload {
+            Payload::ComplexContent(x) => node.child(x.to_xml(xsd)),
+            Payload::SimpleContent(x) => node.child(x.to_xml(xsd)),
+            Payload::Parent(parent) => {
+                let node = match &parent.children {
+                    None => node,
+                    Some(Children::All(x)) => node.child(x.to_xml(xsd)),
+                    Some(Children::Choice(x)) => node.child(x.to_xml(xsd)),
+                    Some(Children::Group(x)) => node.child(x.to_xml(xsd)),
+                    Some(Children::Sequence(x)) => node.child(x.to_xml(xsd)),
+                };
+                node.children(parent.attributes.iter().map(|a| a.to_xml(xsd)))
+            }
+            Payload::None => node,
+        }
+    }
 }
 
 impl Parent {
@@ -104,6 +147,10 @@ impl Parent {
         for inner in node.children() {
             let t = inner.name.as_str();
             match t {
+                ALL => {
+                    parent.children =
+                        Some(Children::All(All::from_xml(inner, lineage.clone(), xsd)?));
+                }
                 CHOICE => {
                     parent.children = Some(Children::Choice(Choice::from_xml(
                         inner,
@@ -125,13 +172,18 @@ impl Parent {
                         xsd,
                     )?));
                 }
-                ATTRIBUTE | ATTRIBUTE_GROUP => {
-                    parent
-                        .attributes
-                        .push(AttributeItem::from_xml(inner, lineage.clone(), xsd)?)
-                }
+                ANY_ATTRIBUTE | ATTRIBUTE | ATTRIBUTE_GROUP => parent
+                    .attributes
+                    .push(AttributeItem::from_xml(inner, lineage.clone(), xsd)?),
                 ANNOTATION => { /* ignore because it's parsed by ComplexType::from_xml */ }
-                _ => return raise!("unable to parse complexType, unexpected node '{}'", t),
+                _ => {
+                    return raise_at!(
+                        xsd,
+                        inner,
+                        "unable to parse complexType, unexpected node '{}'",
+                        t
+                    )
+                }
             }
         }
         Ok(parent)
@@ -164,8 +216,9 @@ fn parse_parent_sequence() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ct = ComplexType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
-    assert_eq!(format!("{}", ct.id), "complexType:14524941899531583637");
+    let ct =
+        ComplexType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
+    assert_eq!(format!("{}", ct.id), "complexType[0]");
     assert_eq!(ct.documentation().as_str(), "");
     let parent = if let Payload::Parent(p) = ct.payload {
         p
@@ -181,11 +234,13 @@ fn parse_parent_sequence() {
             }
         },
         AttributeItem::Attribute(_) => panic!("expected AttributeGroup got Attribute"),
+        AttributeItem::AnyAttribute(_) => panic!("expected AttributeGroup got AnyAttribute"),
     }
     match parent.children.unwrap() {
         Children::Choice(_) => panic!("want Sequence got Choice"),
         Children::Group(_) => panic!("want Sequence got Group"),
         Children::Sequence(_) => {}
+        Children::All(_) => panic!("want Sequence got All"),
     }
 }
 
@@ -201,7 +256,8 @@ fn parse_parent_group() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ct = ComplexType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ct =
+        ComplexType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(format!("{}", ct.id), "complexType:system-margins");
     assert_eq!(
         ct.documentation().as_str(),
@@ -217,6 +273,7 @@ fn parse_parent_group() {
         Children::Choice(_) => panic!("want Group got Choice"),
         Children::Group(_) => {}
         Children::Sequence(_) => panic!("want Group got Sequence"),
+        Children::All(_) => panic!("want Group got All"),
     }
 }
 
@@ -241,7 +298,8 @@ fn parse_parent_choice() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ct = ComplexType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ct =
+        ComplexType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(format!("{}", ct.id), "complexType:arrow");
     assert_eq!(
         ct.documentation().as_str(),
@@ -261,6 +319,7 @@ fn parse_parent_choice() {
             }
         },
         AttributeItem::Attribute(_) => panic!("expected AttributeGroup got Attribute"),
+        AttributeItem::AnyAttribute(_) => panic!("expected AttributeGroup got AnyAttribute"),
     }
     match parent.attributes.get(1).unwrap() {
         AttributeItem::AttributeGroup(ag) => match ag {
@@ -270,11 +329,13 @@ fn parse_parent_choice() {
             }
         },
         AttributeItem::Attribute(_) => panic!("expected AttributeGroup got Attribute"),
+        AttributeItem::AnyAttribute(_) => panic!("expected AttributeGroup got AnyAttribute"),
     }
     match parent.children.unwrap() {
         Children::Choice(_) => {}
         Children::Group(_) => panic!("want Choice got Group"),
         Children::Sequence(_) => panic!("want Choice got Sequence"),
+        Children::All(_) => panic!("want Choice got All"),
     }
 }
 
@@ -294,7 +355,8 @@ fn parse_complex_content() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ct = ComplexType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ct =
+        ComplexType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(format!("{}", ct.id), "complexType:heel-toe");
     assert_eq!(
         ct.documentation().as_str(),
@@ -313,6 +375,7 @@ fn parse_complex_content() {
             assert_eq!(a.defined_by.value(), "yes-no");
         }
         AttributeItem::AttributeGroup(_) => panic!("expected Attribute got AttributeGroup"),
+        AttributeItem::AnyAttribute(_) => panic!("expected Attribute got AnyAttribute"),
     }
 }
 
@@ -333,7 +396,8 @@ fn parse_simple_content() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ct = ComplexType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ct =
+        ComplexType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(format!("{}", ct.id), "complexType:hole-closed");
     assert_eq!(
         ct.documentation().as_str(),
@@ -355,5 +419,29 @@ fn parse_simple_content() {
             assert!(a.defined_by.is_type());
             assert_eq!(a.defined_by.value(), "hole-closed-location");
         }
+        AttributeItem::AnyAttribute(_) => panic!("expected Attribute got AnyAttribute"),
     }
 }
+
+#[test]
+fn parse_attributes_only() {
+    let xml_str = r#"
+	<xs:complexType name="empty-placement">
+		<xs:annotation>
+			<xs:documentation>The empty-placement type represents an empty element.</xs:documentation>
+		</xs:annotation>
+		<xs:attributeGroup ref="print-style"/>
+		<xs:attributeGroup ref="placement"/>
+	</xs:complexType>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xml = doc.root();
+    let ct = ComplexType::from_xml(xml, Lineage::Index(6), &Xsd::fragment("xs", xml)).unwrap();
+    assert_eq!(format!("{}", ct.id), "complexType:empty-placement");
+    let parent = if let Payload::Parent(p) = ct.payload {
+        p
+    } else {
+        panic!("wrong payload type");
+    };
+    assert_eq!(parent.attributes.len(), 2);
+    assert!(parent.children.is_none());
+}
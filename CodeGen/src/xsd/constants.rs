@@ -1,8 +1,12 @@
+pub const ALL: &str = "all";
 pub const ANNOTATION: &str = "annotation";
+pub const ANY: &str = "any";
+pub const ANY_ATTRIBUTE: &str = "anyAttribute";
 pub const APP_INFO: &str = "appinfo";
 pub const ATTRIBUTE: &str = "attribute";
 pub const ATTRIBUTE_GROUP: &str = "attributeGroup";
 pub const BASE: &str = "base";
+pub const BLOCK: &str = "block";
 pub const BYTE: &str = "byte";
 pub const CHOICE: &str = "choice";
 pub const COMPLEX_CONTENT: &str = "complexContent";
@@ -16,7 +20,9 @@ pub const DURATION: &str = "duration";
 pub const ELEMENT: &str = "element";
 pub const ENUMERATION: &str = "enumeration";
 pub const EXTENSION: &str = "extension";
+pub const FINAL: &str = "final";
 pub const FIXED: &str = "fixed";
+pub const FRACTION_DIGITS: &str = "fractionDigits";
 pub const G_DAY: &str = "gDay";
 pub const G_MONTH: &str = "gMonth";
 pub const G_MONTH_DAY: &str = "gMonthDay";
@@ -28,6 +34,7 @@ pub const IDREF: &str = "IDREF";
 pub const IMPORT: &str = "import";
 pub const INT: &str = "int";
 pub const INTEGER: &str = "integer";
+pub const ITEM_TYPE: &str = "itemType";
 pub const LANGUAGE: &str = "language";
 pub const LENGTH: &str = "length";
 pub const LIST: &str = "list";
@@ -50,17 +57,21 @@ pub const NON_POSITIVE_INTEGER: &str = "nonPositiveInteger";
 pub const NORMALIZED_STRING: &str = "normalizedString";
 pub const PATTERN: &str = "pattern";
 pub const POSITIVE_INTEGER: &str = "positiveInteger";
+pub const PROCESS_CONTENTS: &str = "processContents";
 pub const REF: &str = "ref";
 pub const REQUIRED: &str = "required";
 pub const RESTRICTION: &str = "restriction";
+pub const SCHEMA: &str = "schema";
 pub const SCHEMA_LOCATION: &str = "schemaLocation";
 pub const SEQUENCE: &str = "sequence";
 pub const SHORT: &str = "short";
 pub const SIMPLE_CONTENT: &str = "simpleContent";
 pub const SIMPLE_TYPE: &str = "simpleType";
 pub const STRING: &str = "string";
+pub const TARGET_NAMESPACE: &str = "targetNamespace";
 pub const TIME: &str = "time";
 pub const TOKEN: &str = "token";
+pub const TOTAL_DIGITS: &str = "totalDigits";
 pub const TYPE: &str = "type";
 pub const UNBOUNDED: &str = "unbounded";
 pub const UNION: &str = "union";
@@ -70,3 +81,4 @@ pub const UNSIGNED_LONG: &str = "unsignedLong";
 pub const UNSIGNED_SHORT: &str = "unsignedShort";
 pub const USE: &str = "use";
 pub const VALUE: &str = "value";
+pub const WHITE_SPACE: &str = "whiteSpace";
@@ -0,0 +1,627 @@
+use crate::error::Result;
+use crate::xsd::all::All;
+use crate::xsd::any::{Any, Namespace, ProcessContents};
+use crate::xsd::attribute::Attribute;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::attributes::{AttributeItem, Attributes};
+use crate::xsd::choice::{Choice, ChoiceItem};
+use crate::xsd::complex_type::{Children, ComplexType, Payload};
+use crate::xsd::element::Element;
+use crate::xsd::group::{self, Group};
+use crate::xsd::id::RootNodeType;
+use crate::xsd::restriction::FacetType;
+use crate::xsd::sequence::{self, Sequence};
+use crate::xsd::simple_type::{self, SimpleType};
+use crate::xsd::{simple_content, Entry, Occurs, Xsd};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// The differences between two schemas, e.g. MusicXML 3.0 and 3.1. Top-level entries are matched
+/// by their node type and name. Use `Display` for a human-readable report and `to_json` for a
+/// machine-readable one.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct SchemaDiff {
+    pub changes: Vec<EntryChange>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    fn symbol(&self) -> char {
+        match self {
+            ChangeKind::Added => '+',
+            ChangeKind::Removed => '-',
+            ChangeKind::Changed => '~',
+        }
+    }
+}
+
+/// A top-level entry that was added, removed or changed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct EntryChange {
+    pub kind: ChangeKind,
+    /// The node type of the entry, e.g. `simpleType`.
+    pub entry_type: String,
+    pub name: String,
+    /// What changed within the entry. Empty for added and removed entries.
+    pub details: Vec<Detail>,
+}
+
+/// The part of an entry that a `Detail` is about.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aspect {
+    /// The restriction or extension base, the union members or the list item type.
+    Base,
+    Enumeration,
+    /// Any facet other than `enumeration`.
+    Facet,
+    Attribute,
+    /// A child element anywhere in the content model.
+    Element,
+    /// The content model as a whole, e.g. a change to the order or the occurrence of children.
+    ContentModel,
+}
+
+impl Display for Aspect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Aspect::Base => "base",
+            Aspect::Enumeration => "enumeration",
+            Aspect::Facet => "facet",
+            Aspect::Attribute => "attribute",
+            Aspect::Element => "element",
+            Aspect::ContentModel => "content model",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// One change within an entry. `old` is `None` for an addition and `new` is `None` for a removal.
+/// `name` is empty for the `Base` and `ContentModel` aspects.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Detail {
+    pub aspect: Aspect,
+    pub kind: ChangeKind,
+    pub name: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl SchemaDiff {
+    /// Compares the top-level entries of `old` with those of `new`. Annotations and imports are
+    /// ignored.
+    pub fn compare(old: &Xsd, new: &Xsd) -> Self {
+        let old_entries = summarize_entries(old);
+        let new_entries = summarize_entries(new);
+        let mut changes = Vec::new();
+        for (key, old_summary) in &old_entries {
+            let (kind, details) = match new_entries.get(key) {
+                None => (ChangeKind::Removed, Vec::new()),
+                Some(new_summary) => (ChangeKind::Changed, old_summary.compare(new_summary)),
+            };
+            if kind == ChangeKind::Changed && details.is_empty() {
+                continue;
+            }
+            changes.push(EntryChange {
+                kind,
+                entry_type: key.0.to_string(),
+                name: key.1.clone(),
+                details,
+            });
+        }
+        for (type_, name) in new_entries.keys() {
+            if !old_entries.contains_key(&(*type_, name.clone())) {
+                changes.push(EntryChange {
+                    kind: ChangeKind::Added,
+                    entry_type: type_.to_string(),
+                    name: name.clone(),
+                    details: Vec::new(),
+                });
+            }
+        }
+        changes.sort_by(|a, b| {
+            (&a.entry_type, &a.name, a.kind).cmp(&(&b.entry_type, &b.name, b.kind))
+        });
+        SchemaDiff { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.kind == kind).count()
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        wrap!(serde_json::to_string_pretty(self))
+    }
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.count(ChangeKind::Added),
+            self.count(ChangeKind::Removed),
+            self.count(ChangeKind::Changed)
+        )?;
+        for change in &self.changes {
+            writeln!(
+                f,
+                "{} {} '{}'",
+                change.kind.symbol(),
+                change.entry_type,
+                change.name
+            )?;
+            for d in &change.details {
+                write!(f, "    {} {}", d.kind.symbol(), d.aspect)?;
+                if !d.name.is_empty() {
+                    write!(f, " '{}'", d.name)?;
+                }
+                match (&d.old, &d.new) {
+                    (Some(old), Some(new)) => writeln!(f, ": {} -> {}", old, new)?,
+                    (Some(value), None) | (None, Some(value)) => writeln!(f, ": {}", value)?,
+                    (None, None) => writeln!(f)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The parts of an entry that we compare, each reduced to strings.
+#[derive(Clone, Debug, Default)]
+struct Summary {
+    base: Option<String>,
+    enumerations: Vec<String>,
+    facets: BTreeMap<String, String>,
+    attributes: BTreeMap<String, String>,
+    elements: BTreeMap<String, String>,
+    content_model: Option<String>,
+}
+
+impl Summary {
+    fn compare(&self, new: &Summary) -> Vec<Detail> {
+        let mut details = Vec::new();
+        if self.base != new.base {
+            details.push(Detail {
+                aspect: Aspect::Base,
+                kind: ChangeKind::Changed,
+                name: String::new(),
+                old: self.base.clone(),
+                new: new.base.clone(),
+            });
+        }
+        for value in &self.enumerations {
+            if !new.enumerations.contains(value) {
+                details.push(Detail {
+                    aspect: Aspect::Enumeration,
+                    kind: ChangeKind::Removed,
+                    name: value.clone(),
+                    old: None,
+                    new: None,
+                });
+            }
+        }
+        for value in &new.enumerations {
+            if !self.enumerations.contains(value) {
+                details.push(Detail {
+                    aspect: Aspect::Enumeration,
+                    kind: ChangeKind::Added,
+                    name: value.clone(),
+                    old: None,
+                    new: None,
+                });
+            }
+        }
+        compare_maps(Aspect::Facet, &self.facets, &new.facets, &mut details);
+        compare_maps(
+            Aspect::Attribute,
+            &self.attributes,
+            &new.attributes,
+            &mut details,
+        );
+        compare_maps(Aspect::Element, &self.elements, &new.elements, &mut details);
+        if self.content_model != new.content_model {
+            details.push(Detail {
+                aspect: Aspect::ContentModel,
+                kind: ChangeKind::Changed,
+                name: String::new(),
+                old: self.content_model.clone(),
+                new: new.content_model.clone(),
+            });
+        }
+        details
+    }
+}
+
+fn compare_maps(
+    aspect: Aspect,
+    old: &BTreeMap<String, String>,
+    new: &BTreeMap<String, String>,
+    details: &mut Vec<Detail>,
+) {
+    for (name, old_value) in old {
+        match new.get(name) {
+            None => details.push(Detail {
+                aspect,
+                kind: ChangeKind::Removed,
+                name: name.clone(),
+                old: Some(old_value.clone()),
+                new: None,
+            }),
+            Some(new_value) if new_value != old_value => details.push(Detail {
+                aspect,
+                kind: ChangeKind::Changed,
+                name: name.clone(),
+                old: Some(old_value.clone()),
+                new: Some(new_value.clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (name, new_value) in new {
+        if !old.contains_key(name) {
+            details.push(Detail {
+                aspect,
+                kind: ChangeKind::Added,
+                name: name.clone(),
+                old: None,
+                new: Some(new_value.clone()),
+            });
+        }
+    }
+}
+
+fn summarize_entries(xsd: &Xsd) -> BTreeMap<(RootNodeType, String), Summary> {
+    let mut map = BTreeMap::new();
+    for entry in xsd.entries() {
+        let type_ = match entry.id().root_node_type() {
+            None | Some(RootNodeType::Annotation) | Some(RootNodeType::Import) => continue,
+            Some(t) => t,
+        };
+        let mut summary = Summary::default();
+        match entry {
+            Entry::Annotation(_) | Entry::Import(_) => continue,
+            Entry::Attribute(a) => add_attribute(a, &mut summary),
+            Entry::AttributeGroup(AttributeGroup::Def(d)) => {
+                add_attributes(&d.attributes, &mut summary)
+            }
+            Entry::AttributeGroup(AttributeGroup::Ref(r)) => summary.base = Some(r.ref_.clone()),
+            Entry::ComplexType(ct) => add_complex_type(ct, &mut summary),
+            Entry::Element(e) => {
+                let t = element_type(e, &mut summary);
+                summary.base = Some(t);
+            }
+            Entry::Group(g) => {
+                let members: Vec<String> = g
+                    .members
+                    .iter()
+                    .map(|m| match m {
+                        group::Member::Choice(c) => choice(c, &mut summary),
+                        group::Member::Element(e) => element(e, &mut summary),
+                        group::Member::Sequence(s) => sequence(s, &mut summary),
+                    })
+                    .collect();
+                summary.content_model = Some(members.join(", "));
+            }
+            Entry::SimpleType(st) => add_simple_type(st, "", &mut summary),
+        }
+        map.insert((type_, entry.id().local_name().to_owned()), summary);
+    }
+    map
+}
+
+/// Adds the base and facets of a simple type. The facets of an in-line simple type, e.g. a union
+/// member, are prefixed with the name of the member.
+fn add_simple_type(st: &SimpleType, prefix: &str, summary: &mut Summary) {
+    match &st.payload {
+        simple_type::Payload::Restriction(r) => {
+            if prefix.is_empty() {
+                summary.base = Some(r.base.to_string());
+            }
+            for facet in &r.facets {
+                let facet_type = facet.facet_type();
+                if facet_type == FacetType::Enumeration {
+                    summary.enumerations.push(facet.value());
+                    continue;
+                }
+                let key = format!("{}{}", prefix, facet_type);
+                let value = match summary.facets.get(&key) {
+                    // e.g. a restriction can have more than one pattern.
+                    Some(existing) => format!("{} | {}", existing, facet.value()),
+                    None => facet.value(),
+                };
+                summary.facets.insert(key, value);
+            }
+        }
+        simple_type::Payload::List(l) => summary.base = Some(format!("list of {}", l.item_type)),
+        simple_type::Payload::Union(u) => {
+            let members: Vec<String> = u.members.iter().map(|m| m.to_string()).collect();
+            summary.base = Some(format!("union of {}", members.join(" ")));
+            for inline in &u.simple_types {
+                add_simple_type(inline, format!("{} ", inline.name).as_str(), summary);
+            }
+        }
+    }
+}
+
+fn add_complex_type(ct: &ComplexType, summary: &mut Summary) {
+    match &ct.payload {
+        Payload::ComplexContent(cc) => {
+            summary.base = Some(cc.extension.base.clone());
+            add_attributes(&cc.extension.attributes, summary);
+        }
+        Payload::SimpleContent(sc) => match &sc.payload {
+            simple_content::Payload::Extension(ext) => {
+                summary.base = Some(ext.base.clone());
+                add_attributes(&ext.attributes, summary);
+            }
+        },
+        Payload::Parent(p) => {
+            summary.content_model = p.children.as_ref().map(|children| match children {
+                Children::All(a) => all(a, summary),
+                Children::Choice(c) => choice(c, summary),
+                Children::Group(g) => group(g),
+                Children::Sequence(s) => sequence(s, summary),
+            });
+            add_attributes(&p.attributes, summary);
+        }
+        Payload::None => {}
+    }
+}
+
+fn add_attributes(attributes: &Attributes, summary: &mut Summary) {
+    for item in attributes {
+        match item {
+            AttributeItem::Attribute(a) => add_attribute(a, summary),
+            AttributeItem::AttributeGroup(AttributeGroup::Ref(r)) => {
+                summary
+                    .attributes
+                    .insert(format!("attributeGroup {}", r.ref_), "ref".to_owned());
+            }
+            AttributeItem::AttributeGroup(AttributeGroup::Def(d)) => {
+                add_attributes(&d.attributes, summary)
+            }
+            AttributeItem::AnyAttribute(a) => {
+                summary.attributes.insert(
+                    "anyAttribute".to_owned(),
+                    wildcard(&a.namespace, a.process_contents),
+                );
+            }
+        }
+    }
+}
+
+fn add_attribute(a: &Attribute, summary: &mut Summary) {
+    let name = if a.name.is_empty() {
+        a.defined_by.value()
+    } else {
+        a.name.as_str()
+    };
+    let mut description = if a.defined_by.is_ref() {
+        format!("ref={}", a.defined_by.value())
+    } else {
+        format!("type={}", a.defined_by.value())
+    };
+    if a.required {
+        description.push_str(" required");
+    }
+    if let Some(default) = &a.default {
+        description.push_str(format!(" default={}", default).as_str());
+    }
+    if let Some(fixed) = &a.fixed {
+        description.push_str(format!(" fixed={}", fixed).as_str());
+    }
+    if let Some(st) = &a.simple_type {
+        add_simple_type(st, format!("{} ", name).as_str(), summary);
+    }
+    summary.attributes.insert(name.to_owned(), description);
+}
+
+fn occurs(o: &Occurs) -> String {
+    match (o.min_occurs, o.max_occurs) {
+        (1, Some(1)) => "".to_owned(),
+        (0, Some(1)) => "?".to_owned(),
+        (0, None) => "*".to_owned(),
+        (1, None) => "+".to_owned(),
+        (min, None) => format!("{{{},}}", min),
+        (min, Some(max)) => format!("{{{},{}}}", min, max),
+    }
+}
+
+/// Describes the type of an element. Anonymous complex types are described by their content model.
+fn element_type(e: &Element, summary: &mut Summary) -> String {
+    match e {
+        Element::Reference(r) => r.type_.to_string(),
+        Element::Definition(d) => {
+            let mut inner = Summary::default();
+            add_complex_type(&d.complex_type, &mut inner);
+            // the children of an anonymous type are reported as elements of the enclosing entry.
+            for (name, t) in inner.elements {
+                summary.elements.entry(name).or_insert(t);
+            }
+            match inner.content_model {
+                Some(content) => format!("complexType ({})", content),
+                None => "complexType".to_owned(),
+            }
+        }
+    }
+}
+
+fn element(e: &Element, summary: &mut Summary) -> String {
+    let t = element_type(e, summary);
+    let existing = summary.elements.get(e.name()).cloned();
+    let value = match existing {
+        Some(existing) if existing != t => format!("{} | {}", existing, t),
+        Some(existing) => existing,
+        None => t,
+    };
+    summary.elements.insert(e.name().to_owned(), value);
+    format!("{}{}", e.name(), occurs(e.occurs()))
+}
+
+fn group(g: &Group) -> String {
+    match g {
+        Group::Reference(r) => format!("group({}){}", r.ref_, occurs(&r.occurs)),
+        Group::Definition(d) => format!("group({})", d.id.local_name()),
+    }
+}
+
+fn sequence(s: &Sequence, summary: &mut Summary) -> String {
+    let members: Vec<String> = s
+        .members
+        .iter()
+        .map(|m| match m {
+            sequence::Member::Any(a) => any(a),
+            sequence::Member::Choice(c) => choice(c, summary),
+            sequence::Member::Element(e) => element(e, summary),
+            sequence::Member::Group(g) => group(g),
+            sequence::Member::Sequence(s) => sequence(s, summary),
+        })
+        .collect();
+    format!("({}){}", members.join(", "), occurs(&s.occurs))
+}
+
+fn choice(c: &Choice, summary: &mut Summary) -> String {
+    let members: Vec<String> = c
+        .choices
+        .iter()
+        .map(|item| match item {
+            ChoiceItem::Any(a) => any(a),
+            ChoiceItem::Choice(c) => choice(c, summary),
+            ChoiceItem::Element(e) => element(e, summary),
+            ChoiceItem::Group(g) => group(g),
+            ChoiceItem::Sequence(s) => sequence(s, summary),
+        })
+        .collect();
+    format!("({}){}", members.join(" | "), occurs(&c.occurs))
+}
+
+fn all(a: &All, summary: &mut Summary) -> String {
+    let members: Vec<String> = a.elements.iter().map(|e| element(e, summary)).collect();
+    format!("({}){}", members.join(" & "), occurs(&a.occurs))
+}
+
+fn any(a: &Any) -> String {
+    format!(
+        "any({}){}",
+        wildcard(&a.namespace, a.process_contents),
+        occurs(&a.occurs)
+    )
+}
+
+fn wildcard(namespace: &Namespace, process_contents: ProcessContents) -> String {
+    format!(
+        "namespace={} processContents={}",
+        namespace, process_contents
+    )
+}
+
+#[test]
+fn diff_two_schemas() {
+    let old = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="yes-no">
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="yes"/>
+                <xs:enumeration value="no"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="percent">
+            <xs:restriction base="xs:decimal">
+                <xs:minInclusive value="0"/>
+                <xs:maxInclusive value="100"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:complexType name="empty-print">
+            <xs:sequence>
+                <xs:element name="a" type="xs:string"/>
+            </xs:sequence>
+            <xs:attribute name="print-object" type="yes-no"/>
+        </xs:complexType>
+        <xs:complexType name="gone"/>
+    </xs:schema>"#;
+    let new = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="yes-no">
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="yes"/>
+                <xs:enumeration value="no"/>
+                <xs:enumeration value="maybe"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="percent">
+            <xs:restriction base="xs:decimal">
+                <xs:minInclusive value="0"/>
+                <xs:maxInclusive value="200"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:complexType name="empty-print">
+            <xs:sequence>
+                <xs:element name="a" type="xs:string"/>
+                <xs:element name="b" type="yes-no" minOccurs="0"/>
+            </xs:sequence>
+            <xs:attribute name="print-object" type="yes-no" use="required"/>
+        </xs:complexType>
+        <xs:complexType name="new"/>
+    </xs:schema>"#;
+    let old = Xsd::parse(exile::parse(old).unwrap().root()).unwrap();
+    let new = Xsd::parse(exile::parse(new).unwrap().root()).unwrap();
+    let diff = SchemaDiff::compare(&old, &new);
+    let summary: Vec<(ChangeKind, &str)> = diff
+        .changes
+        .iter()
+        .map(|c| (c.kind, c.name.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (ChangeKind::Changed, "empty-print"),
+            (ChangeKind::Removed, "gone"),
+            (ChangeKind::Added, "new"),
+            (ChangeKind::Changed, "percent"),
+            (ChangeKind::Changed, "yes-no"),
+        ]
+    );
+    let empty_print = &diff.changes[0];
+    let aspects: Vec<(Aspect, ChangeKind, &str)> = empty_print
+        .details
+        .iter()
+        .map(|d| (d.aspect, d.kind, d.name.as_str()))
+        .collect();
+    assert_eq!(
+        aspects,
+        vec![
+            (Aspect::Attribute, ChangeKind::Changed, "print-object"),
+            (Aspect::Element, ChangeKind::Added, "b"),
+            (Aspect::ContentModel, ChangeKind::Changed, ""),
+        ]
+    );
+    assert_eq!(empty_print.details[2].new.as_deref(), Some("(a, b?)"));
+    let percent = &diff.changes[3].details[0];
+    assert_eq!(percent.name, "maxInclusive");
+    assert_eq!(percent.old.as_deref(), Some("100"));
+    assert_eq!(percent.new.as_deref(), Some("200"));
+    let yes_no = &diff.changes[4].details[0];
+    assert_eq!(
+        (yes_no.aspect, yes_no.kind, yes_no.name.as_str()),
+        (Aspect::Enumeration, ChangeKind::Added, "maybe")
+    );
+    let text = diff.to_string();
+    assert!(text.starts_with("1 added, 1 removed, 3 changed\n"));
+    assert!(text.contains("    + enumeration 'maybe'\n"));
+    let json = diff.to_json().unwrap();
+    assert!(json.contains("\"kind\": \"added\""));
+    assert!(json.contains("\"aspect\": \"enumeration\""));
+}
@@ -1,9 +1,10 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::complex_type::ComplexType;
-use crate::xsd::constants::{ANNOTATION, COMPLEX_TYPE, ELEMENT, NAME, TYPE};
+use crate::xsd::constants::{ANNOTATION, BLOCK, COMPLEX_TYPE, ELEMENT, FINAL, NAME, TYPE};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::primitives::{BaseType, PrefixedParse, PrefixedString};
+use crate::xsd::write::{type_name, Node};
 use crate::xsd::{name_attribute, type_attribute, Occurs, Xsd};
 
 #[derive(Clone, Debug)]
@@ -50,8 +51,15 @@ impl Element {
         }
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        match self {
+            Element::Definition(x) => x.to_xml(xsd),
+            Element::Reference(x) => x.to_xml(xsd),
+        }
+    }
+
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Element> {
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         if let Some(_) = node.attributes.map().get(TYPE) {
             Ok(Element::Reference(ElementRef::from_xml(
                 node, lineage, xsd,
@@ -71,6 +79,10 @@ pub struct ElementDef {
     pub name: String,
     pub occurs: Occurs,
     pub complex_type: ComplexType,
+    /// The `block` attribute, e.g. `extension substitution`.
+    pub block: Option<String>,
+    /// The `final` attribute, e.g. `#all`.
+    pub final_: Option<String>,
 }
 
 impl ElementDef {
@@ -93,7 +105,7 @@ impl ElementDef {
                 COMPLEX_TYPE => {
                     complex_type = Some(ComplexType::from_xml(inner, lineage.clone(), xsd)?)
                 }
-                _ => return raise!("unsupported inner type: '{}'", t),
+                _ => return raise_at!(xsd, inner, "unsupported inner type: '{}'", t),
             }
         }
         let complex_type = if let Some(x) = complex_type {
@@ -108,8 +120,20 @@ impl ElementDef {
             name,
             occurs: Occurs::from_xml(node)?,
             complex_type,
+            block: node.attributes.map().get(BLOCK).cloned(),
+            final_: node.attributes.map().get(FINAL).cloned(),
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, ELEMENT)
+            .attr(NAME, &self.name)
+            .occurs(&self.occurs)
+            .attr_opt(BLOCK, self.block.as_ref())
+            .attr_opt(FINAL, self.final_.as_ref())
+            .annotation(xsd, &self.annotation)
+            .child(self.complex_type.to_xml(xsd))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -119,6 +143,10 @@ pub struct ElementRef {
     pub name: String,
     pub type_: BaseType,
     pub occurs: Occurs,
+    /// The `block` attribute, e.g. `extension substitution`.
+    pub block: Option<String>,
+    /// The `final` attribute, e.g. `#all`.
+    pub final_: Option<String>,
 }
 
 impl ElementRef {
@@ -149,8 +177,20 @@ impl ElementRef {
             name,
             type_: BaseType::parse_prefixed(type_attribute(node)?, xsd.prefix.as_str())?,
             occurs: Occurs::from_xml(node)?,
+            block: node.attributes.map().get(BLOCK).cloned(),
+            final_: node.attributes.map().get(FINAL).cloned(),
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, ELEMENT)
+            .attr(NAME, &self.name)
+            .attr(TYPE, type_name(&self.type_, xsd))
+            .occurs(&self.occurs)
+            .attr_opt(BLOCK, self.block.as_ref())
+            .attr_opt(FINAL, self.final_.as_ref())
+            .annotation(xsd, &self.annotation)
+    }
 }
 
 #[test]
@@ -184,7 +224,8 @@ fn parse_score_partwise() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let ele = Element::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ele =
+        Element::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     let got_id = format!("{}", ele.id());
     let want_id = "element:score-partwise";
     assert_eq!(got_id.as_str(), want_id);
@@ -209,6 +250,7 @@ fn parse_score_partwise() {
             Children::Choice(_) => panic!("want Sequence got Choice"),
             Children::Group(_) => panic!("want Sequence got Group"),
             Children::Sequence(_) => {}
+            Children::All(_) => panic!("want Sequence got All"),
         },
         Payload::None => panic!("want Parent got None"),
     }
@@ -221,7 +263,8 @@ fn parse_credit() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 6;
-    let ele = Element::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let ele =
+        Element::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     let got_id = format!("{}", ele.id());
     let want_id = "element:credit";
     assert_eq!(got_id.as_str(), want_id);
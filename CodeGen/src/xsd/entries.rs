@@ -0,0 +1,121 @@
+use crate::error::Result;
+use crate::xsd::id::{Id, RootNodeType};
+use crate::xsd::Entry;
+use std::collections::HashMap;
+
+/// The storage behind `Xsd`. Entries are kept in document order and are indexed both by `Id` and
+/// by `(RootNodeType, name)`, so two entries of the same type cannot have the same name. Removal
+/// leaves an empty slot behind so that the indices of the remaining entries do not change.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Entries {
+    slots: Vec<Option<Entry>>,
+    by_id: HashMap<Id, usize>,
+    by_name: HashMap<(RootNodeType, String), usize>,
+}
+
+impl Entries {
+    pub(crate) fn add(&mut self, entry: Entry) -> Result<()> {
+        let id = entry.id().clone();
+        if self.by_id.contains_key(&id) {
+            return raise!("duplicate entry '{}'", id);
+        }
+        let index = self.slots.len();
+        if let Some(key) = name_key(&id) {
+            if self.by_name.contains_key(&key) {
+                return raise!("duplicate {} named '{}', see '{}'", key.0, key.1, id);
+            }
+            self.by_name.insert(key, index);
+        }
+        self.by_id.insert(id, index);
+        self.slots.push(Some(entry));
+        Ok(())
+    }
+
+    pub(crate) fn get(&self, id: &Id) -> Option<&Entry> {
+        let &index = self.by_id.get(id)?;
+        self.slots.get(index)?.as_ref()
+    }
+
+    pub(crate) fn get_by_name(&self, type_: RootNodeType, name: &str) -> Option<&Entry> {
+        let &index = self.by_name.get(&(type_, name.to_owned()))?;
+        self.slots.get(index)?.as_ref()
+    }
+
+    pub(crate) fn remove(&mut self, id: &Id) -> Option<Entry> {
+        let index = self.by_id.remove(id)?;
+        if let Some(key) = name_key(id) {
+            self.by_name.remove(&key);
+        }
+        self.slots.get_mut(index)?.take()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.slots.iter().filter_map(|slot| slot.as_ref())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.by_id.len()
+    }
+}
+
+fn name_key(id: &Id) -> Option<(RootNodeType, String)> {
+    // an unnamed entry, e.g. `annotation[0]`, is only found by its id.
+    if id.local_name().is_empty() {
+        return None;
+    }
+    Some((id.root_node_type()?, id.local_name().to_owned()))
+}
+
+#[test]
+fn add_find_remove() {
+    use crate::xsd::annotation::Annotation;
+    let annotation = |name: &str, index: u64| {
+        Entry::Annotation(Annotation {
+            id: Id::root_with_index(index, RootNodeType::Annotation, name.to_owned()),
+            items: Vec::new(),
+        })
+    };
+    let mut entries = Entries::default();
+    entries.add(annotation("a", 0)).unwrap();
+    entries.add(annotation("b", 1)).unwrap();
+    entries.add(annotation("c", 2)).unwrap();
+    assert!(entries.add(annotation("b", 3)).is_err());
+    assert_eq!(entries.len(), 3);
+    let b = Id::new(RootNodeType::Annotation, "b".to_owned());
+    assert_eq!(entries.get(&b).unwrap().id(), &b);
+    assert!(entries.get_by_name(RootNodeType::Annotation, "c").is_some());
+    assert!(entries.get_by_name(RootNodeType::Element, "c").is_none());
+    assert!(entries.remove(&b).is_some());
+    assert!(entries.remove(&b).is_none());
+    assert!(entries.get(&b).is_none());
+    assert!(entries.get_by_name(RootNodeType::Annotation, "b").is_none());
+    let names: Vec<String> = entries
+        .iter()
+        .map(|e| e.id().local_name().to_owned())
+        .collect();
+    assert_eq!(names, vec!["a".to_owned(), "c".to_owned()]);
+    assert_eq!(entries.len(), 2);
+}
+
+#[test]
+fn duplicate_names() {
+    use crate::xsd::annotation::Annotation;
+    let annotation = |id: &str| {
+        Entry::Annotation(Annotation {
+            id: id.parse().unwrap(),
+            items: Vec::new(),
+        })
+    };
+    let mut entries = Entries::default();
+    entries.add(annotation("annotation:a")).unwrap();
+    // the Ids differ, but the second cannot be found by name.
+    assert!(entries.add(annotation("annotation:a[1]")).is_err());
+    assert_eq!(entries.len(), 1);
+    let a: Id = "annotation:a".parse().unwrap();
+    assert!(entries.remove(&a).is_some());
+    assert!(entries.get_by_name(RootNodeType::Annotation, "a").is_none());
+    // once the name is free it can be used again, and the entry can be found by it.
+    entries.add(annotation("annotation:a[1]")).unwrap();
+    let found = entries.get_by_name(RootNodeType::Annotation, "a").unwrap();
+    assert_eq!(found.id().to_string(), "annotation:a[1]");
+}
@@ -1,8 +1,11 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::attributes::{AttributeItem, Attributes};
-use crate::xsd::constants::{ANNOTATION, ATTRIBUTE, ATTRIBUTE_GROUP, EXTENSION, NAME};
+use crate::xsd::constants::{
+    ANNOTATION, ANY_ATTRIBUTE, ATTRIBUTE, ATTRIBUTE_GROUP, BASE, EXTENSION, NAME,
+};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::{base_attribute, Xsd};
 
 #[derive(Clone, Debug)]
@@ -23,7 +26,7 @@ impl Extension {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(EXTENSION, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let base = base_attribute(node)?;
         let mut annotation = None;
         let mut attributes = Attributes::new();
@@ -31,10 +34,10 @@ impl Extension {
             let t = inner.name.as_str();
             match t {
                 ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
-                ATTRIBUTE | ATTRIBUTE_GROUP => {
+                ANY_ATTRIBUTE | ATTRIBUTE | ATTRIBUTE_GROUP => {
                     attributes.push(AttributeItem::from_xml(inner, lineage.clone(), xsd)?);
                 }
-                _ => return raise!("unsupported {} member '{}'", EXTENSION, t),
+                _ => return raise_at!(xsd, inner, "unsupported {} member '{}'", EXTENSION, t),
             }
         }
         Ok(Extension {
@@ -44,6 +47,13 @@ impl Extension {
             attributes,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, EXTENSION)
+            .attr(BASE, &self.base)
+            .annotation(xsd, &self.annotation)
+            .children(self.attributes.iter().map(|a| a.to_xml(xsd)))
+    }
 }
 
 #[test]
@@ -60,9 +70,9 @@ fn parse() {
 	</xs:extension>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:extension:2676136846689128820".to_owned();
+    let want_id = "element:foo/extension[0]".to_owned();
     let want_doc = "";
-    let ext = Extension::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let ext = Extension::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = ext.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", ext.id);
@@ -84,6 +94,7 @@ fn parse() {
             assert!(x.required);
         }
         AttributeItem::AttributeGroup(_) => panic!("expected 'Attribute' but got 'AttributeGroup'"),
+        AttributeItem::AnyAttribute(_) => panic!("expected 'Attribute' but got 'AnyAttribute'"),
     }
     let attribute_item = ext.attributes.get(2).unwrap();
     match attribute_item {
@@ -94,5 +105,8 @@ fn parse() {
                 assert_eq!(r.ref_.as_str(), "print-style-align");
             }
         },
+        AttributeItem::AnyAttribute(_) => {
+            panic!("expected 'AttributeGroup' but got 'AnyAttribute'")
+        }
     }
 }
@@ -0,0 +1,398 @@
+use crate::error::Result;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::constants::{
+    ANNOTATION, ATTRIBUTE, ATTRIBUTE_GROUP, BASE, ELEMENT, GROUP, ITEM_TYPE, MAX_OCCURS,
+    MEMBER_TYPES, MIN_OCCURS, NAME, REF, SEQUENCE, SIMPLE_TYPE, TYPE,
+};
+use crate::xsd::id::RootNodeType;
+use crate::xsd::write::{Content, Node};
+use crate::xsd::{Entry, Xsd};
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+/// Writes a self-contained version of a schema for tools that cannot handle `xs:group` or
+/// `xs:attributeGroup`. Every group reference is replaced with the content of the group, every
+/// attribute group reference is replaced with its attributes, and the group definitions are
+/// removed. References to groups in other namespaces, e.g. `xlink`, are left as they are.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Flattener {
+    /// Also replace each attribute `type` that refers to a named `xs:simpleType` with an anonymous
+    /// copy of that type. Named simple types that are no longer referenced are removed. Elements
+    /// keep their `type`, since an element with an anonymous simple type cannot be parsed.
+    pub inline_simple_types: bool,
+}
+
+impl Flattener {
+    pub fn flatten(&self, xsd: &Xsd) -> Result<Node> {
+        let mut schema = xsd.to_xml();
+        let mut content = Vec::new();
+        for item in std::mem::take(&mut schema.content) {
+            match item {
+                // the definitions are no longer needed once every reference has been expanded.
+                Content::Node(n) if is(xsd, &n, GROUP) || is(xsd, &n, ATTRIBUTE_GROUP) => continue,
+                Content::Node(node) => content.extend(
+                    self.expand(xsd, node, &mut Vec::new())?
+                        .into_iter()
+                        .map(Content::Node),
+                ),
+                text => content.push(text),
+            }
+        }
+        schema.content = content;
+        if self.inline_simple_types {
+            remove_unused_simple_types(xsd, &mut schema);
+        }
+        Ok(schema)
+    }
+
+    /// Flattens `node` and its descendants. A group or attribute group reference expands into the
+    /// nodes that replace it. `stack` holds the names of the groups that are being expanded.
+    fn expand(&self, xsd: &Xsd, mut node: Node, stack: &mut Vec<String>) -> Result<Vec<Node>> {
+        if let Some(ref_) = node.attribute(REF).and_then(|r| local_name(xsd, r)) {
+            if is(xsd, &node, GROUP) {
+                let particle = group_particle(xsd, ref_, &node, stack)?;
+                return self.expand_group(xsd, ref_.to_owned(), vec![particle], stack);
+            } else if is(xsd, &node, ATTRIBUTE_GROUP) {
+                let attributes = attribute_group_attributes(xsd, ref_, stack)?;
+                return self.expand_group(xsd, ref_.to_owned(), attributes, stack);
+            }
+        }
+        if self.inline_simple_types && is(xsd, &node, ATTRIBUTE) {
+            inline_simple_type(xsd, &mut node);
+        }
+        let mut content = Vec::new();
+        for item in std::mem::take(&mut node.content) {
+            match item {
+                Content::Node(child) => content.extend(
+                    self.expand(xsd, child, stack)?
+                        .into_iter()
+                        .map(Content::Node),
+                ),
+                text => content.push(text),
+            }
+        }
+        node.content = content;
+        Ok(vec![node])
+    }
+
+    fn expand_group(
+        &self,
+        xsd: &Xsd,
+        name: String,
+        nodes: Vec<Node>,
+        stack: &mut Vec<String>,
+    ) -> Result<Vec<Node>> {
+        stack.push(name);
+        let mut expanded = Vec::new();
+        for node in nodes {
+            expanded.extend(self.expand(xsd, node, stack)?);
+        }
+        stack.pop();
+        Ok(expanded)
+    }
+}
+
+/// Copies the schemas that the schema at `path` imports, at any depth, into `dir`. The flattened
+/// schema keeps its `xs:import` entries, which `Xsd::load_with_imports` resolves by file name, so
+/// a flattened schema that is written to `dir` can be loaded with its imports from `dir`.
+pub fn copy_imports<P1, P2>(path: P1, dir: P2) -> Result<()>
+where
+    P1: AsRef<Path>,
+    P2: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let import_dir = path.parent().unwrap_or(path);
+    let xsd = Xsd::load_with_imports(path, import_dir)?;
+    let mut file_names = BTreeSet::new();
+    collect_imports(&xsd, &mut file_names);
+    for file_name in file_names {
+        let from = import_dir.join(file_name);
+        let to = dir.as_ref().join(file_name);
+        // e.g. the flattened schema is written next to the original.
+        if to.canonicalize().ok() == from.canonicalize().ok() {
+            continue;
+        }
+        wrap!(
+            std::fs::copy(&from, &to),
+            "unable to copy '{}' to '{}'",
+            from.display(),
+            to.display()
+        )?;
+    }
+    Ok(())
+}
+
+/// Adds the file names of the schemas that `xsd` and its imports import.
+fn collect_imports<'a>(xsd: &'a Xsd, file_names: &mut BTreeSet<&'a str>) {
+    for entry in xsd.entries() {
+        if let Entry::Import(import) = entry {
+            file_names.insert(import.file_name());
+        }
+    }
+    for imported in xsd.imports() {
+        collect_imports(imported, file_names);
+    }
+}
+
+fn is(xsd: &Xsd, node: &Node, name: &str) -> bool {
+    match node.name.strip_prefix(xsd.prefix()) {
+        Some(rest) if !xsd.prefix().is_empty() => rest.strip_prefix(':') == Some(name),
+        _ => node.name == name,
+    }
+}
+
+/// The unprefixed name of `qname` if it refers to something in this schema, or `None` if its
+/// prefix refers to another namespace.
+fn local_name<'a>(xsd: &Xsd, qname: &'a str) -> Option<&'a str> {
+    match qname.find(':') {
+        None => Some(qname),
+        Some(i) => {
+            let namespace = xsd.namespace(&qname[..i]);
+            if namespace.is_some() && namespace == xsd.target_namespace() {
+                Some(&qname[i + 1..])
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn check_recursion(stack: &[String], name: &str) -> Result<()> {
+    if stack.iter().any(|s| s == name) {
+        return raise!(
+            "'{}' refers to itself: {} -> {}",
+            name,
+            stack.join(" -> "),
+            name
+        );
+    }
+    Ok(())
+}
+
+/// The sequence, choice or all defined by group `name`, with the occurrence constraints of the
+/// `reference` to it applied.
+fn group_particle(xsd: &Xsd, name: &str, reference: &Node, stack: &[String]) -> Result<Node> {
+    check_recursion(stack, name)?;
+    let def = match xsd.get(RootNodeType::Group, name) {
+        Some(Entry::Group(def)) => def,
+        _ => return raise!("group '{}' not found", name),
+    };
+    let mut particle = def
+        .to_xml(xsd)
+        .content
+        .into_iter()
+        .find_map(|c| match c {
+            Content::Node(n) if !is(xsd, &n, ANNOTATION) => Some(n),
+            _ => None,
+        })
+        .ok_or_else(|| make_err!("group '{}' is empty", name))?;
+    let occurs: Vec<(String, String)> = reference
+        .attributes
+        .iter()
+        .filter(|(k, _)| k == MIN_OCCURS || k == MAX_OCCURS)
+        .cloned()
+        .collect();
+    if occurs.is_empty() {
+        return Ok(particle);
+    }
+    let has_occurs = particle.attribute(MIN_OCCURS).is_some();
+    let has_occurs = has_occurs || particle.attribute(MAX_OCCURS).is_some();
+    if has_occurs || is(xsd, &particle, ELEMENT) {
+        // both have occurrence constraints, so they cannot be combined into one node.
+        let mut sequence = Node::new(xsd, SEQUENCE).child(particle);
+        sequence.attributes = occurs;
+        Ok(sequence)
+    } else {
+        particle.attributes.extend(occurs);
+        Ok(particle)
+    }
+}
+
+/// The attributes, and attribute group references, of attribute group `name`.
+fn attribute_group_attributes(xsd: &Xsd, name: &str, stack: &[String]) -> Result<Vec<Node>> {
+    check_recursion(stack, name)?;
+    let def = match xsd.get(RootNodeType::AttributeGroup, name) {
+        Some(Entry::AttributeGroup(AttributeGroup::Def(def))) => def,
+        _ => return raise!("attributeGroup '{}' not found", name),
+    };
+    Ok(def
+        .to_xml(xsd)
+        .content
+        .into_iter()
+        .filter_map(|c| match c {
+            Content::Node(n) if !is(xsd, &n, ANNOTATION) => Some(n),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Replaces the `type` of an `xs:attribute` with an anonymous copy of the named simple type that it
+/// refers to. Built-in types are left as they are.
+fn inline_simple_type(xsd: &Xsd, node: &mut Node) {
+    let simple_type = match node
+        .attribute(TYPE)
+        .and_then(|t| local_name(xsd, t))
+        .and_then(|t| xsd.get(RootNodeType::SimpleType, t))
+    {
+        Some(Entry::SimpleType(st)) => st,
+        _ => return,
+    };
+    let anonymous = Content::Node(simple_type.to_xml_anonymous(xsd));
+    node.attributes.retain(|(k, _)| k != TYPE);
+    // the annotation, if any, must come first.
+    let i = match node.content.first() {
+        Some(Content::Node(n)) if is(xsd, n, ANNOTATION) => 1,
+        _ => 0,
+    };
+    node.content.insert(i, anonymous);
+}
+
+/// Removes the top-level named simple types of `schema` that nothing refers to, repeating until
+/// none are left since a removed type may have been the only user of another.
+fn remove_unused_simple_types(xsd: &Xsd, schema: &mut Node) {
+    loop {
+        let mut used = HashSet::new();
+        collect_type_references(xsd, schema, &mut used);
+        let before = schema.content.len();
+        schema.content.retain(|c| match c {
+            Content::Node(n) if is(xsd, n, SIMPLE_TYPE) => n
+                .attribute(NAME)
+                .map(|name| used.contains(name))
+                .unwrap_or(true),
+            _ => true,
+        });
+        if schema.content.len() == before {
+            return;
+        }
+    }
+}
+
+fn collect_type_references(xsd: &Xsd, node: &Node, used: &mut HashSet<String>) {
+    for (key, value) in &node.attributes {
+        if key == TYPE || key == BASE || key == ITEM_TYPE || key == MEMBER_TYPES {
+            used.extend(
+                value
+                    .split_whitespace()
+                    .filter_map(|t| local_name(xsd, t))
+                    .map(|t| t.to_owned()),
+            );
+        }
+    }
+    for content in &node.content {
+        if let Content::Node(child) = content {
+            collect_type_references(xsd, child, used);
+        }
+    }
+}
+
+#[cfg(test)]
+const TEST_XSD: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+	<xs:simpleType name="yes-no">
+		<xs:restriction base="xs:token">
+			<xs:enumeration value="yes"/>
+			<xs:enumeration value="no"/>
+		</xs:restriction>
+	</xs:simpleType>
+	<xs:simpleType name="tenths">
+		<xs:restriction base="xs:decimal"/>
+	</xs:simpleType>
+	<xs:attributeGroup name="position">
+		<xs:attribute name="default-x" type="tenths"/>
+	</xs:attributeGroup>
+	<xs:attributeGroup name="print-style">
+		<xs:attributeGroup ref="position"/>
+		<xs:attribute name="color" type="xs:token"/>
+	</xs:attributeGroup>
+	<xs:group name="staff">
+		<xs:sequence>
+			<xs:element name="staff" type="xs:positiveInteger"/>
+		</xs:sequence>
+	</xs:group>
+	<xs:group name="voice">
+		<xs:sequence minOccurs="0">
+			<xs:element name="voice" type="xs:string"/>
+		</xs:sequence>
+	</xs:group>
+	<xs:complexType name="note">
+		<xs:sequence>
+			<xs:group ref="staff" minOccurs="0"/>
+			<xs:group ref="voice" maxOccurs="unbounded"/>
+			<xs:element name="cue" type="yes-no"/>
+		</xs:sequence>
+		<xs:attributeGroup ref="print-style"/>
+	</xs:complexType>
+</xs:schema>"#;
+
+#[test]
+fn flatten_groups() {
+    let xsd = Xsd::parse_str(TEST_XSD).unwrap();
+    let got = Flattener::default().flatten(&xsd).unwrap().to_string();
+    let want = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+	<xs:simpleType name="yes-no">
+		<xs:restriction base="xs:token">
+			<xs:enumeration value="yes"/>
+			<xs:enumeration value="no"/>
+		</xs:restriction>
+	</xs:simpleType>
+	<xs:simpleType name="tenths">
+		<xs:restriction base="xs:decimal"/>
+	</xs:simpleType>
+	<xs:complexType name="note">
+		<xs:sequence>
+			<xs:sequence minOccurs="0">
+				<xs:element name="staff" type="xs:positiveInteger"/>
+			</xs:sequence>
+			<xs:sequence maxOccurs="unbounded">
+				<xs:sequence minOccurs="0">
+					<xs:element name="voice" type="xs:string"/>
+				</xs:sequence>
+			</xs:sequence>
+			<xs:element name="cue" type="yes-no"/>
+		</xs:sequence>
+		<xs:attribute name="default-x" type="tenths"/>
+		<xs:attribute name="color" type="xs:token"/>
+	</xs:complexType>
+</xs:schema>"#;
+    assert_eq!(got, want);
+}
+
+#[test]
+fn flatten_simple_types() {
+    let xsd = Xsd::parse_str(TEST_XSD).unwrap();
+    let flattener = Flattener {
+        inline_simple_types: true,
+    };
+    let got = flattener.flatten(&xsd).unwrap();
+    let reparsed = Xsd::parse_str(got.to_string()).unwrap();
+    // yes-no is still used by an element, tenths was only used by an attribute.
+    assert!(reparsed.get(RootNodeType::SimpleType, "yes-no").is_some());
+    assert!(reparsed.get(RootNodeType::SimpleType, "tenths").is_none());
+    let got = got.to_string();
+    assert!(got.contains(r#"<xs:element name="cue" type="yes-no"/>"#));
+    assert!(got.contains(
+        r#"<xs:attribute name="default-x">
+			<xs:simpleType>
+				<xs:restriction base="xs:decimal"/>"#
+    ));
+}
+
+#[test]
+fn flatten_recursion_err() {
+    let xml = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+	<xs:attributeGroup name="a">
+		<xs:attributeGroup ref="b"/>
+	</xs:attributeGroup>
+	<xs:attributeGroup name="b">
+		<xs:attributeGroup ref="a"/>
+	</xs:attributeGroup>
+	<xs:complexType name="c">
+		<xs:attributeGroup ref="a"/>
+	</xs:complexType>
+</xs:schema>"#;
+    let xsd = Xsd::parse_str(xml).unwrap();
+    let err = Flattener::default().flatten(&xsd).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("'a' refers to itself: a -> b -> a"));
+}
@@ -0,0 +1,482 @@
+use crate::error::Result;
+use crate::xsd::attribute::Attribute;
+use crate::xsd::attribute_group::AttributeGroup;
+use crate::xsd::attributes::{AttributeItem, Attributes};
+use crate::xsd::choice::{Choice, ChoiceItem};
+use crate::xsd::common::DefinedBy;
+use crate::xsd::complex_type::{Children, ComplexType, Payload};
+use crate::xsd::element::Element;
+use crate::xsd::group::{self, Group, GroupDefinition};
+use crate::xsd::id::{Id, RootNodeType};
+use crate::xsd::primitives::{BaseType, PrefixedParse};
+use crate::xsd::sequence::{self, Sequence};
+use crate::xsd::{simple_content, simple_type, Entry, Xsd};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// The way in which one node of the XSD refers to a top-level entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ReferenceKind {
+    /// The `type` of an `xs:element`.
+    ElementType,
+    /// The `type` of an `xs:attribute`.
+    AttributeType,
+    /// The `ref` of an `xs:attribute`, e.g. `ref="xml:lang"`.
+    AttributeRef,
+    /// The `ref` of an `xs:group`.
+    GroupRef,
+    /// The `ref` of an `xs:attributeGroup`.
+    AttributeGroupRef,
+    /// The `base` of an `xs:restriction`.
+    RestrictionBase,
+    /// The `base` of an `xs:extension`.
+    ExtensionBase,
+    /// One of the `memberTypes` of an `xs:union`.
+    UnionMember,
+    /// The `itemType` of an `xs:list`.
+    ListItem,
+}
+
+impl Display for ReferenceKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ReferenceKind::ElementType => "element type",
+            ReferenceKind::AttributeType => "attribute type",
+            ReferenceKind::AttributeRef => "attribute ref",
+            ReferenceKind::GroupRef => "group ref",
+            ReferenceKind::AttributeGroupRef => "attributeGroup ref",
+            ReferenceKind::RestrictionBase => "restriction base",
+            ReferenceKind::ExtensionBase => "extension base",
+            ReferenceKind::UnionMember => "union member",
+            ReferenceKind::ListItem => "list itemType",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A resolved reference from somewhere within the top-level entry `source` to the top-level entry
+/// `target`.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Edge {
+    /// The top-level entry that holds the reference.
+    pub source: Id,
+    /// The node, within `source`, at which the reference is made. This is `source` itself for a
+    /// reference made directly by a top-level entry, e.g. a named `xs:simpleType`'s restriction.
+    pub site: Id,
+    pub kind: ReferenceKind,
+    /// The top-level entry that is referenced. It may belong to an imported schema.
+    pub target: Id,
+}
+
+/// The references between the top-level entries of an `Xsd`, indexed in both directions. Build it
+/// with `Graph::build` (or `Xsd::graph`) to find out, for example, which complex types, attributes
+/// and unions make use of the simple type `yes-no`.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    edges: Vec<Edge>,
+    forward: HashMap<Id, Vec<usize>>,
+    reverse: HashMap<Id, Vec<usize>>,
+}
+
+impl Graph {
+    /// Resolves every reference in `xsd`. References to built-in `xs:` types are not part of the
+    /// graph. Returns an error listing all of the references that could not be resolved.
+    pub fn build(xsd: &Xsd) -> Result<Self> {
+        let mut builder = Builder {
+            xsd,
+            source: None,
+            edges: Vec::new(),
+            dangling: Vec::new(),
+        };
+        for entry in xsd.entries() {
+            builder.source = Some(entry.id().clone());
+            builder.entry(entry);
+        }
+        if !builder.dangling.is_empty() {
+            let mut message = format!(
+                "{} reference(s) could not be resolved",
+                builder.dangling.len()
+            );
+            if xsd.imports().count() == 0 {
+                message.push_str(" (if these are in other namespaces, use Xsd::load_with_imports)");
+            }
+            for d in &builder.dangling {
+                message.push_str(format!("\n  {}", d).as_str());
+            }
+            return raise!("{}", message);
+        }
+        let mut graph = Graph::default();
+        for edge in builder.edges {
+            let index = graph.edges.len();
+            graph
+                .forward
+                .entry(edge.source.clone())
+                .or_default()
+                .push(index);
+            graph
+                .reverse
+                .entry(edge.target.clone())
+                .or_default()
+                .push(index);
+            graph.edges.push(edge);
+        }
+        Ok(graph)
+    }
+
+    /// All of the edges in the order in which the references appear in the XSD document.
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.edges.iter()
+    }
+
+    /// The references made by the top-level entry `id`.
+    pub fn references(&self, id: &Id) -> impl Iterator<Item = &Edge> {
+        self.lookup(&self.forward, id)
+    }
+
+    /// The references made to the top-level entry `id`, i.e. the reverse edges.
+    pub fn referenced_by(&self, id: &Id) -> impl Iterator<Item = &Edge> {
+        self.lookup(&self.reverse, id)
+    }
+
+    /// The distinct top-level entries that refer to the entry `id`, in document order.
+    pub fn users(&self, id: &Id) -> Vec<&Id> {
+        let mut users: Vec<&Id> = Vec::new();
+        for edge in self.referenced_by(id) {
+            if !users.contains(&&edge.source) {
+                users.push(&edge.source);
+            }
+        }
+        users
+    }
+
+    fn lookup<'a>(
+        &'a self,
+        index: &'a HashMap<Id, Vec<usize>>,
+        id: &Id,
+    ) -> impl Iterator<Item = &'a Edge> {
+        index
+            .get(id)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+            .iter()
+            .map(move |&i| &self.edges[i])
+    }
+}
+
+/// A reference that could not be resolved.
+struct Dangling {
+    site: Id,
+    kind: ReferenceKind,
+    name: String,
+}
+
+impl Display for Dangling {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} '{}' not found", self.site, self.kind, self.name)
+    }
+}
+
+struct Builder<'a> {
+    xsd: &'a Xsd,
+    source: Option<Id>,
+    edges: Vec<Edge>,
+    dangling: Vec<Dangling>,
+}
+
+impl<'a> Builder<'a> {
+    fn entry(&mut self, entry: &Entry) {
+        match entry {
+            Entry::Annotation(_) | Entry::Import(_) => {}
+            Entry::Attribute(a) => self.attribute(a),
+            Entry::AttributeGroup(g) => self.attribute_group(g),
+            Entry::ComplexType(ct) => self.complex_type(ct),
+            Entry::Element(e) => self.element(e),
+            Entry::Group(g) => self.group_definition(g),
+            Entry::SimpleType(st) => self.simple_type(st),
+        }
+    }
+
+    fn attribute(&mut self, a: &Attribute) {
+        if let Some(st) = &a.simple_type {
+            // the attribute's type is the in-line simple type, not a top-level entry.
+            self.simple_type(st);
+            return;
+        }
+        match &a.defined_by {
+            DefinedBy::Ref(r) => self.link(
+                &a.id,
+                ReferenceKind::AttributeRef,
+                &[RootNodeType::Attribute],
+                r,
+            ),
+            DefinedBy::Type(t) => self.link_type(
+                &a.id,
+                ReferenceKind::AttributeType,
+                &[RootNodeType::SimpleType],
+                t,
+            ),
+        }
+    }
+
+    fn attributes(&mut self, attributes: &Attributes) {
+        for item in attributes {
+            match item {
+                AttributeItem::Attribute(a) => self.attribute(a),
+                AttributeItem::AttributeGroup(g) => self.attribute_group(g),
+                // wildcards name namespaces, not entries.
+                AttributeItem::AnyAttribute(_) => {}
+            }
+        }
+    }
+
+    fn attribute_group(&mut self, g: &AttributeGroup) {
+        match g {
+            AttributeGroup::Def(d) => self.attributes(&d.attributes),
+            AttributeGroup::Ref(r) => self.link(
+                &r.id,
+                ReferenceKind::AttributeGroupRef,
+                &[RootNodeType::AttributeGroup],
+                &r.ref_,
+            ),
+        }
+    }
+
+    fn complex_type(&mut self, ct: &ComplexType) {
+        match &ct.payload {
+            Payload::ComplexContent(cc) => {
+                self.link(
+                    &cc.extension.id,
+                    ReferenceKind::ExtensionBase,
+                    &[RootNodeType::ComplexType],
+                    &cc.extension.base,
+                );
+                self.attributes(&cc.extension.attributes);
+            }
+            Payload::SimpleContent(sc) => match &sc.payload {
+                simple_content::Payload::Extension(ext) => {
+                    self.link_type(
+                        &ext.id,
+                        ReferenceKind::ExtensionBase,
+                        &[RootNodeType::SimpleType, RootNodeType::ComplexType],
+                        &ext.base,
+                    );
+                    self.attributes(&ext.attributes);
+                }
+            },
+            Payload::Parent(p) => {
+                match &p.children {
+                    None => {}
+                    Some(Children::All(a)) => a.elements.iter().for_each(|e| self.element(e)),
+                    Some(Children::Choice(c)) => self.choice(c),
+                    Some(Children::Group(g)) => self.group(g),
+                    Some(Children::Sequence(s)) => self.sequence(s),
+                }
+                self.attributes(&p.attributes);
+            }
+            Payload::None => {}
+        }
+    }
+
+    fn element(&mut self, e: &Element) {
+        match e {
+            Element::Definition(d) => self.complex_type(&d.complex_type),
+            Element::Reference(r) => self.link_base_type(
+                &r.id,
+                ReferenceKind::ElementType,
+                &[RootNodeType::SimpleType, RootNodeType::ComplexType],
+                &r.type_,
+            ),
+        }
+    }
+
+    fn group(&mut self, g: &Group) {
+        match g {
+            Group::Definition(d) => self.group_definition(d),
+            Group::Reference(r) => self.link(
+                &r.id,
+                ReferenceKind::GroupRef,
+                &[RootNodeType::Group],
+                &r.ref_,
+            ),
+        }
+    }
+
+    fn group_definition(&mut self, g: &GroupDefinition) {
+        for member in &g.members {
+            match member {
+                group::Member::Choice(c) => self.choice(c),
+                group::Member::Element(e) => self.element(e),
+                group::Member::Sequence(s) => self.sequence(s),
+            }
+        }
+    }
+
+    fn sequence(&mut self, s: &Sequence) {
+        for member in &s.members {
+            match member {
+                sequence::Member::Any(_) => {}
+                sequence::Member::Choice(c) => self.choice(c),
+                sequence::Member::Element(e) => self.element(e),
+                sequence::Member::Group(g) => self.group(g),
+                sequence::Member::Sequence(s) => self.sequence(s),
+            }
+        }
+    }
+
+    fn choice(&mut self, c: &Choice) {
+        for item in &c.choices {
+            match item {
+                ChoiceItem::Any(_) => {}
+                ChoiceItem::Choice(c) => self.choice(c),
+                ChoiceItem::Element(e) => self.element(e),
+                ChoiceItem::Group(g) => self.group(g),
+                ChoiceItem::Sequence(s) => self.sequence(s),
+            }
+        }
+    }
+
+    fn simple_type(&mut self, st: &simple_type::SimpleType) {
+        match &st.payload {
+            simple_type::Payload::Restriction(r) => self.link_base_type(
+                &r.id,
+                ReferenceKind::RestrictionBase,
+                &[RootNodeType::SimpleType],
+                &r.base,
+            ),
+            simple_type::Payload::List(l) => self.link_type(
+                &l.id,
+                ReferenceKind::ListItem,
+                &[RootNodeType::SimpleType],
+                &l.item_type,
+            ),
+            simple_type::Payload::Union(u) => {
+                for member in &u.members {
+                    if u.inline_member(member).is_none() {
+                        self.link_base_type(
+                            &u.id,
+                            ReferenceKind::UnionMember,
+                            &[RootNodeType::SimpleType],
+                            member,
+                        );
+                    }
+                }
+                for inline in &u.simple_types {
+                    self.simple_type(inline);
+                }
+            }
+        }
+    }
+
+    /// Links a type name that may be a built-in `xs:` type.
+    fn link_type(&mut self, site: &Id, kind: ReferenceKind, types: &[RootNodeType], name: &str) {
+        match BaseType::parse_prefixed(name, self.xsd.prefix()) {
+            Ok(base_type) => self.link_base_type(site, kind, types, &base_type),
+            Err(_) => self.link(site, kind, types, name),
+        }
+    }
+
+    /// Links a `BaseType`. Built-in types, including those that we do not model, such as
+    /// `xs:anyURI`, are not part of the graph.
+    fn link_base_type(
+        &mut self,
+        site: &Id,
+        kind: ReferenceKind,
+        types: &[RootNodeType],
+        base_type: &BaseType,
+    ) {
+        if let BaseType::Custom(name) = base_type {
+            let builtin_prefix = format!("{}:", self.xsd.prefix());
+            if !name.starts_with(builtin_prefix.as_str()) {
+                self.link(site, kind, types, name);
+            }
+        }
+    }
+
+    /// Adds an edge to the first entry named `qname` that has one of the given `types`, or records
+    /// the reference as dangling.
+    fn link(&mut self, site: &Id, kind: ReferenceKind, types: &[RootNodeType], qname: &str) {
+        let found = types
+            .iter()
+            .find_map(|&t| self.xsd.resolve(t, qname))
+            .map(|entry| entry.id().clone());
+        match found {
+            Some(target) => self.edges.push(Edge {
+                source: self.source.clone().unwrap_or_else(|| site.clone()),
+                site: site.clone(),
+                kind,
+                target,
+            }),
+            None => self.dangling.push(Dangling {
+                site: site.clone(),
+                kind,
+                name: qname.to_owned(),
+            }),
+        }
+    }
+}
+
+#[test]
+fn build_graph() {
+    let xml_str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:simpleType name="yes-no">
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="yes"/>
+                <xs:enumeration value="no"/>
+            </xs:restriction>
+        </xs:simpleType>
+        <xs:simpleType name="yes-no-number">
+            <xs:union memberTypes="yes-no xs:decimal"/>
+        </xs:simpleType>
+        <xs:attributeGroup name="printout">
+            <xs:attribute name="print-object" type="yes-no"/>
+        </xs:attributeGroup>
+        <xs:complexType name="empty-print">
+            <xs:attributeGroup ref="printout"/>
+            <xs:attribute name="parentheses" type="yes-no"/>
+        </xs:complexType>
+    </xs:schema>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::parse(doc.root()).unwrap();
+    let graph = Graph::build(&xsd).unwrap();
+    assert_eq!(graph.edges().count(), 4);
+    let yes_no = xsd.get(RootNodeType::SimpleType, "yes-no").unwrap().id();
+    assert_eq!(graph.referenced_by(yes_no).count(), 3);
+    assert_eq!(graph.references(yes_no).count(), 0);
+    let users: Vec<String> = graph
+        .users(yes_no)
+        .iter()
+        .map(|id| id.local_name().to_owned())
+        .collect();
+    assert_eq!(users, vec!["yes-no-number", "printout", "empty-print"]);
+    let empty_print = xsd.get(RootNodeType::ComplexType, "empty-print").unwrap();
+    let kinds: Vec<ReferenceKind> = graph.references(empty_print.id()).map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ReferenceKind::AttributeGroupRef,
+            ReferenceKind::AttributeType
+        ]
+    );
+}
+
+#[test]
+fn dangling_references() {
+    let xml_str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="fermata">
+            <xs:simpleContent>
+                <xs:extension base="fermata-shape">
+                    <xs:attribute name="type" type="upright-inverted"/>
+                    <xs:attribute name="id" type="xs:ID"/>
+                </xs:extension>
+            </xs:simpleContent>
+        </xs:complexType>
+    </xs:schema>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::parse(doc.root()).unwrap();
+    let message = format!("{}", Graph::build(&xsd).err().unwrap());
+    assert!(message.contains("2 reference(s) could not be resolved"));
+    assert!(message.contains("'fermata-shape'"));
+    assert!(message.contains("'upright-inverted'"));
+}
@@ -1,10 +1,11 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::choice::Choice;
-use crate::xsd::constants::{ANNOTATION, CHOICE, ELEMENT, GROUP, SEQUENCE};
+use crate::xsd::constants::{ANNOTATION, CHOICE, ELEMENT, GROUP, NAME, REF, SEQUENCE};
 use crate::xsd::element::Element;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::sequence::Sequence;
+use crate::xsd::write::Node;
 use crate::xsd::{is_ref, name_attribute, ref_attribute, Occurs, Xsd};
 
 #[derive(Clone, Debug)]
@@ -14,8 +15,15 @@ pub enum Group {
 }
 
 impl Group {
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        match self {
+            Group::Definition(x) => x.to_xml(xsd),
+            Group::Reference(x) => x.to_xml(xsd),
+        }
+    }
+
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         if is_ref(node) {
             Ok(Group::Reference(GroupReference::from_xml(
                 node, lineage, xsd,
@@ -63,7 +71,7 @@ impl GroupDefinition {
         // way funky: The first match arm happens if it is a top-level root entry, otherwise the
         // caller has already created an ID for this and the second match arm is in effect.
         let (id, lineage) = match lineage {
-            Lineage::Index(i) => Id::make(Lineage::Index(i), node)?,
+            Lineage::Index(i) => Id::make(Lineage::Index(i), node, xsd)?,
             Lineage::Parent(p) => (p.clone(), Lineage::Parent(p.clone())),
         };
         let mut annotation = None;
@@ -84,7 +92,7 @@ impl GroupDefinition {
                     let sequence = Sequence::from_xml(inner, lineage.clone(), xsd)?;
                     members.push(Member::Sequence(sequence));
                 }
-                _ => return raise!("unsupported {} node, '{}'", GROUP, t),
+                _ => return raise_at!(xsd, inner, "unsupported {} node, '{}'", GROUP, t),
             }
         }
         Ok(GroupDefinition {
@@ -93,6 +101,18 @@ impl GroupDefinition {
             members,
         })
     }
+
+    /// Writes the `xs:group`, its name is the name of its `Id`.
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, GROUP)
+            .attr_opt(NAME, Some(self.id.local_name()).filter(|n| !n.is_empty()))
+            .annotation(xsd, &self.annotation)
+            .children(self.members.iter().map(|member| match member {
+                Member::Choice(x) => x.to_xml(xsd),
+                Member::Element(x) => x.to_xml(xsd),
+                Member::Sequence(x) => x.to_xml(xsd),
+            }))
+    }
 }
 
 impl GroupReference {
@@ -113,7 +133,7 @@ impl GroupReference {
             let t = inner.name.as_str();
             match t {
                 ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
-                _ => return raise!("unsupported {} node, '{}'", GROUP, t),
+                _ => return raise_at!(xsd, inner, "unsupported {} node, '{}'", GROUP, t),
             }
         }
         let ref_ = ref_attribute(node)?;
@@ -124,6 +144,13 @@ impl GroupReference {
             occurs: Occurs::from_xml(node)?,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, GROUP)
+            .attr(REF, &self.ref_)
+            .occurs(&self.occurs)
+            .annotation(xsd, &self.annotation)
+    }
 }
 
 #[test]
@@ -151,7 +178,7 @@ fn parse_group_definition() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let grp = Group::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let grp = Group::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     let grp = match grp {
         Group::Definition(def) => def,
         Group::Reference(_) => panic!("expected Definition, got Reference"),
@@ -178,7 +205,7 @@ fn parse_group_reference() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let grp = Group::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let grp = Group::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     let grp = match grp {
         Group::Definition(_) => panic!("expected Reference, got Definition"),
         Group::Reference(ref_) => ref_,
@@ -200,7 +227,7 @@ fn parse_group_reference_max_occurs() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let grp = Group::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let grp = Group::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     let grp = match grp {
         Group::Definition(_) => panic!("expected Reference, got Definition"),
         Group::Reference(ref_) => ref_,
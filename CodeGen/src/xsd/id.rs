@@ -1,14 +1,26 @@
 use crate::error::Result;
 use crate::xsd::constants::{
-    ANNOTATION, ATTRIBUTE_GROUP, COMPLEX_TYPE, ELEMENT, GROUP, IMPORT, NAME, REF, SIMPLE_TYPE, TYPE,
+    ANNOTATION, ATTRIBUTE, ATTRIBUTE_GROUP, COMPLEX_TYPE, ELEMENT, GROUP, IMPORT, NAME, REF,
+    SIMPLE_TYPE, TYPE,
 };
+use crate::xsd::source::Location;
+use crate::xsd::Xsd;
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+/// Identifies a node of the XSD document by its path from a top-level entry, e.g.
+/// `complexType:note/sequence[0]/element:pitch`. Each step is the node type followed by either
+/// the node's name, or, for a node that has no name, its position among the siblings of the same
+/// type. A node that has the same type and name as an earlier sibling also has its position among
+/// those siblings, e.g. `choice[0]/element:pitch[1]`. The `Display` and `FromStr` implementations
+/// round-trip, so an `Id` can be given on the command line.
+///
+/// An `Id` only depends on the names of the node and its ancestors and on the position of unnamed
+/// or same-named nodes, it does not change when unrelated content of the document changes.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Id {
     Root(RootNodeId),
     Child(ChildNodeId),
@@ -20,6 +32,8 @@ impl Id {
             index: None,
             type_,
             name,
+            ordinal: None,
+            location: None,
         })
     }
 
@@ -28,6 +42,8 @@ impl Id {
             index: Some(index),
             type_,
             name,
+            ordinal: None,
+            location: None,
         })
     }
 
@@ -43,7 +59,21 @@ impl Id {
             } else {
                 None
             },
+            ordinal: None,
+            parent: Box::new(parent.clone()),
+            location: None,
+        })
+    }
+
+    /// The `Id` of the `ordinal`th unnamed node of type `type_` under `parent`, e.g.
+    /// `complexType:note/sequence[0]`.
+    pub fn anonymous_child<S: AsRef<str>>(parent: &Id, type_: S, ordinal: u64) -> Self {
+        Id::Child(ChildNodeId {
+            type_: type_.as_ref().into(),
+            name: None,
+            ordinal: Some(ordinal),
             parent: Box::new(parent.clone()),
+            location: None,
         })
     }
 
@@ -54,6 +84,15 @@ impl Id {
         }
     }
 
+    /// The name of the node itself, without the names of its ancestors. Empty for a node that has no
+    /// name.
+    pub fn local_name(&self) -> &str {
+        match self {
+            Id::Root(r) => r.name.as_str(),
+            Id::Child(c) => c.name.as_deref().unwrap_or(""),
+        }
+    }
+
     pub fn parent(&self) -> Option<&Id> {
         match self {
             Id::Root(_) => None,
@@ -75,45 +114,77 @@ impl Id {
         }
     }
 
+    /// Where the node was found in the XSD document. This is only known when the `Xsd` was parsed
+    /// from text, see `Xsd::load` and `Xsd::parse_str`. The location is not part of the identity,
+    /// i.e. it is ignored by `PartialEq`, `Hash` and `Ord`.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Id::Root(r) => r.location.as_ref(),
+            Id::Child(c) => c.location.as_ref(),
+        }
+    }
+
     pub fn display(&self) -> String {
         format!("{}", &self)
     }
 
-    pub(crate) fn make(lineage: Lineage, node: &exile::Element) -> Result<(Self, Lineage)> {
-        let attrs = node.attributes.map();
-        let name = if let Some(s) = attrs.get("id") {
-            s.clone()
-        } else if let Some(s) = attrs.get(NAME) {
-            s.clone()
-        } else if let Some(s) = attrs.get(TYPE) {
-            s.clone()
-        } else if let Some(s) = attrs.get(REF) {
-            s.clone()
-        } else {
-            let mut hasher = DefaultHasher::new();
-            node.hash(&mut hasher);
-            let result = hasher.finish();
-            format!("{}", result)
-        };
+    /// The position of a node that has no name among its siblings of the same type, or of a node
+    /// whose name is not unique among its siblings of the same type.
+    pub fn ordinal(&self) -> Option<u64> {
+        match self {
+            Id::Root(r) => r.ordinal,
+            Id::Child(c) => c.ordinal,
+        }
+    }
+
+    /// Creates the `Id` of `node`. A node is named by its `id`, `name`, `type` or `ref` attribute,
+    /// in that order of preference, see `node_name`. A node without any of these is identified by
+    /// its position among its siblings of the same type, and a node with the same name as an
+    /// earlier sibling of the same type by its position among those siblings.
+    ///
+    /// These positions are known for the nodes of the document that `xsd` was parsed from. Any
+    /// other node, e.g. one given to `from_xml` with an `Xsd::new` schema, is assumed to be
+    /// identified after its earlier siblings, so its position is the number of nodes with the
+    /// same parent, type and name that `xsd` has already identified this way.
+    pub(crate) fn make(
+        lineage: Lineage,
+        node: &exile::Element,
+        xsd: &Xsd,
+    ) -> Result<(Self, Lineage)> {
+        let name = node_name(node).cloned();
         let type_ = node.name.as_str();
+        let ordinal = match (&name, xsd.ordinal(node)) {
+            (None, Some(ordinal)) => Some(ordinal),
+            (Some(_), Some(_)) => xsd.name_ordinal(node).filter(|&ordinal| ordinal > 0),
+            (_, None) => {
+                let key = format!(
+                    "{}/{}:{}",
+                    lineage.parent().map(Id::display).unwrap_or_default(),
+                    type_,
+                    name.as_deref().unwrap_or_default()
+                );
+                let ordinal = xsd.unmapped_ordinal(key);
+                Some(ordinal).filter(|&ordinal| name.is_none() || ordinal > 0)
+            }
+        };
         match lineage {
             Lineage::Index(i) => {
                 let id = Id::Root(RootNodeId {
                     index: Some(i),
                     type_: RootNodeType::parse(type_)?,
-                    name: name.into(),
+                    name: name.unwrap_or_default(),
+                    ordinal,
+                    location: xsd.locate(node),
                 });
                 Ok((id.clone(), Lineage::Parent(id.clone())))
             }
             Lineage::Parent(p) => {
                 let id = Id::Child(ChildNodeId {
                     type_: type_.into(),
-                    name: if name.is_empty() {
-                        None
-                    } else {
-                        Some(name.into())
-                    },
+                    name,
+                    ordinal,
                     parent: Box::new(p),
+                    location: xsd.locate(node),
                 });
                 Ok((id.clone(), Lineage::Parent(id.clone())))
             }
@@ -130,9 +201,74 @@ impl Display for Id {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+impl FromStr for Id {
+    type Err = crate::error::Error;
+
+    /// Parses the `Display` form of an `Id`, e.g. `complexType:note/sequence[0]/element:pitch`.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut steps = s.split('/');
+        let (type_, name, ordinal) = parse_step(steps.next().unwrap_or(""))?;
+        let mut id = Id::Root(RootNodeId {
+            index: None,
+            type_: RootNodeType::parse(type_)?,
+            name: name.unwrap_or_default(),
+            ordinal,
+            location: None,
+        });
+        for step in steps {
+            let (type_, name, ordinal) = parse_step(step)?;
+            id = Id::Child(ChildNodeId {
+                type_: type_.into(),
+                name,
+                ordinal,
+                parent: Box::new(id),
+                location: None,
+            });
+        }
+        Ok(id)
+    }
+}
+
+/// Parses one step of an `Id` path, `type:name`, `type[ordinal]` or `type:name[ordinal]`. The name
+/// may itself contain a colon, e.g. `attribute:xml:lang`.
+fn parse_step(step: &str) -> Result<(&str, Option<String>, Option<u64>)> {
+    let (head, ordinal) = match step.rfind('[') {
+        Some(open) if step.ends_with(']') => {
+            let ordinal = wrap!(
+                step[open + 1..step.len() - 1].parse::<u64>(),
+                "invalid position in id step '{}'",
+                step
+            )?;
+            (&step[..open], Some(ordinal))
+        }
+        _ => (step, None),
+    };
+    match head.find(':') {
+        None if ordinal.is_some() && !head.is_empty() => Ok((head, None, ordinal)),
+        Some(colon) if colon > 0 && colon + 1 < head.len() => {
+            Ok((&head[..colon], Some(head[colon + 1..].to_owned()), ordinal))
+        }
+        _ => raise!(
+            "invalid id step '{}', expected 'type:name', 'type[position]' or 'type:name[position]'",
+            step
+        ),
+    }
+}
+
+/// The name that identifies `node`, which is its `id`, `name`, `type` or `ref` attribute, in that
+/// order of preference.
+pub(crate) fn node_name(node: &exile::Element) -> Option<&String> {
+    let attrs = node.attributes.map();
+    ["id", NAME, TYPE, REF]
+        .iter()
+        .find_map(|&key| attrs.get(key))
+        .filter(|name| !name.is_empty())
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum RootNodeType {
     Annotation,
+    Attribute,
     AttributeGroup,
     ComplexType,
     Element,
@@ -145,6 +281,7 @@ impl Display for RootNodeType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             RootNodeType::Annotation => ANNOTATION,
+            RootNodeType::Attribute => ATTRIBUTE,
             RootNodeType::AttributeGroup => ATTRIBUTE_GROUP,
             RootNodeType::ComplexType => COMPLEX_TYPE,
             RootNodeType::Element => ELEMENT,
@@ -160,6 +297,7 @@ impl RootNodeType {
     pub fn parse<S: AsRef<str>>(s: S) -> Result<RootNodeType> {
         let et = match s.as_ref() {
             ANNOTATION => RootNodeType::Annotation,
+            ATTRIBUTE => RootNodeType::Attribute,
             ATTRIBUTE_GROUP => RootNodeType::AttributeGroup,
             COMPLEX_TYPE => RootNodeType::ComplexType,
             ELEMENT => RootNodeType::Element,
@@ -177,14 +315,20 @@ pub struct RootNodeId {
     index: Option<u64>,
     type_: RootNodeType,
     name: String,
+    ordinal: Option<u64>,
+    location: Option<Location>,
 }
 
 impl RootNodeId {
     pub fn index(&self) -> Option<u64> {
         self.index
     }
+    /// The name of the entry, or for an entry that has no name, e.g. an `xs:annotation`, its `Id`.
     pub fn name(&self) -> Cow<'_, str> {
-        Cow::Borrowed(self.name.as_str())
+        match self.ordinal {
+            None => Cow::Borrowed(self.name.as_str()),
+            Some(_) => Cow::Owned(self.to_string()),
+        }
     }
     pub fn type_(&self) -> RootNodeType {
         self.type_
@@ -193,13 +337,29 @@ impl RootNodeId {
 
 impl Display for RootNodeId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}", self.type_, self.name.as_str())
+        write!(f, "{}", self.type_)?;
+        if !self.name.is_empty() {
+            write!(f, ":{}", self.name.as_str())?;
+        }
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "[{}]", ordinal)?;
+        }
+        Ok(())
     }
 }
 
 impl PartialEq for RootNodeId {
     fn eq(&self, other: &Self) -> bool {
-        self.type_ == other.type_ && self.name == other.name
+        self.type_ == other.type_ && self.name == other.name && self.ordinal == other.ordinal
+    }
+}
+
+// the index is not part of the identity, see `PartialEq`.
+impl Hash for RootNodeId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.type_.hash(state);
+        self.name.hash(state);
+        self.ordinal.hash(state);
     }
 }
 
@@ -210,11 +370,7 @@ impl Ord for RootNodeId {
                 return left.cmp(&right);
             }
         }
-        if self.type_ == other.type_ {
-            self.name.cmp(&other.name)
-        } else {
-            self.type_.cmp(&other.type_)
-        }
+        (self.type_, &self.name, self.ordinal).cmp(&(other.type_, &other.name, other.ordinal))
     }
 }
 
@@ -224,20 +380,61 @@ impl PartialOrd for RootNodeId {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq)]
 pub struct ChildNodeId {
     type_: String,
     name: Option<String>,
+    ordinal: Option<u64>,
     parent: Box<Id>,
+    location: Option<Location>,
+}
+
+impl ChildNodeId {
+    fn key(&self) -> (&str, Option<&str>, Option<u64>, &Id) {
+        (
+            self.type_.as_str(),
+            self.name.as_deref(),
+            self.ordinal,
+            self.parent.as_ref(),
+        )
+    }
+}
+
+// the location is not part of the identity.
+impl PartialEq for ChildNodeId {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Hash for ChildNodeId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Ord for ChildNodeId {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl PartialOrd for ChildNodeId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl Display for ChildNodeId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         Display::fmt(self.parent.as_ref(), f)?;
-        write!(f, ":{}", self.type_.as_str())?;
+        write!(f, "/{}", self.type_.as_str())?;
         if let Some(name) = &self.name {
             write!(f, ":{}", name.as_str())?;
         }
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "[{}]", ordinal)?;
+        }
         Ok(())
     }
 }
@@ -269,3 +466,108 @@ impl Lineage {
         }
     }
 }
+
+#[test]
+fn display_from_str_round_trip() {
+    for s in &[
+        "complexType:note",
+        "annotation[3]",
+        "complexType:note/sequence[0]/element:pitch",
+        "complexType:note/sequence[1]/choice[0]/group:full-note",
+        "attributeGroup:print-style/attribute:xml:lang",
+        "complexType:note/choice[0]/element:pitch[1]",
+        "simpleType:yes-no[1]",
+    ] {
+        let id = s.parse::<Id>().unwrap();
+        assert_eq!(id.to_string(), *s);
+    }
+}
+
+#[test]
+fn from_str_structure() {
+    let id: Id = "complexType:note/sequence[1]/element:pitch"
+        .parse()
+        .unwrap();
+    assert_eq!(id.local_name(), "pitch");
+    assert_eq!(id.ordinal(), None);
+    let sequence = id.parent().unwrap();
+    assert_eq!(sequence.local_name(), "");
+    assert_eq!(sequence.ordinal(), Some(1));
+    let note = sequence.parent().unwrap();
+    assert_eq!(note, &Id::new(RootNodeType::ComplexType, "note".into()));
+    assert_eq!(
+        sequence,
+        &Id::anonymous_child(note, "sequence", 1),
+        "an anonymous child is identified by its position"
+    );
+    assert_ne!(sequence, &Id::anonymous_child(note, "sequence", 0));
+}
+
+#[test]
+fn from_str_err() {
+    for s in &[
+        "",
+        "complexType",
+        "complexType:",
+        "bogus:note",
+        "complexType:note/sequence",
+        "complexType:note/sequence[x]",
+        "complexType:note//element:pitch",
+        "complexType:note/element:[1]",
+        "complexType:note/[1]",
+    ] {
+        assert!(s.parse::<Id>().is_err(), "expected '{}' to be an error", s);
+    }
+}
+
+#[test]
+fn make_ordinal() {
+    let doc = exile::parse(r#"<xs:sequence><xs:choice/><xs:choice/></xs:sequence>"#).unwrap();
+    let parent = Id::new(RootNodeType::ComplexType, "note".into());
+    let second = doc.root().children().nth(1).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let (id, _) = Id::make(Lineage::Parent(parent.clone()), second, &xsd).unwrap();
+    assert_eq!(id, Id::anonymous_child(&parent, "choice", 1));
+    // a node that is not under the fragment's root is numbered in the order it is identified.
+    let other = exile::parse(r#"<xs:choice/>"#).unwrap();
+    for ordinal in 0..2 {
+        let (id, _) = Id::make(Lineage::Parent(parent.clone()), other.root(), &xsd).unwrap();
+        assert_eq!(id, Id::anonymous_child(&parent, "choice", ordinal));
+    }
+    let annotation = exile::parse(r#"<xs:annotation/>"#).unwrap();
+    let (id, _) = Id::make(Lineage::Index(0), annotation.root(), &Xsd::new("xs")).unwrap();
+    assert_eq!(id.to_string(), "annotation[0]");
+}
+
+#[test]
+fn make_same_named_siblings() {
+    let doc = exile::parse(
+        r#"<xs:choice>
+            <xs:element ref="pitch"/>
+            <xs:element ref="rest"/>
+            <xs:element ref="pitch"/>
+        </xs:choice>"#,
+    )
+    .unwrap();
+    let parent: Id = "complexType:note/choice[0]".parse().unwrap();
+    let make = |xsd: &Xsd| -> Vec<String> {
+        doc.root()
+            .children()
+            .map(|child| {
+                let lineage = Lineage::Parent(parent.clone());
+                Id::make(lineage, child, xsd).unwrap().0.to_string()
+            })
+            .collect()
+    };
+    let want = vec![
+        "complexType:note/choice[0]/element:pitch",
+        "complexType:note/choice[0]/element:rest",
+        "complexType:note/choice[0]/element:pitch[1]",
+    ];
+    assert_eq!(make(&Xsd::fragment("xs", doc.root())), want);
+    // the same Ids for nodes that are not part of the document being parsed.
+    assert_eq!(make(&Xsd::new("xs")), want);
+    let id: Id = want[2].parse().unwrap();
+    assert_eq!(id.local_name(), "pitch");
+    assert_eq!(id.ordinal(), Some(1));
+}
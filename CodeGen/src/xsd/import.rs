@@ -2,8 +2,9 @@ use crate::error::Result;
 
 use crate::xsd::annotation::Annotation;
 
-use crate::xsd::constants::{ANNOTATION, IMPORT, SCHEMA_LOCATION};
+use crate::xsd::constants::{ANNOTATION, IMPORT, NAMESPACE, SCHEMA_LOCATION};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::{namespace_attribute, Xsd};
 
 #[derive(Clone, Debug)]
@@ -22,9 +23,15 @@ impl Import {
         return "".to_owned();
     }
 
+    /// The file name of the `schemaLocation`, by which `Xsd::load_with_imports` finds the imported
+    /// schema.
+    pub fn file_name(&self) -> &str {
+        self.schema_location.rsplit('/').next().unwrap_or("")
+    }
+
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(IMPORT, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let namespace = namespace_attribute(node)?;
         let schema_location = node
             .attributes
@@ -47,4 +54,11 @@ impl Import {
             schema_location,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, IMPORT)
+            .attr(NAMESPACE, &self.namespace)
+            .attr(SCHEMA_LOCATION, &self.schema_location)
+            .annotation(xsd, &self.annotation)
+    }
 }
@@ -1,7 +1,8 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
-use crate::xsd::constants::{ANNOTATION, LIST, NAME};
+use crate::xsd::constants::{ANNOTATION, ITEM_TYPE, LIST, NAME};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::Xsd;
 
 #[derive(Clone, Debug)]
@@ -11,8 +12,6 @@ pub struct List {
     pub item_type: String,
 }
 
-const ITEM_TYPE: &str = "itemType";
-
 impl List {
     pub fn documentation(&self) -> String {
         if let Some(annotation) = &self.annotation {
@@ -23,7 +22,7 @@ impl List {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(LIST, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let item_type = node
             .attributes
             .map()
@@ -44,6 +43,12 @@ impl List {
             item_type,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, LIST)
+            .attr(ITEM_TYPE, &self.item_type)
+            .annotation(xsd, &self.annotation)
+    }
 }
 
 #[test]
@@ -59,9 +64,9 @@ fn parse() {
         "#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:list:4941444217670409626".to_owned();
+    let want_id = "element:foo/list[0]".to_owned();
     let want_doc = "Hello";
-    let list = List::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let list = List::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = list.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", list.id);
@@ -2,7 +2,9 @@
 // has macros, must go first
 mod utils;
 
+pub mod all;
 pub mod annotation;
+pub mod any;
 pub mod attribute;
 pub mod attribute_group;
 pub mod attributes;
@@ -11,49 +13,108 @@ pub mod common;
 pub mod complex_content;
 pub mod complex_type;
 pub mod constants;
+pub mod diff;
 pub mod element;
+mod entries;
 pub mod extension;
+pub mod flatten;
+pub mod graph;
 pub mod group;
 pub mod id;
 pub mod import;
 pub mod list;
+pub mod pattern;
 pub mod primitives;
 pub mod restriction;
 pub mod sequence;
 pub mod simple_content;
 pub mod simple_type;
+pub mod source;
 pub mod union;
+pub mod write;
 
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
+use crate::xsd::attribute::Attribute;
 use crate::xsd::attribute_group::AttributeGroup;
 use crate::xsd::complex_type::ComplexType;
 use crate::xsd::constants::{
     ANNOTATION, ATTRIBUTE_GROUP, BASE, COMPLEX_TYPE, DEFAULT, ELEMENT, FIXED, GROUP, IMPORT,
-    MAX_OCCURS, MIN_OCCURS, NAME, NAMESPACE, REF, REQUIRED, SIMPLE_TYPE, TYPE, UNBOUNDED, USE,
-    VALUE,
+    MAX_OCCURS, MIN_OCCURS, NAME, NAMESPACE, REF, REQUIRED, SCHEMA, SIMPLE_TYPE, TARGET_NAMESPACE,
+    TYPE, UNBOUNDED, USE, VALUE,
 };
 use crate::xsd::element::Element;
+use crate::xsd::entries::Entries;
+use crate::xsd::graph::Graph;
 use crate::xsd::group::GroupDefinition;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::import::Import;
 use crate::xsd::simple_type::SimpleType;
+use crate::xsd::source::{Location, SourceMap};
+use crate::xsd::write::Node;
 use std::cmp::Ordering;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Display, Formatter};
 use std::path::Path;
+use std::sync::Mutex;
+
+/// The XML Schema namespace.
+pub const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// The namespace that the `xml` prefix is bound to without needing to be declared.
+pub const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
 
 #[derive(Clone, Debug)]
 pub struct Xsd {
-    entries: Vec<Entry>,
+    entries: Entries,
     prefix: String,
+    target_namespace: Option<String>,
+    /// The namespace prefixes declared on the schema node, e.g. `xlink` is mapped to
+    /// `http://www.w3.org/1999/xlink`.
+    namespaces: BTreeMap<String, String>,
+    /// The schemas loaded by following `xs:import` entries, see `load_with_imports`.
+    imports: Vec<Xsd>,
+    /// The other attributes of the schema node, e.g. `elementFormDefault`. They are not used, but
+    /// are kept so that `to_xml` can write them back.
+    attributes: BTreeMap<String, String>,
+    /// Where the nodes of the document being parsed are. This is only populated while parsing
+    /// since it refers to the nodes of the document by address.
+    source_map: SourceMap,
+    /// The names of the top-level simple types of the document being parsed, including those that
+    /// have not been parsed yet, see `has_simple_type`. This is only populated while parsing.
+    simple_type_names: BTreeSet<String>,
+    /// How many nodes that are not part of the document being parsed have been identified, by
+    /// parent, type and name, see `Id::make`.
+    unmapped_ordinals: UnmappedOrdinals,
+}
+
+/// A counter per key that can be shared between threads. A clone starts from the same counts.
+#[derive(Debug, Default)]
+struct UnmappedOrdinals(Mutex<HashMap<String, u64>>);
+
+impl Clone for UnmappedOrdinals {
+    fn clone(&self) -> Self {
+        let counts = self
+            .0
+            .lock()
+            .map(|counts| counts.clone())
+            .unwrap_or_default();
+        UnmappedOrdinals(Mutex::new(counts))
+    }
 }
 
 impl Default for Xsd {
     fn default() -> Self {
         Self {
-            entries: Vec::new(),
+            entries: Entries::default(),
             prefix: "xs".to_owned(),
+            target_namespace: None,
+            namespaces: BTreeMap::new(),
+            imports: Vec::new(),
+            attributes: BTreeMap::new(),
+            source_map: SourceMap::default(),
+            simple_type_names: BTreeSet::new(),
+            unmapped_ordinals: UnmappedOrdinals::default(),
         }
     }
 }
@@ -65,94 +126,335 @@ impl Xsd {
             "unable to load '{}'",
             filepath.as_ref().display()
         )?;
-        let doc = exile::parse(&xml_str).unwrap();
-        Self::parse(doc.root())
+        Self::parse_text(&xml_str, Some(filepath.as_ref()))
+    }
+
+    /// Parses the XSD from a string. Unlike `parse`, the `Id` of each node, and any error, will
+    /// carry the line and column at which the node was found.
+    pub fn parse_str<S: AsRef<str>>(xml: S) -> Result<Self> {
+        Self::parse_text(xml.as_ref(), None)
+    }
+
+    fn parse_text(xml: &str, file: Option<&Path>) -> Result<Self> {
+        let doc = match file {
+            Some(path) => wrap!(exile::parse(xml), "unable to parse '{}'", path.display())?,
+            None => wrap!(exile::parse(xml), "unable to parse the xml")?,
+        };
+        let source_map = SourceMap::new(file, xml, doc.root());
+        Self::parse_with_source_map(doc.root(), source_map)
+    }
+
+    /// Loads the XSD and follows its `xs:import` entries, loading each imported schema from
+    /// `import_dir`. Only the file name of an import's `schemaLocation` is used, so an import of
+    /// `http://www.musicxml.org/xsd/xml.xsd` is loaded from `import_dir/xml.xsd`. The network is
+    /// never accessed.
+    pub fn load_with_imports<P1, P2>(filepath: P1, import_dir: P2) -> Result<Self>
+    where
+        P1: AsRef<Path>,
+        P2: AsRef<Path>,
+    {
+        let mut xsd = Self::load(filepath)?;
+        let mut loaded = Vec::new();
+        xsd.load_imports(import_dir.as_ref(), &mut loaded)?;
+        Ok(xsd)
+    }
+
+    fn load_imports(&mut self, import_dir: &Path, loaded: &mut Vec<String>) -> Result<()> {
+        let imports: Vec<Import> = self
+            .entries()
+            .filter_map(|entry| match entry {
+                Entry::Import(import) => Some(import.clone()),
+                _ => None,
+            })
+            .collect();
+        for import in imports {
+            if loaded.contains(&import.namespace) {
+                continue;
+            }
+            loaded.push(import.namespace.clone());
+            let path = import_dir.join(import.file_name());
+            let mut imported = wrap!(
+                Self::load(&path),
+                "unable to load the import of '{}' from '{}'",
+                import.namespace,
+                path.display()
+            )?;
+            if imported.target_namespace() != Some(import.namespace.as_str()) {
+                return raise!(
+                    "expected '{}' to have targetNamespace '{}', got {:?}",
+                    path.display(),
+                    import.namespace,
+                    imported.target_namespace
+                );
+            }
+            imported.load_imports(import_dir, loaded)?;
+            self.imports.push(imported);
+        }
+        Ok(())
     }
 
     pub fn parse(root: &exile::Element) -> Result<Self> {
-        if root.name != "schema" {
-            return raise!("expected the root node to be named 'schema'");
+        Self::parse_with_source_map(root, SourceMap::from_tree(root))
+    }
+
+    fn parse_with_source_map(root: &exile::Element, source_map: SourceMap) -> Result<Self> {
+        if root.name != SCHEMA {
+            return Err(make_err!("expected the root node to be named 'schema'")
+                .at(source_map.locate(root)));
         }
         let mut prefix = "";
         for (k, v) in root.attributes.map() {
-            if v.as_str() == "http://www.w3.org/2001/XMLSchema" {
-                if k.starts_with("xmlns:") {
-                    let mut split = k.split(':');
-                    let _ = split.next().ok_or(make_err!("expected to find xmlns:"))?;
-                    let ns: &str = split
-                        .next()
-                        .ok_or(make_err!("expected to find xmlns prefix"))?;
-                    prefix = ns;
-                    break;
-                }
+            if v.as_str() == XSD_NAMESPACE && k.starts_with("xmlns:") {
+                let mut split = k.split(':');
+                let _ = split.next().ok_or(make_err!("expected to find xmlns:"))?;
+                let ns: &str = split
+                    .next()
+                    .ok_or(make_err!("expected to find xmlns prefix"))?;
+                prefix = ns;
+                break;
             }
         }
         if prefix.is_empty() {
             return raise!("xmlns prefix is empty");
         }
+        let mut namespaces = BTreeMap::new();
+        let mut attributes = BTreeMap::new();
+        for (k, v) in root.attributes.map() {
+            if let Some(declared) = k.strip_prefix("xmlns:") {
+                namespaces.insert(declared.to_owned(), v.clone());
+            } else if k != TARGET_NAMESPACE {
+                attributes.insert(k.clone(), v.clone());
+            }
+        }
         let mut xsd = Xsd {
-            entries: Vec::new(),
+            entries: Entries::default(),
             prefix: prefix.to_owned(),
+            target_namespace: root.attributes.map().get(TARGET_NAMESPACE).cloned(),
+            namespaces,
+            imports: Vec::new(),
+            attributes,
+            source_map,
+            simple_type_names: root
+                .children()
+                .filter(|node| node.name == SIMPLE_TYPE)
+                .filter_map(|node| node.attributes.map().get(NAME).cloned())
+                .collect(),
+            unmapped_ordinals: UnmappedOrdinals::default(),
         };
         for (i, entry_node) in root.children().enumerate() {
-            let entry = Entry::from_xml(entry_node, Lineage::Index(i as u64), &xsd)?;
-            xsd.add_entry(entry)?;
+            let location = xsd.locate(entry_node);
+            let entry = Entry::from_xml(entry_node, Lineage::Index(i as u64), &xsd)
+                .map_err(|e| e.at(location.clone()))?;
+            xsd.add_entry(entry).map_err(|e| e.at(location))?;
         }
+        xsd.source_map = SourceMap::default();
+        xsd.simple_type_names.clear();
         Ok(xsd)
     }
 
+    /// Writes the schema. The result has the same entries as the parsed document, though comments
+    /// and attributes that have their default value, e.g. `minOccurs="1"`, are not written.
+    pub fn to_xml(&self) -> Node {
+        let mut node = Node::new(self, SCHEMA);
+        if !self.namespaces.contains_key(self.prefix()) {
+            node = node.attr(format!("xmlns:{}", self.prefix()), XSD_NAMESPACE);
+        }
+        for (prefix, namespace) in &self.namespaces {
+            node = node.attr(format!("xmlns:{}", prefix), namespace);
+        }
+        node = node.attr_opt(TARGET_NAMESPACE, self.target_namespace.as_ref());
+        for (k, v) in &self.attributes {
+            node = node.attr(k.as_str(), v);
+        }
+        node.children(self.entries().map(|entry| entry.to_xml(self)))
+    }
+
+    /// Writes the schema as an XML document, see `to_xml`.
+    pub fn to_xml_string(&self) -> String {
+        write::document(&self.to_xml())
+    }
+
+    /// Writes the schema to a file, see `to_xml`.
+    pub fn save<P: AsRef<Path>>(&self, filepath: P) -> Result<()> {
+        wrap!(
+            std::fs::write(filepath.as_ref(), self.to_xml_string()),
+            "unable to write '{}'",
+            filepath.as_ref().display()
+        )
+    }
+
     pub fn new<S: AsRef<str>>(prefix: S) -> Self {
         Self {
-            entries: Vec::new(),
             prefix: prefix.as_ref().into(),
+            ..Default::default()
+        }
+    }
+
+    /// An empty schema for parsing the nodes under `root`, which need not be a schema node, e.g. a
+    /// single `xs:complexType`. Unnamed nodes can only be identified under `root`, see `Id::make`.
+    pub(crate) fn fragment<S: AsRef<str>>(prefix: S, root: &exile::Element) -> Self {
+        Self {
+            prefix: prefix.as_ref().into(),
+            source_map: SourceMap::from_tree(root),
+            ..Default::default()
         }
     }
+
+    /// The location of `node` in the document that is being parsed.
+    pub(crate) fn locate(&self, node: &exile::Element) -> Option<Location> {
+        self.source_map.locate(node)
+    }
+
+    /// Whether the schema has a top-level simple type named `name`. While parsing, this includes
+    /// the simple types that come later in the document.
+    pub(crate) fn has_simple_type<S: AsRef<str>>(&self, name: S) -> bool {
+        self.simple_type_names.contains(name.as_ref())
+            || self.get(RootNodeType::SimpleType, name.as_ref()).is_some()
+    }
+
+    /// The position of `node` among its siblings of the same name in the document that is being
+    /// parsed.
+    pub(crate) fn ordinal(&self, node: &exile::Element) -> Option<u64> {
+        self.source_map.ordinal(node)
+    }
+
+    /// The position of a named `node` among its siblings of the same name and node name in the
+    /// document that is being parsed.
+    pub(crate) fn name_ordinal(&self, node: &exile::Element) -> Option<u64> {
+        self.source_map.name_ordinal(node)
+    }
+
+    /// Counts the nodes with the given key that were identified without being part of the
+    /// document that is being parsed, returning the count before this one.
+    pub(crate) fn unmapped_ordinal(&self, key: String) -> u64 {
+        let mut counts = match self.unmapped_ordinals.0.lock() {
+            Ok(counts) => counts,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let count = counts.entry(key).or_insert(0);
+        *count += 1;
+        *count - 1
+    }
+
     pub fn prefix(&self) -> &str {
         self.prefix.as_str()
     }
 
-    pub fn add_entry(&mut self, entry: Entry) -> Result<()> {
-        // TODO - make an efficient storage
-        self.entries.push(entry);
-        Ok(())
+    pub fn target_namespace(&self) -> Option<&str> {
+        self.target_namespace.as_deref()
     }
 
-    pub fn find(&self, id: &Id) -> Result<&Entry> {
-        // TODO - make an efficient lookup
-        for entry in &self.entries {
-            if entry.id() == id {
-                return Ok(entry);
-            }
+    /// The namespace bound to `prefix` by the schema node. The `xml` prefix is always bound to
+    /// `XML_NAMESPACE`.
+    pub fn namespace<S: AsRef<str>>(&self, prefix: S) -> Option<&str> {
+        match prefix.as_ref() {
+            "xml" => Some(XML_NAMESPACE),
+            p => self.namespaces.get(p).map(|s| s.as_str()),
         }
-        raise!("id '{}' not found", id)
     }
 
-    pub fn remove(&mut self, id: &Id) -> Result<Entry> {
-        // TODO - efficient removal
-        let mut pos = None;
-        for (i, entry) in self.entries.iter().enumerate() {
-            if entry.id() == id {
-                pos = Some(i);
-                break;
+    /// The prefix that the schema node binds to `namespace`, i.e. the inverse of `namespace`.
+    pub fn prefix_of<S: AsRef<str>>(&self, namespace: S) -> Option<&str> {
+        let namespace = namespace.as_ref();
+        if namespace == XML_NAMESPACE {
+            return Some("xml");
+        }
+        self.namespaces
+            .iter()
+            .find(|(_, ns)| ns.as_str() == namespace)
+            .map(|(prefix, _)| prefix.as_str())
+    }
+
+    /// The schemas that were loaded by following `xs:import` entries.
+    pub fn imports(&self) -> impl Iterator<Item = &Xsd> {
+        self.imports.iter()
+    }
+
+    /// Finds the imported schema, at any depth, whose `targetNamespace` is `namespace`.
+    pub fn import<S: AsRef<str>>(&self, namespace: S) -> Option<&Xsd> {
+        let namespace = namespace.as_ref();
+        for import in &self.imports {
+            if import.target_namespace() == Some(namespace) {
+                return Some(import);
+            }
+            if let Some(found) = import.import(namespace) {
+                return Some(found);
             }
         }
-        if let Some(i) = pos {
-            // Note - this can panic, but shouldn't unless a data race occurs.
-            Ok(self.entries.remove(i))
-        } else {
-            raise!("entry '{}' not found", id)
+        None
+    }
+
+    /// Finds the top-level entry of type `root_node_type` named by `qname`. When `qname` has a
+    /// prefix that refers to another namespace, e.g. `xml:lang`, the entry is looked up in the
+    /// imported schema for that namespace. Returns `None` if it cannot be found, for example
+    /// because the imports were not loaded.
+    pub fn resolve<S: AsRef<str>>(&self, root_node_type: RootNodeType, qname: S) -> Option<&Entry> {
+        let (schema, local_name) = self.resolve_schema(qname.as_ref())?;
+        schema.get(root_node_type, local_name)
+    }
+
+    /// The schema that `qname` belongs to, i.e. this one or an import, and the unprefixed name.
+    /// Names found in the returned schema must be resolved against it rather than against `self`.
+    pub fn resolve_schema<'a>(&self, qname: &'a str) -> Option<(&Xsd, &'a str)> {
+        match qname.find(':') {
+            None => Some((self, qname)),
+            Some(i) => {
+                let namespace = self.namespace(&qname[..i])?;
+                let schema = if self.target_namespace() == Some(namespace) {
+                    self
+                } else {
+                    self.import(namespace)?
+                };
+                Some((schema, &qname[i + 1..]))
+            }
         }
     }
 
-    // TODO - this should be an iterator so the underlying data structure can change.
-    pub fn entries(&self) -> &Vec<Entry> {
-        &self.entries
+    /// Adds an entry. Returns an error if an entry with the same `Id` already exists.
+    pub fn add_entry(&mut self, entry: Entry) -> Result<()> {
+        self.entries.add(entry)
+    }
+
+    pub fn find(&self, id: &Id) -> Result<&Entry> {
+        self.entries
+            .get(id)
+            .ok_or_else(|| make_err!("id '{}' not found", id))
+    }
+
+    /// Finds the top-level entry of type `root_node_type` with the given unprefixed `name`, e.g.
+    /// `get(RootNodeType::ComplexType, "note")`. See `resolve` for prefixed names.
+    pub fn get<S: AsRef<str>>(&self, root_node_type: RootNodeType, name: S) -> Option<&Entry> {
+        self.entries.get_by_name(root_node_type, name.as_ref())
+    }
+
+    pub fn remove(&mut self, id: &Id) -> Result<Entry> {
+        self.entries
+            .remove(id)
+            .ok_or_else(|| make_err!("entry '{}' not found", id))
+    }
+
+    /// The entries in the order in which they appear in the XSD document.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Resolves the references between the entries. See `Graph::build`.
+    pub fn graph(&self) -> Result<Graph> {
+        Graph::build(self)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 }
 
 impl Display for Xsd {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for entry in &self.entries {
+        for entry in self.entries() {
             writeln!(f, "{}", entry.id())?;
         }
         Ok(())
@@ -162,6 +464,7 @@ impl Display for Xsd {
 #[derive(Clone, Debug)]
 pub enum Entry {
     Annotation(Annotation),
+    Attribute(Attribute),
     AttributeGroup(AttributeGroup),
     ComplexType(ComplexType),
     Element(Element),
@@ -178,6 +481,9 @@ impl Entry {
             RootNodeType::Annotation => {
                 Ok(Entry::Annotation(Annotation::from_xml(node, lineage, xsd)?))
             }
+            RootNodeType::Attribute => {
+                Ok(Entry::Attribute(Attribute::from_xml(node, lineage, xsd)?))
+            }
             RootNodeType::AttributeGroup => Ok(Entry::AttributeGroup(AttributeGroup::from_xml(
                 node, lineage, xsd,
             )?)),
@@ -193,9 +499,23 @@ impl Entry {
         }
     }
 
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        match self {
+            Entry::Annotation(x) => x.to_xml(xsd),
+            Entry::Attribute(x) => x.to_xml(xsd),
+            Entry::AttributeGroup(x) => x.to_xml(xsd),
+            Entry::ComplexType(x) => x.to_xml(xsd),
+            Entry::Element(x) => x.to_xml(xsd),
+            Entry::Group(x) => x.to_xml(xsd),
+            Entry::Import(x) => x.to_xml(xsd),
+            Entry::SimpleType(x) => x.to_xml(xsd),
+        }
+    }
+
     pub fn id(&self) -> &Id {
         match self {
             Entry::Annotation(x) => &x.id,
+            Entry::Attribute(x) => &x.id,
             Entry::AttributeGroup(x) => x.id(),
             Entry::ComplexType(x) => &x.id,
             Entry::Element(x) => x.id(),
@@ -208,6 +528,7 @@ impl Entry {
     pub fn documentation(&self) -> String {
         match self {
             Entry::Annotation(x) => x.documentation(),
+            Entry::Attribute(x) => x.documentation(),
             Entry::AttributeGroup(x) => x.documentation(),
             Entry::ComplexType(x) => x.documentation(),
             Entry::Element(x) => x.documentation(),
@@ -0,0 +1,900 @@
+use crate::error::Result;
+use regex::Regex;
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+/*
+The regular expression dialect of the pattern facet, https://www.w3.org/TR/xmlschema-2/#regexs
+
+regExp      ::= branch ( '|' branch )*
+branch      ::= piece*
+piece       ::= atom quantifier?
+quantifier  ::= [?*+] | ( '{' quantity '}' )
+atom        ::= Char | charClass | ( '(' regExp ')' )
+charClass   ::= charClassEsc | charClassExpr | WildcardEsc
+charClassExpr ::= '[' '^'? charGroup ( '-' charClassExpr )? ']'
+
+A pattern always matches the whole value, there are no anchors and ^ and $ are ordinary characters.
+*/
+
+/// A parsed `xs:pattern`, which can be written as a Rust `regex` or as a C++ `std::regex`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Pattern {
+    source: String,
+    branches: Vec<Branch>,
+}
+
+type Branch = Vec<Piece>;
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Piece {
+    atom: Atom,
+    min: u64,
+    max: Option<u64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum Atom {
+    Char(char),
+    /// `.`, which matches anything except a line break.
+    Wildcard,
+    Escape(Escape),
+    Class(Class),
+    Group(Vec<Branch>),
+}
+
+/// A multi-character escape, `negated` for the upper case forms such as `\D`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Escape {
+    negated: bool,
+    kind: EscapeKind,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum EscapeKind {
+    /// `\s`
+    Space,
+    /// `\i`
+    NameStart,
+    /// `\c`
+    Name,
+    /// `\d`
+    Digit,
+    /// `\w`
+    Word,
+    /// `\p{..}`
+    Property(String),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct Class {
+    negated: bool,
+    items: Vec<ClassItem>,
+    /// The class in `[a-z-[aeiou]]` whose characters are removed.
+    subtraction: Option<Box<Class>>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+enum ClassItem {
+    Range(char, char),
+    Escape(Escape),
+}
+
+impl Pattern {
+    pub fn new<S: AsRef<str>>(source: S) -> Result<Self> {
+        let source = source.as_ref();
+        let mut parser = Parser {
+            source,
+            chars: source.chars().collect(),
+            position: 0,
+        };
+        let branches = parser.reg_exp()?;
+        if let Some(c) = parser.peek() {
+            return parser.err(format!("unexpected '{}'", c));
+        }
+        Ok(Self {
+            source: source.to_owned(),
+            branches,
+        })
+    }
+
+    /// The pattern as it was written in the XSD.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// A pattern that matches the values matched by either `self` or `other`, which is how several
+    /// pattern facets in one restriction are combined.
+    pub fn or(mut self, other: Pattern) -> Self {
+        self.source = format!("{}|{}", self.source, other.source);
+        self.branches.extend(other.branches);
+        self
+    }
+
+    /// Translates the pattern into the syntax of the `regex` crate, anchored at both ends.
+    pub fn to_rust(&self) -> Result<String> {
+        let mut s = String::from("^(?:");
+        rust_branches(&self.branches, &mut s)?;
+        s.push_str(")$");
+        Ok(s)
+    }
+
+    /// Compiles the pattern with the `regex` crate.
+    pub fn regex(&self) -> Result<Regex> {
+        let translated = self.to_rust()?;
+        wrap!(
+            Regex::new(&translated),
+            "unable to compile pattern '{}' as '{}'",
+            self.source,
+            translated
+        )
+    }
+
+    /// Translates the pattern into the ECMAScript syntax of C++ `std::regex`, to be used with
+    /// `std::regex_match`, which matches the whole string. `std::regex` matches the bytes of a
+    /// UTF-8 string, so a character class with non-ASCII characters is written as the alternatives
+    /// of the UTF-8 byte sequences of its characters, e.g. `[^a]` matches `é` as a whole. Unicode
+    /// properties are not supported.
+    pub fn to_cpp(&self) -> Result<String> {
+        let mut s = String::new();
+        self.cpp_branches(&self.branches, &mut s)?;
+        Ok(s)
+    }
+
+    fn cpp_branches(&self, branches: &[Branch], s: &mut String) -> Result<()> {
+        for (i, branch) in branches.iter().enumerate() {
+            if i > 0 {
+                s.push('|');
+            }
+            for piece in branch {
+                let atom = match &piece.atom {
+                    Atom::Char(c) if c.is_ascii() => cpp_char(*c),
+                    Atom::Char(c) => {
+                        let mut bytes = [0; 4];
+                        let bytes = c.encode_utf8(&mut bytes).as_bytes();
+                        let escaped: String =
+                            bytes.iter().map(|b| format!("\\x{:02X}", b)).collect();
+                        if bytes.len() > 1 && !is_once(piece) {
+                            format!("(?:{})", escaped)
+                        } else {
+                            escaped
+                        }
+                    }
+                    Atom::Wildcard => {
+                        cpp_class(&Ranges::from_slice(LINE_BREAK).complement(MAX_CHAR))
+                    }
+                    Atom::Escape(e) => cpp_class(&self.escape_chars(e)?),
+                    Atom::Class(class) => cpp_class(&self.class_chars(class)?),
+                    Atom::Group(branches) => {
+                        let mut group = String::from("(?:");
+                        self.cpp_branches(branches, &mut group)?;
+                        group.push(')');
+                        group
+                    }
+                };
+                s.push_str(&atom);
+                push_quantifier(piece, s);
+            }
+        }
+        Ok(())
+    }
+
+    /// The characters matched by `class`.
+    fn class_chars(&self, class: &Class) -> Result<Ranges> {
+        let mut set = Ranges::default();
+        for item in &class.items {
+            let item = match item {
+                ClassItem::Range(from, to) => Ranges::range(*from as u32, *to as u32),
+                ClassItem::Escape(e) => self.escape_chars(e)?,
+            };
+            set = set.union(&item);
+        }
+        if class.negated {
+            set = set.complement(MAX_CHAR);
+        }
+        if let Some(subtraction) = &class.subtraction {
+            set = set.subtract(&self.class_chars(subtraction)?, MAX_CHAR);
+        }
+        Ok(set)
+    }
+
+    /// The characters matched by a multi-character escape. `\d` and `\w` are approximated by
+    /// their ASCII members, plus every non-ASCII character in the case of `\w`.
+    fn escape_chars(&self, escape: &Escape) -> Result<Ranges> {
+        let set = match &escape.kind {
+            EscapeKind::Space => Ranges::from_slice(SPACE),
+            EscapeKind::NameStart => Ranges::from_slice(NAME_START),
+            EscapeKind::Name => Ranges::from_slice(NAME_START).union(&Ranges::from_slice(NAME)),
+            EscapeKind::Digit => Ranges::range('0' as u32, '9' as u32),
+            EscapeKind::Word => Ranges::from_slice(WORD),
+            EscapeKind::Property(name) => {
+                return raise!(
+                    "the property '{}' in pattern '{}' is not supported by std::regex",
+                    name,
+                    self.source
+                )
+            }
+        };
+        Ok(if escape.negated {
+            set.complement(MAX_CHAR)
+        } else {
+            set
+        })
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Pattern::new(s)
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+struct Parser<'s> {
+    source: &'s str,
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn next(&mut self) -> Result<char> {
+        match self.peek() {
+            Some(c) => {
+                self.position += 1;
+                Ok(c)
+            }
+            None => self.err("unexpected end"),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.next()? {
+            c if c == expected => Ok(()),
+            c => self.err(format!("expected '{}' but found '{}'", expected, c)),
+        }
+    }
+
+    fn err<T, S: AsRef<str>>(&self, message: S) -> Result<T> {
+        raise!(
+            "{} at position {} in pattern '{}'",
+            message.as_ref(),
+            self.position,
+            self.source
+        )
+    }
+
+    fn reg_exp(&mut self) -> Result<Vec<Branch>> {
+        let mut branches = vec![self.branch()?];
+        while self.peek() == Some('|') {
+            self.position += 1;
+            branches.push(self.branch()?);
+        }
+        Ok(branches)
+    }
+
+    fn branch(&mut self) -> Result<Branch> {
+        let mut pieces = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            let (min, max) = self.quantifier()?;
+            pieces.push(Piece { atom, min, max });
+        }
+        Ok(pieces)
+    }
+
+    fn atom(&mut self) -> Result<Atom> {
+        match self.next()? {
+            '.' => Ok(Atom::Wildcard),
+            '\\' => match self.escape()? {
+                ClassItem::Range(c, _) => Ok(Atom::Char(c)),
+                ClassItem::Escape(e) => Ok(Atom::Escape(e)),
+            },
+            '[' => Ok(Atom::Class(self.class()?)),
+            '(' => {
+                let branches = self.reg_exp()?;
+                self.expect(')')?;
+                Ok(Atom::Group(branches))
+            }
+            c if "?*+{}])".contains(c) => {
+                self.position -= 1;
+                self.err(format!("unexpected '{}'", c))
+            }
+            c => Ok(Atom::Char(c)),
+        }
+    }
+
+    fn quantifier(&mut self) -> Result<(u64, Option<u64>)> {
+        let quantity = match self.peek() {
+            Some('?') => (0, Some(1)),
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('{') => {
+                self.position += 1;
+                let min = self.number()?;
+                let max = if self.peek() == Some(',') {
+                    self.position += 1;
+                    if self.peek() == Some('}') {
+                        None
+                    } else {
+                        Some(self.number()?)
+                    }
+                } else {
+                    Some(min)
+                };
+                if max.is_some_and(|max| max < min) {
+                    return self.err("the maximum is less than the minimum");
+                }
+                self.expect('}')?;
+                return Ok((min, max));
+            }
+            _ => return Ok((1, Some(1))),
+        };
+        self.position += 1;
+        Ok(quantity)
+    }
+
+    fn number(&mut self) -> Result<u64> {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let digits: String = self.chars[start..self.position].iter().collect();
+        match digits.parse() {
+            Ok(n) => Ok(n),
+            Err(_) => self.err("expected a number"),
+        }
+    }
+
+    /// Parses what follows a backslash. A single character escape is returned as a range of one.
+    fn escape(&mut self) -> Result<ClassItem> {
+        let c = self.next()?;
+        let single = match c {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '\\' | '|' | '.' | '?' | '*' | '+' | '(' | ')' | '{' | '}' | '-' | '[' | ']' | '^' => c,
+            _ => {
+                let kind = match c.to_ascii_lowercase() {
+                    's' => EscapeKind::Space,
+                    'i' => EscapeKind::NameStart,
+                    'c' => EscapeKind::Name,
+                    'd' => EscapeKind::Digit,
+                    'w' => EscapeKind::Word,
+                    'p' => {
+                        self.expect('{')?;
+                        let start = self.position;
+                        while self.peek().is_some_and(|c| c != '}') {
+                            self.position += 1;
+                        }
+                        let name: String = self.chars[start..self.position].iter().collect();
+                        self.expect('}')?;
+                        EscapeKind::Property(name)
+                    }
+                    _ => {
+                        self.position -= 1;
+                        return self.err(format!("unknown escape '\\{}'", c));
+                    }
+                };
+                return Ok(ClassItem::Escape(Escape {
+                    negated: c.is_ascii_uppercase(),
+                    kind,
+                }));
+            }
+        };
+        Ok(ClassItem::Range(single, single))
+    }
+
+    /// Parses a character class expression after its opening bracket, including the closing one.
+    fn class(&mut self) -> Result<Class> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.position += 1;
+        }
+        let mut items = Vec::new();
+        let mut subtraction = None;
+        loop {
+            let c = self.next()?;
+            match c {
+                ']' if items.is_empty() => return self.err("empty character class"),
+                ']' => break,
+                '-' if self.peek() == Some('[') && !items.is_empty() => {
+                    self.position += 1;
+                    subtraction = Some(Box::new(self.class()?));
+                    self.expect(']')?;
+                    break;
+                }
+                '[' => return self.err("unexpected '['"),
+                _ => {
+                    let from = match c {
+                        '\\' => self.escape()?,
+                        c => ClassItem::Range(c, c),
+                    };
+                    // a '-' is a range unless it is the last character or begins a subtraction.
+                    let ranged = self.peek() == Some('-')
+                        && self.peek_at(1).is_some_and(|c| c != ']' && c != '[');
+                    match from {
+                        ClassItem::Range(from, _) if ranged => {
+                            self.position += 1;
+                            let to = match self.next()? {
+                                '\\' => match self.escape()? {
+                                    ClassItem::Range(to, _) => to,
+                                    ClassItem::Escape(_) => {
+                                        return self.err("a range cannot end with an escape")
+                                    }
+                                },
+                                to => to,
+                            };
+                            if to < from {
+                                return self
+                                    .err(format!("the range '{}-{}' is reversed", from, to));
+                            }
+                            items.push(ClassItem::Range(from, to));
+                        }
+                        item => items.push(item),
+                    }
+                }
+            }
+        }
+        Ok(Class {
+            negated,
+            items,
+            subtraction,
+        })
+    }
+}
+
+fn is_once(piece: &Piece) -> bool {
+    piece.min == 1 && piece.max == Some(1)
+}
+
+fn push_quantifier(piece: &Piece, s: &mut String) {
+    match (piece.min, piece.max) {
+        (1, Some(1)) => {}
+        (0, Some(1)) => s.push('?'),
+        (0, None) => s.push('*'),
+        (1, None) => s.push('+'),
+        (min, None) => {
+            let _ = write!(s, "{{{},}}", min);
+        }
+        (min, Some(max)) if min == max => {
+            let _ = write!(s, "{{{}}}", min);
+        }
+        (min, Some(max)) => {
+            let _ = write!(s, "{{{},{}}}", min, max);
+        }
+    }
+}
+
+fn rust_branches(branches: &[Branch], s: &mut String) -> Result<()> {
+    for (i, branch) in branches.iter().enumerate() {
+        if i > 0 {
+            s.push('|');
+        }
+        for piece in branch {
+            match &piece.atom {
+                Atom::Char(c) => s.push_str(&regex::escape(&c.to_string())),
+                Atom::Wildcard => s.push_str("[^\\n\\r]"),
+                Atom::Escape(e) => s.push_str(&rust_escape(e)?),
+                Atom::Class(class) => s.push_str(&rust_class(class)?),
+                Atom::Group(branches) => {
+                    s.push_str("(?:");
+                    rust_branches(branches, s)?;
+                    s.push(')');
+                }
+            }
+            push_quantifier(piece, s);
+        }
+    }
+    Ok(())
+}
+
+/// A multi-character escape as a Rust regex, which is also valid inside a character class.
+fn rust_escape(escape: &Escape) -> Result<String> {
+    let (positive, negative) = match &escape.kind {
+        EscapeKind::Space => ("[\\t\\n\\r ]".to_owned(), "[^\\t\\n\\r ]".to_owned()),
+        EscapeKind::NameStart => {
+            let set = rust_ranges(&Ranges::from_slice(NAME_START));
+            (format!("[{}]", set), format!("[^{}]", set))
+        }
+        EscapeKind::Name => {
+            let set = Ranges::from_slice(NAME_START).union(&Ranges::from_slice(NAME));
+            let set = rust_ranges(&set);
+            (format!("[{}]", set), format!("[^{}]", set))
+        }
+        EscapeKind::Digit => ("\\d".to_owned(), "\\D".to_owned()),
+        EscapeKind::Word => (
+            "[^\\p{P}\\p{Z}\\p{C}]".to_owned(),
+            "[\\p{P}\\p{Z}\\p{C}]".to_owned(),
+        ),
+        EscapeKind::Property(name) => {
+            // a block such as IsBasicLatin would need a table of the Unicode blocks.
+            if name.starts_with("Is") {
+                return raise!("the Unicode block '{}' is not supported", name);
+            }
+            (format!("\\p{{{}}}", name), format!("\\P{{{}}}", name))
+        }
+    };
+    Ok(if escape.negated { negative } else { positive })
+}
+
+fn rust_class(class: &Class) -> Result<String> {
+    let mut s = String::from("[");
+    if class.negated {
+        s.push('^');
+    }
+    for item in &class.items {
+        match item {
+            ClassItem::Range(from, to) => {
+                s.push_str(&rust_ranges(&Ranges::range(*from as u32, *to as u32)))
+            }
+            ClassItem::Escape(e) => s.push_str(&rust_escape(e)?),
+        }
+    }
+    s.push(']');
+    match &class.subtraction {
+        // the negation of the outer class applies before the subtraction, so it is nested.
+        Some(subtraction) => Ok(format!("[{}--{}]", s, rust_class(subtraction)?)),
+        None => Ok(s),
+    }
+}
+
+fn rust_ranges(ranges: &Ranges) -> String {
+    let mut s = String::new();
+    let char_at = |c: u32| {
+        let c = std::char::from_u32(c).unwrap_or(std::char::REPLACEMENT_CHARACTER);
+        if c.is_ascii_alphanumeric() {
+            c.to_string()
+        } else {
+            format!("\\x{{{:X}}}", c as u32)
+        }
+    };
+    for &(from, to) in &ranges.0 {
+        s.push_str(&char_at(from));
+        if to > from {
+            s.push('-');
+            s.push_str(&char_at(to));
+        }
+    }
+    s
+}
+
+fn cpp_char(c: char) -> String {
+    match c {
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' => {
+            format!("\\{}", c)
+        }
+        c if c.is_ascii_graphic() || c == ' ' => c.to_string(),
+        c => format!("\\x{:02X}", c as u32),
+    }
+}
+
+/// A C++ `std::regex` that matches one of `chars`, written with ASCII bytes in a class of their
+/// own so that a class of only ASCII characters stays a class.
+fn cpp_class(chars: &Ranges) -> String {
+    let byte_at = |b: u8| {
+        if b.is_ascii_alphanumeric() {
+            (b as char).to_string()
+        } else {
+            format!("\\x{:02X}", b)
+        }
+    };
+    let byte_class = |from: u8, to: u8| {
+        if from == to {
+            byte_at(from)
+        } else {
+            format!("[{}-{}]", byte_at(from), byte_at(to))
+        }
+    };
+    let mut ascii = String::new();
+    let mut alternatives = Vec::new();
+    for sequence in chars.to_utf8() {
+        match sequence.as_slice() {
+            [(from, to)] => {
+                ascii.push_str(&byte_at(*from));
+                if to > from {
+                    ascii.push('-');
+                    ascii.push_str(&byte_at(*to));
+                }
+            }
+            bytes => alternatives.push(
+                bytes
+                    .iter()
+                    .map(|&(from, to)| byte_class(from, to))
+                    .collect::<String>(),
+            ),
+        }
+    }
+    if !ascii.is_empty() {
+        alternatives.insert(0, format!("[{}]", ascii));
+    }
+    match alternatives.len() {
+        // a class that matches no byte, \x00-\xFF is not a valid range for a signed char.
+        0 => "[^\\x00-\\x7F\\x80-\\xFF]".to_owned(),
+        1 if !ascii.is_empty() => alternatives.remove(0),
+        _ => format!("(?:{})", alternatives.join("|")),
+    }
+}
+
+const MAX_CHAR: u32 = 0x10_FFFF;
+
+/// The characters that `.` does not match.
+const LINE_BREAK: &[(u32, u32)] = &[(0xA, 0xA), (0xD, 0xD)];
+
+/// The characters of `\s`.
+const SPACE: &[(u32, u32)] = &[(0x9, 0xA), (0xD, 0xD), (0x20, 0x20)];
+
+/// The characters of `\i`, which are the NameStartChar of XML 1.0 fifth edition.
+const NAME_START: &[(u32, u32)] = &[
+    (0x3A, 0x3A),
+    (0x41, 0x5A),
+    (0x5F, 0x5F),
+    (0x61, 0x7A),
+    (0xC0, 0xD6),
+    (0xD8, 0xF6),
+    (0xF8, 0x2FF),
+    (0x370, 0x37D),
+    (0x37F, 0x1FFF),
+    (0x200C, 0x200D),
+    (0x2070, 0x218F),
+    (0x2C00, 0x2FEF),
+    (0x3001, 0xD7FF),
+    (0xF900, 0xFDCF),
+    (0xFDF0, 0xFFFD),
+    (0x10000, 0xE_FFFF),
+];
+
+/// The characters that `\c` adds to `\i`.
+const NAME: &[(u32, u32)] = &[
+    (0x2D, 0x2E),
+    (0x30, 0x39),
+    (0xB7, 0xB7),
+    (0x300, 0x36F),
+    (0x203F, 0x2040),
+];
+
+/// The ASCII characters of `\w`, i.e. those that are not punctuation, separators or controls, and
+/// everything beyond ASCII.
+const WORD: &[(u32, u32)] = &[
+    (0x24, 0x24),
+    (0x2B, 0x2B),
+    (0x30, 0x39),
+    (0x3C, 0x3E),
+    (0x41, 0x5A),
+    (0x5E, 0x5E),
+    (0x60, 0x7A),
+    (0x7C, 0x7C),
+    (0x7E, 0x7E),
+    (0x80, MAX_CHAR),
+];
+
+/// A set of characters or bytes as sorted, non-overlapping, non-adjacent inclusive ranges.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+struct Ranges(Vec<(u32, u32)>);
+
+impl Ranges {
+    fn range(from: u32, to: u32) -> Self {
+        Ranges(vec![(from, to)])
+    }
+
+    fn from_slice(ranges: &[(u32, u32)]) -> Self {
+        Ranges::default().union(&Ranges(ranges.to_vec()))
+    }
+
+    fn union(&self, other: &Ranges) -> Self {
+        let mut all: Vec<(u32, u32)> = self.0.iter().chain(&other.0).copied().collect();
+        all.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::new();
+        for (from, to) in all {
+            match merged.last_mut() {
+                Some(last) if from <= last.1.saturating_add(1) => last.1 = last.1.max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+        Ranges(merged)
+    }
+
+    fn complement(&self, max: u32) -> Self {
+        let mut result = Vec::new();
+        let mut next = 0;
+        for &(from, to) in &self.0 {
+            if from > next {
+                result.push((next, from - 1));
+            }
+            next = to + 1;
+        }
+        if next <= max {
+            result.push((next, max));
+        }
+        Ranges(result)
+    }
+
+    fn subtract(&self, other: &Ranges, max: u32) -> Self {
+        self.complement(max).union(other).complement(max)
+    }
+
+    /// The UTF-8 encodings of these characters as sequences of byte ranges, e.g. `é-ë` is
+    /// `[(0xC3, 0xC3), (0xA9, 0xAB)]`. Surrogates, which are not characters, are skipped.
+    fn to_utf8(&self) -> Vec<Vec<(u8, u8)>> {
+        let chars = self.subtract(&Ranges::range(0xD800, 0xDFFF), MAX_CHAR);
+        let mut sequences = Vec::new();
+        let mut todo: Vec<(u32, u32)> = chars.0.iter().rev().copied().collect();
+        'todo: while let Some((from, to)) = todo.pop() {
+            // split the range where the length of the encoding changes.
+            for &max in &[0x7F, 0x7FF, 0xFFFF] {
+                if from <= max && max < to {
+                    todo.push((max + 1, to));
+                    todo.push((from, max));
+                    continue 'todo;
+                }
+            }
+            if to <= 0x7F {
+                sequences.push(vec![(from as u8, to as u8)]);
+                continue;
+            }
+            // split the range until all but the first differing byte cover 0x80-0xBF.
+            for i in 1..4 {
+                let mask = (1 << (6 * i)) - 1;
+                if from & !mask != to & !mask {
+                    if from & mask != 0 {
+                        todo.push(((from | mask) + 1, to));
+                        todo.push((from, from | mask));
+                        continue 'todo;
+                    }
+                    if to & mask != mask {
+                        todo.push((to & !mask, to));
+                        todo.push((from, (to & !mask) - 1));
+                        continue 'todo;
+                    }
+                }
+            }
+            let (mut first, mut last) = ([0; 4], [0; 4]);
+            let first = std::char::from_u32(from).unwrap().encode_utf8(&mut first);
+            let last = std::char::from_u32(to).unwrap().encode_utf8(&mut last);
+            sequences.push(first.bytes().zip(last.bytes()).collect());
+        }
+        sequences
+    }
+}
+
+#[test]
+fn pattern_to_rust() {
+    let p = Pattern::new("#[\\dA-F]{6}([\\dA-F][\\dA-F])?").unwrap();
+    assert_eq!(
+        p.to_rust().unwrap(),
+        "^(?:\\#[\\dA-F]{6}(?:[\\dA-F][\\dA-F])?)$"
+    );
+    let regex = p.regex().unwrap();
+    assert!(regex.is_match("#40800F"));
+    assert!(regex.is_match("#FF40800F"));
+    assert!(!regex.is_match("#40800"));
+    assert!(!regex.is_match("x#40800F"));
+}
+
+#[test]
+fn pattern_implicit_anchors() {
+    let regex = Pattern::new("a|b").unwrap().regex().unwrap();
+    assert!(regex.is_match("a"));
+    assert!(!regex.is_match("ab"));
+    // ^ and $ are ordinary characters.
+    let regex = Pattern::new("^a$").unwrap().regex().unwrap();
+    assert!(regex.is_match("^a$"));
+    assert!(!regex.is_match("a"));
+}
+
+#[test]
+fn pattern_name_escapes() {
+    let regex = Pattern::new("coda\\c*").unwrap().regex().unwrap();
+    assert!(regex.is_match("coda"));
+    assert!(regex.is_match("codaSquare-2.x"));
+    assert!(!regex.is_match("coda square"));
+    let regex = Pattern::new("\\i\\c*").unwrap().regex().unwrap();
+    assert!(regex.is_match("_x1"));
+    assert!(regex.is_match("é"));
+    assert!(!regex.is_match("1x"));
+    let regex = Pattern::new("\\I\\C").unwrap().regex().unwrap();
+    assert!(regex.is_match("1 "));
+    assert!(!regex.is_match("a "));
+}
+
+#[test]
+fn pattern_subtraction() {
+    let p = Pattern::new("[a-z-[aeiou]]+").unwrap();
+    let regex = p.regex().unwrap();
+    assert!(regex.is_match("xyz"));
+    assert!(!regex.is_match("xaz"));
+    assert_eq!(p.to_cpp().unwrap(), "[b-df-hj-np-tv-z]+");
+    // the negation applies before the subtraction.
+    let p = Pattern::new("[^a-z-[0-9]]").unwrap();
+    let regex = p.regex().unwrap();
+    assert!(regex.is_match("A"));
+    assert!(!regex.is_match("5"));
+    assert!(!regex.is_match("q"));
+    let cpp = cpp_bytes_regex(&p);
+    assert!(cpp.is_match("A".as_bytes()));
+    assert!(cpp.is_match("é".as_bytes()));
+    assert!(!cpp.is_match("5".as_bytes()));
+    assert!(!cpp.is_match("q".as_bytes()));
+}
+
+/// The C++ translation of `pattern` as a `regex::bytes::Regex` that, like `std::regex` with a
+/// `std::string`, matches bytes rather than characters.
+#[cfg(test)]
+fn cpp_bytes_regex(pattern: &Pattern) -> regex::bytes::Regex {
+    let cpp = pattern.to_cpp().unwrap();
+    regex::bytes::Regex::new(&format!("(?-u)^(?:{})$", cpp)).unwrap()
+}
+
+#[test]
+fn pattern_to_cpp() {
+    let cpp = |s: &str| Pattern::new(s).unwrap().to_cpp().unwrap();
+    assert_eq!(
+        cpp("[^:Z]*"),
+        "(?:[\\x00-9\\x3B-Y\\x5B-\\x7F]|[\\xC2-\\xDF][\\x80-\\xBF]|\\xE0[\\xA0-\\xBF][\\x80-\\xBF]|\
+         [\\xE1-\\xEC][\\x80-\\xBF][\\x80-\\xBF]|\\xED[\\x80-\\x9F][\\x80-\\xBF]|\
+         [\\xEE-\\xEF][\\x80-\\xBF][\\x80-\\xBF]|\\xF0[\\x90-\\xBF][\\x80-\\xBF][\\x80-\\xBF]|\
+         [\\xF1-\\xF3][\\x80-\\xBF][\\x80-\\xBF][\\x80-\\xBF]|\\xF4[\\x80-\\x8F][\\x80-\\xBF][\\x80-\\xBF])*"
+    );
+    assert_eq!(
+        cpp("([ ]*)|([1-9][0-9]*(, ?[1-9][0-9]*)*)"),
+        "(?:[\\x20]*)|(?:[1-9][0-9]*(?:, ?[1-9][0-9]*)*)"
+    );
+    assert_eq!(cpp("\\d\\s"), "[0-9][\\x09-\\x0A\\x0D\\x20]");
+    assert_eq!(cpp("é+"), "(?:\\xC3\\xA9)+");
+    assert!(Pattern::new("\\p{Lu}").unwrap().to_cpp().is_err());
+}
+
+#[test]
+fn pattern_to_cpp_non_ascii() {
+    let cpp = |s: &str| cpp_bytes_regex(&Pattern::new(s).unwrap());
+    // a negated class matches a multi-byte character as a whole, and only once.
+    let regex = cpp("[^é]");
+    assert!(regex.is_match("è".as_bytes()));
+    assert!(regex.is_match("𝄞".as_bytes()));
+    assert!(!regex.is_match("é".as_bytes()));
+    assert!(!regex.is_match("èè".as_bytes()));
+    assert!(!regex.is_match(b"\xC3"));
+    let regex = cpp("a.b{2,}c{1,3}");
+    assert!(regex.is_match("a€bbc".as_bytes()));
+    assert!(!regex.is_match("a\nbbc".as_bytes()));
+    let regex = cpp("coda\\c*");
+    assert!(regex.is_match("codaÉtoile".as_bytes()));
+    assert!(!regex.is_match("coda×".as_bytes()));
+    assert!(!regex.is_match("segno".as_bytes()));
+    // a class that matches nothing.
+    assert!(!cpp("[a-[a]]").is_match(b"a"));
+}
+
+#[test]
+fn pattern_or() {
+    let p = Pattern::new("a+").unwrap().or(Pattern::new("b").unwrap());
+    assert_eq!(p.source(), "a+|b");
+    let regex = p.regex().unwrap();
+    assert!(regex.is_match("aa"));
+    assert!(regex.is_match("b"));
+    assert!(!regex.is_match("ab"));
+}
+
+#[test]
+fn pattern_errors() {
+    for bad in &["(a", "a)", "[a", "[]", "*", "a{3,2}", "\\q", "[z-a]"] {
+        assert!(Pattern::new(bad).is_err(), "{}", bad);
+    }
+}
@@ -1,10 +1,12 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
 use crate::xsd::constants::{
-    ANNOTATION, ENUMERATION, LENGTH, MAX_EXCLUSIVE, MAX_INCLUSIVE, MAX_LENGTH, MIN_EXCLUSIVE,
-    MIN_INCLUSIVE, MIN_LENGTH, NAME, PATTERN, RESTRICTION,
+    ANNOTATION, BASE, ENUMERATION, FRACTION_DIGITS, LENGTH, MAX_EXCLUSIVE, MAX_INCLUSIVE,
+    MAX_LENGTH, MIN_EXCLUSIVE, MIN_INCLUSIVE, MIN_LENGTH, NAME, PATTERN, RESTRICTION, TOTAL_DIGITS,
+    VALUE, WHITE_SPACE,
 };
 use crate::xsd::restriction::FacetType::Pattern;
+use crate::xsd::write::{type_name, Node};
 use crate::xsd::{base_attribute, value_attribute, Xsd};
 use exile::Element;
 
@@ -55,6 +57,7 @@ length|minLength|maxLength|enumeration|whiteSpace|pattern)*))
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub enum FacetType {
     Enumeration,
+    FractionDigits,
     Length,
     MaxExclusive,
     MaxInclusive,
@@ -63,12 +66,15 @@ pub enum FacetType {
     MinInclusive,
     MinLength,
     Pattern,
+    TotalDigits,
+    WhiteSpace,
 }
 
 impl Display for FacetType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             FacetType::Enumeration => ENUMERATION,
+            FacetType::FractionDigits => FRACTION_DIGITS,
             FacetType::Length => LENGTH,
             FacetType::MaxExclusive => MAX_EXCLUSIVE,
             FacetType::MaxInclusive => MAX_INCLUSIVE,
@@ -77,6 +83,8 @@ impl Display for FacetType {
             FacetType::MinInclusive => MIN_INCLUSIVE,
             FacetType::MinLength => MIN_LENGTH,
             FacetType::Pattern => PATTERN,
+            FacetType::TotalDigits => TOTAL_DIGITS,
+            FacetType::WhiteSpace => WHITE_SPACE,
         };
         write!(f, "{}", s)
     }
@@ -87,6 +95,7 @@ impl FacetType {
         let s = s.as_ref();
         match s {
             ENUMERATION => Ok(FacetType::Enumeration),
+            FRACTION_DIGITS => Ok(FacetType::FractionDigits),
             LENGTH => Ok(FacetType::Length),
             MAX_EXCLUSIVE => Ok(FacetType::MaxExclusive),
             MAX_INCLUSIVE => Ok(FacetType::MaxInclusive),
@@ -95,6 +104,8 @@ impl FacetType {
             MIN_INCLUSIVE => Ok(FacetType::MinInclusive),
             MIN_LENGTH => Ok(FacetType::MinLength),
             PATTERN => Ok(FacetType::Pattern),
+            TOTAL_DIGITS => Ok(FacetType::TotalDigits),
+            WHITE_SPACE => Ok(FacetType::WhiteSpace),
             _ => raise!("unsupported or invalid facet type {}", s),
         }
     }
@@ -115,9 +126,53 @@ impl Number {
     }
 }
 
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::Integer(i) => write!(f, "{}", i),
+            Number::Decimal(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+/// The value of a `whiteSpace` facet, i.e. how whitespace in a value is to be normalized.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum WhiteSpace {
+    /// The value is not changed.
+    Preserve,
+    /// Each tab, line feed and carriage return is replaced with a space.
+    Replace,
+    /// As with `Replace`, then runs of spaces are collapsed to a single space and leading and
+    /// trailing spaces are removed.
+    Collapse,
+}
+
+impl Display for WhiteSpace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            WhiteSpace::Preserve => "preserve",
+            WhiteSpace::Replace => "replace",
+            WhiteSpace::Collapse => "collapse",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl WhiteSpace {
+    fn parse<S: AsRef<str>>(s: S) -> Result<WhiteSpace> {
+        match s.as_ref() {
+            "preserve" => Ok(WhiteSpace::Preserve),
+            "replace" => Ok(WhiteSpace::Replace),
+            "collapse" => Ok(WhiteSpace::Collapse),
+            other => raise!("invalid whiteSpace value '{}'", other),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Facet {
     Enumeration(String),
+    FractionDigits(u64),
     Length(u64),
     MaxExclusive(Number),
     MaxInclusive(Number),
@@ -126,15 +181,55 @@ pub enum Facet {
     MinInclusive(Number),
     MinLength(u64),
     Pattern(String),
+    TotalDigits(u64),
+    WhiteSpace(WhiteSpace),
 }
 
 impl Facet {
+    pub fn facet_type(&self) -> FacetType {
+        match self {
+            Facet::Enumeration(_) => FacetType::Enumeration,
+            Facet::FractionDigits(_) => FacetType::FractionDigits,
+            Facet::Length(_) => FacetType::Length,
+            Facet::MaxExclusive(_) => FacetType::MaxExclusive,
+            Facet::MaxInclusive(_) => FacetType::MaxInclusive,
+            Facet::MaxLength(_) => FacetType::MaxLength,
+            Facet::MinExclusive(_) => FacetType::MinExclusive,
+            Facet::MinInclusive(_) => FacetType::MinInclusive,
+            Facet::MinLength(_) => FacetType::MinLength,
+            Facet::Pattern(_) => FacetType::Pattern,
+            Facet::TotalDigits(_) => FacetType::TotalDigits,
+            Facet::WhiteSpace(_) => FacetType::WhiteSpace,
+        }
+    }
+
+    /// The facet's `value` attribute as it would be written in the XSD.
+    pub fn value(&self) -> String {
+        match self {
+            Facet::Enumeration(s) | Facet::Pattern(s) => s.clone(),
+            Facet::FractionDigits(x)
+            | Facet::Length(x)
+            | Facet::MaxLength(x)
+            | Facet::MinLength(x)
+            | Facet::TotalDigits(x) => x.to_string(),
+            Facet::WhiteSpace(w) => w.to_string(),
+            Facet::MaxExclusive(n)
+            | Facet::MaxInclusive(n)
+            | Facet::MinExclusive(n)
+            | Facet::MinInclusive(n) => n.to_string(),
+        }
+    }
+
     fn from_xml(node: &Element, xsd: &Xsd) -> Result<Facet> {
         check!(FACET, node, xsd)?;
         let t = FacetType::parse(&node.name)?;
         let v = value_attribute(node)?;
         let result = match t {
             FacetType::Enumeration => Facet::Enumeration(v.clone()),
+            FacetType::FractionDigits => {
+                let x = wrap!(v.parse::<u64>())?;
+                Facet::FractionDigits(x)
+            }
             FacetType::Length => {
                 let x = wrap!(v.parse::<u64>())?;
                 Facet::Length(x)
@@ -164,6 +259,14 @@ impl Facet {
                 Facet::MinLength(x)
             }
             Pattern => Facet::Pattern(v.clone()),
+            FacetType::TotalDigits => {
+                let x = wrap!(v.parse::<u64>())?;
+                if x == 0 {
+                    return raise!("totalDigits must be greater than zero");
+                }
+                Facet::TotalDigits(x)
+            }
+            FacetType::WhiteSpace => Facet::WhiteSpace(WhiteSpace::parse(v)?),
         };
         Ok(result)
     }
@@ -187,7 +290,7 @@ impl Restriction {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(RESTRICTION, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let base = base_attribute(node)?;
         let base = BaseType::parse_prefixed(base.as_str(), xsd.prefix.as_str())?;
         let mut annotation = None;
@@ -197,7 +300,7 @@ impl Restriction {
             if t == ANNOTATION {
                 annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?);
             } else {
-                let facet = Facet::from_xml(inner, xsd)?;
+                let facet = Facet::from_xml(inner, xsd).map_err(|e| e.at(xsd.locate(inner)))?;
                 facets.push(facet);
             }
         }
@@ -209,6 +312,15 @@ impl Restriction {
             facets,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, RESTRICTION)
+            .attr(BASE, type_name(&self.base, xsd))
+            .annotation(xsd, &self.annotation)
+            .children(self.facets.iter().map(|facet| {
+                Node::new(xsd, facet.facet_type().to_string()).attr(VALUE, facet.value())
+            }))
+    }
 }
 
 #[test]
@@ -223,9 +335,9 @@ fn parse() {
         "#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:restriction:18375205485067440936".to_owned();
+    let want_id = "element:foo/restriction[0]".to_owned();
     let want_doc = "";
-    let r = Restriction::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let r = Restriction::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = r.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", r.id);
@@ -257,3 +369,32 @@ fn parse() {
         panic!("wrong facet type");
     }
 }
+
+#[test]
+fn parse_digits_and_white_space() {
+    let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::SimpleType, "foo".into());
+    let xml_str = r#"
+		<xs:restriction base="xs:decimal">
+			<xs:totalDigits value="5"/>
+			<xs:fractionDigits value="2"/>
+			<xs:whiteSpace value="collapse"/>
+		</xs:restriction>
+        "#;
+    let doc = exile::parse(xml_str).unwrap();
+    let r = Restriction::from_xml(
+        doc.root(),
+        Lineage::Parent(parent),
+        &Xsd::fragment("xs", doc.root()),
+    )
+    .unwrap();
+    assert_eq!(
+        r.facets,
+        vec![
+            Facet::TotalDigits(5),
+            Facet::FractionDigits(2),
+            Facet::WhiteSpace(WhiteSpace::Collapse)
+        ]
+    );
+    assert_eq!(r.facets[2].facet_type(), FacetType::WhiteSpace);
+    assert_eq!(r.facets[2].value(), "collapse");
+}
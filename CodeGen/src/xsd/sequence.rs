@@ -1,10 +1,12 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
+use crate::xsd::any::Any;
 use crate::xsd::choice::Choice;
-use crate::xsd::constants::{ANNOTATION, CHOICE, ELEMENT, GROUP, NAME, SEQUENCE};
+use crate::xsd::constants::{ANNOTATION, ANY, CHOICE, ELEMENT, GROUP, NAME, SEQUENCE};
 use crate::xsd::element::Element;
 use crate::xsd::group::Group;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::{Occurs, Xsd};
 
 #[derive(Clone, Debug)]
@@ -17,6 +19,7 @@ pub struct Sequence {
 
 #[derive(Clone, Debug)]
 pub enum Member {
+    Any(Any),
     Choice(Choice),
     Element(Element),
     Group(Group),
@@ -33,13 +36,14 @@ impl Sequence {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(SEQUENCE, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut annotation = None;
         let mut members = Vec::new();
         for inner in node.children() {
             let t = inner.name.as_str();
             match t {
                 ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
+                ANY => members.push(Member::Any(Any::from_xml(inner, lineage.clone(), xsd)?)),
                 CHOICE => members.push(Member::Choice(Choice::from_xml(
                     inner,
                     lineage.clone(),
@@ -56,7 +60,7 @@ impl Sequence {
                     lineage.clone(),
                     xsd,
                 )?)),
-                _ => return raise!("unknown {} member: '{}'", SEQUENCE, t),
+                _ => return raise_at!(xsd, inner, "unknown {} member: '{}'", SEQUENCE, t),
             }
         }
         Ok(Sequence {
@@ -66,6 +70,19 @@ impl Sequence {
             members,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        Node::new(xsd, SEQUENCE)
+            .occurs(&self.occurs)
+            .annotation(xsd, &self.annotation)
+            .children(self.members.iter().map(|member| match member {
+                Member::Any(x) => x.to_xml(xsd),
+                Member::Choice(x) => x.to_xml(xsd),
+                Member::Element(x) => x.to_xml(xsd),
+                Member::Group(x) => x.to_xml(xsd),
+                Member::Sequence(x) => x.to_xml(xsd),
+            }))
+    }
 }
 
 #[test]
@@ -93,9 +110,9 @@ fn parse() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let seq = Sequence::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let seq = Sequence::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_id = format!("{}", seq.id);
-    let want_id = "element:foo:sequence:12280079412076832312";
+    let want_id = "element:foo/sequence[0]";
     assert_eq!(got_id.as_str(), want_id);
     let got_doc = seq.documentation();
     let want_doc = "";
@@ -113,11 +130,13 @@ fn parse() {
         Member::Element(_) => {}
         Member::Group(_) => panic!("expecting '{}', got '{}'", "Element", "Group"),
         Member::Sequence(_) => panic!("expecting '{}', got '{}'", "Element", "Sequence"),
+        Member::Any(_) => panic!("expecting '{}', got '{}'", "Element", "Any"),
     };
     match seq.members.get(7).unwrap() {
         Member::Choice(_) => panic!("expecting '{}', got '{}'", "Sequence", "Choice"),
         Member::Element(_e) => panic!("expecting '{}', got '{}'", "Sequence", "Element"),
         Member::Group(_) => panic!("expecting '{}', got '{}'", "Sequence", "Group"),
         Member::Sequence(_) => {}
+        Member::Any(_) => panic!("expecting '{}', got '{}'", "Sequence", "Any"),
     };
 }
@@ -3,6 +3,7 @@ use crate::xsd::annotation::Annotation;
 use crate::xsd::constants::{ANNOTATION, EXTENSION, NAME, SIMPLE_CONTENT};
 use crate::xsd::extension::Extension;
 use crate::xsd::id::{Id, Lineage, RootNodeType};
+use crate::xsd::write::Node;
 use crate::xsd::Xsd;
 
 #[derive(Clone, Debug)]
@@ -27,7 +28,7 @@ impl SimpleContent {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(SIMPLE_CONTENT, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut annotation = None;
         let mut payload = None;
         for inner in node.children() {
@@ -41,7 +42,7 @@ impl SimpleContent {
                         xsd,
                     )?));
                 }
-                _ => return raise!("unsupported simpleContent node '{}'", t),
+                _ => return raise_at!(xsd, inner, "unsupported simpleContent node '{}'", t),
             }
         }
         let payload = if let Some(p) = payload {
@@ -55,6 +56,15 @@ impl SimpleContent {
             payload,
         })
     }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let payload = match &self.payload {
+            Payload::Extension(x) => x.to_xml(xsd),
+        };
+        Node::new(xsd, SIMPLE_CONTENT)
+            .annotation(xsd, &self.annotation)
+            .child(payload)
+    }
 }
 
 #[test]
@@ -71,9 +81,9 @@ fn parse() {
 	</xs:simpleContent>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:simpleContent:17852308769138094955".to_owned();
+    let want_id = "element:foo/simpleContent[0]".to_owned();
     let want_doc = "";
-    let sc = SimpleContent::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let sc = SimpleContent::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = sc.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", sc.id);
@@ -92,6 +102,9 @@ fn parse() {
                     AttributeGroup::Def(_) => panic!("expected Ref got Def"),
                     AttributeGroup::Ref(r) => assert_eq!(r.ref_.as_str(), "part-name-text"),
                 },
+                AttributeItem::AnyAttribute(_) => {
+                    panic!("expected 'AttributeGroup' but got 'AnyAttribute'")
+                }
             }
         }
     }
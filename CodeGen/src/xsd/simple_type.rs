@@ -5,6 +5,7 @@ use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::list::List;
 use crate::xsd::restriction::Restriction;
 use crate::xsd::union::Union;
+use crate::xsd::write::Node;
 use crate::xsd::{name_attribute, Xsd};
 
 #[derive(Clone, Debug)]
@@ -32,7 +33,60 @@ impl SimpleType {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(SIMPLE_TYPE, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let name = name_attribute(node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
+        Self::parse(node, id, lineage, xsd, name)
+    }
+
+    /// Parses an `xs:simpleType` that has no `name` attribute, e.g. one that is defined in-line
+    /// within an `xs:union`, giving it the `name` provided by the caller.
+    pub fn from_xml_anonymous<S: Into<String>>(
+        node: &exile::Element,
+        lineage: Lineage,
+        xsd: &Xsd,
+        name: S,
+    ) -> Result<Self> {
+        check!(SIMPLE_TYPE, node, xsd)?;
+        let name = name.into();
+        // the synthesized name gives the nested type, and anything nested in it, a stable id.
+        let (id, lineage) = match lineage {
+            Lineage::Parent(p) => {
+                let id = Id::child(&p, SIMPLE_TYPE, Some(&name));
+                (id.clone(), Lineage::Parent(id))
+            }
+            index => Id::make(index, node, xsd)?,
+        };
+        Self::parse(node, id, lineage, xsd, name)
+    }
+
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        self.write(xsd, Some(&self.name))
+    }
+
+    /// Writes the `xs:simpleType` without the name that was given to it by `from_xml_anonymous`.
+    pub fn to_xml_anonymous(&self, xsd: &Xsd) -> Node {
+        self.write(xsd, None)
+    }
+
+    fn write(&self, xsd: &Xsd, name: Option<&String>) -> Node {
+        let payload = match &self.payload {
+            Payload::Restriction(x) => x.to_xml(xsd),
+            Payload::List(x) => x.to_xml(xsd),
+            Payload::Union(x) => x.to_xml(xsd),
+        };
+        Node::new(xsd, SIMPLE_TYPE)
+            .attr_opt(NAME, name)
+            .annotation(xsd, &self.annotation)
+            .child(payload)
+    }
+
+    fn parse(
+        node: &exile::Element,
+        id: Id,
+        lineage: Lineage,
+        xsd: &Xsd,
+        name: String,
+    ) -> Result<Self> {
         let mut annotation = None;
         let mut payload = None;
         for inner in node.children() {
@@ -54,14 +108,14 @@ impl SimpleType {
                     xsd,
                 )?)),
                 _ => {
-                    return raise!("unexpected element name '{}'", t);
+                    return raise_at!(xsd, inner, "unexpected element name '{}'", t);
                 }
             };
         }
         let payload = payload.ok_or(make_err!("{} is incomplete", SIMPLE_TYPE))?;
         Ok(SimpleType {
             id,
-            name: name_attribute(node)?,
+            name,
             annotation,
             payload,
         })
@@ -85,7 +139,8 @@ fn parse_enum() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let st = SimpleType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let st =
+        SimpleType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(st.id.index().unwrap(), want_index);
     let got_id = st.id.to_string();
     let want_id = "simpleType:above-below".to_owned();
@@ -130,7 +185,8 @@ fn parse_numeric() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 4;
-    let st = SimpleType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let st =
+        SimpleType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(st.id.index().unwrap(), want_index);
     let got_id = st.id.to_string();
     let want_id = "simpleType:midi-16".to_owned();
@@ -176,7 +232,8 @@ fn parse_pattern() {
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
     let want_index: u64 = 3;
-    let st = SimpleType::from_xml(&xml, Lineage::Index(want_index), &Xsd::new("xs")).unwrap();
+    let st =
+        SimpleType::from_xml(xml, Lineage::Index(want_index), &Xsd::fragment("xs", xml)).unwrap();
     assert_eq!(st.id.index().unwrap(), want_index);
     let got_id = st.id.to_string();
     let want_id = "simpleType:time-only".to_owned();
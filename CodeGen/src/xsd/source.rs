@@ -0,0 +1,283 @@
+use crate::xsd::id::node_name;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Where a node was found in the XSD document, i.e. the position of the `<` of its start tag.
+/// `line` and `column` are 1-based.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Location {
+    pub file: Option<Arc<str>>,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Location {
+    pub fn new(file: Option<&str>, line: u32, column: u32) -> Self {
+        Self {
+            file: file.map(Arc::from),
+            line,
+            column,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Records where the elements of a parsed document are: the position of each element among its
+/// siblings of the same name, the position of a named element among its siblings of the same name
+/// and node name, see `Id::make`, and, when the text is available, its `Location` in the text. The XML
+/// parser does not record locations, so the text is scanned for start tags, which are then paired,
+/// in document order, with the elements of the tree.
+///
+/// Elements are keyed by their address, so a `SourceMap` is only valid while the document it was
+/// built from is alive and unmoved.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceMap {
+    file: Option<Arc<str>>,
+    positions: HashMap<usize, (u32, u32)>,
+    ordinals: HashMap<usize, u64>,
+    name_ordinals: HashMap<usize, u64>,
+}
+
+impl SourceMap {
+    /// If the start tags found in `text` do not line up with the elements under `root`, then there
+    /// will be no locations rather than wrong ones.
+    pub(crate) fn new(file: Option<&Path>, text: &str, root: &exile::Element) -> Self {
+        let mut map = Self::from_tree(root);
+        map.file = file.map(|p| Arc::from(p.display().to_string().as_str()));
+        let tags = start_tags(text);
+        let mut elements = Vec::new();
+        pre_order(root, &mut elements);
+        if tags.len() == elements.len() {
+            map.positions = elements.into_iter().zip(tags).collect();
+        }
+        map
+    }
+
+    /// A map without locations, for a document that was not parsed from text by us.
+    pub(crate) fn from_tree(root: &exile::Element) -> Self {
+        let mut ordinals = HashMap::new();
+        let mut name_ordinals = HashMap::new();
+        ordinals.insert(address(root), 0);
+        number_children(root, &mut ordinals, &mut name_ordinals);
+        Self {
+            file: None,
+            positions: HashMap::new(),
+            ordinals,
+            name_ordinals,
+        }
+    }
+
+    /// The position of `node` among its siblings of the same name, e.g. 1 for the second
+    /// `xs:sequence` of a parent.
+    pub(crate) fn ordinal(&self, node: &exile::Element) -> Option<u64> {
+        self.ordinals.get(&address(node)).copied()
+    }
+
+    /// The position of a named `node` among its siblings of the same name and node name, e.g. 1
+    /// for the second `<xs:element ref="pitch"/>` of a parent.
+    pub(crate) fn name_ordinal(&self, node: &exile::Element) -> Option<u64> {
+        self.name_ordinals.get(&address(node)).copied()
+    }
+
+    pub(crate) fn locate(&self, node: &exile::Element) -> Option<Location> {
+        let &(line, column) = self.positions.get(&address(node))?;
+        Some(Location {
+            file: self.file.clone(),
+            line,
+            column,
+        })
+    }
+}
+
+fn address(node: &exile::Element) -> usize {
+    node as *const exile::Element as usize
+}
+
+fn number_children(
+    node: &exile::Element,
+    ordinals: &mut HashMap<usize, u64>,
+    name_ordinals: &mut HashMap<usize, u64>,
+) {
+    let mut counts: HashMap<&str, u64> = HashMap::new();
+    let mut name_counts: HashMap<(&str, &str), u64> = HashMap::new();
+    for child in node.children() {
+        let count = counts.entry(child.name.as_str()).or_insert(0);
+        ordinals.insert(address(child), *count);
+        *count += 1;
+        if let Some(name) = node_name(child) {
+            let count = name_counts.entry((child.name.as_str(), name)).or_insert(0);
+            name_ordinals.insert(address(child), *count);
+            *count += 1;
+        }
+        number_children(child, ordinals, name_ordinals);
+    }
+}
+
+fn pre_order(node: &exile::Element, out: &mut Vec<usize>) {
+    out.push(address(node));
+    for child in node.children() {
+        pre_order(child, out);
+    }
+}
+
+/// Returns the line and column of each start tag, skipping comments, processing instructions,
+/// CDATA sections, the doctype and end tags.
+fn start_tags(text: &str) -> Vec<(u32, u32)> {
+    let mut cursor = Cursor::new(text);
+    let mut tags = Vec::new();
+    while let Some(c) = cursor.peek() {
+        if c != '<' {
+            cursor.next();
+            continue;
+        }
+        let position = (cursor.line, cursor.column);
+        if cursor.starts_with("<!--") {
+            cursor.skip_past("-->");
+        } else if cursor.starts_with("<![CDATA[") {
+            cursor.skip_past("]]>");
+        } else if cursor.starts_with("<?") {
+            cursor.skip_past("?>");
+        } else if cursor.starts_with("<!") {
+            cursor.skip_declaration();
+        } else if cursor.starts_with("</") {
+            cursor.skip_past(">");
+        } else {
+            tags.push(position);
+            cursor.skip_tag();
+        }
+    }
+    tags
+}
+
+struct Cursor<'a> {
+    rest: &'a str,
+    line: u32,
+    column: u32,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            rest: text,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.rest = &self.rest[c.len_utf8()..];
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.rest.starts_with(s)
+    }
+
+    fn skip_past(&mut self, s: &str) {
+        while !self.rest.is_empty() && !self.starts_with(s) {
+            self.next();
+        }
+        for _ in s.chars() {
+            self.next();
+        }
+    }
+
+    /// Skips a start tag, the `>` of an attribute value does not end the tag.
+    fn skip_tag(&mut self) {
+        let mut quote = None;
+        while let Some(c) = self.next() {
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(_) => {}
+                None if c == '"' || c == '\'' => quote = Some(c),
+                None if c == '>' => return,
+                None => {}
+            }
+        }
+    }
+
+    /// Skips a declaration such as `<!DOCTYPE ...>`, including any internal subset in brackets.
+    fn skip_declaration(&mut self) {
+        let mut depth = 0;
+        while let Some(c) = self.next() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '>' if depth <= 0 => return,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn locate_elements() {
+    let text = r#"<?xml version="1.0"?>
+<!-- a comment with <xs:fake> in it -->
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="a>b">
+        <xs:restriction base="xs:string"/>
+    </xs:simpleType>
+</xs:schema>"#;
+    let doc = exile::parse(text).unwrap();
+    let map = SourceMap::new(Some(Path::new("test.xsd")), text, doc.root());
+    let root = map.locate(doc.root()).unwrap();
+    assert_eq!(root.to_string(), "test.xsd:3:1");
+    let simple_type = doc.root().children().next().unwrap();
+    assert_eq!(
+        map.locate(simple_type).unwrap(),
+        Location::new(Some("test.xsd"), 4, 5)
+    );
+    let restriction = simple_type.children().next().unwrap();
+    assert_eq!(map.locate(restriction).unwrap().line, 5);
+    assert_eq!(map.ordinal(restriction), Some(0));
+}
+
+#[test]
+fn ordinals() {
+    let text = r#"<a><b/><c/><b/><b><b/></b></a>"#;
+    let doc = exile::parse(text).unwrap();
+    let map = SourceMap::from_tree(doc.root());
+    let got: Vec<(String, Option<u64>)> = doc
+        .root()
+        .children()
+        .map(|child| (child.name.clone(), map.ordinal(child)))
+        .collect();
+    let want = vec![
+        ("b".to_owned(), Some(0)),
+        ("c".to_owned(), Some(0)),
+        ("b".to_owned(), Some(1)),
+        ("b".to_owned(), Some(2)),
+    ];
+    assert_eq!(got, want);
+    let nested = doc
+        .root()
+        .children()
+        .last()
+        .unwrap()
+        .children()
+        .next()
+        .unwrap();
+    assert_eq!(map.ordinal(nested), Some(0));
+}
@@ -1,15 +1,24 @@
 use crate::error::Result;
 use crate::xsd::annotation::Annotation;
-use crate::xsd::constants::{ANNOTATION, MEMBER_TYPES, NAME, UNION};
+use crate::xsd::constants::{ANNOTATION, MEMBER_TYPES, NAME, SIMPLE_TYPE, UNION};
 use crate::xsd::id::{Id, Lineage, RootNodeType};
 use crate::xsd::primitives::{BaseType, PrefixedParse};
+use crate::xsd::simple_type::SimpleType;
+use crate::xsd::write::{type_name, Node};
 use crate::xsd::Xsd;
 
 #[derive(Clone, Debug)]
 pub struct Union {
     pub id: Id,
     pub annotation: Option<Annotation>,
+    /// The `memberTypes` followed by the in-line `xs:simpleType` members. In-line members are given
+    /// as `BaseType::Custom` using the names synthesized for them, see `simple_types`.
     pub members: Vec<BaseType>,
+    /// The anonymous `xs:simpleType` definitions found in the union. Each is given a name made from
+    /// the name of the nearest named ancestor of the union and its index in `members`, e.g.
+    /// `number-or-normal-member-1`. The name is not that of any simple type in the schema, so an
+    /// in-line member can be told apart from the others by name, see `inline_member`.
+    pub simple_types: Vec<SimpleType>,
 }
 
 impl Union {
@@ -22,42 +31,111 @@ impl Union {
 
     pub fn from_xml(node: &exile::Element, lineage: Lineage, xsd: &Xsd) -> Result<Self> {
         check!(UNION, node, xsd)?;
-        let (id, lineage) = Id::make(lineage, node)?;
+        let (id, lineage) = Id::make(lineage, node, xsd)?;
         let mut items = Vec::new();
-        let members = node
-            .attributes
-            .map()
-            .get(MEMBER_TYPES)
-            .ok_or_else(|| make_err!("'{}' attribute not found", MEMBER_TYPES))?;
-        for item in members.split(' ') {
-            if !item.is_empty() {
-                items.push(item.to_owned());
+        if let Some(member_types) = node.attributes.map().get(MEMBER_TYPES) {
+            for item in member_types.split(' ') {
+                if !item.is_empty() {
+                    items.push(item.to_owned());
+                }
             }
         }
+        let mut members = Vec::new();
+        for item in &items {
+            let base_type = BaseType::parse_prefixed(item.as_str(), xsd.prefix.as_str())?;
+            members.push(base_type);
+        }
         let mut annotation = None;
+        let mut simple_types = Vec::new();
         for inner in node.children() {
             let t = inner.name.as_str();
-            if t == ANNOTATION {
-                annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?);
-                break;
+            match t {
+                ANNOTATION => annotation = Some(Annotation::from_xml(inner, lineage.clone(), xsd)?),
+                SIMPLE_TYPE => {
+                    let owner = match owner_name(&id) {
+                        Some(owner) => owner,
+                        None => {
+                            return raise_at!(
+                            xsd,
+                            inner,
+                            "an in-line member of '{}' needs a named ancestor to be named after",
+                            id
+                        )
+                        }
+                    };
+                    let name = format!("{}-member-{}", owner, members.len());
+                    if xsd.has_simple_type(&name) {
+                        return raise_at!(
+                            xsd,
+                            inner,
+                            "the name '{}' for an in-line member of '{}' is already used by a \
+                             simple type",
+                            name,
+                            id
+                        );
+                    }
+                    let st = SimpleType::from_xml_anonymous(inner, lineage.clone(), xsd, &name)?;
+                    members.push(BaseType::Custom(name));
+                    simple_types.push(st);
+                }
+                _ => return raise_at!(xsd, inner, "unsupported {} node, '{}'", UNION, t),
             }
         }
-        let mut members = Vec::new();
-        for item in &items {
-            let base_type = BaseType::parse_prefixed(item.as_str(), xsd.prefix.as_str())?;
-            members.push(base_type);
+        if members.is_empty() {
+            return raise_at!(xsd, node, "'{}' has no members", UNION);
         }
-        // TODO also parse in-line definitions of xs:simpleType
         let u = Union {
             id,
             annotation,
             members,
+            simple_types,
         };
         Ok(u)
     }
+
+    /// The in-line simple type that `member` was synthesized for, if it is one.
+    pub fn inline_member(&self, member: &BaseType) -> Option<&SimpleType> {
+        match member {
+            BaseType::Custom(name) => self.simple_types.iter().find(|st| &st.name == name),
+            _ => None,
+        }
+    }
+
+    /// Writes the `xs:union`. The members that were synthesized for the in-line simple types are
+    /// written as the simple types themselves rather than in `memberTypes`, in the order that they
+    /// have in `members`.
+    pub fn to_xml(&self, xsd: &Xsd) -> Node {
+        let mut member_types = Vec::new();
+        let mut inline = Vec::new();
+        for member in &self.members {
+            match self.inline_member(member) {
+                Some(st) => inline.push(st.to_xml_anonymous(xsd)),
+                None => member_types.push(type_name(member, xsd)),
+            }
+        }
+        Node::new(xsd, UNION)
+            .attr_opt(
+                MEMBER_TYPES,
+                Some(member_types.join(" ")).filter(|s| !s.is_empty()),
+            )
+            .annotation(xsd, &self.annotation)
+            .children(inline)
+    }
+}
+
+/// The name of the nearest ancestor of `id` that has a name, e.g. the `xs:simpleType` that the
+/// union belongs to, or the `xs:attribute` when that `xs:simpleType` is anonymous.
+fn owner_name(id: &Id) -> Option<&str> {
+    let mut ancestor = id.parent();
+    while let Some(a) = ancestor {
+        if !a.local_name().is_empty() {
+            return Some(a.local_name());
+        }
+        ancestor = a.parent();
+    }
+    None
 }
 
-// TODO fix test
 #[test]
 fn parse() {
     let parent = crate::xsd::id::Id::new(crate::xsd::id::RootNodeType::Element, "foo".into());
@@ -65,18 +143,122 @@ fn parse() {
     let xml_str = r#"<xs:union memberTypes="xs:decimal css-font-size"/>"#;
     let doc = exile::parse(xml_str).unwrap();
     let xml = doc.root();
-    let want_id = "element:foo:union:18365985102726890478".to_owned();
+    let want_id = "element:foo/union[0]".to_owned();
     let want_doc = "";
-    let union = Union::from_xml(&xml, lineage, &Xsd::new("xs")).unwrap();
+    let union = Union::from_xml(xml, lineage, &Xsd::fragment("xs", xml)).unwrap();
     let got_doc = union.documentation();
     assert_eq!(got_doc.as_str(), want_doc);
     let got_id = format!("{}", union.id);
     assert_eq!(got_id, want_id);
-    // let got_type = union.id.entry_type;
-    // assert_eq!(got_type, RootNodeType::Other(UNION.to_owned()));
     let want_members = vec![
         BaseType::Decimal,
         BaseType::Custom("css-font-size".to_owned()),
     ];
     assert_eq!(union.members, want_members);
 }
+
+#[test]
+fn parse_inline_simple_type() {
+    let parent = crate::xsd::id::Id::new(
+        crate::xsd::id::RootNodeType::SimpleType,
+        "number-or-normal".into(),
+    );
+    let lineage = Lineage::Parent(parent);
+    let xml_str = r#"
+    <xs:union memberTypes="xs:decimal">
+        <xs:simpleType>
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="normal"/>
+            </xs:restriction>
+        </xs:simpleType>
+    </xs:union>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let union = Union::from_xml(doc.root(), lineage, &Xsd::fragment("xs", doc.root())).unwrap();
+    let want_members = vec![
+        BaseType::Decimal,
+        BaseType::Custom("number-or-normal-member-1".to_owned()),
+    ];
+    assert_eq!(union.members, want_members);
+    assert_eq!(union.simple_types.len(), 1);
+    let st = union.simple_types.first().unwrap();
+    assert_eq!(st.name, "number-or-normal-member-1");
+}
+
+#[test]
+fn inline_simple_type_names() {
+    let xml_str = r#"
+    <xs:union>
+        <xs:simpleType>
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="normal"/>
+            </xs:restriction>
+        </xs:simpleType>
+    </xs:union>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    // the union of an anonymous simple type is named after the attribute that holds it.
+    let attribute = Id::new(RootNodeType::Attribute, "spacing".into());
+    let simple_type = Id::anonymous_child(&attribute, SIMPLE_TYPE, 0);
+    let union = Union::from_xml(doc.root(), Lineage::Parent(simple_type), &xsd).unwrap();
+    assert_eq!(
+        union.members,
+        vec![BaseType::Custom("spacing-member-0".to_owned())]
+    );
+    // there is nothing to name the member after.
+    assert!(Union::from_xml(doc.root(), Lineage::Index(0), &xsd).is_err());
+}
+
+#[test]
+fn inline_simple_type_name_collision() {
+    let xml_str = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="number-or-normal">
+        <xs:union memberTypes="xs:decimal">
+            <xs:simpleType>
+                <xs:restriction base="xs:token">
+                    <xs:enumeration value="normal"/>
+                </xs:restriction>
+            </xs:simpleType>
+        </xs:union>
+    </xs:simpleType>
+    <xs:simpleType name="number-or-normal-member-1">
+        <xs:restriction base="xs:token"/>
+    </xs:simpleType>
+</xs:schema>"#;
+    let message = format!("{}", Xsd::parse_str(xml_str).unwrap_err());
+    // the location of the in-line xs:simpleType.
+    assert!(message.starts_with("4:13: "), "{}", message);
+    assert!(message.contains("'number-or-normal-member-1' for an in-line member"));
+}
+
+#[test]
+fn no_members_location() {
+    let xml_str = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="empty">
+        <xs:union/>
+    </xs:simpleType>
+</xs:schema>"#;
+    let message = format!("{}", Xsd::parse_str(xml_str).unwrap_err());
+    assert!(message.starts_with("3:9: "), "{}", message);
+    assert!(message.contains("'union' has no members"));
+}
+
+#[test]
+fn to_xml_member_order() {
+    let xml_str = r#"
+    <xs:union memberTypes="xs:decimal">
+        <xs:simpleType>
+            <xs:restriction base="xs:token">
+                <xs:enumeration value="normal"/>
+            </xs:restriction>
+        </xs:simpleType>
+    </xs:union>"#;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::fragment("xs", doc.root());
+    let parent = Id::new(RootNodeType::SimpleType, "number-or-normal".into());
+    let mut union = Union::from_xml(doc.root(), Lineage::Parent(parent), &xsd).unwrap();
+    // an in-line member that comes before a memberTypes member is still written as a child.
+    union.members.reverse();
+    let xml = crate::xsd::write::document(&union.to_xml(&xsd));
+    assert!(xml.contains("memberTypes=\"xs:decimal\""), "{}", xml);
+    assert!(xml.contains("<xs:enumeration value=\"normal\""), "{}", xml);
+}
@@ -7,14 +7,21 @@ macro_rules! check {
     ($expected:expr, $node:expr, $xsd:expr) => {{
         if let Some(ns) = &$node.namespace {
             if ns.as_str() != $xsd.prefix() {
-                return raise!(
+                return raise_at!(
+                    $xsd,
+                    $node,
                     "expected namespace prefix '{}', got '{}'.",
                     $xsd.prefix(),
                     ns.as_str()
                 );
             }
         } else {
-            return raise!("no namespace prefix, expected '{}'.", $xsd.prefix());
+            return raise_at!(
+                $xsd,
+                $node,
+                "no namespace prefix, expected '{}'.",
+                $xsd.prefix()
+            );
         };
         Ok(())
     } as crate::error::Result<()>};
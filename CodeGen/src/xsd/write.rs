@@ -0,0 +1,199 @@
+use crate::xsd::annotation::Annotation;
+use crate::xsd::constants::{MAX_OCCURS, MIN_OCCURS, UNBOUNDED};
+use crate::xsd::primitives::{BaseType, PrefixedString};
+use crate::xsd::{Occurs, Xsd};
+use std::fmt::{Display, Formatter, Write};
+
+/// An XML element that is being written, see `Xsd::to_xml`. Attributes are written in the order
+/// in which they were added, so that, for example, `name` can come first.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Node {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub content: Vec<Content>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Content {
+    Node(Node),
+    Text(String),
+}
+
+impl Node {
+    /// Creates a node in the XSD namespace, e.g. `Node::new(xsd, "sequence")` is `xs:sequence`.
+    pub fn new<S: AsRef<str>>(xsd: &Xsd, name: S) -> Self {
+        let name = if xsd.prefix().is_empty() {
+            name.as_ref().to_owned()
+        } else {
+            format!("{}:{}", xsd.prefix(), name.as_ref())
+        };
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    pub fn attr<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.attributes.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// The value of the attribute named `key`, if present.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn attr_opt<K: Into<String>, V: ToString>(self, key: K, value: Option<V>) -> Self {
+        match value {
+            Some(value) => self.attr(key, value),
+            None => self,
+        }
+    }
+
+    /// Adds `minOccurs` and `maxOccurs` unless they have their default value of 1.
+    pub fn occurs(self, occurs: &Occurs) -> Self {
+        let min = Some(occurs.min_occurs).filter(|&min| min != 1);
+        let max = match occurs.max_occurs {
+            None => Some(UNBOUNDED.to_owned()),
+            Some(1) => None,
+            Some(max) => Some(max.to_string()),
+        };
+        self.attr_opt(MIN_OCCURS, min).attr_opt(MAX_OCCURS, max)
+    }
+
+    pub fn child(mut self, node: Node) -> Self {
+        self.content.push(Content::Node(node));
+        self
+    }
+
+    pub fn children<I: IntoIterator<Item = Node>>(mut self, nodes: I) -> Self {
+        self.content.extend(nodes.into_iter().map(Content::Node));
+        self
+    }
+
+    pub fn annotation(self, xsd: &Xsd, annotation: &Option<Annotation>) -> Self {
+        match annotation {
+            Some(a) => self.child(a.to_xml(xsd)),
+            None => self,
+        }
+    }
+
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.content.push(Content::Text(text.into()));
+        self
+    }
+
+    fn write(&self, f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+        indent(f, depth)?;
+        write!(f, "<{}", self.name)?;
+        for (key, value) in &self.attributes {
+            write!(f, " {}=\"{}\"", key, escape(value, true))?;
+        }
+        if self.content.is_empty() {
+            return f.write_str("/>");
+        }
+        f.write_char('>')?;
+        // text is written in-line, so that whitespace is not added to it.
+        let is_text = self.content.iter().any(|c| matches!(c, Content::Text(_)));
+        for content in &self.content {
+            match content {
+                Content::Text(text) => f.write_str(&escape(text, false))?,
+                Content::Node(node) if is_text => node.write(f, 0)?,
+                Content::Node(node) => {
+                    f.write_char('\n')?;
+                    node.write(f, depth + 1)?;
+                }
+            }
+        }
+        if !is_text {
+            f.write_char('\n')?;
+            indent(f, depth)?;
+        }
+        write!(f, "</{}>", self.name)
+    }
+}
+
+/// Pretty-prints the node, indenting with tabs as `musicxml.xsd` does.
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+/// Writes `node` as the root of an XML document, i.e. preceded by the XML declaration.
+pub fn document(node: &Node) -> String {
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}\n", node)
+}
+
+fn indent(f: &mut Formatter<'_>, depth: usize) -> std::fmt::Result {
+    for _ in 0..depth {
+        f.write_char('\t')?;
+    }
+    Ok(())
+}
+
+fn escape(s: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The name of a type as it is written in a `type`, `base` or `memberTypes` attribute, i.e. a
+/// built-in type is prefixed, e.g. `xs:string`, while a custom type is written as it was parsed.
+pub fn type_name(base_type: &BaseType, xsd: &Xsd) -> String {
+    match base_type {
+        BaseType::Custom(s) => s.clone(),
+        builtin => builtin.as_str(xsd.prefix()).into_owned(),
+    }
+}
+
+#[test]
+fn write_node() {
+    let xsd = Xsd::new("xs");
+    let node = Node::new(&xsd, "sequence")
+        .occurs(&Occurs {
+            min_occurs: 0,
+            max_occurs: None,
+        })
+        .child(
+            Node::new(&xsd, "element")
+                .attr("name", "a&b")
+                .attr("type", "x"),
+        )
+        .child(
+            Node::new(&xsd, "annotation")
+                .child(Node::new(&xsd, "documentation").text("1 < 2 & \"3\"")),
+        );
+    let want = r#"<xs:sequence minOccurs="0" maxOccurs="unbounded">
+	<xs:element name="a&amp;b" type="x"/>
+	<xs:annotation>
+		<xs:documentation>1 &lt; 2 &amp; "3"</xs:documentation>
+	</xs:annotation>
+</xs:sequence>"#;
+    assert_eq!(node.to_string(), want);
+}
+
+#[test]
+fn write_type_name() {
+    let xsd = Xsd::new("xs");
+    assert_eq!(type_name(&BaseType::Decimal, &xsd), "xs:decimal");
+    assert_eq!(
+        type_name(&BaseType::Custom("yes-no".into()), &xsd),
+        "yes-no"
+    );
+    assert_eq!(
+        type_name(&BaseType::Custom("xlink:href".into()), &xsd),
+        "xlink:href"
+    );
+}
@@ -0,0 +1,278 @@
+use libmxgen::generate::paths::{Paths, SchemaVersion};
+use libmxgen::generate::{check, run, GenArgs};
+use std::path::PathBuf;
+
+#[test]
+fn generate_3_1() {
+    let tmp = tempfile::tempdir().unwrap();
+    let paths = Paths {
+        repo: tmp.path().to_owned(),
+        ..Paths::default()
+    };
+    let versions = vec![SchemaVersion::V3_1];
+    let core = paths.for_version(SchemaVersion::V3_1).core;
+    assert!(core.starts_with(tmp.path()));
+    run(GenArgs {
+        paths,
+        versions,
+        ..GenArgs::default()
+    })
+    .unwrap();
+    let enums_h = std::fs::read_to_string(core.join("Enums.h")).unwrap();
+    // 'double-sharp-down' was added to accidental-value in 3.1.
+    assert!(enums_h.contains("doubleSharpDown"));
+    // hand-written classes are copied from the 3.0 tree.
+    assert!(core.join("Color.h").is_file());
+    // types that are new in 3.1.
+    assert!(core.join("SmuflGlyphName.h").is_file());
+    assert!(core.join("SmuflCodaGlyphName.h").is_file());
+    // the child order of each complex type with element content.
+    let content_models = std::fs::read_to_string(core.join("ContentModels.cpp")).unwrap();
+    assert!(content_models
+        .contains("bool isPitchContentValid( const std::vector<std::string>& childNames )"));
+}
+
+#[test]
+fn generate_with_rust_backend() {
+    let tmp = tempfile::tempdir().unwrap();
+    let paths = Paths {
+        repo: tmp.path().to_owned(),
+        ..Paths::default()
+    };
+    run(GenArgs {
+        paths,
+        versions: vec![SchemaVersion::V3_1],
+        backend: String::from("rust"),
+        ..GenArgs::default()
+    })
+    .unwrap();
+    let lib = tmp
+        .path()
+        .join("Rust")
+        .join("mxcore-3.1")
+        .join("src")
+        .join("lib.rs");
+    assert!(std::fs::read_to_string(lib)
+        .unwrap()
+        .contains("MusicXML 3.1"));
+    assert!(run(GenArgs {
+        backend: String::from("json"),
+        ..GenArgs::default()
+    })
+    .is_err());
+}
+
+#[test]
+fn schema_version() {
+    assert_eq!("3.1".parse::<SchemaVersion>().unwrap(), SchemaVersion::V3_1);
+    assert!("4.0".parse::<SchemaVersion>().is_err());
+    assert_eq!(SchemaVersion::V3_0.to_string(), "3.0");
+    let paths = Paths::default();
+    assert_eq!(paths.for_version(SchemaVersion::V3_0).core, paths.core);
+    assert!(paths
+        .xsd(SchemaVersion::V3_1)
+        .ends_with("Documents/musicxml-3.1.xsd"));
+}
+
+#[test]
+fn paths_from_config() {
+    let tmp = tempfile::tempdir().unwrap();
+    let config = tmp.path().join("mxgen.json");
+    std::fs::write(
+        &config,
+        r#"{ "repo": "checkout", "core": "out", "xsd_3_1": "schemas/musicxml.xsd" }"#,
+    )
+    .unwrap();
+    let paths = Paths::from_config(&config).unwrap();
+    assert_eq!(paths.repo, tmp.path().join("checkout"));
+    assert_eq!(paths.core, tmp.path().join("out"));
+    assert_eq!(paths.enums_h, tmp.path().join("out").join("Enums.h"));
+    assert_eq!(
+        paths.xsd(SchemaVersion::V3_1),
+        tmp.path().join("schemas").join("musicxml.xsd")
+    );
+    assert_eq!(
+        paths.xsd(SchemaVersion::V3_0),
+        tmp.path()
+            .join("checkout")
+            .join("Documents")
+            .join("musicxml.xsd")
+    );
+    assert!(paths.templates.is_none());
+    std::fs::write(&config, r#"{ "core": "out", "enums_h": "MxEnums.h" }"#).unwrap();
+    let paths = Paths::from_config(&config).unwrap();
+    assert_eq!(paths.enums_h, tmp.path().join("out").join("MxEnums.h"));
+    let core_3_1 = paths.for_version(SchemaVersion::V3_1).core;
+    assert_eq!(
+        paths.for_version(SchemaVersion::V3_1).enums_h,
+        core_3_1.join("MxEnums.h")
+    );
+    std::fs::write(&config, r#"{ "enums_h": "elsewhere/Enums.h" }"#).unwrap();
+    assert!(Paths::from_config(&config).is_err());
+    std::fs::write(&config, r#"{ "output": "out" }"#).unwrap();
+    assert!(Paths::from_config(&config).is_err());
+}
+
+#[test]
+fn generate_to_out_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("core");
+    run(GenArgs {
+        versions: vec![SchemaVersion::V3_1],
+        out_dir: Some(out_dir.clone()),
+        ..GenArgs::default()
+    })
+    .unwrap();
+    assert!(out_dir.join("Enums.h").is_file());
+    assert!(out_dir.join("Color.h").is_file());
+    assert!(run(GenArgs {
+        versions: SchemaVersion::all().to_vec(),
+        out_dir: Some(out_dir),
+        ..GenArgs::default()
+    })
+    .is_err());
+}
+
+/// The generated mx::core sources that are checked in must be what mxgen writes.
+#[test]
+fn checked_in_sources_are_current() {
+    let stale = check(GenArgs::default()).unwrap();
+    assert!(stale.is_empty(), "run `mxgen generate`, stale: {:?}", stale);
+}
+
+#[test]
+fn check_stale_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("mxcore");
+    let args = GenArgs {
+        versions: vec![SchemaVersion::V3_1],
+        backend: String::from("rust"),
+        out_dir: Some(out_dir.clone()),
+        ..GenArgs::default()
+    };
+    let lib = out_dir.join("src").join("lib.rs");
+    let xml = out_dir.join("src").join("xml.rs");
+    // nothing has been generated yet.
+    assert!(check(args.clone()).unwrap().contains(&lib));
+    run(args.clone()).unwrap();
+    assert!(check(args.clone()).unwrap().is_empty());
+    std::fs::write(&lib, "// edited").unwrap();
+    std::fs::remove_file(&xml).unwrap();
+    assert_eq!(check(args).unwrap(), vec![lib, xml]);
+}
+
+#[test]
+fn generate_rust_3_1() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("mxcore");
+    run(GenArgs {
+        versions: vec![SchemaVersion::V3_1],
+        backend: String::from("rust"),
+        out_dir: Some(out_dir.clone()),
+        ..GenArgs::default()
+    })
+    .unwrap();
+    let manifest = std::fs::read_to_string(out_dir.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"mxcore\""));
+    assert!(manifest.contains("regex"));
+    let lib = std::fs::read_to_string(out_dir.join("src").join("lib.rs")).unwrap();
+    assert!(lib.contains("pub use enums::*;"));
+    let enums = std::fs::read_to_string(out_dir.join("src").join("enums.rs")).unwrap();
+    assert!(enums.contains("    DoubleSharpDown,\n"));
+    assert!(enums.contains("    OtherDynamics(String),\n"));
+    let numbers = std::fs::read_to_string(out_dir.join("src").join("numbers.rs")).unwrap();
+    assert!(numbers.contains("pub struct BeamLevel(i64);"));
+    let unions = std::fs::read_to_string(out_dir.join("src").join("unions.rs")).unwrap();
+    assert!(unions.contains("pub enum YesNoNumber {"));
+    let strings = std::fs::read_to_string(out_dir.join("src").join("strings.rs")).unwrap();
+    // the pattern of smufl-coda-glyph-name is checked, see generated_rust_crate_compiles.
+    assert!(strings.contains("pub struct SmuflCodaGlyphName(String);"));
+    assert!(!out_dir.join("src").join("aliases.rs").exists());
+    let complex = std::fs::read_to_string(out_dir.join("src").join("complex.rs")).unwrap();
+    assert!(complex.contains("pub struct Note {"));
+    assert!(complex.contains("    pub staff: Option<PositiveInteger>,\n"));
+    assert!(complex.contains("    pub beam: Vec<Beam>,\n"));
+    assert!(complex.contains("impl FromXml for Note {"));
+    assert!(complex.contains("pub enum NoteChoice {"));
+    let document = std::fs::read_to_string(out_dir.join("src").join("document.rs")).unwrap();
+    assert!(document.contains("    ScorePartwise(ScorePartwise),\n"));
+    assert!(out_dir.join("src").join("xml.rs").exists());
+}
+
+/// The generated crate is compiled and tested with the dependencies that are already in the local
+/// registry, so this needs no network access once mxgen itself has been built.
+#[test]
+fn generated_rust_crate_compiles() {
+    let tmp = tempfile::tempdir().unwrap();
+    let out_dir = tmp.path().join("mxcore");
+    run(GenArgs {
+        versions: vec![SchemaVersion::V3_1],
+        backend: String::from("rust"),
+        out_dir: Some(out_dir.clone()),
+        ..GenArgs::default()
+    })
+    .unwrap();
+    std::fs::create_dir(out_dir.join("tests")).unwrap();
+    std::fs::write(
+        out_dir.join("tests").join("facets.rs"),
+        r#"use mxcore::SmuflCodaGlyphName;
+
+#[test]
+fn smufl_coda_glyph_name_pattern() {
+    assert!("codaSquare".parse::<SmuflCodaGlyphName>().is_ok());
+    assert!(" coda ".parse::<SmuflCodaGlyphName>().is_ok());
+    assert!("segno".parse::<SmuflCodaGlyphName>().is_err());
+    assert!(SmuflCodaGlyphName::new("accidentalSharp").is_err());
+}
+"#,
+    )
+    .unwrap();
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let output = std::process::Command::new(cargo)
+        .args(["test", "--offline", "--quiet", "--test", "facets"])
+        .current_dir(&out_dir)
+        .env("CARGO_TARGET_DIR", tmp.path().join("target"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "cargo test failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn generate_with_templates_dir() {
+    let tmp = tempfile::tempdir().unwrap();
+    let templates = tmp.path().join("templates");
+    std::fs::create_dir(&templates).unwrap();
+    std::fs::write(
+        templates.join("core.h.template"),
+        "// custom header\n{{contents}}\n",
+    )
+    .unwrap();
+    let generate = |templates: Option<PathBuf>, out_dir: PathBuf| {
+        let paths = Paths {
+            templates,
+            ..Paths::default()
+        };
+        run(GenArgs {
+            paths,
+            out_dir: Some(out_dir.clone()),
+            ..GenArgs::default()
+        })
+        .unwrap();
+        let enums_h = std::fs::read_to_string(out_dir.join("Enums.h")).unwrap();
+        let enums_cpp = std::fs::read_to_string(out_dir.join("Enums.cpp")).unwrap();
+        (enums_h, enums_cpp)
+    };
+    let (h, cpp) = generate(Some(templates), tmp.path().join("custom"));
+    assert!(h.starts_with("// custom header\n"));
+    assert!(!h.contains("#pragma once"));
+    // the templates that are not in the directory are the built-in ones.
+    assert!(cpp.contains("#include \"mx/core/Enums.h\""));
+    // the custom template only applies to the run that it was given to.
+    let (h, _) = generate(None, tmp.path().join("builtin"));
+    assert!(!h.contains("// custom header"));
+    assert!(h.contains("#pragma once"));
+}
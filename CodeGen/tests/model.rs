@@ -0,0 +1,261 @@
+use libmxgen::generate::paths::Paths;
+use libmxgen::model::complex_type::{Content, Particle};
+use libmxgen::model::creator::Creator;
+use libmxgen::model::{Def, Shape};
+use libmxgen::xsd::primitives::{BaseType, PrefixedString};
+use libmxgen::xsd::Xsd;
+
+#[test]
+fn complex_types() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let note = match model.get(Shape::Complex, "note") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'note', got {:?}", other),
+    };
+    assert!(!note.documentation.is_empty());
+    assert!(!note.attributes.is_empty());
+    match &note.content {
+        Content::Particle(Particle::Sequence(_)) => {}
+        other => panic!("expected Sequence got {:?}", other),
+    }
+    let count = model
+        .defs()
+        .filter(|d| matches!(d, Def::ComplexType(_)))
+        .count();
+    assert!(count > 100);
+}
+
+#[test]
+fn elements() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    for name in &["score-partwise", "score-timewise"] {
+        match model.get(Shape::Element, name) {
+            Some(Def::Element(e)) => assert_eq!(e.name.original(), *name),
+            other => panic!("expected Element '{}', got {:?}", name, other),
+        }
+    }
+}
+
+#[test]
+fn resolved_attributes() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let accidental_mark = match model.get(Shape::Complex, "accidental-mark") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'accidental-mark', got {:?}", other),
+    };
+    // print-style brings in position, font and color; placement is its own attribute group.
+    let names: Vec<&str> = accidental_mark
+        .all_attributes
+        .iter()
+        .map(|a| a.name.original())
+        .collect();
+    for name in &[
+        "default-x",
+        "relative-y",
+        "font-family",
+        "font-size",
+        "color",
+        "placement",
+    ] {
+        assert!(names.contains(name), "'{}' not found in {:?}", name, names);
+    }
+    assert_eq!(names.last(), Some(&"placement"));
+    assert!(accidental_mark.all_attributes.iter().all(|a| !a.required));
+}
+
+#[test]
+fn flattened_groups() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    for name in &["editorial", "full-note", "music-data"] {
+        match model.get(Shape::Group, name) {
+            Some(Def::Group(g)) => assert_eq!(g.name.original(), *name),
+            other => panic!("expected Group '{}', got {:?}", name, other),
+        }
+    }
+    let note = match model.get(Shape::Complex, "note") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'note', got {:?}", other),
+    };
+    let particle = match &note.content {
+        Content::Particle(p) => model.flatten(p).unwrap(),
+        other => panic!("expected Particle got {:?}", other),
+    };
+    let mut names = Vec::new();
+    collect_elements(&particle, &mut names);
+    for name in &["chord", "pitch", "duration", "footnote", "level", "voice"] {
+        assert!(names.contains(&name.to_string()), "'{}' not found", name);
+    }
+}
+
+fn collect_elements(particle: &Particle, names: &mut Vec<String>) {
+    match particle {
+        Particle::Element(e) => names.push(e.name.original().to_owned()),
+        Particle::Sequence(c) | Particle::Choice(c) | Particle::All(c) => {
+            for member in &c.members {
+                collect_elements(member, names);
+            }
+        }
+        Particle::GroupRef(r) => panic!("unexpected GroupRef '{}'", r.name.original()),
+        Particle::Any(_) => {}
+    }
+}
+
+#[test]
+fn inline_union_members() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let union = match model.get(Shape::Simple, "number-or-normal") {
+        Some(Def::UnionSimpleType(u)) => u,
+        other => panic!("expected UnionSimpleType, got {:?}", other),
+    };
+    assert_eq!(union.members.len(), 2);
+    let inline_name = union.members.get(1).unwrap().name();
+    assert_eq!(inline_name, "number-or-normal-member-1");
+    match union.inline_member(inline_name) {
+        Some(Def::Enumeration(e)) => assert_eq!(e.members.len(), 1),
+        other => panic!("expected Enumeration, got {:?}", other),
+    }
+    // in-line members are not top-level types.
+    assert!(model.get(Shape::Simple, inline_name).is_none());
+}
+
+#[test]
+fn wildcards_and_all() {
+    let xml_str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" elementFormDefault="qualified">
+    <xs:complexType name="extensible">
+        <xs:sequence>
+            <xs:element name="point" type="point"/>
+            <xs:any namespace="##other" processContents="lax" minOccurs="0" maxOccurs="unbounded"/>
+        </xs:sequence>
+        <xs:anyAttribute namespace="##other"/>
+    </xs:complexType>
+    <xs:complexType name="point">
+        <xs:all>
+            <xs:element name="x" type="xs:decimal"/>
+            <xs:element name="y" type="xs:decimal"/>
+        </xs:all>
+    </xs:complexType>
+</xs:schema>"###;
+    let doc = exile::parse(xml_str).unwrap();
+    let xsd = Xsd::parse(doc.root()).unwrap();
+    let graph = xsd.graph().unwrap();
+    assert_eq!(graph.edges().count(), 1);
+    let model = Creator::default().create(&xsd).unwrap();
+    let extensible = match model.get(Shape::Complex, "extensible") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'extensible', got {:?}", other),
+    };
+    assert!(extensible.all_attributes.is_empty());
+    let mut names = Vec::new();
+    match &extensible.content {
+        Content::Particle(p) => collect_elements(p, &mut names),
+        other => panic!("expected Particle got {:?}", other),
+    }
+    assert_eq!(names, vec!["point".to_owned()]);
+    match model.get(Shape::Complex, "point") {
+        Some(Def::ComplexType(ct)) => match &ct.content {
+            Content::Particle(Particle::All(c)) => assert_eq!(c.members.len(), 2),
+            other => panic!("expected All got {:?}", other),
+        },
+        other => panic!("expected ComplexType 'point', got {:?}", other),
+    }
+}
+
+#[test]
+fn content_model_automata() {
+    let paths = Paths::default();
+    for path in &[&paths.xsd_3_0, &paths.xsd_3_1] {
+        let xsd = Xsd::load(path).unwrap();
+        let model = Creator::default().create(&xsd).unwrap();
+        let mut count = 0;
+        for def in model.defs() {
+            if let Def::ComplexType(ct) = def {
+                if let Content::Particle(p) = &ct.content {
+                    // musicxml.xsd satisfies unique particle attribution.
+                    model.automaton(p).unwrap();
+                    count += 1;
+                }
+            }
+        }
+        assert!(count > 50);
+    }
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let pitch = match model.get(Shape::Complex, "pitch") {
+        Some(Def::ComplexType(ct)) => match &ct.content {
+            Content::Particle(p) => model.automaton(p).unwrap(),
+            other => panic!("expected Particle got {:?}", other),
+        },
+        other => panic!("expected ComplexType 'pitch', got {:?}", other),
+    };
+    assert!(pitch.run(&["step", "alter", "octave"]).is_ok());
+    assert!(pitch.run(&["step", "octave"]).is_ok());
+    assert!(pitch.run(&["alter", "step", "octave"]).is_err());
+}
+
+#[test]
+fn imported_attributes() {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_0.parent().unwrap();
+    let xsd = Xsd::load_with_imports(&paths.xsd_3_0, import_dir).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    let lang = model.attribute("xml:lang").unwrap();
+    assert_eq!(lang.type_, BaseType::Custom("xml:lang-type".to_owned()));
+    match model.imported("xml:lang-type") {
+        Some(Def::UnionSimpleType(u)) => assert_eq!(u.inline_members.len(), 1),
+        other => panic!("expected UnionSimpleType 'xml:lang-type', got {:?}", other),
+    }
+    match model.imported("xml:space-type") {
+        Some(Def::Enumeration(e)) => assert_eq!(e.members.len(), 2),
+        other => panic!("expected Enumeration 'xml:space-type', got {:?}", other),
+    }
+    assert_eq!(
+        model.attribute("xlink:href").unwrap().type_,
+        BaseType::Custom("xs:anyURI".to_owned())
+    );
+    // imported types are not generated.
+    assert!(model.get(Shape::Simple, "xml:lang-type").is_none());
+
+    let lyric_language = match model.get(Shape::Complex, "lyric-language") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'lyric-language', got {:?}", other),
+    };
+    let reference = lyric_language
+        .all_attributes
+        .iter()
+        .find(|a| a.name.original() == "xml:lang")
+        .unwrap();
+    assert!(reference.is_ref);
+    let declaration = model.declaration(reference).unwrap();
+    assert_eq!(declaration.type_, lang.type_);
+    assert!(declaration.required);
+}
+
+#[test]
+fn imported_attributes_not_loaded() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let model = Creator::default().create(&xsd).unwrap();
+    assert!(model.attribute("xml:lang").is_none());
+    let lyric_language = match model.get(Shape::Complex, "lyric-language") {
+        Some(Def::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType 'lyric-language', got {:?}", other),
+    };
+    let reference = lyric_language
+        .all_attributes
+        .iter()
+        .find(|a| a.name.original() == "xml:lang")
+        .unwrap();
+    let message = format!("{}", model.declaration(reference).unwrap_err());
+    assert!(message.contains("xml:lang"), "{}", message);
+}
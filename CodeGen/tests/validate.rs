@@ -0,0 +1,210 @@
+use libmxgen::generate::paths::Paths;
+use libmxgen::validate::{Validator, Violation, ViolationKind};
+use libmxgen::xsd::Xsd;
+
+fn load() -> Xsd {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_1.parent().unwrap();
+    Xsd::load_with_imports(&paths.xsd_3_1, import_dir).unwrap()
+}
+
+const SCORE: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<score-partwise version="3.1">
+  <part-list>
+    <score-part id="P1">
+      <part-name>Music</part-name>
+    </score-part>
+  </part-list>
+  <part id="P1">
+    <measure number="1">
+      <attributes>
+        <divisions>1</divisions>
+        <time>
+          <beats>4</beats>
+          <beat-type>4</beat-type>
+        </time>
+      </attributes>
+      <note default-x="10" color="#FF0000">
+        <pitch>
+          <step>C</step>
+          <octave>4</octave>
+        </pitch>
+        <duration>4</duration>
+        <type>whole</type>
+      </note>
+    </measure>
+  </part>
+</score-partwise>"##;
+
+fn validate(xml: &str) -> Vec<Violation> {
+    let xsd = load();
+    Validator::new(&xsd).validate_str(xml).unwrap()
+}
+
+/// Validates `SCORE` with `from` replaced by `to`, which must cause exactly one violation.
+fn violation(from: &str, to: &str) -> Violation {
+    assert!(SCORE.contains(from));
+    let violations = validate(&SCORE.replacen(from, to, 1));
+    assert_eq!(violations.len(), 1, "{:#?}", violations);
+    violations.into_iter().next().unwrap()
+}
+
+#[test]
+fn valid() {
+    let violations = validate(SCORE);
+    assert!(violations.is_empty(), "{:#?}", violations);
+}
+
+#[test]
+fn unknown_root() {
+    let got = validate("<score/>");
+    assert_eq!(got.len(), 1);
+    assert_eq!(got[0].kind, ViolationKind::Content);
+    assert_eq!(got[0].path, "/score");
+}
+
+#[test]
+fn content_order() {
+    let got = violation(
+        "<duration>4</duration>\n        <type>whole</type>",
+        "<type>whole</type>\n        <duration>4</duration>",
+    );
+    assert_eq!(got.kind, ViolationKind::Content);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/type");
+    assert!(got
+        .message
+        .starts_with("unexpected element 'type', expected"));
+    assert!(got.message.contains("'duration'"));
+}
+
+#[test]
+fn content_incomplete() {
+    let got = violation("<octave>4</octave>", "");
+    assert_eq!(got.kind, ViolationKind::Content);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/pitch");
+    assert_eq!(
+        got.message,
+        "'pitch' is incomplete, expected one of 'alter', 'octave'"
+    );
+}
+
+#[test]
+fn content_unknown_element() {
+    let got = violation("<type>whole</type>", "<type>whole</type><bogus/>");
+    assert_eq!(got.kind, ViolationKind::Content);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/bogus");
+}
+
+#[test]
+fn text_in_element_only_content() {
+    let got = violation("<pitch>", "<pitch>hello");
+    assert_eq!(got.kind, ViolationKind::Text);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/pitch");
+}
+
+#[test]
+fn missing_attribute() {
+    let got = violation(r#"<part id="P1">"#, "<part>");
+    assert_eq!(got.kind, ViolationKind::MissingAttribute);
+    assert_eq!(got.path, "/score-partwise/part");
+    assert_eq!(got.message, "missing required attribute 'id'");
+}
+
+#[test]
+fn unknown_attribute() {
+    let got = violation(
+        r#"<measure number="1">"#,
+        r#"<measure number="1" foo="bar">"#,
+    );
+    assert_eq!(got.kind, ViolationKind::UnknownAttribute);
+    assert_eq!(got.path, "/score-partwise/part/measure/@foo");
+}
+
+#[test]
+fn enumeration() {
+    let got = violation("<step>C</step>", "<step>H</step>");
+    assert_eq!(got.kind, ViolationKind::Enumeration);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/pitch/step");
+    assert!(got.message.starts_with("'H' is not one of 'A', 'B', 'C'"));
+}
+
+#[test]
+fn range() {
+    let got = violation("<octave>4</octave>", "<octave>10</octave>");
+    assert_eq!(got.kind, ViolationKind::Range);
+    assert_eq!(got.message, "'10' is greater than the maximum of 9");
+    let got = violation("<divisions>1</divisions>", "<divisions>0</divisions>");
+    assert_eq!(got.kind, ViolationKind::Range);
+}
+
+#[test]
+fn value() {
+    let got = violation(r#"default-x="10""#, r#"default-x="ten""#);
+    assert_eq!(got.kind, ViolationKind::Value);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/@default-x");
+    assert_eq!(got.message, "'ten' is not a valid 'decimal'");
+}
+
+#[test]
+fn pattern() {
+    let got = violation(r##"color="#FF0000""##, r#"color="red""#);
+    assert_eq!(got.kind, ViolationKind::Pattern);
+    assert_eq!(got.path, "/score-partwise/part/measure/note/@color");
+}
+
+#[test]
+fn whitespace_is_collapsed() {
+    let violations = validate(&SCORE.replace("<step>C</step>", "<step>\n  C\n</step>"));
+    assert!(violations.is_empty(), "{:#?}", violations);
+}
+
+#[test]
+fn foundsuite() {
+    let xsd = load();
+    let validator = Validator::new(&xsd);
+    let dir = Paths::default().repo.join("Resources").join("foundsuite");
+    // the number of violations in the files that have any, all other files must be valid.
+    let expected = [
+        ("Deutscher Tanz D.820.1.xml", 3),
+        ("O_Holy_Night-Adam-1871.xml", 65),
+        ("O_Holy_Night.xml", 34),
+        ("Rimsky-Korsakov Op11 No4.xml", 7),
+    ];
+    let mut count = 0;
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_none_or(|ext| ext != "xml") {
+            continue;
+        }
+        let filename = path.file_name().unwrap().to_str().unwrap();
+        let want = expected
+            .iter()
+            .find(|(name, _)| *name == filename)
+            .map_or(0, |(_, n)| *n);
+        let violations = validator.validate_file(&path).unwrap();
+        assert_eq!(violations.len(), want, "{}: {:#?}", filename, violations);
+        count += 1;
+    }
+    assert!(count > 30);
+    // these files have mistakes that were made by the programs that wrote them.
+    let violations = validator
+        .validate_file(dir.join("Deutscher Tanz D.820.1.xml"))
+        .unwrap();
+    let got: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+    assert_eq!(
+        got,
+        vec![
+            "/score-partwise/part-list/score-part/midi-instrument/midi-program: '0' is out of \
+             range for 'positiveInteger'",
+            "/score-partwise/part/measure[2]/direction/sound/@dynamics: '-1.11' is less than the \
+             minimum of 0",
+            "/score-partwise/part/measure[4]/direction/sound/@dynamics: '-1.11' is less than the \
+             minimum of 0",
+        ]
+    );
+    // a UTF-16 document with no mistakes.
+    let violations = validator
+        .validate_file(dir.join("Black Note Study Op 10 no 5.xml"))
+        .unwrap();
+    assert!(violations.is_empty(), "{:#?}", violations);
+}
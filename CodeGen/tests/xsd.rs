@@ -1,5 +1,8 @@
 use libmxgen::generate::paths::Paths;
-use libmxgen::xsd::Xsd;
+use libmxgen::xsd::diff::{Aspect, ChangeKind, SchemaDiff};
+use libmxgen::xsd::flatten::{copy_imports, Flattener};
+use libmxgen::xsd::id::RootNodeType;
+use libmxgen::xsd::{Entry, Xsd};
 
 #[test]
 fn xsd() {
@@ -7,3 +10,421 @@ fn xsd() {
     let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
     println!("{}", xsd);
 }
+
+#[test]
+fn imports() {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_0.parent().unwrap();
+    let xsd = Xsd::load_with_imports(&paths.xsd_3_0, import_dir).unwrap();
+    assert_eq!(xsd.imports().count(), 2);
+    for qname in &[
+        "xml:lang",
+        "xml:space",
+        "xlink:href",
+        "xlink:type",
+        "xlink:role",
+        "xlink:title",
+        "xlink:show",
+        "xlink:actuate",
+    ] {
+        match xsd.resolve(RootNodeType::Attribute, qname) {
+            Some(Entry::Attribute(a)) => {
+                assert_eq!(Some(a.name.as_str()), qname.split(':').next_back())
+            }
+            other => panic!("expected Attribute for '{}', got {:?}", qname, other),
+        }
+    }
+    // a type in the main schema is found without a prefix.
+    match xsd.resolve(RootNodeType::SimpleType, "above-below") {
+        Some(Entry::SimpleType(st)) => assert_eq!(st.name, "above-below"),
+        other => panic!("expected SimpleType, got {:?}", other),
+    }
+}
+
+#[test]
+fn imports_not_loaded() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    assert_eq!(xsd.imports().count(), 0);
+    assert!(xsd.resolve(RootNodeType::Attribute, "xml:lang").is_none());
+}
+
+#[test]
+fn graph() {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_0.parent().unwrap();
+    let xsd = Xsd::load_with_imports(&paths.xsd_3_0, import_dir).unwrap();
+    let graph = xsd.graph().unwrap();
+    let above_below = xsd.get(RootNodeType::SimpleType, "above-below").unwrap();
+    let users = graph.users(above_below.id());
+    assert!(!users.is_empty());
+    for user in users {
+        let found = graph
+            .references(user)
+            .any(|edge| &edge.target == above_below.id());
+        assert!(found, "'{}' does not reference 'above-below'", user);
+    }
+    let lang = xsd.resolve(RootNodeType::Attribute, "xml:lang").unwrap();
+    assert!(graph.referenced_by(lang.id()).count() > 0);
+}
+
+#[test]
+fn graph_without_imports() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let message = format!("{}", xsd.graph().err().unwrap());
+    assert!(message.contains("'xml:lang' not found"));
+    assert!(message.contains("load_with_imports"));
+}
+
+#[test]
+fn diff_3_0_and_3_1() {
+    let paths = Paths::default();
+    let import_dir = paths.xsd_3_0.parent().unwrap();
+    let xsd_3_0 = Xsd::load_with_imports(&paths.xsd_3_0, import_dir).unwrap();
+    let xsd_3_1 = Xsd::load_with_imports(&paths.xsd_3_1, import_dir).unwrap();
+    assert!(SchemaDiff::compare(&xsd_3_0, &xsd_3_0).is_empty());
+    let diff = SchemaDiff::compare(&xsd_3_0, &xsd_3_1);
+    let appearance = diff
+        .changes
+        .iter()
+        .find(|c| c.entry_type == "complexType" && c.name == "appearance")
+        .unwrap();
+    assert_eq!(appearance.kind, ChangeKind::Changed);
+    assert!(appearance
+        .details
+        .iter()
+        .any(|d| d.aspect == Aspect::Element && d.kind == ChangeKind::Added && d.name == "glyph"));
+    assert!(diff
+        .changes
+        .iter()
+        .any(|c| c.kind == ChangeKind::Added && c.name == "optional-unique-id"));
+    assert!(diff.to_json().unwrap().contains("\"name\": \"appearance\""));
+}
+
+#[test]
+fn locations() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let text = std::fs::read_to_string(&paths.xsd_3_0).unwrap();
+    let want_line = text
+        .lines()
+        .position(|line| line.contains(r#"<xs:simpleType name="above-below">"#))
+        .unwrap() as u32
+        + 1;
+    let entry = xsd.get(RootNodeType::SimpleType, "above-below").unwrap();
+    let location = entry.id().location().unwrap();
+    assert_eq!(location.line, want_line);
+    assert_eq!(location.column, 2);
+    assert!(location.file.as_deref().unwrap().ends_with("musicxml.xsd"));
+}
+
+#[test]
+fn error_location() {
+    let xml = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="a">
+        <xs:restriction base="xs:string">
+            <xs:bogus value="x"/>
+        </xs:restriction>
+    </xs:simpleType>
+</xs:schema>"#;
+    let err = Xsd::parse_str(xml).err().unwrap();
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.column), (4, 13));
+    assert!(err.to_string().starts_with("4:13: error: "));
+}
+
+#[test]
+fn duplicate_name() {
+    let xml = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:simpleType name="a">
+        <xs:restriction base="xs:string"/>
+    </xs:simpleType>
+    <xs:simpleType name="a">
+        <xs:restriction base="xs:token"/>
+    </xs:simpleType>
+</xs:schema>"#;
+    let err = Xsd::parse_str(xml).err().unwrap();
+    assert!(err.to_string().starts_with("5:5: error: "), "{}", err);
+    assert!(err.to_string().contains("duplicate simpleType named 'a'"));
+}
+
+/// Finds the `Id` of each sequence member of the named complex type.
+fn member_ids(xsd: &Xsd, name: &str) -> Vec<String> {
+    use libmxgen::xsd::complex_type::{Children, Payload};
+    use libmxgen::xsd::sequence::Member;
+    let ct = match xsd.get(RootNodeType::ComplexType, name) {
+        Some(Entry::ComplexType(ct)) => ct,
+        other => panic!("expected ComplexType, got {:?}", other),
+    };
+    let sequence = match &ct.payload {
+        Payload::Parent(p) => match &p.children {
+            Some(Children::Sequence(s)) => s,
+            other => panic!("expected Sequence, got {:?}", other),
+        },
+        other => panic!("expected Parent, got {:?}", other),
+    };
+    let mut ids = vec![sequence.id.to_string()];
+    for member in &sequence.members {
+        match member {
+            Member::Element(e) => ids.push(e.id().to_string()),
+            Member::Choice(c) => ids.push(c.id.to_string()),
+            other => panic!("unexpected member {:?}", other),
+        }
+    }
+    ids
+}
+
+#[test]
+fn structural_ids() {
+    let xml = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+    <xs:annotation><xs:documentation>notes</xs:documentation></xs:annotation>
+    <xs:complexType name="note">
+        <xs:sequence>
+            <xs:element name="pitch" type="xs:string"/>
+            <xs:choice>
+                <xs:element name="a" type="xs:string"/>
+            </xs:choice>
+            <xs:choice>
+                <xs:element name="b" type="xs:string"/>
+            </xs:choice>
+        </xs:sequence>
+    </xs:complexType>
+</xs:schema>"#;
+    let want = vec![
+        "complexType:note/sequence[0]",
+        "complexType:note/sequence[0]/element:pitch",
+        "complexType:note/sequence[0]/choice[0]",
+        "complexType:note/sequence[0]/choice[1]",
+    ];
+    let xsd = Xsd::parse_str(xml).unwrap();
+    assert_eq!(member_ids(&xsd, "note"), want);
+    assert_eq!(
+        xsd.entries().next().unwrap().id().to_string(),
+        "annotation[0]"
+    );
+
+    // the ids do not depend on the content of other nodes, or on the location of the node.
+    let changed = xml
+        .replace("notes", "different documentation")
+        .replace(
+            r#"<xs:complexType name="note">"#,
+            r#"<xs:complexType name="rest">
+        <xs:sequence><xs:element name="x" type="xs:string"/></xs:sequence>
+    </xs:complexType>
+    <xs:complexType name="note">"#,
+        )
+        .replace(r#"name="a" type="xs:string""#, r#"name="a" type="xs:int""#);
+    let changed = Xsd::parse_str(changed).unwrap();
+    assert_eq!(member_ids(&changed, "note"), want);
+
+    // each id can be parsed back and found.
+    for entry in xsd.entries() {
+        let parsed: libmxgen::xsd::id::Id = entry.id().to_string().parse().unwrap();
+        assert_eq!(&parsed, entry.id());
+        assert_eq!(xsd.find(&parsed).unwrap().id(), entry.id());
+    }
+}
+
+#[test]
+fn musicxml_ids_round_trip() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let mut seen = std::collections::HashSet::new();
+    for entry in xsd.entries() {
+        let s = entry.id().to_string();
+        let parsed: libmxgen::xsd::id::Id = s.parse().unwrap();
+        assert_eq!(xsd.find(&parsed).unwrap().id(), entry.id());
+        assert!(seen.insert(s));
+    }
+}
+
+/// A canonical form of an XML element for comparing documents: comments and whitespace are
+/// ignored, as are attributes that have their default value.
+fn canonical(node: &exile::Element, out: &mut String) {
+    out.push_str(&node.fullname());
+    for (k, v) in node.attributes.map() {
+        let is_default = matches!(
+            (k.as_str(), v.as_str()),
+            ("minOccurs", "1")
+                | ("maxOccurs", "1")
+                | ("use", "optional")
+                | ("processContents", "strict")
+                | ("namespace", "##any")
+        );
+        if !is_default {
+            out.push_str(&format!(" {}={:?}", k, v));
+        }
+    }
+    if let Some(text) = node.text() {
+        out.push_str(&format!(" {:?}", text.trim()));
+    }
+    out.push_str(" {\n");
+    for child in node.children() {
+        canonical(child, out);
+    }
+    out.push_str("}\n");
+}
+
+fn assert_round_trip(path: &std::path::Path) {
+    let original = std::fs::read_to_string(path).unwrap();
+    let xsd = Xsd::load(path).unwrap();
+    let written = xsd.to_xml_string();
+
+    // the written document has the same content as the original.
+    let (mut want, mut got) = (String::new(), String::new());
+    canonical(exile::parse(&original).unwrap().root(), &mut want);
+    canonical(exile::parse(&written).unwrap().root(), &mut got);
+    if want != got {
+        let line = want
+            .lines()
+            .zip(got.lines())
+            .find(|(w, g)| w != g)
+            .map(|(w, g)| format!("want: {}\n got: {}", w, g));
+        panic!(
+            "'{}' did not round trip\n{}",
+            path.display(),
+            line.unwrap_or_default()
+        );
+    }
+
+    // and it parses into the same schema, which is written the same way.
+    let reparsed = Xsd::parse_str(&written).unwrap();
+    assert!(SchemaDiff::compare(&xsd, &reparsed).is_empty());
+    assert_eq!(reparsed.to_xml_string(), written);
+    let ids = |xsd: &Xsd| {
+        xsd.entries()
+            .map(|e| e.id().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&reparsed), ids(&xsd));
+}
+
+#[test]
+fn round_trip() {
+    let paths = Paths::default();
+    assert_round_trip(&paths.xsd_3_0);
+    assert_round_trip(&paths.xsd_3_1);
+    let import_dir = paths.xsd_3_0.parent().unwrap();
+    assert_round_trip(&import_dir.join("xml.xsd"));
+    assert_round_trip(&import_dir.join("xlink.xsd"));
+}
+
+#[test]
+fn save() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("musicxml.xsd");
+    xsd.save(&path).unwrap();
+    let saved = Xsd::load(&path).unwrap();
+    assert!(SchemaDiff::compare(&xsd, &saved).is_empty());
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(text.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<xs:schema "));
+}
+
+fn assert_flattens(path: &std::path::Path, inline_simple_types: bool) {
+    let xsd = Xsd::load(path).unwrap();
+    let flattener = Flattener {
+        inline_simple_types,
+    };
+    let written = flattener.flatten(&xsd).unwrap().to_string();
+    let flattened = Xsd::parse_str(&written).unwrap();
+    for entry in flattened.entries() {
+        match entry {
+            Entry::Group(_) | Entry::AttributeGroup(_) => {
+                panic!("'{}' was not removed", entry.id())
+            }
+            Entry::SimpleType(_) if inline_simple_types => {
+                let name = entry.id().to_string();
+                let name = name.trim_start_matches("simpleType:");
+                assert!(
+                    written.contains(&format!("=\"{}\"", name)),
+                    "'{}' is unused",
+                    name
+                );
+            }
+            _ => {}
+        }
+    }
+    assert!(!written.contains("<xs:group"));
+    assert!(!written.contains("<xs:attributeGroup"));
+    // the complex types, elements and attributes are all still there.
+    for entry in xsd.entries() {
+        if let Entry::ComplexType(_) | Entry::Element(_) | Entry::Attribute(_) = entry {
+            assert!(
+                flattened.find(entry.id()).is_ok(),
+                "'{}' is missing",
+                entry.id()
+            );
+        }
+    }
+}
+
+#[test]
+fn flatten_copy_imports() {
+    let paths = Paths::default();
+    let xsd = Xsd::load(&paths.xsd_3_1).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("flat.xsd");
+    let written = Flattener::default().flatten(&xsd).unwrap().to_string();
+    std::fs::write(&path, written).unwrap();
+    assert!(Xsd::load_with_imports(&path, dir.path()).is_err());
+    copy_imports(&paths.xsd_3_1, dir.path()).unwrap();
+    let flattened = Xsd::load_with_imports(&path, dir.path()).unwrap();
+    assert!(flattened
+        .import("http://www.w3.org/XML/1998/namespace")
+        .is_some());
+    assert!(flattened.import("http://www.w3.org/1999/xlink").is_some());
+}
+
+#[test]
+fn flatten() {
+    let paths = Paths::default();
+    for inline_simple_types in &[false, true] {
+        assert_flattens(&paths.xsd_3_0, *inline_simple_types);
+        assert_flattens(&paths.xsd_3_1, *inline_simple_types);
+    }
+    // groups and attribute groups are replaced with their content.
+    let xsd = Xsd::load(&paths.xsd_3_0).unwrap();
+    let written = Flattener::default().flatten(&xsd).unwrap().to_string();
+    let flattened = Xsd::parse_str(&written).unwrap();
+    let note = flattened
+        .get(RootNodeType::ComplexType, "note")
+        .unwrap()
+        .to_xml(&flattened)
+        .to_string();
+    assert!(note.contains("<xs:element name=\"pitch\" type=\"pitch\"/>"));
+    assert!(note.contains("<xs:element ref=\"footnote\"") || note.contains("name=\"footnote\""));
+    assert!(note.contains("<xs:attribute name=\"default-x\" type=\"tenths\"/>"));
+}
+
+#[test]
+fn patterns() {
+    use libmxgen::xsd::pattern::Pattern;
+    use libmxgen::xsd::restriction::Facet;
+    use libmxgen::xsd::simple_type::Payload;
+    let paths = Paths::default();
+    let mut count = 0;
+    for path in &[&paths.xsd_3_0, &paths.xsd_3_1] {
+        let xsd = Xsd::load(path).unwrap();
+        for entry in xsd.entries() {
+            let restriction = match entry {
+                Entry::SimpleType(st) => match &st.payload {
+                    Payload::Restriction(r) => r,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            for facet in &restriction.facets {
+                if let Facet::Pattern(p) = facet {
+                    let pattern = Pattern::new(p).unwrap();
+                    pattern.regex().unwrap();
+                    pattern.to_cpp().unwrap();
+                    count += 1;
+                }
+            }
+        }
+    }
+    assert!(count > 10);
+}
@@ -0,0 +1,20941 @@
+// MusicXML Class Library
+// Copyright (c) by Matthew James Briggs
+// Distributed under the MIT License
+
+// self
+#include "mx/core/ContentModels.h"
+
+// std
+#include <map>
+#include <utility>
+
+namespace mx
+{
+    namespace core
+    {
+        namespace
+        {
+            /// The index of an element in the tables of getElementType and isContentValid, or -1.
+            int findElement( const std::string& parentType, const std::string& elementName )
+            {
+                static const std::map<std::pair<std::string, std::string>, int> elements =
+                {
+                    { { "", "score-partwise" }, 0 },
+                    { { "", "score-timewise" }, 1 },
+                    { { "Accord", "tuning-alter" }, 2 },
+                    { { "Accord", "tuning-octave" }, 3 },
+                    { { "Accord", "tuning-step" }, 4 },
+                    { { "AccordionRegistration", "accordion-high" }, 5 },
+                    { { "AccordionRegistration", "accordion-low" }, 6 },
+                    { { "AccordionRegistration", "accordion-middle" }, 7 },
+                    { { "Appearance", "distance" }, 8 },
+                    { { "Appearance", "line-width" }, 9 },
+                    { { "Appearance", "note-size" }, 10 },
+                    { { "Appearance", "other-appearance" }, 11 },
+                    { { "Arrow", "arrow-direction" }, 12 },
+                    { { "Arrow", "arrow-style" }, 13 },
+                    { { "Arrow", "circular-arrow" }, 14 },
+                    { { "Articulations", "accent" }, 15 },
+                    { { "Articulations", "breath-mark" }, 16 },
+                    { { "Articulations", "caesura" }, 17 },
+                    { { "Articulations", "detached-legato" }, 18 },
+                    { { "Articulations", "doit" }, 19 },
+                    { { "Articulations", "falloff" }, 20 },
+                    { { "Articulations", "other-articulation" }, 21 },
+                    { { "Articulations", "plop" }, 22 },
+                    { { "Articulations", "scoop" }, 23 },
+                    { { "Articulations", "spiccato" }, 24 },
+                    { { "Articulations", "staccatissimo" }, 25 },
+                    { { "Articulations", "staccato" }, 26 },
+                    { { "Articulations", "stress" }, 27 },
+                    { { "Articulations", "tenuto" }, 28 },
+                    { { "Articulations", "unstress" }, 29 },
+                    { { "Attributes", "clef" }, 30 },
+                    { { "Attributes", "directive" }, 31 },
+                    { { "Attributes", "divisions" }, 32 },
+                    { { "Attributes", "footnote" }, 33 },
+                    { { "Attributes", "instruments" }, 34 },
+                    { { "Attributes", "key" }, 35 },
+                    { { "Attributes", "level" }, 36 },
+                    { { "Attributes", "measure-style" }, 37 },
+                    { { "Attributes", "part-symbol" }, 38 },
+                    { { "Attributes", "staff-details" }, 39 },
+                    { { "Attributes", "staves" }, 40 },
+                    { { "Attributes", "time" }, 41 },
+                    { { "Attributes", "transpose" }, 42 },
+                    { { "Backup", "duration" }, 43 },
+                    { { "Backup", "footnote" }, 44 },
+                    { { "Backup", "level" }, 45 },
+                    { { "Barline", "bar-style" }, 46 },
+                    { { "Barline", "coda" }, 47 },
+                    { { "Barline", "ending" }, 48 },
+                    { { "Barline", "fermata" }, 49 },
+                    { { "Barline", "footnote" }, 50 },
+                    { { "Barline", "level" }, 51 },
+                    { { "Barline", "repeat" }, 52 },
+                    { { "Barline", "segno" }, 53 },
+                    { { "Barline", "wavy-line" }, 54 },
+                    { { "Bass", "bass-alter" }, 55 },
+                    { { "Bass", "bass-step" }, 56 },
+                    { { "BeatRepeat", "slash-dot" }, 57 },
+                    { { "BeatRepeat", "slash-type" }, 58 },
+                    { { "Bend", "bend-alter" }, 59 },
+                    { { "Bend", "pre-bend" }, 60 },
+                    { { "Bend", "release" }, 61 },
+                    { { "Bend", "with-bar" }, 62 },
+                    { { "Clef", "clef-octave-change" }, 63 },
+                    { { "Clef", "line" }, 64 },
+                    { { "Clef", "sign" }, 65 },
+                    { { "Credit", "bookmark" }, 66 },
+                    { { "Credit", "credit-image" }, 67 },
+                    { { "Credit", "credit-type" }, 68 },
+                    { { "Credit", "credit-words" }, 69 },
+                    { { "Credit", "link" }, 70 },
+                    { { "Defaults", "appearance" }, 71 },
+                    { { "Defaults", "lyric-font" }, 72 },
+                    { { "Defaults", "lyric-language" }, 73 },
+                    { { "Defaults", "music-font" }, 74 },
+                    { { "Defaults", "page-layout" }, 75 },
+                    { { "Defaults", "scaling" }, 76 },
+                    { { "Defaults", "staff-layout" }, 77 },
+                    { { "Defaults", "system-layout" }, 78 },
+                    { { "Defaults", "word-font" }, 79 },
+                    { { "Degree", "degree-alter" }, 80 },
+                    { { "Degree", "degree-type" }, 81 },
+                    { { "Degree", "degree-value" }, 82 },
+                    { { "Direction", "direction-type" }, 83 },
+                    { { "Direction", "footnote" }, 84 },
+                    { { "Direction", "level" }, 85 },
+                    { { "Direction", "offset" }, 86 },
+                    { { "Direction", "sound" }, 87 },
+                    { { "Direction", "staff" }, 88 },
+                    { { "Direction", "voice" }, 89 },
+                    { { "DirectionType", "accordion-registration" }, 90 },
+                    { { "DirectionType", "bracket" }, 91 },
+                    { { "DirectionType", "coda" }, 92 },
+                    { { "DirectionType", "damp" }, 93 },
+                    { { "DirectionType", "damp-all" }, 94 },
+                    { { "DirectionType", "dashes" }, 95 },
+                    { { "DirectionType", "dynamics" }, 96 },
+                    { { "DirectionType", "eyeglasses" }, 97 },
+                    { { "DirectionType", "harp-pedals" }, 98 },
+                    { { "DirectionType", "image" }, 99 },
+                    { { "DirectionType", "metronome" }, 100 },
+                    { { "DirectionType", "octave-shift" }, 101 },
+                    { { "DirectionType", "other-direction" }, 102 },
+                    { { "DirectionType", "pedal" }, 103 },
+                    { { "DirectionType", "percussion" }, 104 },
+                    { { "DirectionType", "principal-voice" }, 105 },
+                    { { "DirectionType", "rehearsal" }, 106 },
+                    { { "DirectionType", "scordatura" }, 107 },
+                    { { "DirectionType", "segno" }, 108 },
+                    { { "DirectionType", "string-mute" }, 109 },
+                    { { "DirectionType", "wedge" }, 110 },
+                    { { "DirectionType", "words" }, 111 },
+                    { { "Dynamics", "f" }, 112 },
+                    { { "Dynamics", "ff" }, 113 },
+                    { { "Dynamics", "fff" }, 114 },
+                    { { "Dynamics", "ffff" }, 115 },
+                    { { "Dynamics", "fffff" }, 116 },
+                    { { "Dynamics", "ffffff" }, 117 },
+                    { { "Dynamics", "fp" }, 118 },
+                    { { "Dynamics", "fz" }, 119 },
+                    { { "Dynamics", "mf" }, 120 },
+                    { { "Dynamics", "mp" }, 121 },
+                    { { "Dynamics", "other-dynamics" }, 122 },
+                    { { "Dynamics", "p" }, 123 },
+                    { { "Dynamics", "pp" }, 124 },
+                    { { "Dynamics", "ppp" }, 125 },
+                    { { "Dynamics", "pppp" }, 126 },
+                    { { "Dynamics", "ppppp" }, 127 },
+                    { { "Dynamics", "pppppp" }, 128 },
+                    { { "Dynamics", "rf" }, 129 },
+                    { { "Dynamics", "rfz" }, 130 },
+                    { { "Dynamics", "sf" }, 131 },
+                    { { "Dynamics", "sffz" }, 132 },
+                    { { "Dynamics", "sfp" }, 133 },
+                    { { "Dynamics", "sfpp" }, 134 },
+                    { { "Dynamics", "sfz" }, 135 },
+                    { { "Encoding", "encoder" }, 136 },
+                    { { "Encoding", "encoding-date" }, 137 },
+                    { { "Encoding", "encoding-description" }, 138 },
+                    { { "Encoding", "software" }, 139 },
+                    { { "Encoding", "supports" }, 140 },
+                    { { "Figure", "extend" }, 141 },
+                    { { "Figure", "figure-number" }, 142 },
+                    { { "Figure", "prefix" }, 143 },
+                    { { "Figure", "suffix" }, 144 },
+                    { { "FiguredBass", "duration" }, 145 },
+                    { { "FiguredBass", "figure" }, 146 },
+                    { { "FiguredBass", "footnote" }, 147 },
+                    { { "FiguredBass", "level" }, 148 },
+                    { { "Forward", "duration" }, 149 },
+                    { { "Forward", "footnote" }, 150 },
+                    { { "Forward", "level" }, 151 },
+                    { { "Forward", "staff" }, 152 },
+                    { { "Forward", "voice" }, 153 },
+                    { { "Frame", "first-fret" }, 154 },
+                    { { "Frame", "frame-frets" }, 155 },
+                    { { "Frame", "frame-note" }, 156 },
+                    { { "Frame", "frame-strings" }, 157 },
+                    { { "FrameNote", "barre" }, 158 },
+                    { { "FrameNote", "fingering" }, 159 },
+                    { { "FrameNote", "fret" }, 160 },
+                    { { "FrameNote", "string" }, 161 },
+                    { { "Grouping", "feature" }, 162 },
+                    { { "Harmonic", "artificial" }, 163 },
+                    { { "Harmonic", "base-pitch" }, 164 },
+                    { { "Harmonic", "natural" }, 165 },
+                    { { "Harmonic", "sounding-pitch" }, 166 },
+                    { { "Harmonic", "touching-pitch" }, 167 },
+                    { { "Harmony", "bass" }, 168 },
+                    { { "Harmony", "degree" }, 169 },
+                    { { "Harmony", "footnote" }, 170 },
+                    { { "Harmony", "frame" }, 171 },
+                    { { "Harmony", "function" }, 172 },
+                    { { "Harmony", "inversion" }, 173 },
+                    { { "Harmony", "kind" }, 174 },
+                    { { "Harmony", "level" }, 175 },
+                    { { "Harmony", "offset" }, 176 },
+                    { { "Harmony", "root" }, 177 },
+                    { { "Harmony", "staff" }, 178 },
+                    { { "HarpPedals", "pedal-tuning" }, 179 },
+                    { { "Hole", "hole-closed" }, 180 },
+                    { { "Hole", "hole-shape" }, 181 },
+                    { { "Hole", "hole-type" }, 182 },
+                    { { "Identification", "creator" }, 183 },
+                    { { "Identification", "encoding" }, 184 },
+                    { { "Identification", "miscellaneous" }, 185 },
+                    { { "Identification", "relation" }, 186 },
+                    { { "Identification", "rights" }, 187 },
+                    { { "Identification", "source" }, 188 },
+                    { { "Interchangeable", "beat-type" }, 189 },
+                    { { "Interchangeable", "beats" }, 190 },
+                    { { "Interchangeable", "time-relation" }, 191 },
+                    { { "Key", "cancel" }, 192 },
+                    { { "Key", "fifths" }, 193 },
+                    { { "Key", "key-accidental" }, 194 },
+                    { { "Key", "key-alter" }, 195 },
+                    { { "Key", "key-octave" }, 196 },
+                    { { "Key", "key-step" }, 197 },
+                    { { "Key", "mode" }, 198 },
+                    { { "Lyric", "elision" }, 199 },
+                    { { "Lyric", "end-line" }, 200 },
+                    { { "Lyric", "end-paragraph" }, 201 },
+                    { { "Lyric", "extend" }, 202 },
+                    { { "Lyric", "footnote" }, 203 },
+                    { { "Lyric", "humming" }, 204 },
+                    { { "Lyric", "laughing" }, 205 },
+                    { { "Lyric", "level" }, 206 },
+                    { { "Lyric", "syllabic" }, 207 },
+                    { { "Lyric", "text" }, 208 },
+                    { { "MeasureLayout", "measure-distance" }, 209 },
+                    { { "MeasureStyle", "beat-repeat" }, 210 },
+                    { { "MeasureStyle", "measure-repeat" }, 211 },
+                    { { "MeasureStyle", "multiple-rest" }, 212 },
+                    { { "MeasureStyle", "slash" }, 213 },
+                    { { "Metronome", "beat-unit" }, 214 },
+                    { { "Metronome", "beat-unit-dot" }, 215 },
+                    { { "Metronome", "metronome-note" }, 216 },
+                    { { "Metronome", "metronome-relation" }, 217 },
+                    { { "Metronome", "per-minute" }, 218 },
+                    { { "MetronomeNote", "metronome-beam" }, 219 },
+                    { { "MetronomeNote", "metronome-dot" }, 220 },
+                    { { "MetronomeNote", "metronome-type" }, 221 },
+                    { { "MidiInstrument", "elevation" }, 222 },
+                    { { "MidiInstrument", "midi-bank" }, 223 },
+                    { { "MidiInstrument", "midi-channel" }, 224 },
+                    { { "MidiInstrument", "midi-name" }, 225 },
+                    { { "MidiInstrument", "midi-program" }, 226 },
+                    { { "MidiInstrument", "midi-unpitched" }, 227 },
+                    { { "MidiInstrument", "pan" }, 228 },
+                    { { "MidiInstrument", "volume" }, 229 },
+                    { { "Miscellaneous", "miscellaneous-field" }, 230 },
+                    { { "NameDisplay", "accidental-text" }, 231 },
+                    { { "NameDisplay", "display-text" }, 232 },
+                    { { "Notations", "accidental-mark" }, 233 },
+                    { { "Notations", "arpeggiate" }, 234 },
+                    { { "Notations", "articulations" }, 235 },
+                    { { "Notations", "dynamics" }, 236 },
+                    { { "Notations", "fermata" }, 237 },
+                    { { "Notations", "footnote" }, 238 },
+                    { { "Notations", "glissando" }, 239 },
+                    { { "Notations", "level" }, 240 },
+                    { { "Notations", "non-arpeggiate" }, 241 },
+                    { { "Notations", "ornaments" }, 242 },
+                    { { "Notations", "other-notation" }, 243 },
+                    { { "Notations", "slide" }, 244 },
+                    { { "Notations", "slur" }, 245 },
+                    { { "Notations", "technical" }, 246 },
+                    { { "Notations", "tied" }, 247 },
+                    { { "Notations", "tuplet" }, 248 },
+                    { { "Note", "accidental" }, 249 },
+                    { { "Note", "beam" }, 250 },
+                    { { "Note", "chord" }, 251 },
+                    { { "Note", "cue" }, 252 },
+                    { { "Note", "dot" }, 253 },
+                    { { "Note", "duration" }, 254 },
+                    { { "Note", "footnote" }, 255 },
+                    { { "Note", "grace" }, 256 },
+                    { { "Note", "instrument" }, 257 },
+                    { { "Note", "level" }, 258 },
+                    { { "Note", "lyric" }, 259 },
+                    { { "Note", "notations" }, 260 },
+                    { { "Note", "notehead" }, 261 },
+                    { { "Note", "notehead-text" }, 262 },
+                    { { "Note", "pitch" }, 263 },
+                    { { "Note", "play" }, 264 },
+                    { { "Note", "rest" }, 265 },
+                    { { "Note", "staff" }, 266 },
+                    { { "Note", "stem" }, 267 },
+                    { { "Note", "tie" }, 268 },
+                    { { "Note", "time-modification" }, 269 },
+                    { { "Note", "type" }, 270 },
+                    { { "Note", "unpitched" }, 271 },
+                    { { "Note", "voice" }, 272 },
+                    { { "NoteheadText", "accidental-text" }, 273 },
+                    { { "NoteheadText", "display-text" }, 274 },
+                    { { "Ornaments", "accidental-mark" }, 275 },
+                    { { "Ornaments", "delayed-inverted-turn" }, 276 },
+                    { { "Ornaments", "delayed-turn" }, 277 },
+                    { { "Ornaments", "inverted-turn" }, 278 },
+                    { { "Ornaments", "other-ornament" }, 279 },
+                    { { "Ornaments", "schleifer" }, 280 },
+                    { { "Ornaments", "shake" }, 281 },
+                    { { "Ornaments", "tremolo" }, 282 },
+                    { { "Ornaments", "trill-mark" }, 283 },
+                    { { "Ornaments", "turn" }, 284 },
+                    { { "Ornaments", "vertical-turn" }, 285 },
+                    { { "Ornaments", "wavy-line" }, 286 },
+                    { { "PageLayout", "page-height" }, 287 },
+                    { { "PageLayout", "page-margins" }, 288 },
+                    { { "PageLayout", "page-width" }, 289 },
+                    { { "PageMargins", "bottom-margin" }, 290 },
+                    { { "PageMargins", "left-margin" }, 291 },
+                    { { "PageMargins", "right-margin" }, 292 },
+                    { { "PageMargins", "top-margin" }, 293 },
+                    { { "PartGroup", "footnote" }, 294 },
+                    { { "PartGroup", "group-abbreviation" }, 295 },
+                    { { "PartGroup", "group-abbreviation-display" }, 296 },
+                    { { "PartGroup", "group-barline" }, 297 },
+                    { { "PartGroup", "group-name" }, 298 },
+                    { { "PartGroup", "group-name-display" }, 299 },
+                    { { "PartGroup", "group-symbol" }, 300 },
+                    { { "PartGroup", "group-time" }, 301 },
+                    { { "PartGroup", "level" }, 302 },
+                    { { "PartList", "part-group" }, 303 },
+                    { { "PartList", "score-part" }, 304 },
+                    { { "PedalTuning", "pedal-alter" }, 305 },
+                    { { "PedalTuning", "pedal-step" }, 306 },
+                    { { "Percussion", "beater" }, 307 },
+                    { { "Percussion", "effect" }, 308 },
+                    { { "Percussion", "glass" }, 309 },
+                    { { "Percussion", "membrane" }, 310 },
+                    { { "Percussion", "metal" }, 311 },
+                    { { "Percussion", "other-percussion" }, 312 },
+                    { { "Percussion", "pitched" }, 313 },
+                    { { "Percussion", "stick" }, 314 },
+                    { { "Percussion", "stick-location" }, 315 },
+                    { { "Percussion", "timpani" }, 316 },
+                    { { "Percussion", "wood" }, 317 },
+                    { { "Pitch", "alter" }, 318 },
+                    { { "Pitch", "octave" }, 319 },
+                    { { "Pitch", "step" }, 320 },
+                    { { "Play", "ipa" }, 321 },
+                    { { "Play", "mute" }, 322 },
+                    { { "Play", "other-play" }, 323 },
+                    { { "Play", "semi-pitched" }, 324 },
+                    { { "Print", "measure-layout" }, 325 },
+                    { { "Print", "measure-numbering" }, 326 },
+                    { { "Print", "page-layout" }, 327 },
+                    { { "Print", "part-abbreviation-display" }, 328 },
+                    { { "Print", "part-name-display" }, 329 },
+                    { { "Print", "staff-layout" }, 330 },
+                    { { "Print", "system-layout" }, 331 },
+                    { { "Rest", "display-octave" }, 332 },
+                    { { "Rest", "display-step" }, 333 },
+                    { { "Root", "root-alter" }, 334 },
+                    { { "Root", "root-step" }, 335 },
+                    { { "Scaling", "millimeters" }, 336 },
+                    { { "Scaling", "tenths" }, 337 },
+                    { { "Scordatura", "accord" }, 338 },
+                    { { "ScoreInstrument", "ensemble" }, 339 },
+                    { { "ScoreInstrument", "instrument-abbreviation" }, 340 },
+                    { { "ScoreInstrument", "instrument-name" }, 341 },
+                    { { "ScoreInstrument", "instrument-sound" }, 342 },
+                    { { "ScoreInstrument", "solo" }, 343 },
+                    { { "ScoreInstrument", "virtual-instrument" }, 344 },
+                    { { "ScorePart", "group" }, 345 },
+                    { { "ScorePart", "identification" }, 346 },
+                    { { "ScorePart", "midi-device" }, 347 },
+                    { { "ScorePart", "midi-instrument" }, 348 },
+                    { { "ScorePart", "part-abbreviation" }, 349 },
+                    { { "ScorePart", "part-abbreviation-display" }, 350 },
+                    { { "ScorePart", "part-name" }, 351 },
+                    { { "ScorePart", "part-name-display" }, 352 },
+                    { { "ScorePart", "score-instrument" }, 353 },
+                    { { "ScorePartwise", "credit" }, 354 },
+                    { { "ScorePartwise", "defaults" }, 355 },
+                    { { "ScorePartwise", "identification" }, 356 },
+                    { { "ScorePartwise", "movement-number" }, 357 },
+                    { { "ScorePartwise", "movement-title" }, 358 },
+                    { { "ScorePartwise", "part" }, 359 },
+                    { { "ScorePartwise", "part-list" }, 360 },
+                    { { "ScorePartwise", "work" }, 361 },
+                    { { "ScorePartwisePart", "measure" }, 362 },
+                    { { "ScorePartwisePartMeasure", "attributes" }, 363 },
+                    { { "ScorePartwisePartMeasure", "backup" }, 364 },
+                    { { "ScorePartwisePartMeasure", "barline" }, 365 },
+                    { { "ScorePartwisePartMeasure", "bookmark" }, 366 },
+                    { { "ScorePartwisePartMeasure", "direction" }, 367 },
+                    { { "ScorePartwisePartMeasure", "figured-bass" }, 368 },
+                    { { "ScorePartwisePartMeasure", "forward" }, 369 },
+                    { { "ScorePartwisePartMeasure", "grouping" }, 370 },
+                    { { "ScorePartwisePartMeasure", "harmony" }, 371 },
+                    { { "ScorePartwisePartMeasure", "link" }, 372 },
+                    { { "ScorePartwisePartMeasure", "note" }, 373 },
+                    { { "ScorePartwisePartMeasure", "print" }, 374 },
+                    { { "ScorePartwisePartMeasure", "sound" }, 375 },
+                    { { "ScoreTimewise", "credit" }, 376 },
+                    { { "ScoreTimewise", "defaults" }, 377 },
+                    { { "ScoreTimewise", "identification" }, 378 },
+                    { { "ScoreTimewise", "measure" }, 379 },
+                    { { "ScoreTimewise", "movement-number" }, 380 },
+                    { { "ScoreTimewise", "movement-title" }, 381 },
+                    { { "ScoreTimewise", "part-list" }, 382 },
+                    { { "ScoreTimewise", "work" }, 383 },
+                    { { "ScoreTimewiseMeasure", "part" }, 384 },
+                    { { "ScoreTimewiseMeasurePart", "attributes" }, 385 },
+                    { { "ScoreTimewiseMeasurePart", "backup" }, 386 },
+                    { { "ScoreTimewiseMeasurePart", "barline" }, 387 },
+                    { { "ScoreTimewiseMeasurePart", "bookmark" }, 388 },
+                    { { "ScoreTimewiseMeasurePart", "direction" }, 389 },
+                    { { "ScoreTimewiseMeasurePart", "figured-bass" }, 390 },
+                    { { "ScoreTimewiseMeasurePart", "forward" }, 391 },
+                    { { "ScoreTimewiseMeasurePart", "grouping" }, 392 },
+                    { { "ScoreTimewiseMeasurePart", "harmony" }, 393 },
+                    { { "ScoreTimewiseMeasurePart", "link" }, 394 },
+                    { { "ScoreTimewiseMeasurePart", "note" }, 395 },
+                    { { "ScoreTimewiseMeasurePart", "print" }, 396 },
+                    { { "ScoreTimewiseMeasurePart", "sound" }, 397 },
+                    { { "Slash", "slash-dot" }, 398 },
+                    { { "Slash", "slash-type" }, 399 },
+                    { { "Sound", "midi-device" }, 400 },
+                    { { "Sound", "midi-instrument" }, 401 },
+                    { { "Sound", "offset" }, 402 },
+                    { { "Sound", "play" }, 403 },
+                    { { "StaffDetails", "capo" }, 404 },
+                    { { "StaffDetails", "staff-lines" }, 405 },
+                    { { "StaffDetails", "staff-size" }, 406 },
+                    { { "StaffDetails", "staff-tuning" }, 407 },
+                    { { "StaffDetails", "staff-type" }, 408 },
+                    { { "StaffLayout", "staff-distance" }, 409 },
+                    { { "StaffTuning", "tuning-alter" }, 410 },
+                    { { "StaffTuning", "tuning-octave" }, 411 },
+                    { { "StaffTuning", "tuning-step" }, 412 },
+                    { { "Stick", "stick-material" }, 413 },
+                    { { "Stick", "stick-type" }, 414 },
+                    { { "SystemDividers", "left-divider" }, 415 },
+                    { { "SystemDividers", "right-divider" }, 416 },
+                    { { "SystemLayout", "system-distance" }, 417 },
+                    { { "SystemLayout", "system-dividers" }, 418 },
+                    { { "SystemLayout", "system-margins" }, 419 },
+                    { { "SystemLayout", "top-system-distance" }, 420 },
+                    { { "SystemMargins", "left-margin" }, 421 },
+                    { { "SystemMargins", "right-margin" }, 422 },
+                    { { "Technical", "arrow" }, 423 },
+                    { { "Technical", "bend" }, 424 },
+                    { { "Technical", "double-tongue" }, 425 },
+                    { { "Technical", "down-bow" }, 426 },
+                    { { "Technical", "fingering" }, 427 },
+                    { { "Technical", "fingernails" }, 428 },
+                    { { "Technical", "fret" }, 429 },
+                    { { "Technical", "hammer-on" }, 430 },
+                    { { "Technical", "handbell" }, 431 },
+                    { { "Technical", "harmonic" }, 432 },
+                    { { "Technical", "hole" }, 433 },
+                    { { "Technical", "open-string" }, 434 },
+                    { { "Technical", "other-technical" }, 435 },
+                    { { "Technical", "pluck" }, 436 },
+                    { { "Technical", "pull-off" }, 437 },
+                    { { "Technical", "snap-pizzicato" }, 438 },
+                    { { "Technical", "stopped" }, 439 },
+                    { { "Technical", "string" }, 440 },
+                    { { "Technical", "tap" }, 441 },
+                    { { "Technical", "thumb-position" }, 442 },
+                    { { "Technical", "triple-tongue" }, 443 },
+                    { { "Technical", "up-bow" }, 444 },
+                    { { "Time", "beat-type" }, 445 },
+                    { { "Time", "beats" }, 446 },
+                    { { "Time", "interchangeable" }, 447 },
+                    { { "Time", "senza-misura" }, 448 },
+                    { { "TimeModification", "actual-notes" }, 449 },
+                    { { "TimeModification", "normal-dot" }, 450 },
+                    { { "TimeModification", "normal-notes" }, 451 },
+                    { { "TimeModification", "normal-type" }, 452 },
+                    { { "Transpose", "chromatic" }, 453 },
+                    { { "Transpose", "diatonic" }, 454 },
+                    { { "Transpose", "double" }, 455 },
+                    { { "Transpose", "octave-change" }, 456 },
+                    { { "Tuplet", "tuplet-actual" }, 457 },
+                    { { "Tuplet", "tuplet-normal" }, 458 },
+                    { { "TupletPortion", "tuplet-dot" }, 459 },
+                    { { "TupletPortion", "tuplet-number" }, 460 },
+                    { { "TupletPortion", "tuplet-type" }, 461 },
+                    { { "Unpitched", "display-octave" }, 462 },
+                    { { "Unpitched", "display-step" }, 463 },
+                    { { "VirtualInstrument", "virtual-library" }, 464 },
+                    { { "VirtualInstrument", "virtual-name" }, 465 },
+                    { { "Work", "opus" }, 466 },
+                    { { "Work", "work-number" }, 467 },
+                    { { "Work", "work-title" }, 468 }
+                };
+
+                const auto found = elements.find( std::make_pair( parentType, elementName ) );
+                return found == elements.cend() ? -1 : found->second;
+            }
+        }
+
+        std::string getElementType( const std::string& parentType, const std::string& elementName )
+        {
+            static const char* const types[] =
+            {
+                "ScorePartwise",
+                "ScoreTimewise",
+                "",
+                "",
+                "",
+                "Empty",
+                "Empty",
+                "",
+                "Distance",
+                "LineWidth",
+                "NoteSize",
+                "OtherAppearance",
+                "",
+                "",
+                "",
+                "EmptyPlacement",
+                "BreathMark",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyLine",
+                "EmptyLine",
+                "PlacementText",
+                "EmptyLine",
+                "EmptyLine",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "Clef",
+                "AttributesDirective",
+                "",
+                "FormattedText",
+                "",
+                "Key",
+                "Level",
+                "MeasureStyle",
+                "PartSymbol",
+                "StaffDetails",
+                "",
+                "Time",
+                "Transpose",
+                "",
+                "FormattedText",
+                "Level",
+                "BarStyleColor",
+                "EmptyPrintStyleAlign",
+                "Ending",
+                "Fermata",
+                "FormattedText",
+                "Level",
+                "Repeat",
+                "EmptyPrintStyleAlign",
+                "WavyLine",
+                "BassAlter",
+                "BassStep",
+                "Empty",
+                "",
+                "",
+                "Empty",
+                "Empty",
+                "PlacementText",
+                "",
+                "",
+                "",
+                "Bookmark",
+                "Image",
+                "",
+                "FormattedText",
+                "Link",
+                "Appearance",
+                "LyricFont",
+                "LyricLanguage",
+                "EmptyFont",
+                "PageLayout",
+                "Scaling",
+                "StaffLayout",
+                "SystemLayout",
+                "EmptyFont",
+                "DegreeAlter",
+                "DegreeType",
+                "DegreeValue",
+                "DirectionType",
+                "FormattedText",
+                "Level",
+                "Offset",
+                "Sound",
+                "",
+                "",
+                "AccordionRegistration",
+                "Bracket",
+                "EmptyPrintStyleAlign",
+                "EmptyPrintStyleAlign",
+                "EmptyPrintStyleAlign",
+                "Dashes",
+                "Dynamics",
+                "EmptyPrintStyleAlign",
+                "HarpPedals",
+                "Image",
+                "Metronome",
+                "OctaveShift",
+                "OtherDirection",
+                "Pedal",
+                "Percussion",
+                "PrincipalVoice",
+                "FormattedText",
+                "Scordatura",
+                "EmptyPrintStyleAlign",
+                "StringMute",
+                "Wedge",
+                "FormattedText",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "TypedText",
+                "",
+                "",
+                "",
+                "Supports",
+                "Extend",
+                "StyleText",
+                "StyleText",
+                "StyleText",
+                "",
+                "Figure",
+                "FormattedText",
+                "Level",
+                "",
+                "FormattedText",
+                "Level",
+                "",
+                "",
+                "FirstFret",
+                "",
+                "FrameNote",
+                "",
+                "Barre",
+                "Fingering",
+                "Fret",
+                "String",
+                "Feature",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Empty",
+                "Bass",
+                "Degree",
+                "FormattedText",
+                "Frame",
+                "StyleText",
+                "Inversion",
+                "Kind",
+                "Level",
+                "Offset",
+                "Root",
+                "",
+                "PedalTuning",
+                "HoleClosed",
+                "",
+                "",
+                "TypedText",
+                "Encoding",
+                "Miscellaneous",
+                "TypedText",
+                "TypedText",
+                "",
+                "",
+                "",
+                "",
+                "Cancel",
+                "",
+                "",
+                "",
+                "KeyOctave",
+                "",
+                "",
+                "TextFontColor",
+                "Empty",
+                "Empty",
+                "Extend",
+                "FormattedText",
+                "Empty",
+                "Empty",
+                "Level",
+                "",
+                "TextElementData",
+                "",
+                "BeatRepeat",
+                "MeasureRepeat",
+                "MultipleRest",
+                "Slash",
+                "",
+                "Empty",
+                "MetronomeNote",
+                "",
+                "PerMinute",
+                "MetronomeBeam",
+                "Empty",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "MiscellaneousField",
+                "AccidentalText",
+                "FormattedText",
+                "AccidentalMark",
+                "Arpeggiate",
+                "Articulations",
+                "Dynamics",
+                "Fermata",
+                "FormattedText",
+                "Glissando",
+                "Level",
+                "NonArpeggiate",
+                "Ornaments",
+                "OtherNotation",
+                "Slide",
+                "Slur",
+                "Technical",
+                "Tied",
+                "Tuplet",
+                "Accidental",
+                "Beam",
+                "Empty",
+                "Empty",
+                "EmptyPlacement",
+                "",
+                "FormattedText",
+                "Grace",
+                "Instrument",
+                "Level",
+                "Lyric",
+                "Notations",
+                "Notehead",
+                "NoteheadText",
+                "Pitch",
+                "Play",
+                "Rest",
+                "",
+                "Stem",
+                "Tie",
+                "TimeModification",
+                "NoteType",
+                "Unpitched",
+                "",
+                "AccidentalText",
+                "FormattedText",
+                "AccidentalMark",
+                "HorizontalTurn",
+                "HorizontalTurn",
+                "HorizontalTurn",
+                "PlacementText",
+                "EmptyPlacement",
+                "EmptyTrillSound",
+                "Tremolo",
+                "EmptyTrillSound",
+                "HorizontalTurn",
+                "EmptyTrillSound",
+                "WavyLine",
+                "",
+                "PageMargins",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "FormattedText",
+                "GroupName",
+                "NameDisplay",
+                "GroupBarline",
+                "GroupName",
+                "NameDisplay",
+                "GroupSymbol",
+                "Empty",
+                "Level",
+                "PartGroup",
+                "ScorePart",
+                "",
+                "",
+                "Beater",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "Stick",
+                "",
+                "Empty",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "OtherPlay",
+                "",
+                "MeasureLayout",
+                "MeasureNumbering",
+                "PageLayout",
+                "NameDisplay",
+                "NameDisplay",
+                "StaffLayout",
+                "SystemLayout",
+                "",
+                "",
+                "RootAlter",
+                "RootStep",
+                "",
+                "",
+                "Accord",
+                "",
+                "",
+                "",
+                "",
+                "Empty",
+                "VirtualInstrument",
+                "",
+                "Identification",
+                "MidiDevice",
+                "MidiInstrument",
+                "PartName",
+                "NameDisplay",
+                "PartName",
+                "NameDisplay",
+                "ScoreInstrument",
+                "Credit",
+                "Defaults",
+                "Identification",
+                "",
+                "",
+                "ScorePartwisePart",
+                "PartList",
+                "Work",
+                "ScorePartwisePartMeasure",
+                "Attributes",
+                "Backup",
+                "Barline",
+                "Bookmark",
+                "Direction",
+                "FiguredBass",
+                "Forward",
+                "Grouping",
+                "Harmony",
+                "Link",
+                "Note",
+                "Print",
+                "Sound",
+                "Credit",
+                "Defaults",
+                "Identification",
+                "ScoreTimewiseMeasure",
+                "",
+                "",
+                "PartList",
+                "Work",
+                "ScoreTimewiseMeasurePart",
+                "Attributes",
+                "Backup",
+                "Barline",
+                "Bookmark",
+                "Direction",
+                "FiguredBass",
+                "Forward",
+                "Grouping",
+                "Harmony",
+                "Link",
+                "Note",
+                "Print",
+                "Sound",
+                "Empty",
+                "",
+                "MidiDevice",
+                "MidiInstrument",
+                "Offset",
+                "Play",
+                "",
+                "",
+                "",
+                "StaffTuning",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "EmptyPrintObjectStyleAlign",
+                "EmptyPrintObjectStyleAlign",
+                "",
+                "SystemDividers",
+                "SystemMargins",
+                "",
+                "",
+                "",
+                "Arrow",
+                "Bend",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "Fingering",
+                "EmptyPlacement",
+                "Fret",
+                "HammerOnPullOff",
+                "Handbell",
+                "Harmonic",
+                "Hole",
+                "EmptyPlacement",
+                "PlacementText",
+                "PlacementText",
+                "HammerOnPullOff",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "String",
+                "PlacementText",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "EmptyPlacement",
+                "",
+                "",
+                "Interchangeable",
+                "",
+                "",
+                "Empty",
+                "",
+                "",
+                "",
+                "",
+                "Empty",
+                "",
+                "TupletPortion",
+                "TupletPortion",
+                "TupletDot",
+                "TupletNumber",
+                "TupletType",
+                "",
+                "",
+                "",
+                "",
+                "Opus",
+                "",
+                ""
+            };
+
+            const int element = findElement( parentType, elementName );
+            return element < 0 ? std::string{} : std::string{ types[element] };
+        }
+
+        bool isContentValid( const std::string& parentType, const std::string& elementName, const std::vector<std::string>& childNames )
+        {
+            switch( findElement( parentType, elementName ) )
+            {
+                case 0:
+                    return isScorePartwiseContentValid( childNames );
+                case 1:
+                    return isScoreTimewiseContentValid( childNames );
+                case 2:
+                    return childNames.empty();
+                case 3:
+                    return childNames.empty();
+                case 4:
+                    return childNames.empty();
+                case 5:
+                    return childNames.empty();
+                case 6:
+                    return childNames.empty();
+                case 7:
+                    return childNames.empty();
+                case 8:
+                    return childNames.empty();
+                case 9:
+                    return childNames.empty();
+                case 10:
+                    return childNames.empty();
+                case 11:
+                    return childNames.empty();
+                case 12:
+                    return childNames.empty();
+                case 13:
+                    return childNames.empty();
+                case 14:
+                    return childNames.empty();
+                case 15:
+                    return childNames.empty();
+                case 16:
+                    return childNames.empty();
+                case 17:
+                    return childNames.empty();
+                case 18:
+                    return childNames.empty();
+                case 19:
+                    return childNames.empty();
+                case 20:
+                    return childNames.empty();
+                case 21:
+                    return childNames.empty();
+                case 22:
+                    return childNames.empty();
+                case 23:
+                    return childNames.empty();
+                case 24:
+                    return childNames.empty();
+                case 25:
+                    return childNames.empty();
+                case 26:
+                    return childNames.empty();
+                case 27:
+                    return childNames.empty();
+                case 28:
+                    return childNames.empty();
+                case 29:
+                    return childNames.empty();
+                case 30:
+                    return isClefContentValid( childNames );
+                case 31:
+                    return childNames.empty();
+                case 32:
+                    return childNames.empty();
+                case 33:
+                    return childNames.empty();
+                case 34:
+                    return childNames.empty();
+                case 35:
+                    return isKeyContentValid( childNames );
+                case 36:
+                    return childNames.empty();
+                case 37:
+                    return isMeasureStyleContentValid( childNames );
+                case 38:
+                    return childNames.empty();
+                case 39:
+                    return isStaffDetailsContentValid( childNames );
+                case 40:
+                    return childNames.empty();
+                case 41:
+                    return isTimeContentValid( childNames );
+                case 42:
+                    return isTransposeContentValid( childNames );
+                case 43:
+                    return childNames.empty();
+                case 44:
+                    return childNames.empty();
+                case 45:
+                    return childNames.empty();
+                case 46:
+                    return childNames.empty();
+                case 47:
+                    return childNames.empty();
+                case 48:
+                    return childNames.empty();
+                case 49:
+                    return childNames.empty();
+                case 50:
+                    return childNames.empty();
+                case 51:
+                    return childNames.empty();
+                case 52:
+                    return childNames.empty();
+                case 53:
+                    return childNames.empty();
+                case 54:
+                    return childNames.empty();
+                case 55:
+                    return childNames.empty();
+                case 56:
+                    return childNames.empty();
+                case 57:
+                    return childNames.empty();
+                case 58:
+                    return childNames.empty();
+                case 59:
+                    return childNames.empty();
+                case 60:
+                    return childNames.empty();
+                case 61:
+                    return childNames.empty();
+                case 62:
+                    return childNames.empty();
+                case 63:
+                    return childNames.empty();
+                case 64:
+                    return childNames.empty();
+                case 65:
+                    return childNames.empty();
+                case 66:
+                    return childNames.empty();
+                case 67:
+                    return childNames.empty();
+                case 68:
+                    return childNames.empty();
+                case 69:
+                    return childNames.empty();
+                case 70:
+                    return childNames.empty();
+                case 71:
+                    return isAppearanceContentValid( childNames );
+                case 72:
+                    return childNames.empty();
+                case 73:
+                    return childNames.empty();
+                case 74:
+                    return childNames.empty();
+                case 75:
+                    return isPageLayoutContentValid( childNames );
+                case 76:
+                    return isScalingContentValid( childNames );
+                case 77:
+                    return isStaffLayoutContentValid( childNames );
+                case 78:
+                    return isSystemLayoutContentValid( childNames );
+                case 79:
+                    return childNames.empty();
+                case 80:
+                    return childNames.empty();
+                case 81:
+                    return childNames.empty();
+                case 82:
+                    return childNames.empty();
+                case 83:
+                    return isDirectionTypeContentValid( childNames );
+                case 84:
+                    return childNames.empty();
+                case 85:
+                    return childNames.empty();
+                case 86:
+                    return childNames.empty();
+                case 87:
+                    return isSoundContentValid( childNames );
+                case 88:
+                    return childNames.empty();
+                case 89:
+                    return childNames.empty();
+                case 90:
+                    return isAccordionRegistrationContentValid( childNames );
+                case 91:
+                    return childNames.empty();
+                case 92:
+                    return childNames.empty();
+                case 93:
+                    return childNames.empty();
+                case 94:
+                    return childNames.empty();
+                case 95:
+                    return childNames.empty();
+                case 96:
+                    return isDynamicsContentValid( childNames );
+                case 97:
+                    return childNames.empty();
+                case 98:
+                    return isHarpPedalsContentValid( childNames );
+                case 99:
+                    return childNames.empty();
+                case 100:
+                    return isMetronomeContentValid( childNames );
+                case 101:
+                    return childNames.empty();
+                case 102:
+                    return childNames.empty();
+                case 103:
+                    return childNames.empty();
+                case 104:
+                    return isPercussionContentValid( childNames );
+                case 105:
+                    return childNames.empty();
+                case 106:
+                    return childNames.empty();
+                case 107:
+                    return isScordaturaContentValid( childNames );
+                case 108:
+                    return childNames.empty();
+                case 109:
+                    return childNames.empty();
+                case 110:
+                    return childNames.empty();
+                case 111:
+                    return childNames.empty();
+                case 112:
+                    return childNames.empty();
+                case 113:
+                    return childNames.empty();
+                case 114:
+                    return childNames.empty();
+                case 115:
+                    return childNames.empty();
+                case 116:
+                    return childNames.empty();
+                case 117:
+                    return childNames.empty();
+                case 118:
+                    return childNames.empty();
+                case 119:
+                    return childNames.empty();
+                case 120:
+                    return childNames.empty();
+                case 121:
+                    return childNames.empty();
+                case 122:
+                    return childNames.empty();
+                case 123:
+                    return childNames.empty();
+                case 124:
+                    return childNames.empty();
+                case 125:
+                    return childNames.empty();
+                case 126:
+                    return childNames.empty();
+                case 127:
+                    return childNames.empty();
+                case 128:
+                    return childNames.empty();
+                case 129:
+                    return childNames.empty();
+                case 130:
+                    return childNames.empty();
+                case 131:
+                    return childNames.empty();
+                case 132:
+                    return childNames.empty();
+                case 133:
+                    return childNames.empty();
+                case 134:
+                    return childNames.empty();
+                case 135:
+                    return childNames.empty();
+                case 136:
+                    return childNames.empty();
+                case 137:
+                    return childNames.empty();
+                case 138:
+                    return childNames.empty();
+                case 139:
+                    return childNames.empty();
+                case 140:
+                    return childNames.empty();
+                case 141:
+                    return childNames.empty();
+                case 142:
+                    return childNames.empty();
+                case 143:
+                    return childNames.empty();
+                case 144:
+                    return childNames.empty();
+                case 145:
+                    return childNames.empty();
+                case 146:
+                    return isFigureContentValid( childNames );
+                case 147:
+                    return childNames.empty();
+                case 148:
+                    return childNames.empty();
+                case 149:
+                    return childNames.empty();
+                case 150:
+                    return childNames.empty();
+                case 151:
+                    return childNames.empty();
+                case 152:
+                    return childNames.empty();
+                case 153:
+                    return childNames.empty();
+                case 154:
+                    return childNames.empty();
+                case 155:
+                    return childNames.empty();
+                case 156:
+                    return isFrameNoteContentValid( childNames );
+                case 157:
+                    return childNames.empty();
+                case 158:
+                    return childNames.empty();
+                case 159:
+                    return childNames.empty();
+                case 160:
+                    return childNames.empty();
+                case 161:
+                    return childNames.empty();
+                case 162:
+                    return childNames.empty();
+                case 163:
+                    return childNames.empty();
+                case 164:
+                    return childNames.empty();
+                case 165:
+                    return childNames.empty();
+                case 166:
+                    return childNames.empty();
+                case 167:
+                    return childNames.empty();
+                case 168:
+                    return isBassContentValid( childNames );
+                case 169:
+                    return isDegreeContentValid( childNames );
+                case 170:
+                    return childNames.empty();
+                case 171:
+                    return isFrameContentValid( childNames );
+                case 172:
+                    return childNames.empty();
+                case 173:
+                    return childNames.empty();
+                case 174:
+                    return childNames.empty();
+                case 175:
+                    return childNames.empty();
+                case 176:
+                    return childNames.empty();
+                case 177:
+                    return isRootContentValid( childNames );
+                case 178:
+                    return childNames.empty();
+                case 179:
+                    return isPedalTuningContentValid( childNames );
+                case 180:
+                    return childNames.empty();
+                case 181:
+                    return childNames.empty();
+                case 182:
+                    return childNames.empty();
+                case 183:
+                    return childNames.empty();
+                case 184:
+                    return isEncodingContentValid( childNames );
+                case 185:
+                    return isMiscellaneousContentValid( childNames );
+                case 186:
+                    return childNames.empty();
+                case 187:
+                    return childNames.empty();
+                case 188:
+                    return childNames.empty();
+                case 189:
+                    return childNames.empty();
+                case 190:
+                    return childNames.empty();
+                case 191:
+                    return childNames.empty();
+                case 192:
+                    return childNames.empty();
+                case 193:
+                    return childNames.empty();
+                case 194:
+                    return childNames.empty();
+                case 195:
+                    return childNames.empty();
+                case 196:
+                    return childNames.empty();
+                case 197:
+                    return childNames.empty();
+                case 198:
+                    return childNames.empty();
+                case 199:
+                    return childNames.empty();
+                case 200:
+                    return childNames.empty();
+                case 201:
+                    return childNames.empty();
+                case 202:
+                    return childNames.empty();
+                case 203:
+                    return childNames.empty();
+                case 204:
+                    return childNames.empty();
+                case 205:
+                    return childNames.empty();
+                case 206:
+                    return childNames.empty();
+                case 207:
+                    return childNames.empty();
+                case 208:
+                    return childNames.empty();
+                case 209:
+                    return childNames.empty();
+                case 210:
+                    return isBeatRepeatContentValid( childNames );
+                case 211:
+                    return childNames.empty();
+                case 212:
+                    return childNames.empty();
+                case 213:
+                    return isSlashContentValid( childNames );
+                case 214:
+                    return childNames.empty();
+                case 215:
+                    return childNames.empty();
+                case 216:
+                    return isMetronomeNoteContentValid( childNames );
+                case 217:
+                    return childNames.empty();
+                case 218:
+                    return childNames.empty();
+                case 219:
+                    return childNames.empty();
+                case 220:
+                    return childNames.empty();
+                case 221:
+                    return childNames.empty();
+                case 222:
+                    return childNames.empty();
+                case 223:
+                    return childNames.empty();
+                case 224:
+                    return childNames.empty();
+                case 225:
+                    return childNames.empty();
+                case 226:
+                    return childNames.empty();
+                case 227:
+                    return childNames.empty();
+                case 228:
+                    return childNames.empty();
+                case 229:
+                    return childNames.empty();
+                case 230:
+                    return childNames.empty();
+                case 231:
+                    return childNames.empty();
+                case 232:
+                    return childNames.empty();
+                case 233:
+                    return childNames.empty();
+                case 234:
+                    return childNames.empty();
+                case 235:
+                    return isArticulationsContentValid( childNames );
+                case 236:
+                    return isDynamicsContentValid( childNames );
+                case 237:
+                    return childNames.empty();
+                case 238:
+                    return childNames.empty();
+                case 239:
+                    return childNames.empty();
+                case 240:
+                    return childNames.empty();
+                case 241:
+                    return childNames.empty();
+                case 242:
+                    return isOrnamentsContentValid( childNames );
+                case 243:
+                    return childNames.empty();
+                case 244:
+                    return childNames.empty();
+                case 245:
+                    return childNames.empty();
+                case 246:
+                    return isTechnicalContentValid( childNames );
+                case 247:
+                    return childNames.empty();
+                case 248:
+                    return isTupletContentValid( childNames );
+                case 249:
+                    return childNames.empty();
+                case 250:
+                    return childNames.empty();
+                case 251:
+                    return childNames.empty();
+                case 252:
+                    return childNames.empty();
+                case 253:
+                    return childNames.empty();
+                case 254:
+                    return childNames.empty();
+                case 255:
+                    return childNames.empty();
+                case 256:
+                    return childNames.empty();
+                case 257:
+                    return childNames.empty();
+                case 258:
+                    return childNames.empty();
+                case 259:
+                    return isLyricContentValid( childNames );
+                case 260:
+                    return isNotationsContentValid( childNames );
+                case 261:
+                    return childNames.empty();
+                case 262:
+                    return isNoteheadTextContentValid( childNames );
+                case 263:
+                    return isPitchContentValid( childNames );
+                case 264:
+                    return isPlayContentValid( childNames );
+                case 265:
+                    return isRestContentValid( childNames );
+                case 266:
+                    return childNames.empty();
+                case 267:
+                    return childNames.empty();
+                case 268:
+                    return childNames.empty();
+                case 269:
+                    return isTimeModificationContentValid( childNames );
+                case 270:
+                    return childNames.empty();
+                case 271:
+                    return isUnpitchedContentValid( childNames );
+                case 272:
+                    return childNames.empty();
+                case 273:
+                    return childNames.empty();
+                case 274:
+                    return childNames.empty();
+                case 275:
+                    return childNames.empty();
+                case 276:
+                    return childNames.empty();
+                case 277:
+                    return childNames.empty();
+                case 278:
+                    return childNames.empty();
+                case 279:
+                    return childNames.empty();
+                case 280:
+                    return childNames.empty();
+                case 281:
+                    return childNames.empty();
+                case 282:
+                    return childNames.empty();
+                case 283:
+                    return childNames.empty();
+                case 284:
+                    return childNames.empty();
+                case 285:
+                    return childNames.empty();
+                case 286:
+                    return childNames.empty();
+                case 287:
+                    return childNames.empty();
+                case 288:
+                    return isPageMarginsContentValid( childNames );
+                case 289:
+                    return childNames.empty();
+                case 290:
+                    return childNames.empty();
+                case 291:
+                    return childNames.empty();
+                case 292:
+                    return childNames.empty();
+                case 293:
+                    return childNames.empty();
+                case 294:
+                    return childNames.empty();
+                case 295:
+                    return childNames.empty();
+                case 296:
+                    return isNameDisplayContentValid( childNames );
+                case 297:
+                    return childNames.empty();
+                case 298:
+                    return childNames.empty();
+                case 299:
+                    return isNameDisplayContentValid( childNames );
+                case 300:
+                    return childNames.empty();
+                case 301:
+                    return childNames.empty();
+                case 302:
+                    return childNames.empty();
+                case 303:
+                    return isPartGroupContentValid( childNames );
+                case 304:
+                    return isScorePartContentValid( childNames );
+                case 305:
+                    return childNames.empty();
+                case 306:
+                    return childNames.empty();
+                case 307:
+                    return childNames.empty();
+                case 308:
+                    return childNames.empty();
+                case 309:
+                    return childNames.empty();
+                case 310:
+                    return childNames.empty();
+                case 311:
+                    return childNames.empty();
+                case 312:
+                    return childNames.empty();
+                case 313:
+                    return childNames.empty();
+                case 314:
+                    return isStickContentValid( childNames );
+                case 315:
+                    return childNames.empty();
+                case 316:
+                    return childNames.empty();
+                case 317:
+                    return childNames.empty();
+                case 318:
+                    return childNames.empty();
+                case 319:
+                    return childNames.empty();
+                case 320:
+                    return childNames.empty();
+                case 321:
+                    return childNames.empty();
+                case 322:
+                    return childNames.empty();
+                case 323:
+                    return childNames.empty();
+                case 324:
+                    return childNames.empty();
+                case 325:
+                    return isMeasureLayoutContentValid( childNames );
+                case 326:
+                    return childNames.empty();
+                case 327:
+                    return isPageLayoutContentValid( childNames );
+                case 328:
+                    return isNameDisplayContentValid( childNames );
+                case 329:
+                    return isNameDisplayContentValid( childNames );
+                case 330:
+                    return isStaffLayoutContentValid( childNames );
+                case 331:
+                    return isSystemLayoutContentValid( childNames );
+                case 332:
+                    return childNames.empty();
+                case 333:
+                    return childNames.empty();
+                case 334:
+                    return childNames.empty();
+                case 335:
+                    return childNames.empty();
+                case 336:
+                    return childNames.empty();
+                case 337:
+                    return childNames.empty();
+                case 338:
+                    return isAccordContentValid( childNames );
+                case 339:
+                    return childNames.empty();
+                case 340:
+                    return childNames.empty();
+                case 341:
+                    return childNames.empty();
+                case 342:
+                    return childNames.empty();
+                case 343:
+                    return childNames.empty();
+                case 344:
+                    return isVirtualInstrumentContentValid( childNames );
+                case 345:
+                    return childNames.empty();
+                case 346:
+                    return isIdentificationContentValid( childNames );
+                case 347:
+                    return childNames.empty();
+                case 348:
+                    return isMidiInstrumentContentValid( childNames );
+                case 349:
+                    return childNames.empty();
+                case 350:
+                    return isNameDisplayContentValid( childNames );
+                case 351:
+                    return childNames.empty();
+                case 352:
+                    return isNameDisplayContentValid( childNames );
+                case 353:
+                    return isScoreInstrumentContentValid( childNames );
+                case 354:
+                    return isCreditContentValid( childNames );
+                case 355:
+                    return isDefaultsContentValid( childNames );
+                case 356:
+                    return isIdentificationContentValid( childNames );
+                case 357:
+                    return childNames.empty();
+                case 358:
+                    return childNames.empty();
+                case 359:
+                    return isScorePartwisePartContentValid( childNames );
+                case 360:
+                    return isPartListContentValid( childNames );
+                case 361:
+                    return isWorkContentValid( childNames );
+                case 362:
+                    return isScorePartwisePartMeasureContentValid( childNames );
+                case 363:
+                    return isAttributesContentValid( childNames );
+                case 364:
+                    return isBackupContentValid( childNames );
+                case 365:
+                    return isBarlineContentValid( childNames );
+                case 366:
+                    return childNames.empty();
+                case 367:
+                    return isDirectionContentValid( childNames );
+                case 368:
+                    return isFiguredBassContentValid( childNames );
+                case 369:
+                    return isForwardContentValid( childNames );
+                case 370:
+                    return isGroupingContentValid( childNames );
+                case 371:
+                    return isHarmonyContentValid( childNames );
+                case 372:
+                    return childNames.empty();
+                case 373:
+                    return isNoteContentValid( childNames );
+                case 374:
+                    return isPrintContentValid( childNames );
+                case 375:
+                    return isSoundContentValid( childNames );
+                case 376:
+                    return isCreditContentValid( childNames );
+                case 377:
+                    return isDefaultsContentValid( childNames );
+                case 378:
+                    return isIdentificationContentValid( childNames );
+                case 379:
+                    return isScoreTimewiseMeasureContentValid( childNames );
+                case 380:
+                    return childNames.empty();
+                case 381:
+                    return childNames.empty();
+                case 382:
+                    return isPartListContentValid( childNames );
+                case 383:
+                    return isWorkContentValid( childNames );
+                case 384:
+                    return isScoreTimewiseMeasurePartContentValid( childNames );
+                case 385:
+                    return isAttributesContentValid( childNames );
+                case 386:
+                    return isBackupContentValid( childNames );
+                case 387:
+                    return isBarlineContentValid( childNames );
+                case 388:
+                    return childNames.empty();
+                case 389:
+                    return isDirectionContentValid( childNames );
+                case 390:
+                    return isFiguredBassContentValid( childNames );
+                case 391:
+                    return isForwardContentValid( childNames );
+                case 392:
+                    return isGroupingContentValid( childNames );
+                case 393:
+                    return isHarmonyContentValid( childNames );
+                case 394:
+                    return childNames.empty();
+                case 395:
+                    return isNoteContentValid( childNames );
+                case 396:
+                    return isPrintContentValid( childNames );
+                case 397:
+                    return isSoundContentValid( childNames );
+                case 398:
+                    return childNames.empty();
+                case 399:
+                    return childNames.empty();
+                case 400:
+                    return childNames.empty();
+                case 401:
+                    return isMidiInstrumentContentValid( childNames );
+                case 402:
+                    return childNames.empty();
+                case 403:
+                    return isPlayContentValid( childNames );
+                case 404:
+                    return childNames.empty();
+                case 405:
+                    return childNames.empty();
+                case 406:
+                    return childNames.empty();
+                case 407:
+                    return isStaffTuningContentValid( childNames );
+                case 408:
+                    return childNames.empty();
+                case 409:
+                    return childNames.empty();
+                case 410:
+                    return childNames.empty();
+                case 411:
+                    return childNames.empty();
+                case 412:
+                    return childNames.empty();
+                case 413:
+                    return childNames.empty();
+                case 414:
+                    return childNames.empty();
+                case 415:
+                    return childNames.empty();
+                case 416:
+                    return childNames.empty();
+                case 417:
+                    return childNames.empty();
+                case 418:
+                    return isSystemDividersContentValid( childNames );
+                case 419:
+                    return isSystemMarginsContentValid( childNames );
+                case 420:
+                    return childNames.empty();
+                case 421:
+                    return childNames.empty();
+                case 422:
+                    return childNames.empty();
+                case 423:
+                    return isArrowContentValid( childNames );
+                case 424:
+                    return isBendContentValid( childNames );
+                case 425:
+                    return childNames.empty();
+                case 426:
+                    return childNames.empty();
+                case 427:
+                    return childNames.empty();
+                case 428:
+                    return childNames.empty();
+                case 429:
+                    return childNames.empty();
+                case 430:
+                    return childNames.empty();
+                case 431:
+                    return childNames.empty();
+                case 432:
+                    return isHarmonicContentValid( childNames );
+                case 433:
+                    return isHoleContentValid( childNames );
+                case 434:
+                    return childNames.empty();
+                case 435:
+                    return childNames.empty();
+                case 436:
+                    return childNames.empty();
+                case 437:
+                    return childNames.empty();
+                case 438:
+                    return childNames.empty();
+                case 439:
+                    return childNames.empty();
+                case 440:
+                    return childNames.empty();
+                case 441:
+                    return childNames.empty();
+                case 442:
+                    return childNames.empty();
+                case 443:
+                    return childNames.empty();
+                case 444:
+                    return childNames.empty();
+                case 445:
+                    return childNames.empty();
+                case 446:
+                    return childNames.empty();
+                case 447:
+                    return isInterchangeableContentValid( childNames );
+                case 448:
+                    return childNames.empty();
+                case 449:
+                    return childNames.empty();
+                case 450:
+                    return childNames.empty();
+                case 451:
+                    return childNames.empty();
+                case 452:
+                    return childNames.empty();
+                case 453:
+                    return childNames.empty();
+                case 454:
+                    return childNames.empty();
+                case 455:
+                    return childNames.empty();
+                case 456:
+                    return childNames.empty();
+                case 457:
+                    return isTupletPortionContentValid( childNames );
+                case 458:
+                    return isTupletPortionContentValid( childNames );
+                case 459:
+                    return childNames.empty();
+                case 460:
+                    return childNames.empty();
+                case 461:
+                    return childNames.empty();
+                case 462:
+                    return childNames.empty();
+                case 463:
+                    return childNames.empty();
+                case 464:
+                    return childNames.empty();
+                case 465:
+                    return childNames.empty();
+                case 466:
+                    return childNames.empty();
+                case 467:
+                    return childNames.empty();
+                case 468:
+                    return childNames.empty();
+                default:
+                    return true;
+            }
+        }
+
+        bool isAccordContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "tuning-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "tuning-alter" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tuning-octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "tuning-octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3;
+        }
+
+        bool isAccordionRegistrationContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accordion-high" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "accordion-low" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "accordion-middle" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accordion-low" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "accordion-middle" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accordion-low" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3;
+        }
+
+        bool isAppearanceContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "distance" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "line-width" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "note-size" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-appearance" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "distance" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "line-width" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "note-size" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-appearance" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "distance" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "note-size" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-appearance" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "distance" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "other-appearance" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "other-appearance" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isArrowContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "arrow-direction" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "circular-arrow" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "arrow-style" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3;
+        }
+
+        bool isArticulationsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "accent" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "breath-mark" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "caesura" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "detached-legato" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "doit" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "falloff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-articulation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "plop" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "scoop" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "spiccato" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staccatissimo" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staccato" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "stress" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "strong-accent" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tenuto" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "unstress" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16;
+        }
+
+        bool isAttributesContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "divisions" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "key" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "divisions" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "key" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "divisions" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "key" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "key" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "key" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staves" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "time" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "part-symbol" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "instruments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "clef" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "staff-details" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "transpose" )
+                        {
+                            state = 11;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "directive" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "measure-style" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13;
+        }
+
+        bool isBackupContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "duration" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "footnote" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3;
+        }
+
+        bool isBarlineContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "bar-style" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "coda" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "ending" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "repeat" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10;
+        }
+
+        bool isBassContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "bass-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "bass-alter" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isBeatRepeatContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "slash-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "slash-dot" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "slash-dot" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2;
+        }
+
+        bool isBendContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "bend-alter" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "pre-bend" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "release" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "with-bar" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "with-bar" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "with-bar" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isClefContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "sign" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "clef-octave-change" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "line" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "clef-octave-change" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3;
+        }
+
+        bool isCreditContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "bookmark" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "credit-image" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "credit-type" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "bookmark" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "credit-image" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "credit-type" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "bookmark" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "credit-image" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "bookmark" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "credit-image" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "bookmark" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "bookmark" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "bookmark" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "bookmark" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "credit-words" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 4 || state == 5 || state == 8;
+        }
+
+        bool isDefaultsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "appearance" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "page-layout" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "scaling" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "system-layout" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "appearance" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "page-layout" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "system-layout" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "appearance" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "system-layout" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "appearance" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "appearance" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "music-font" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "word-font" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "lyric-font" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "lyric-language" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9;
+        }
+
+        bool isDegreeContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "degree-value" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "degree-alter" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "degree-type" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3;
+        }
+
+        bool isDirectionContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "direction-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "direction-type" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "direction-type" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "sound" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8;
+        }
+
+        bool isDirectionTypeContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accordion-registration" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "bracket" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "coda" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "damp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "damp-all" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "dashes" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "eyeglasses" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "harp-pedals" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "image" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "metronome" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "octave-shift" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "other-direction" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "pedal" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "percussion" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "principal-voice" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rehearsal" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "scordatura" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "segno" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "string-mute" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "wedge" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "words" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "rehearsal" )
+                        {
+                            state = 23;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "segno" )
+                        {
+                            state = 24;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "words" )
+                        {
+                            state = 25;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "coda" )
+                        {
+                            state = 26;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "dynamics" )
+                        {
+                            state = 27;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 21:
+                        if( name == "percussion" )
+                        {
+                            state = 28;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 23:
+                        if( name == "rehearsal" )
+                        {
+                            state = 23;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 24:
+                        if( name == "segno" )
+                        {
+                            state = 24;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 25:
+                        if( name == "words" )
+                        {
+                            state = 25;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 26:
+                        if( name == "coda" )
+                        {
+                            state = 26;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 27:
+                        if( name == "dynamics" )
+                        {
+                            state = 27;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 28:
+                        if( name == "percussion" )
+                        {
+                            state = 28;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16 || state == 17 || state == 18 || state == 19 || state == 20 || state == 21 || state == 22 || state == 23 || state == 24 || state == 25 || state == 26 || state == 27 || state == 28;
+        }
+
+        bool isDynamicsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 17:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 18:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 19:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 20:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 21:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 22:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 23:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 24:
+                        if( name == "f" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "ff" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "fff" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "ffff" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "fffff" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "ffffff" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "fp" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "fz" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "mf" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "mp" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "other-dynamics" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "p" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pp" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "ppp" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "pppp" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "ppppp" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pppppp" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "rf" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "rfz" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "sf" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "sffz" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "sfp" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "sfpp" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "sfz" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16 || state == 17 || state == 18 || state == 19 || state == 20 || state == 21 || state == 22 || state == 23 || state == 24;
+        }
+
+        bool isEncodingContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "encoder" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "encoding-date" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding-description" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "software" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "supports" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5;
+        }
+
+        bool isFigureContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "extend" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figure-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "prefix" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "suffix" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "extend" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figure-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "suffix" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "extend" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "suffix" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "extend" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isFiguredBassContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "figure" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "duration" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "figure" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "duration" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "figure" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "footnote" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "level" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5;
+        }
+
+        bool isForwardContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "duration" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "footnote" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "level" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "staff" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "staff" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5;
+        }
+
+        bool isFrameContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "frame-strings" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "frame-frets" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "first-fret" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "frame-note" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "frame-note" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "frame-note" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "frame-note" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 4 || state == 5;
+        }
+
+        bool isFrameNoteContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "string" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "fret" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "barre" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "barre" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4;
+        }
+
+        bool isGroupingContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "feature" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "feature" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1;
+        }
+
+        bool isHarmonicContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "artificial" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "base-pitch" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "natural" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "sounding-pitch" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "touching-pitch" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "base-pitch" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "sounding-pitch" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "touching-pitch" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "base-pitch" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "sounding-pitch" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "touching-pitch" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5;
+        }
+
+        bool isHarmonyContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "function" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "kind" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "kind" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "degree" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "inversion" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "kind" )
+                        {
+                            state = 14;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "kind" )
+                        {
+                            state = 14;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "degree" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "degree" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "degree" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "bass" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "degree" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "inversion" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "bass" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "degree" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "degree" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 17:
+                        if( name == "degree" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "frame" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "function" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "root" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16 || state == 17;
+        }
+
+        bool isHarpPedalsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "pedal-tuning" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "pedal-tuning" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "pedal-tuning" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isHoleContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "hole-closed" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "hole-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "hole-closed" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "hole-shape" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3;
+        }
+
+        bool isIdentificationContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "creator" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "rights" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "source" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "creator" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "encoding" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "rights" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "source" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "encoding" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "rights" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "source" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "source" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "miscellaneous" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "relation" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6;
+        }
+
+        bool isInterchangeableContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "beats" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "time-relation" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "beats" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "beat-type" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "beats" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "beat-type" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "beats" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3 || state == 5;
+        }
+
+        bool isKeyContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "cancel" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "fifths" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "key-step" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "fifths" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mode" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "key-alter" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "key-accidental" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "key-step" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "key-octave" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "key-step" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 2 || state == 4 || state == 5 || state == 6 || state == 7;
+        }
+
+        bool isLyricContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "extend" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "humming" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "laughing" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "syllabic" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "text" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "text" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "elision" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "extend" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "text" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "syllabic" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "text" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "elision" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "extend" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "text" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "end-line" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "end-paragraph" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "footnote" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "level" )
+                        {
+                            state = 12;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "text" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4 || state == 5 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12;
+        }
+
+        bool isMeasureLayoutContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "measure-distance" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1;
+        }
+
+        bool isMeasureStyleContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "beat-repeat" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "measure-repeat" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "multiple-rest" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "slash" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isMetronomeContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "beat-unit" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "metronome-note" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "beat-unit" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "beat-unit-dot" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "per-minute" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "metronome-note" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "metronome-relation" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "beat-unit" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "beat-unit-dot" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "per-minute" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "beat-unit-dot" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "metronome-note" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "metronome-relation" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "metronome-note" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "beat-unit-dot" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "metronome-note" )
+                        {
+                            state = 10;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "metronome-note" )
+                        {
+                            state = 10;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 4 || state == 5 || state == 6 || state == 8 || state == 9 || state == 10;
+        }
+
+        bool isMetronomeNoteContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "metronome-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "metronome-beam" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "metronome-dot" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "metronome-tuplet" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "metronome-beam" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "metronome-dot" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "metronome-tuplet" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "metronome-beam" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "metronome-tuplet" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isMidiInstrumentContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-bank" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "midi-channel" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "midi-name" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "midi-program" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "midi-unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-bank" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "midi-name" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "midi-program" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "midi-unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-bank" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "midi-program" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "midi-unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-program" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "midi-unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "volume" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "pan" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "elevation" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8;
+        }
+
+        bool isMiscellaneousContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "miscellaneous-field" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "miscellaneous-field" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1;
+        }
+
+        bool isNameDisplayContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accidental-text" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accidental-text" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accidental-text" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2;
+        }
+
+        bool isNotationsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "arpeggiate" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "articulations" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "dynamics" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "fermata" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "glissando" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "non-arpeggiate" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "ornaments" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "other-notation" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "slide" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "slur" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "technical" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "tied" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16;
+        }
+
+        bool isNoteContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "chord" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "cue" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "grace" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "pitch" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "chord" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pitch" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "chord" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "pitch" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "pitch" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "duration" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "duration" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "duration" )
+                        {
+                            state = 15;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "pitch" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "pitch" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "rest" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "unpitched" )
+                        {
+                            state = 13;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "duration" )
+                        {
+                            state = 33;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "duration" )
+                        {
+                            state = 33;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "duration" )
+                        {
+                            state = 33;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 34;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 35;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 17:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 18:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 19:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 20:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 21:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 22:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 23:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 24:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 25:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 26:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 27:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 28:
+                        if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 29:
+                        if( name == "beam" )
+                        {
+                            state = 36;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 30:
+                        if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 31:
+                        if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 33:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 34:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "tie" )
+                        {
+                            state = 37;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 35:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 36:
+                        if( name == "beam" )
+                        {
+                            state = 38;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 37:
+                        if( name == "accidental" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "beam" )
+                        {
+                            state = 29;
+                        }
+                        else if( name == "dot" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "footnote" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "instrument" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "notehead" )
+                        {
+                            state = 26;
+                        }
+                        else if( name == "notehead-text" )
+                        {
+                            state = 27;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else if( name == "staff" )
+                        {
+                            state = 28;
+                        }
+                        else if( name == "stem" )
+                        {
+                            state = 25;
+                        }
+                        else if( name == "time-modification" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "type" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "voice" )
+                        {
+                            state = 20;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 38:
+                        if( name == "beam" )
+                        {
+                            state = 39;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 39:
+                        if( name == "beam" )
+                        {
+                            state = 40;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 40:
+                        if( name == "beam" )
+                        {
+                            state = 41;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 41:
+                        if( name == "beam" )
+                        {
+                            state = 42;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 42:
+                        if( name == "beam" )
+                        {
+                            state = 43;
+                        }
+                        else if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 43:
+                        if( name == "lyric" )
+                        {
+                            state = 31;
+                        }
+                        else if( name == "notations" )
+                        {
+                            state = 30;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 32;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 8 || state == 9 || state == 10 || state == 15 || state == 16 || state == 17 || state == 18 || state == 19 || state == 20 || state == 21 || state == 22 || state == 23 || state == 24 || state == 25 || state == 26 || state == 27 || state == 28 || state == 29 || state == 30 || state == 31 || state == 32 || state == 33 || state == 34 || state == 35 || state == 36 || state == 37 || state == 38 || state == 39 || state == 40 || state == 41 || state == 42 || state == 43;
+        }
+
+        bool isNoteheadTextContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accidental-text" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accidental-text" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accidental-text" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "accidental-text" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "accidental-text" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "display-text" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isOrnamentsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "accidental-mark" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "delayed-inverted-turn" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "delayed-turn" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "inverted-mordent" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "inverted-turn" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "mordent" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "other-ornament" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "schleifer" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "shake" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "tremolo" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "trill-mark" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "turn" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "vertical-turn" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "wavy-line" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14;
+        }
+
+        bool isPageLayoutContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "page-height" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "page-margins" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "page-width" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "page-margins" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "page-margins" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isPageMarginsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "left-margin" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "right-margin" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "top-margin" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "bottom-margin" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 4;
+        }
+
+        bool isPartGroupContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-abbreviation" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "group-abbreviation-display" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-name" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "group-name-display" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "group-symbol" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-abbreviation" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "group-abbreviation-display" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-name-display" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "group-symbol" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-abbreviation" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "group-abbreviation-display" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-symbol" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-abbreviation-display" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-symbol" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-symbol" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-barline" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "group-time" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "footnote" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "level" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9;
+        }
+
+        bool isPartListContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "part-group" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "score-part" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "part-group" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "score-part" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "part-group" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "score-part" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "part-group" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "score-part" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "part-group" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "score-part" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4;
+        }
+
+        bool isPedalTuningContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "pedal-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "pedal-alter" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2;
+        }
+
+        bool isPercussionContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "beater" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "effect" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "glass" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "membrane" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "metal" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-percussion" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "pitched" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "stick" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "stick-location" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "timpani" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "wood" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11;
+        }
+
+        bool isPitchContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "alter" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3;
+        }
+
+        bool isPlayContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "ipa" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "mute" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-play" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "semi-pitched" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "ipa" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "mute" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-play" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "semi-pitched" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "ipa" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "mute" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-play" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "semi-pitched" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "ipa" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "mute" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-play" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "semi-pitched" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "ipa" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "mute" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "other-play" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "semi-pitched" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isPrintContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "measure-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-numbering" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "page-layout" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "system-layout" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "measure-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-numbering" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "system-layout" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "measure-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-numbering" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "measure-layout" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "measure-numbering" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "staff-layout" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "measure-numbering" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "part-abbreviation-display" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7;
+        }
+
+        bool isRestContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "display-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "display-octave" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 2;
+        }
+
+        bool isRootContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "root-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "root-alter" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isScalingContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "millimeters" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "tenths" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2;
+        }
+
+        bool isScordaturaContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "accord" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "accord" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "accord" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isScoreInstrumentContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "instrument-name" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "ensemble" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "instrument-abbreviation" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "instrument-sound" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "solo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "virtual-instrument" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "ensemble" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "instrument-sound" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "solo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "virtual-instrument" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "ensemble" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "solo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "virtual-instrument" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "virtual-instrument" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "virtual-instrument" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6;
+        }
+
+        bool isScorePartContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "identification" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "part-name" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "part-name" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "group" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "part-abbreviation" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-name-display" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "group" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "part-abbreviation" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "group" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "part-abbreviation-display" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "group" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "group" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "score-instrument" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "midi-device" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9;
+        }
+
+        bool isScorePartwiseContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "work" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "part" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "part" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "part" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 8 || state == 9;
+        }
+
+        bool isScorePartwisePartContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "measure" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "measure" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "measure" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isScorePartwisePartMeasureContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13;
+        }
+
+        bool isScoreTimewiseContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "work" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-number" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "movement-title" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "identification" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "defaults" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "credit" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "part-list" )
+                        {
+                            state = 7;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "measure" )
+                        {
+                            state = 8;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "measure" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "measure" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 8 || state == 9;
+        }
+
+        bool isScoreTimewiseMeasureContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "part" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "part" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "part" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 1 || state == 2;
+        }
+
+        bool isScoreTimewiseMeasurePartContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "attributes" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "backup" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "barline" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "bookmark" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "direction" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "figured-bass" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "forward" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "grouping" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "harmony" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "link" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "note" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "print" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "sound" )
+                        {
+                            state = 9;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13;
+        }
+
+        bool isSlashContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "slash-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "slash-dot" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "slash-dot" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2;
+        }
+
+        bool isSoundContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "midi-device" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "midi-device" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "midi-device" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "midi-device" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "midi-instrument" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "offset" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "play" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isStaffDetailsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "capo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff-lines" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "staff-size" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "staff-tuning" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "staff-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "capo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff-lines" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "staff-size" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "staff-tuning" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "capo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff-size" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "staff-tuning" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "capo" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "staff-size" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "staff-tuning" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "staff-size" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5;
+        }
+
+        bool isStaffLayoutContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "staff-distance" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1;
+        }
+
+        bool isStaffTuningContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "tuning-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "tuning-alter" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "tuning-octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "tuning-octave" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 3;
+        }
+
+        bool isStickContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "stick-type" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "stick-material" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2;
+        }
+
+        bool isSystemDividersContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "left-divider" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "right-divider" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2;
+        }
+
+        bool isSystemLayoutContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "system-distance" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "system-dividers" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "system-margins" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "top-system-distance" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "system-distance" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "system-dividers" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "top-system-distance" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "system-dividers" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "top-system-distance" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "system-dividers" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4;
+        }
+
+        bool isSystemMarginsContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "left-margin" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "right-margin" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2;
+        }
+
+        bool isTechnicalContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 5:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 7:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 8:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 9:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 10:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 11:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 12:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 13:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 14:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 15:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 16:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 17:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 18:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 19:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 20:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 21:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 22:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 23:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 24:
+                        if( name == "arrow" )
+                        {
+                            state = 22;
+                        }
+                        else if( name == "bend" )
+                        {
+                            state = 16;
+                        }
+                        else if( name == "double-tongue" )
+                        {
+                            state = 8;
+                        }
+                        else if( name == "down-bow" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "fingering" )
+                        {
+                            state = 6;
+                        }
+                        else if( name == "fingernails" )
+                        {
+                            state = 20;
+                        }
+                        else if( name == "fret" )
+                        {
+                            state = 12;
+                        }
+                        else if( name == "hammer-on" )
+                        {
+                            state = 14;
+                        }
+                        else if( name == "handbell" )
+                        {
+                            state = 23;
+                        }
+                        else if( name == "harmonic" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "heel" )
+                        {
+                            state = 18;
+                        }
+                        else if( name == "hole" )
+                        {
+                            state = 21;
+                        }
+                        else if( name == "open-string" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "other-technical" )
+                        {
+                            state = 24;
+                        }
+                        else if( name == "pluck" )
+                        {
+                            state = 7;
+                        }
+                        else if( name == "pull-off" )
+                        {
+                            state = 15;
+                        }
+                        else if( name == "snap-pizzicato" )
+                        {
+                            state = 11;
+                        }
+                        else if( name == "stopped" )
+                        {
+                            state = 10;
+                        }
+                        else if( name == "string" )
+                        {
+                            state = 13;
+                        }
+                        else if( name == "tap" )
+                        {
+                            state = 17;
+                        }
+                        else if( name == "thumb-position" )
+                        {
+                            state = 5;
+                        }
+                        else if( name == "toe" )
+                        {
+                            state = 19;
+                        }
+                        else if( name == "triple-tongue" )
+                        {
+                            state = 9;
+                        }
+                        else if( name == "up-bow" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3 || state == 4 || state == 5 || state == 6 || state == 7 || state == 8 || state == 9 || state == 10 || state == 11 || state == 12 || state == 13 || state == 14 || state == 15 || state == 16 || state == 17 || state == 18 || state == 19 || state == 20 || state == 21 || state == 22 || state == 23 || state == 24;
+        }
+
+        bool isTimeContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "beats" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "senza-misura" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "beat-type" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "beats" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "interchangeable" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "beat-type" )
+                        {
+                            state = 6;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 6:
+                        if( name == "beats" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "interchangeable" )
+                        {
+                            state = 5;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 5 || state == 6;
+        }
+
+        bool isTimeModificationContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "actual-notes" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "normal-notes" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "normal-type" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "normal-dot" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 4:
+                        if( name == "normal-dot" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4;
+        }
+
+        bool isTransposeContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "chromatic" )
+                        {
+                            state = 2;
+                        }
+                        else if( name == "diatonic" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "chromatic" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "double" )
+                        {
+                            state = 4;
+                        }
+                        else if( name == "octave-change" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "double" )
+                        {
+                            state = 4;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 2 || state == 3 || state == 4;
+        }
+
+        bool isTupletContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "tuplet-actual" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "tuplet-normal" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "tuplet-normal" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2;
+        }
+
+        bool isTupletPortionContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "tuplet-dot" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet-number" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "tuplet-type" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "tuplet-dot" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "tuplet-type" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "tuplet-dot" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 3:
+                        if( name == "tuplet-dot" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3;
+        }
+
+        bool isUnpitchedContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "display-step" )
+                        {
+                            state = 1;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "display-octave" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 2;
+        }
+
+        bool isVirtualInstrumentContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "virtual-library" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "virtual-name" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "virtual-name" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2;
+        }
+
+        bool isWorkContentValid( const std::vector<std::string>& childNames )
+        {
+            int state = 0;
+            for( const auto& name : childNames )
+            {
+                switch( state )
+                {
+                    case 0:
+                        if( name == "opus" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "work-number" )
+                        {
+                            state = 1;
+                        }
+                        else if( name == "work-title" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 1:
+                        if( name == "opus" )
+                        {
+                            state = 3;
+                        }
+                        else if( name == "work-title" )
+                        {
+                            state = 2;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    case 2:
+                        if( name == "opus" )
+                        {
+                            state = 3;
+                        }
+                        else
+                        {
+                            return false;
+                        }
+                        break;
+                    default:
+                        return false;
+                }
+            }
+            return state == 0 || state == 1 || state == 2 || state == 3;
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// MusicXML Class Library
+// Copyright (c) by Matthew James Briggs
+// Distributed under the MIT License
+
+#pragma once
+
+#include <string>
+#include <vector>
+
+namespace mx
+{
+    namespace core
+    {
+        /// Returns the name of the complex type of an element that is declared by the complex type
+        /// named parentType, or by the schema if parentType is empty, e.g. "Pitch" for the pitch
+        /// element of "Note". Returns an empty string if the element is unknown or has a simple type.
+        std::string getElementType( const std::string& parentType, const std::string& elementName );
+
+        /// Returns true if the names of an element's children, in document order, are allowed by
+        /// the content model of its complex type. The element is found as in getElementType, and
+        /// elements that are unknown are always valid.
+        bool isContentValid( const std::string& parentType, const std::string& elementName, const std::vector<std::string>& childNames );
+
+        /// Each function returns true if the names of an element's children, in document order,
+        /// are allowed by the content model of its complex type.
+        bool isAccordContentValid( const std::vector<std::string>& childNames );
+        bool isAccordionRegistrationContentValid( const std::vector<std::string>& childNames );
+        bool isAppearanceContentValid( const std::vector<std::string>& childNames );
+        bool isArrowContentValid( const std::vector<std::string>& childNames );
+        bool isArticulationsContentValid( const std::vector<std::string>& childNames );
+        bool isAttributesContentValid( const std::vector<std::string>& childNames );
+        bool isBackupContentValid( const std::vector<std::string>& childNames );
+        bool isBarlineContentValid( const std::vector<std::string>& childNames );
+        bool isBassContentValid( const std::vector<std::string>& childNames );
+        bool isBeatRepeatContentValid( const std::vector<std::string>& childNames );
+        bool isBendContentValid( const std::vector<std::string>& childNames );
+        bool isClefContentValid( const std::vector<std::string>& childNames );
+        bool isCreditContentValid( const std::vector<std::string>& childNames );
+        bool isDefaultsContentValid( const std::vector<std::string>& childNames );
+        bool isDegreeContentValid( const std::vector<std::string>& childNames );
+        bool isDirectionContentValid( const std::vector<std::string>& childNames );
+        bool isDirectionTypeContentValid( const std::vector<std::string>& childNames );
+        bool isDynamicsContentValid( const std::vector<std::string>& childNames );
+        bool isEncodingContentValid( const std::vector<std::string>& childNames );
+        bool isFigureContentValid( const std::vector<std::string>& childNames );
+        bool isFiguredBassContentValid( const std::vector<std::string>& childNames );
+        bool isForwardContentValid( const std::vector<std::string>& childNames );
+        bool isFrameContentValid( const std::vector<std::string>& childNames );
+        bool isFrameNoteContentValid( const std::vector<std::string>& childNames );
+        bool isGroupingContentValid( const std::vector<std::string>& childNames );
+        bool isHarmonicContentValid( const std::vector<std::string>& childNames );
+        bool isHarmonyContentValid( const std::vector<std::string>& childNames );
+        bool isHarpPedalsContentValid( const std::vector<std::string>& childNames );
+        bool isHoleContentValid( const std::vector<std::string>& childNames );
+        bool isIdentificationContentValid( const std::vector<std::string>& childNames );
+        bool isInterchangeableContentValid( const std::vector<std::string>& childNames );
+        bool isKeyContentValid( const std::vector<std::string>& childNames );
+        bool isLyricContentValid( const std::vector<std::string>& childNames );
+        bool isMeasureLayoutContentValid( const std::vector<std::string>& childNames );
+        bool isMeasureStyleContentValid( const std::vector<std::string>& childNames );
+        bool isMetronomeContentValid( const std::vector<std::string>& childNames );
+        bool isMetronomeNoteContentValid( const std::vector<std::string>& childNames );
+        bool isMidiInstrumentContentValid( const std::vector<std::string>& childNames );
+        bool isMiscellaneousContentValid( const std::vector<std::string>& childNames );
+        bool isNameDisplayContentValid( const std::vector<std::string>& childNames );
+        bool isNotationsContentValid( const std::vector<std::string>& childNames );
+        bool isNoteContentValid( const std::vector<std::string>& childNames );
+        bool isNoteheadTextContentValid( const std::vector<std::string>& childNames );
+        bool isOrnamentsContentValid( const std::vector<std::string>& childNames );
+        bool isPageLayoutContentValid( const std::vector<std::string>& childNames );
+        bool isPageMarginsContentValid( const std::vector<std::string>& childNames );
+        bool isPartGroupContentValid( const std::vector<std::string>& childNames );
+        bool isPartListContentValid( const std::vector<std::string>& childNames );
+        bool isPedalTuningContentValid( const std::vector<std::string>& childNames );
+        bool isPercussionContentValid( const std::vector<std::string>& childNames );
+        bool isPitchContentValid( const std::vector<std::string>& childNames );
+        bool isPlayContentValid( const std::vector<std::string>& childNames );
+        bool isPrintContentValid( const std::vector<std::string>& childNames );
+        bool isRestContentValid( const std::vector<std::string>& childNames );
+        bool isRootContentValid( const std::vector<std::string>& childNames );
+        bool isScalingContentValid( const std::vector<std::string>& childNames );
+        bool isScordaturaContentValid( const std::vector<std::string>& childNames );
+        bool isScoreInstrumentContentValid( const std::vector<std::string>& childNames );
+        bool isScorePartContentValid( const std::vector<std::string>& childNames );
+        bool isScorePartwiseContentValid( const std::vector<std::string>& childNames );
+        bool isScorePartwisePartContentValid( const std::vector<std::string>& childNames );
+        bool isScorePartwisePartMeasureContentValid( const std::vector<std::string>& childNames );
+        bool isScoreTimewiseContentValid( const std::vector<std::string>& childNames );
+        bool isScoreTimewiseMeasureContentValid( const std::vector<std::string>& childNames );
+        bool isScoreTimewiseMeasurePartContentValid( const std::vector<std::string>& childNames );
+        bool isSlashContentValid( const std::vector<std::string>& childNames );
+        bool isSoundContentValid( const std::vector<std::string>& childNames );
+        bool isStaffDetailsContentValid( const std::vector<std::string>& childNames );
+        bool isStaffLayoutContentValid( const std::vector<std::string>& childNames );
+        bool isStaffTuningContentValid( const std::vector<std::string>& childNames );
+        bool isStickContentValid( const std::vector<std::string>& childNames );
+        bool isSystemDividersContentValid( const std::vector<std::string>& childNames );
+        bool isSystemLayoutContentValid( const std::vector<std::string>& childNames );
+        bool isSystemMarginsContentValid( const std::vector<std::string>& childNames );
+        bool isTechnicalContentValid( const std::vector<std::string>& childNames );
+        bool isTimeContentValid( const std::vector<std::string>& childNames );
+        bool isTimeModificationContentValid( const std::vector<std::string>& childNames );
+        bool isTransposeContentValid( const std::vector<std::string>& childNames );
+        bool isTupletContentValid( const std::vector<std::string>& childNames );
+        bool isTupletPortionContentValid( const std::vector<std::string>& childNames );
+        bool isUnpitchedContentValid( const std::vector<std::string>& childNames );
+        bool isVirtualInstrumentContentValid( const std::vector<std::string>& childNames );
+        bool isWorkContentValid( const std::vector<std::string>& childNames );
+    }
+}
@@ -392,6 +392,39 @@ namespace mx
             return val;
         }
 
+        DecimalType roundDigits( int totalDigits, int fractionDigits, DecimalType value )
+        {
+            if( fractionDigits >= 0 )
+            {
+                const auto shift = std::pow( static_cast<DecimalType>( 10 ), static_cast<DecimalType>( fractionDigits ) );
+                value = std::round( value * shift ) / shift;
+            }
+
+            if( totalDigits <= 0 )
+            {
+                return value;
+            }
+
+            const auto magnitude = std::abs( value );
+
+            if( magnitude == 0 )
+            {
+                return value;
+            }
+
+            // leading zeros are not digits, e.g. 0.05 has one digit and -1 integer digits
+            const int integerDigits = static_cast<int>( std::floor( std::log10( magnitude ) ) ) + 1;
+
+            if( integerDigits > totalDigits )
+            {
+                const auto largest = std::pow( static_cast<DecimalType>( 10 ), static_cast<DecimalType>( totalDigits ) ) - 1;
+                return value < 0 ? -largest : largest;
+            }
+
+            const auto shift = std::pow( static_cast<DecimalType>( 10 ), static_cast<DecimalType>( totalDigits - integerDigits ) );
+            return std::round( value * shift ) / shift;
+        }
+
         DecimalType noOp( DecimalType value )
         {
             return value;
@@ -415,6 +448,7 @@ namespace mx
 #define MXMININ( minbound ) DecimalClamp( []( DecimalType value ){ return minInclusive( minbound, value ); } )
 #define MXMAXIN( maxbound ) DecimalClamp( []( DecimalType value ){ return maxInclusive( maxbound, value ); } )
 #define MX_NOOP DecimalClamp( noOp )
+#define MXDIGITS( total, fraction, clamp ) DecimalClamp( []( DecimalType value ){ return clamp( roundDigits( total, fraction, value ) ); } )
 
         DivisionsValue::DivisionsValue( DecimalType value )
         : DecimalRange{ MX_NOOP, MX_NOOP, value }
@@ -107,6 +107,11 @@ namespace mx
         /// clamps a DecimalType to a range.
         using DecimalClamp = std::function<DecimalType(DecimalType)>;
 
+        /// This function is an implementation detail. It rounds value to the
+        /// totalDigits and fractionDigits facets, a negative number means that
+        /// there is no facet. Like xs:decimal, leading zeros are not counted.
+        DecimalType roundDigits( int totalDigits, int fractionDigits, DecimalType value );
+
         /// A 'clamped', or 'ranged' Decimal where the value of min and max are
         /// governed by the DecimalClamp functions.
         class DecimalRange : public Decimal
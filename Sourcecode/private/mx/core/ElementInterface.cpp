@@ -3,10 +3,12 @@
 // Distributed under the MIT License
 
 #include "mx/core/ElementInterface.h"
+#include "mx/core/ContentModels.h"
 #include "ezxml/XElement.h"
 #include "ezxml/XElementIterator.h"
 #include "ezxml/XFactory.h"
 #include <sstream>
+#include <vector>
 
 namespace mx
 {
@@ -130,6 +132,43 @@ namespace mx
             
             const bool result = this->fromXElementImpl( message, xelement );
 
+            // check the order and number of the children against the content model
+
+            if( xelement.getType() == ezxml::XElementType::element )
+            {
+                std::vector<std::string> childNames;
+                const auto childEnd = xelement.end();
+
+                for( auto it = xelement.begin(); it != childEnd; ++it )
+                {
+                    childNames.push_back( it->getName() );
+                }
+
+                // an element name can have a different type in each complex type that declares
+                // it, so find the type of the parent from the root down
+
+                std::vector<std::string> ancestorNames;
+
+                for( auto parent = xelement.getParent(); !parent->getIsNull(); parent = parent->getParent() )
+                {
+                    ancestorNames.push_back( parent->getName() );
+                }
+
+                std::string parentType;
+                bool isKnown = true;
+
+                for( auto it = ancestorNames.crbegin(); isKnown && it != ancestorNames.crend(); ++it )
+                {
+                    parentType = getElementType( parentType, *it );
+                    isKnown = !parentType.empty();
+                }
+
+                if( isKnown && !isContentValid( parentType, xelement.getName(), childNames ) )
+                {
+                    message << xelement.getName() << ": the child elements are not allowed by the content model" << std::endl;
+                }
+            }
+
             // check for processing instructions
 
             if( xelement.getType() == ezxml::XElementType::element )
@@ -184,7 +184,15 @@ namespace ezxml
     {
         EZXML_CHECK_NULL_NODE;
         EZXML_CHECK_NODE_ELEMENT;
-        return XElementPtr{ new PugiElement{ myNode.parent(), myXDoc.lock() } };
+        const auto parent = myNode.parent();
+
+        if( parent.type() != pugi::node_element )
+        {
+            // the root of the XML tree, whose parent is the document
+            return XElementPtr{ new PugiElement{} };
+        }
+
+        return XElementPtr{ new PugiElement{ parent, myXDoc.lock() } };
     }
 
 
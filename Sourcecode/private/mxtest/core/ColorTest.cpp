@@ -7,6 +7,7 @@
 
 #include "cpul/cpulTestHarness.h"
 #include "mx/core/Color.h"
+#include <regex>
 #include <sstream>
 
 using namespace mx::core;
@@ -170,4 +171,27 @@ TEST( Color10, Strings )
     CHECK_EQUAL( expected, actual )
 }
 
+// Color is written by hand instead of being generated with a pattern check. The regex is the one
+// that the generator produces for the pattern of the color type, see custom_string_patterns_test
+// in CodeGen/src/generate/cpp/write_strings.rs. Color reads every value that matches it, ignores
+// every value that does not, and only writes values that match it.
+TEST( ColorPattern, Strings )
+{
+    const std::regex pattern{ R"regex(#[0-9A-F]{6}(?:[0-9A-F][0-9A-F])?)regex" };
+    for( const std::string value : { "#800080", "#40800080", "#000000", "#FFFFFFFF" } )
+    {
+        CHECK( std::regex_match( value, pattern ) )
+        Color x;
+        x.parse( value );
+        CHECK_EQUAL( value, toString( x ) )
+    }
+    for( const std::string value : { "", "#80008", "800080", "#80008G", "#abcdef", "#8000800" } )
+    {
+        CHECK( !std::regex_match( value, pattern ) )
+        Color x( 1, 2, 3 );
+        x.parse( value );
+        CHECK_EQUAL( "#010203", toString( x ) )
+    }
+}
+
 #endif
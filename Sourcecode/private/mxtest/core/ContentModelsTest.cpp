@@ -0,0 +1,81 @@
+// MusicXML Class Library
+// Copyright (c) by Matthew James Briggs
+// Distributed under the MIT License
+
+#include "mxtest/control/CompileControl.h"
+#ifdef MX_COMPILE_CORE_TESTS
+
+#include "cpul/cpulTestHarness.h"
+#include "mx/core/ContentModels.h"
+#include "mx/core/Document.h"
+#include "ezxml/XFactory.h"
+#include "ezxml/XDoc.h"
+
+#include <sstream>
+
+using namespace mx::core;
+
+TEST( Sequence, ContentModels )
+{
+    CHECK( isContentValid( "Note", "pitch", { "step", "octave" } ) )
+    CHECK( isContentValid( "Note", "pitch", { "step", "alter", "octave" } ) )
+    CHECK( !isContentValid( "Note", "pitch", { "alter", "step", "octave" } ) )
+    CHECK( !isContentValid( "Note", "pitch", { "step" } ) )
+}
+TEST( Occurs, ContentModels )
+{
+    CHECK( !isContentValid( "ScorePartwisePartMeasure", "figured-bass", {} ) )
+    CHECK( isContentValid( "ScorePartwisePartMeasure", "figured-bass", { "figure", "figure", "duration" } ) )
+}
+TEST( SimpleContent, ContentModels )
+{
+    CHECK( isContentValid( "Pitch", "step", {} ) )
+    CHECK( !isContentValid( "Pitch", "step", { "octave" } ) )
+}
+TEST( AnonymousTypes, ContentModels )
+{
+    CHECK( isContentValid( "", "score-partwise", { "part-list", "part" } ) )
+    CHECK( !isContentValid( "", "score-partwise", { "part" } ) )
+    // measure has a different type in score-partwise and score-timewise
+    CHECK( isContentValid( "ScorePartwisePart", "measure", { "attributes", "note" } ) )
+    CHECK( !isContentValid( "ScorePartwisePart", "measure", { "part", "part" } ) )
+    CHECK( isContentValid( "ScoreTimewise", "measure", { "part", "part" } ) )
+    CHECK( !isContentValid( "ScoreTimewise", "measure", { "attributes", "note" } ) )
+}
+TEST( ElementType, ContentModels )
+{
+    CHECK_EQUAL( "ScorePartwise", getElementType( "", "score-partwise" ) )
+    CHECK_EQUAL( "ScorePartwisePartMeasure", getElementType( "ScorePartwisePart", "measure" ) )
+    CHECK_EQUAL( "ScoreTimewiseMeasure", getElementType( "ScoreTimewise", "measure" ) )
+    CHECK_EQUAL( "Pitch", getElementType( "Note", "pitch" ) )
+    CHECK_EQUAL( "", getElementType( "Pitch", "step" ) )
+    CHECK_EQUAL( "", getElementType( "", "pitch" ) )
+}
+TEST( UnknownElement, ContentModels )
+{
+    CHECK( isContentValid( "", "invalid_element", { "step" } ) )
+    CHECK( isContentValid( "Note", "invalid_element", { "step" } ) )
+}
+TEST( OutOfOrderChild, ContentModels )
+{
+    const std::string xml =
+        "<score-partwise version=\"3.0\">"
+        "<part-list><score-part id=\"P1\"><part-name>P</part-name></score-part></part-list>"
+        "<part id=\"P1\"><measure number=\"1\"><note>"
+        "<pitch><octave>4</octave><step>C</step></pitch>"
+        "<duration>1</duration>"
+        "</note></measure></part>"
+        "</score-partwise>";
+    std::istringstream iss{ xml };
+    auto xdoc = ::ezxml::XFactory::makeXDoc();
+    xdoc->loadStream( iss );
+    auto doc = makeDocument();
+    std::stringstream messages;
+    doc->fromXDoc( messages, *xdoc );
+    const auto result = messages.str();
+    CHECK( result.find( "pitch: the child elements are not allowed by the content model" ) != std::string::npos )
+    CHECK( result.find( "note: the child elements" ) == std::string::npos )
+    CHECK( result.find( "measure: the child elements" ) == std::string::npos )
+}
+
+#endif
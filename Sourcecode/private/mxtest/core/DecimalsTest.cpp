@@ -960,4 +960,22 @@ TEST( TrillBeats06, Decimals )
     CHECK_DOUBLES_EQUAL( expected, actual, kThreshold )
 }
 
+// roundDigits
+TEST( RoundDigits01, Decimals )
+{
+    CHECK_DOUBLES_EQUAL( 0.5, roundDigits( 1, -1, 0.5 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( 0.05, roundDigits( 1, -1, 0.05 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( -0.5, roundDigits( 1, -1, -0.5 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( 0.13, roundDigits( 2, -1, 0.126 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( 0, roundDigits( 1, -1, 0 ), kThreshold )
+}
+
+TEST( RoundDigits02, Decimals )
+{
+    CHECK_DOUBLES_EQUAL( 12.3, roundDigits( 3, -1, 12.345 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( 12.35, roundDigits( -1, 2, 12.345 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( 99, roundDigits( 2, -1, 123.4 ), kThreshold )
+    CHECK_DOUBLES_EQUAL( -99, roundDigits( 2, 0, -123.4 ), kThreshold )
+}
+
 #endif
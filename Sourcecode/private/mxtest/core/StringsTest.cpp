@@ -18,7 +18,10 @@
 #include "mx/core/XsNMToken.h"
 #include "mx/core/XsString.h"
 #include "mx/core/XsToken.h"
+#include <regex>
 #include <sstream>
+#include <utility>
+#include <vector>
 
 using namespace mx::core;
 
@@ -470,4 +473,51 @@ TEST( XlinkHref01, Strings )
     CHECK_EQUAL( "vodjs", x.getValue() );
 }
 
+// EndingNumber and TimeOnly are written by hand instead of being generated with a pattern check.
+// The regexes are the ones that the generator produces for the patterns of the ending-number and
+// time-only types, see custom_string_patterns_test in CodeGen/src/generate/cpp/write_strings.rs.
+// Both classes read the numbers of every value that matches, and drop the characters and numbers
+// that do not, so what they write matches, except that a TimeOnly without numbers is empty.
+TEST( EndingNumberPattern, Strings )
+{
+    const std::regex pattern{ R"regex((?:[\x20]*)|(?:[1-9][0-9]*(?:, ?[1-9][0-9]*)*))regex" };
+    const std::vector<std::pair<std::string, std::string>> valid =
+    {
+        { "", "" }, { "  ", "" }, { "1", "1" }, { "1,2", "1,2" }, { "1, 2, 3", "1,2,3" }, { "10,2", "2,10" }
+    };
+    for( const auto& pair : valid )
+    {
+        CHECK( std::regex_match( pair.first, pattern ) )
+        CHECK_EQUAL( pair.second, toString( EndingNumber{ pair.first } ) )
+    }
+    for( const std::string value : { "0", "-1", "1,,2", "1 ,2", "a" } )
+    {
+        CHECK( !std::regex_match( value, pattern ) )
+        CHECK( std::regex_match( toString( EndingNumber{ value } ), pattern ) )
+    }
+}
+TEST( TimeOnlyPattern, Strings )
+{
+    const std::regex pattern{ R"regex([1-9][0-9]*(?:, ?[1-9][0-9]*)*)regex" };
+    const std::vector<std::pair<std::string, std::string>> valid =
+    {
+        { "1", "1" }, { "1,2", "1,2" }, { "1, 2, 3", "1,2,3" }, { "3,1,3", "1,3" }
+    };
+    for( const auto& pair : valid )
+    {
+        CHECK( std::regex_match( pair.first, pattern ) )
+        CHECK_EQUAL( pair.second, toString( TimeOnly{ pair.first } ) )
+    }
+    for( const std::string value : { "1,,2", "1 ,2", "-1,2" } )
+    {
+        CHECK( !std::regex_match( value, pattern ) )
+        CHECK( std::regex_match( toString( TimeOnly{ value } ), pattern ) )
+    }
+    for( const std::string value : { "", "0", "a" } )
+    {
+        CHECK( !std::regex_match( value, pattern ) )
+        CHECK_EQUAL( "", toString( TimeOnly{ value } ) )
+    }
+}
+
 #endif